@@ -6,8 +6,8 @@ set -e
 FEATURE_SETS=(
   # std
   "std"
-  "std approx bytemuck mint rand serde debug-glam-assert transform-types"
-  "std scalar-math approx bytemuck mint rand serde debug-glam-assert transform-types"
+  "std approx bytemuck half mint rand serde debug-glam-assert transform-types"
+  "std scalar-math approx bytemuck half mint rand serde debug-glam-assert transform-types"
   # no_std
   "libm"
   "libm scalar-math approx bytemuck mint rand serde debug-glam-assert transform-types"
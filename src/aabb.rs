@@ -0,0 +1,337 @@
+// Axis-aligned bounding boxes.
+//
+// Boxes are stored as `min` and `max` corners so that the common queries, union, intersection and
+// containment, are just component-wise `min`, `max` and comparisons on the underlying vector type.
+use crate::{Affine2, Affine3A, DAffine3, DMat4, DVec3, IVec3, Mat3, Mat4, Vec2, Vec3, Vec3A};
+
+macro_rules! impl_aabb_common {
+    ($t:ty, $vec:ident, $aabb:ident) => {
+        /// An axis-aligned bounding box stored as its `min` and `max` corners.
+        #[derive(Clone, Copy, PartialEq)]
+        pub struct $aabb {
+            /// The corner with the smallest coordinates.
+            pub min: $vec,
+            /// The corner with the largest coordinates.
+            pub max: $vec,
+        }
+
+        impl $aabb {
+            /// Creates a box from its `min` and `max` corners.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if any element of `min` is greater than `max` when `glam_assert` is
+            /// enabled.
+            #[inline(always)]
+            pub fn new(min: $vec, max: $vec) -> Self {
+                glam_assert!(min.cmple(max).all());
+                Self { min, max }
+            }
+
+            /// Creates the smallest box containing all of the given `points`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `points` is empty.
+            #[inline]
+            pub fn from_points(points: &[$vec]) -> Self {
+                let first = points[0];
+                points[1..].iter().fold(
+                    Self {
+                        min: first,
+                        max: first,
+                    },
+                    |aabb, &point| aabb.grow(point),
+                )
+            }
+
+            /// Returns the smallest box containing both `self` and `other`.
+            #[inline]
+            pub fn union(self, other: Self) -> Self {
+                Self {
+                    min: self.min.min(other.min),
+                    max: self.max.max(other.max),
+                }
+            }
+
+            /// Returns the overlapping region of `self` and `other`, or `None` if they do not
+            /// intersect.
+            ///
+            /// Boxes which only touch produce a box with zero size along at least one axis.
+            #[inline]
+            pub fn intersection(self, other: Self) -> Option<Self> {
+                let min = self.min.max(other.min);
+                let max = self.max.min(other.max);
+                if min.cmple(max).all() {
+                    Some(Self { min, max })
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the smallest box containing both `self` and `point`.
+            #[inline]
+            pub fn grow(self, point: $vec) -> Self {
+                Self {
+                    min: self.min.min(point),
+                    max: self.max.max(point),
+                }
+            }
+
+            /// Returns `self` with each face moved outwards by `amount`.
+            ///
+            /// A negative `amount` shrinks the box.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if a negative `amount` would make the box inside out when
+            /// `glam_assert` is enabled.
+            #[inline]
+            pub fn expand(self, amount: $t) -> Self {
+                let amount = $vec::splat(amount);
+                Self::new(self.min - amount, self.max + amount)
+            }
+
+            /// Returns `true` if `point` is inside `self` or on its boundary.
+            #[inline]
+            pub fn contains_point(self, point: $vec) -> bool {
+                point.cmpge(self.min).all() && point.cmple(self.max).all()
+            }
+
+            /// Returns `true` if `self` and `other` overlap or touch.
+            #[inline]
+            pub fn intersects(self, other: Self) -> bool {
+                self.min.cmple(other.max).all() && other.min.cmple(self.max).all()
+            }
+
+            /// Returns the point inside or on the boundary of `self` which is closest to
+            /// `point`.
+            #[inline]
+            pub fn closest_point(self, point: $vec) -> $vec {
+                point.clamp(self.min, self.max)
+            }
+
+            /// Returns the size of `self` along each axis.
+            #[inline]
+            pub fn size(self) -> $vec {
+                self.max - self.min
+            }
+        }
+
+        impl Default for $aabb {
+            #[inline(always)]
+            fn default() -> Self {
+                Self {
+                    min: $vec::ZERO,
+                    max: $vec::ZERO,
+                }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Debug for $aabb {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.debug_struct(stringify!($aabb))
+                    .field("min", &self.min)
+                    .field("max", &self.max)
+                    .finish()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Display for $aabb {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "[{}, {}]", self.min, self.max)
+            }
+        }
+    };
+}
+
+macro_rules! impl_aabb_float {
+    ($t:ty, $vec:ident, $aabb:ident) => {
+        impl $aabb {
+            /// Creates a box from its `center` and `half_extents`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if any element of `half_extents` is negative when `glam_assert` is
+            /// enabled.
+            #[inline]
+            pub fn from_center_half_extents(center: $vec, half_extents: $vec) -> Self {
+                Self::new(center - half_extents, center + half_extents)
+            }
+
+            /// Returns the center of `self`.
+            #[inline]
+            pub fn center(self) -> $vec {
+                (self.min + self.max) * 0.5
+            }
+
+            /// Returns half the size of `self` along each axis.
+            #[inline]
+            pub fn half_extents(self) -> $vec {
+                (self.max - self.min) * 0.5
+            }
+
+            /// Returns `true` if, and only if, all elements are finite.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                self.min.is_finite() && self.max.is_finite()
+            }
+
+            /// Returns true if the absolute difference of all elements between `self` and `other`
+            /// is less than or equal to `max_abs_diff`.
+            #[inline]
+            pub fn abs_diff_eq(self, other: Self, max_abs_diff: $t) -> bool {
+                self.min.abs_diff_eq(other.min, max_abs_diff)
+                    && self.max.abs_diff_eq(other.max, max_abs_diff)
+            }
+        }
+    };
+}
+
+macro_rules! impl_aabb3 {
+    ($t:ty, $vec3:ident, $aabb3:ident) => {
+        impl $aabb3 {
+            /// Returns the volume of `self`.
+            #[inline]
+            pub fn volume(self) -> $t {
+                let size = self.size();
+                size.x * size.y * size.z
+            }
+
+            /// Returns the total area of the faces of `self`.
+            #[inline]
+            pub fn surface_area(self) -> $t {
+                let size = self.size();
+                let half_area = size.x * size.y + size.y * size.z + size.z * size.x;
+                half_area + half_area
+            }
+        }
+    };
+}
+
+macro_rules! impl_aabb3_transform {
+    ($t:ty, $vec3:ident, $mat4:ident, $affine3:ident, $aabb3:ident, $transform_point:ident) => {
+        impl $aabb3 {
+            /// Returns the smallest box containing `self` after transformation by `mat`.
+            ///
+            /// Uses Arvo's method, transforming the center and accumulating the absolute values of
+            /// the matrix columns scaled by the half extents. This is cheaper than transforming
+            /// all eight corners and gives the same tight result.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if the 3rd row of `mat` is not `(0, 0, 0, 1)` when `glam_assert` is
+            /// enabled.
+            #[inline]
+            pub fn transform_mat4(self, mat: &$mat4) -> Self {
+                glam_assert!(mat.row(3) == $mat4::IDENTITY.row(3));
+                let center = mat.$transform_point(self.center());
+                let half_extents = self.half_extents();
+                let half_extents = $vec3::new(mat.x_axis.x, mat.x_axis.y, mat.x_axis.z).abs()
+                    * half_extents.x
+                    + $vec3::new(mat.y_axis.x, mat.y_axis.y, mat.y_axis.z).abs() * half_extents.y
+                    + $vec3::new(mat.z_axis.x, mat.z_axis.y, mat.z_axis.z).abs() * half_extents.z;
+                Self::from_center_half_extents(center, half_extents)
+            }
+
+            /// Returns the smallest box containing `self` after transformation by `transform`.
+            ///
+            /// See [`Self::transform_mat4()`] for details.
+            #[inline]
+            pub fn transform_affine3(self, transform: &$affine3) -> Self {
+                let center = transform.$transform_point(self.center());
+                let half_extents = self.half_extents();
+                let m = &transform.matrix3;
+                let half_extents = $vec3::from(m.x_axis).abs() * half_extents.x
+                    + $vec3::from(m.y_axis).abs() * half_extents.y
+                    + $vec3::from(m.z_axis).abs() * half_extents.z;
+                Self::from_center_half_extents(center, half_extents)
+            }
+        }
+    };
+}
+
+impl_aabb_common!(f32, Vec2, Aabb2);
+impl_aabb_float!(f32, Vec2, Aabb2);
+
+impl Aabb2 {
+    /// Returns the area of `self`.
+    #[inline]
+    pub fn area(self) -> f32 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    /// Returns the perimeter of `self`.
+    #[inline]
+    pub fn perimeter(self) -> f32 {
+        let size = self.size();
+        (size.x + size.y) * 2.0
+    }
+
+    /// Returns the smallest box containing `self` after transformation by `mat`.
+    ///
+    /// Uses Arvo's method, see [`Aabb3::transform_mat4()`] for details.
+    ///
+    /// This method assumes that `mat` contains a valid affine transform.
+    #[inline]
+    pub fn transform_mat3(self, mat: &Mat3) -> Self {
+        let center = mat.transform_point2(self.center());
+        let half_extents = self.half_extents();
+        let half_extents = Vec2::new(mat.x_axis.x, mat.x_axis.y).abs() * half_extents.x
+            + Vec2::new(mat.y_axis.x, mat.y_axis.y).abs() * half_extents.y;
+        Self::from_center_half_extents(center, half_extents)
+    }
+
+    /// Returns the smallest box containing `self` after transformation by `transform`.
+    ///
+    /// Uses Arvo's method, see [`Aabb3::transform_mat4()`] for details.
+    #[inline]
+    pub fn transform_affine2(self, transform: &Affine2) -> Self {
+        let center = transform.transform_point2(self.center());
+        let half_extents = self.half_extents();
+        let m = &transform.matrix2;
+        let half_extents = m.x_axis.abs() * half_extents.x + m.y_axis.abs() * half_extents.y;
+        Self::from_center_half_extents(center, half_extents)
+    }
+}
+
+impl_aabb_common!(f32, Vec3, Aabb3);
+impl_aabb_float!(f32, Vec3, Aabb3);
+impl_aabb3!(f32, Vec3, Aabb3);
+impl_aabb3_transform!(f32, Vec3, Mat4, Affine3A, Aabb3, transform_point3);
+
+impl_aabb_common!(f32, Vec3A, Aabb3A);
+impl_aabb_float!(f32, Vec3A, Aabb3A);
+impl_aabb3!(f32, Vec3A, Aabb3A);
+impl_aabb3_transform!(f32, Vec3A, Mat4, Affine3A, Aabb3A, transform_point3a);
+
+impl From<Aabb3> for Aabb3A {
+    #[inline(always)]
+    fn from(aabb: Aabb3) -> Self {
+        Self {
+            min: aabb.min.into(),
+            max: aabb.max.into(),
+        }
+    }
+}
+
+impl From<Aabb3A> for Aabb3 {
+    #[inline(always)]
+    fn from(aabb: Aabb3A) -> Self {
+        Self {
+            min: aabb.min.into(),
+            max: aabb.max.into(),
+        }
+    }
+}
+
+impl_aabb_common!(f64, DVec3, DAabb3);
+impl_aabb_float!(f64, DVec3, DAabb3);
+impl_aabb3!(f64, DVec3, DAabb3);
+impl_aabb3_transform!(f64, DVec3, DMat4, DAffine3, DAabb3, transform_point3);
+
+impl_aabb_common!(i32, IVec3, IAabb3);
+impl_aabb3!(i32, IVec3, IAabb3);
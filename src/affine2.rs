@@ -1,5 +1,5 @@
 use crate::core::storage::Columns3;
-use crate::{DMat2, DMat3, DVec2, Mat2, Mat3, Mat3A, Vec2, Vec3A};
+use crate::{DMat2, DMat3, DRot2, DVec2, Mat2, Mat3, Mat3A, Rot2, Vec2, Vec3A};
 use core::ops::{Add, Deref, DerefMut, Mul, Sub};
 
 #[cfg(not(feature = "std"))]
@@ -17,7 +17,7 @@ macro_rules! define_affine2_struct {
 }
 
 macro_rules! impl_affine2_methods {
-    ($t:ty, $mat2:ident, $mat3:ident, $vec2:ident, $affine2:ident, $matrix:ident, $column:ident) => {
+    ($t:ty, $mat2:ident, $mat3:ident, $vec2:ident, $rot2:ident, $affine2:ident, $matrix:ident, $column:ident) => {
         impl $affine2 {
             /// The degenerate zero transform.
             ///
@@ -137,6 +137,19 @@ macro_rules! impl_affine2_methods {
                 }
             }
 
+            /// Creates an affine transform from the given 2D `rotation`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+            #[inline(always)]
+            pub fn from_rot2(rotation: $rot2) -> Self {
+                Self {
+                    matrix2: $matrix::from_rot2(rotation),
+                    translation: $column::ZERO,
+                }
+            }
+
             /// Creates an affine transformation from the given 2D `translation`.
             #[inline(always)]
             pub fn from_translation(translation: $vec2) -> Self {
@@ -201,6 +214,21 @@ macro_rules! impl_affine2_methods {
                 }
             }
 
+            /// Creates an affine transform from the given 2D `rotation` and `translation`.
+            ///
+            /// Equivalent to `Affine2::from_translation(translation) * Affine2::from_rot2(rotation)`
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+            #[inline(always)]
+            pub fn from_rot2_translation(rotation: $rot2, translation: $vec2) -> Self {
+                Self {
+                    matrix2: $matrix::from_rot2(rotation),
+                    translation,
+                }
+            }
+
             /// The given `Mat3` must be an affine transform,
             #[inline]
             pub fn from_mat3(m: $mat3) -> Self {
@@ -272,6 +300,37 @@ macro_rules! impl_affine2_methods {
                     translation,
                 }
             }
+
+            /// Return the inverse of this transform, or `None` if the transform is not
+            /// invertible.
+            #[must_use]
+            #[inline]
+            pub fn try_inverse(&self) -> Option<Self> {
+                self.inverse_with_epsilon(0.0)
+            }
+
+            /// Return the inverse of this transform, or a zero transform if the transform is
+            /// not invertible.
+            #[must_use]
+            #[inline]
+            pub fn inverse_or_zero(&self) -> Self {
+                self.try_inverse().unwrap_or(Self::ZERO)
+            }
+
+            /// Return the inverse of this transform, or `None` if the absolute value of the
+            /// determinant of the 2x2 matrix is less than or equal to `epsilon`.
+            #[must_use]
+            #[inline]
+            pub fn inverse_with_epsilon(&self, epsilon: $t) -> Option<Self> {
+                let matrix2 = self.matrix2.inverse_with_epsilon(epsilon)?;
+                // transform negative translation by the 2x2 inverse:
+                let translation = -(matrix2 * self.translation);
+
+                Some(Self {
+                    matrix2,
+                    translation,
+                })
+            }
         }
     };
 }
@@ -425,7 +484,16 @@ type TranslateF32 = Vec2;
 type DerefTargetF32 = Columns3<crate::Vec2>;
 
 define_affine2_struct!(Affine2, TransformF32, TranslateF32);
-impl_affine2_methods!(f32, Mat2, Mat3, Vec2, Affine2, TransformF32, TranslateF32);
+impl_affine2_methods!(
+    f32,
+    Mat2,
+    Mat3,
+    Vec2,
+    Rot2,
+    Affine2,
+    TransformF32,
+    TranslateF32
+);
 impl_affine2_traits!(
     f32,
     Mat2,
@@ -476,6 +544,7 @@ impl_affine2_methods!(
     DMat2,
     DMat3,
     DVec2,
+    DRot2,
     DAffine2,
     TransformF64,
     TranslateF64
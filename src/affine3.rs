@@ -411,6 +411,37 @@ macro_rules! impl_affine3_methods {
                     translation,
                 }
             }
+
+            /// Return the inverse of this transform, or `None` if the transform is not
+            /// invertible.
+            #[must_use]
+            #[inline]
+            pub fn try_inverse(&self) -> Option<Self> {
+                self.inverse_with_epsilon(0.0)
+            }
+
+            /// Return the inverse of this transform, or a zero transform if the transform is
+            /// not invertible.
+            #[must_use]
+            #[inline]
+            pub fn inverse_or_zero(&self) -> Self {
+                self.try_inverse().unwrap_or(Self::ZERO)
+            }
+
+            /// Return the inverse of this transform, or `None` if the absolute value of the
+            /// determinant of the 3x3 matrix is less than or equal to `epsilon`.
+            #[must_use]
+            #[inline]
+            pub fn inverse_with_epsilon(&self, epsilon: $t) -> Option<Self> {
+                let matrix3 = self.matrix3.inverse_with_epsilon(epsilon)?;
+                // transform negative translation by the 3x3 inverse:
+                let translation = -(matrix3 * self.translation);
+
+                Some(Self {
+                    matrix3,
+                    translation,
+                })
+            }
         }
     };
 }
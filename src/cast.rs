@@ -1,4 +1,6 @@
 use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+use crate::{I16Vec2, I16Vec3, I16Vec4, U16Vec2, U16Vec3, U16Vec4, U8Vec2, U8Vec3, U8Vec4};
+use crate::{I64Vec2, I64Vec3, I64Vec4, U64Vec2, U64Vec3, U64Vec4};
 use crate::{IVec2, IVec3, IVec4};
 use crate::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 use crate::{UVec2, UVec3, UVec4};
@@ -165,3 +167,98 @@ pub union UVec2Cast {
     pub ux2: [u32; 2],
     pub v2: UVec2,
 }
+
+#[repr(C)]
+pub union I64Vec4Cast {
+    pub ix4: [i64; 4],
+    pub ix2x2: [[i64; 2]; 2],
+    pub v4: I64Vec4,
+}
+
+#[repr(C)]
+pub union I64Vec3Cast {
+    pub ix3: [i64; 3],
+    pub v3: I64Vec3,
+}
+
+#[repr(C)]
+pub union I64Vec2Cast {
+    pub ix2: [i64; 2],
+    pub v2: I64Vec2,
+}
+
+#[repr(C)]
+pub union U64Vec4Cast {
+    pub ux4: [u64; 4],
+    pub ux2x2: [[u64; 2]; 2],
+    pub v4: U64Vec4,
+}
+
+#[repr(C)]
+pub union U64Vec3Cast {
+    pub ux3: [u64; 3],
+    pub v3: U64Vec3,
+}
+
+#[repr(C)]
+pub union U64Vec2Cast {
+    pub ux2: [u64; 2],
+    pub v2: U64Vec2,
+}
+
+#[repr(C)]
+pub union I16Vec4Cast {
+    pub ix4: [i16; 4],
+    pub ix2x2: [[i16; 2]; 2],
+    pub v4: I16Vec4,
+}
+
+#[repr(C)]
+pub union I16Vec3Cast {
+    pub ix3: [i16; 3],
+    pub v3: I16Vec3,
+}
+
+#[repr(C)]
+pub union I16Vec2Cast {
+    pub ix2: [i16; 2],
+    pub v2: I16Vec2,
+}
+
+#[repr(C)]
+pub union U16Vec4Cast {
+    pub ux4: [u16; 4],
+    pub ux2x2: [[u16; 2]; 2],
+    pub v4: U16Vec4,
+}
+
+#[repr(C)]
+pub union U16Vec3Cast {
+    pub ux3: [u16; 3],
+    pub v3: U16Vec3,
+}
+
+#[repr(C)]
+pub union U16Vec2Cast {
+    pub ux2: [u16; 2],
+    pub v2: U16Vec2,
+}
+
+#[repr(C)]
+pub union U8Vec4Cast {
+    pub ux4: [u8; 4],
+    pub ux2x2: [[u8; 2]; 2],
+    pub v4: U8Vec4,
+}
+
+#[repr(C)]
+pub union U8Vec3Cast {
+    pub ux3: [u8; 3],
+    pub v3: U8Vec3,
+}
+
+#[repr(C)]
+pub union U8Vec2Cast {
+    pub ux2: [u8; 2],
+    pub v2: U8Vec2,
+}
@@ -67,9 +67,7 @@ impl<T: FloatEx> Quaternion<T> for XYZW<T> {
     }
 
     #[inline]
-    fn mul_quaternion(self, other: Self) -> Self {
-        glam_assert!(FloatVector4::is_normalized(self));
-        glam_assert!(FloatVector4::is_normalized(other));
+    fn mul_quaternion_unnormalized(self, other: Self) -> Self {
         let (x0, y0, z0, w0) = self.into_tuple();
         let (x1, y1, z1, w1) = other.into_tuple();
         Self::new(
@@ -84,4 +82,48 @@ impl<T: FloatEx> Quaternion<T> for XYZW<T> {
     fn mul_float4_as_vector3(self, other: XYZ<T>) -> XYZ<T> {
         self.mul_vector3(other)
     }
+
+    #[inline]
+    fn exp_quaternion(self) -> Self {
+        let v = XYZ {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+        let angle = v.length();
+        let exp_w = self.w.exp();
+        let (sin, cos) = angle.sin_cos();
+        // sin(angle) / angle rounds to 1 for very small angles
+        let scale = if angle * angle > T::EPSILON {
+            exp_w * sin / angle
+        } else {
+            exp_w
+        };
+        Self::new(v.x * scale, v.y * scale, v.z * scale, exp_w * cos)
+    }
+
+    #[inline]
+    fn ln_quaternion(self) -> Self {
+        let v = XYZ {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+        let v_length = v.length();
+        let ln_length = FloatVector4::length(self).ln();
+        if v_length > T::ZERO {
+            let scale = v_length.atan2(self.w) / v_length;
+            Self::new(v.x * scale, v.y * scale, v.z * scale, ln_length)
+        } else if self.w < T::ZERO {
+            // A negative real number, any axis will do
+            Self::new(
+                T::from_f64(core::f64::consts::PI),
+                T::ZERO,
+                T::ZERO,
+                ln_length,
+            )
+        } else {
+            Self::new(T::ZERO, T::ZERO, T::ZERO, ln_length)
+        }
+    }
 }
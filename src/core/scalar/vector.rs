@@ -1495,3 +1495,110 @@ where
         }
     }
 }
+
+// Integer wrapping, saturating, checked and overflowing arithmetic
+
+macro_rules! impl_integer_vector_op {
+    (binary, $fn:ident, $($c:ident),+) => {
+        #[inline]
+        fn $fn(self, rhs: Self) -> Self {
+            Self {
+                $($c: self.$c.$fn(rhs.$c)),+
+            }
+        }
+    };
+    (checked, $fn:ident, $($c:ident),+) => {
+        #[inline]
+        fn $fn(self, rhs: Self) -> Option<Self> {
+            Some(Self {
+                $($c: self.$c.$fn(rhs.$c)?),+
+            })
+        }
+    };
+    (binary_unsigned, $fn:ident, $($c:ident),+) => {
+        #[inline]
+        fn $fn(self, rhs: Self) -> Self::Unsigned {
+            Self::Unsigned {
+                $($c: self.$c.$fn(rhs.$c)),+
+            }
+        }
+    };
+    (unary_unsigned, $fn:ident, $($c:ident),+) => {
+        #[inline]
+        fn $fn(self) -> Self::Unsigned {
+            Self::Unsigned {
+                $($c: self.$c.$fn()),+
+            }
+        }
+    };
+    (overflowing, $fn:ident, $($c:ident),+) => {
+        #[inline]
+        fn $fn(self, rhs: Self) -> (Self, Self::Mask) {
+            $(let $c = self.$c.$fn(rhs.$c);)+
+            (Self { $($c: $c.0),+ }, Self::Mask { $($c: $c.1),+ })
+        }
+    };
+}
+
+macro_rules! impl_integer_vector {
+    ($inner:ident, $first:ident, $($c:ident),+) => {
+        impl<T: IntegerEx> IntegerVector<T> for $inner<T> {
+            type Unsigned = $inner<T::Unsigned>;
+
+            impl_integer_vector_op!(binary, wrapping_add, $first, $($c),+);
+            impl_integer_vector_op!(binary, wrapping_sub, $first, $($c),+);
+            impl_integer_vector_op!(binary, wrapping_mul, $first, $($c),+);
+            impl_integer_vector_op!(binary, wrapping_div, $first, $($c),+);
+            impl_integer_vector_op!(binary, saturating_add, $first, $($c),+);
+            impl_integer_vector_op!(binary, saturating_sub, $first, $($c),+);
+            impl_integer_vector_op!(binary, saturating_mul, $first, $($c),+);
+            impl_integer_vector_op!(binary, saturating_div, $first, $($c),+);
+            impl_integer_vector_op!(checked, checked_add, $first, $($c),+);
+            impl_integer_vector_op!(checked, checked_sub, $first, $($c),+);
+            impl_integer_vector_op!(checked, checked_mul, $first, $($c),+);
+            impl_integer_vector_op!(checked, checked_div, $first, $($c),+);
+            impl_integer_vector_op!(overflowing, overflowing_add, $first, $($c),+);
+            impl_integer_vector_op!(overflowing, overflowing_sub, $first, $($c),+);
+            impl_integer_vector_op!(overflowing, overflowing_mul, $first, $($c),+);
+            impl_integer_vector_op!(overflowing, overflowing_div, $first, $($c),+);
+            impl_integer_vector_op!(binary, div_euclid, $first, $($c),+);
+            impl_integer_vector_op!(binary, rem_euclid, $first, $($c),+);
+            impl_integer_vector_op!(binary_unsigned, abs_diff, $first, $($c),+);
+            impl_integer_vector_op!(unary_unsigned, count_ones, $first, $($c),+);
+            impl_integer_vector_op!(unary_unsigned, leading_zeros, $first, $($c),+);
+            impl_integer_vector_op!(unary_unsigned, trailing_zeros, $first, $($c),+);
+
+            #[inline]
+            fn element_sum(self) -> T {
+                self.$first $(+ self.$c)+
+            }
+
+            #[inline]
+            fn element_product(self) -> T {
+                self.$first $(* self.$c)+
+            }
+        }
+
+        impl<T: UnsignedIntegerEx> UnsignedIntegerVector<T> for $inner<T> {
+            #[inline]
+            fn is_power_of_two(self) -> Self::Mask {
+                Self::Mask {
+                    $first: self.$first.is_power_of_two(),
+                    $($c: self.$c.is_power_of_two()),+
+                }
+            }
+
+            #[inline]
+            fn next_power_of_two(self) -> Self {
+                Self {
+                    $first: self.$first.next_power_of_two(),
+                    $($c: self.$c.next_power_of_two()),+
+                }
+            }
+        }
+    };
+}
+
+impl_integer_vector!(XY, x, y);
+impl_integer_vector!(XYZ, x, y, z);
+impl_integer_vector!(XYZW, x, y, z, w);
@@ -118,18 +118,15 @@ impl FloatMatrix2x2<f32, XY<f32>> for __m128 {
     }
 
     #[inline]
-    fn inverse(&self) -> Self {
+    fn adjugate_and_determinant(&self) -> (Self, f32) {
         unsafe {
             const SIGN: __m128 = const_f32x4!([1.0, -1.0, -1.0, 1.0]);
             let abcd = *self;
             let dcba = _mm_shuffle_ps(abcd, abcd, 0b00_01_10_11);
             let prod = _mm_mul_ps(abcd, dcba);
             let sub = _mm_sub_ps(prod, _mm_shuffle_ps(prod, prod, 0b01_01_01_01));
-            let det = _mm_shuffle_ps(sub, sub, 0b00_00_00_00);
-            let tmp = _mm_div_ps(SIGN, det);
-            glam_assert!(tmp.is_finite());
             let dbca = _mm_shuffle_ps(abcd, abcd, 0b00_10_01_11);
-            _mm_mul_ps(dbca, tmp)
+            (_mm_mul_ps(dbca, SIGN), _mm_cvtss_f32(sub))
         }
     }
 }
@@ -278,7 +275,7 @@ impl Matrix4x4<f32, __m128> for Columns4<__m128> {
             let swp2a = _mm_shuffle_ps(self.z_axis, self.z_axis, 0b00_01_01_10);
             let swp3a = _mm_shuffle_ps(self.w_axis, self.w_axis, 0b11_10_11_11);
             let swp2b = _mm_shuffle_ps(self.z_axis, self.z_axis, 0b11_10_11_11);
-            let swp3b = _mm_shuffle_ps(self.w_axis, self.w_axis, 0b00_10_01_10);
+            let swp3b = _mm_shuffle_ps(self.w_axis, self.w_axis, 0b00_01_01_10);
             let swp2c = _mm_shuffle_ps(self.z_axis, self.z_axis, 0b00_00_01_10);
             let swp3c = _mm_shuffle_ps(self.w_axis, self.w_axis, 0b01_10_00_00);
 
@@ -332,7 +329,7 @@ impl Matrix4x4<f32, __m128> for Columns4<__m128> {
 impl FloatMatrix4x4<f32, __m128> for Columns4<__m128> {
     type SIMDVector3 = __m128;
 
-    fn inverse(&self) -> Self {
+    fn adjugate_and_determinant(&self) -> (Self, f32) {
         unsafe {
             // Based on https://github.com/g-truc/glm `glm_mat4_inverse`
             let fac0 = {
@@ -461,16 +458,14 @@ impl FloatMatrix4x4<f32, __m128> for Columns4<__m128> {
             let row2 = _mm_shuffle_ps(row0, row1, 0b10_00_10_00);
 
             let dot0 = Vector4::dot(self.x_axis, row2);
-            glam_assert!(dot0 != 0.0);
 
-            let rcp0 = _mm_set1_ps(dot0.recip());
-
-            Self {
-                x_axis: _mm_mul_ps(inv0, rcp0),
-                y_axis: _mm_mul_ps(inv1, rcp0),
-                z_axis: _mm_mul_ps(inv2, rcp0),
-                w_axis: _mm_mul_ps(inv3, rcp0),
-            }
+            let adjugate = Self {
+                x_axis: inv0,
+                y_axis: inv1,
+                z_axis: inv2,
+                w_axis: inv3,
+            };
+            (adjugate, dot0)
         }
     }
 
@@ -74,9 +74,7 @@ impl Quaternion<f32> for __m128 {
     }
 
     #[inline]
-    fn mul_quaternion(self, other: Self) -> Self {
-        glam_assert!(FloatVector4::is_normalized(self));
-        glam_assert!(FloatVector4::is_normalized(other));
+    fn mul_quaternion_unnormalized(self, other: Self) -> Self {
         unsafe {
             // Based on https://github.com/nfrechette/rtm `rtm::quat_mul`
             let lhs = self;
@@ -132,4 +130,52 @@ impl Quaternion<f32> for __m128 {
                 .add(b.cross(other).mul(w.mul(TWO)))
         }
     }
+
+    #[inline]
+    fn exp_quaternion(self) -> Self {
+        let angle = FloatVector3::length(self);
+        let exp_w = Vector4::w(self).exp();
+        unsafe {
+            // sin(angle) / angle and cos(angle) round to 1 for very small angles
+            let (scale, cos) = if angle * angle > f32::EPSILON {
+                // sin and cos of the angle in a single m128_sin
+                let sin_cos = m128_sin(_mm_set_ps(
+                    0.0,
+                    0.0,
+                    angle + core::f32::consts::FRAC_PI_2,
+                    angle,
+                ));
+                let sin = _mm_cvtss_f32(sin_cos);
+                let cos = _mm_cvtss_f32(_mm_shuffle_ps(sin_cos, sin_cos, 0b01_01_01_01));
+                (exp_w * sin / angle, cos)
+            } else {
+                (exp_w, 1.0)
+            };
+            _mm_add_ps(
+                _mm_mul_ps(self, _mm_set_ps(0.0, scale, scale, scale)),
+                _mm_set_ps(exp_w * cos, 0.0, 0.0, 0.0),
+            )
+        }
+    }
+
+    #[inline]
+    fn ln_quaternion(self) -> Self {
+        let v_length = FloatVector3::length(self);
+        let ln_length = FloatVector4::length(self).ln();
+        let w = Vector4::w(self);
+        unsafe {
+            if v_length > 0.0 {
+                let scale = v_length.atan2(w) / v_length;
+                _mm_add_ps(
+                    _mm_mul_ps(self, _mm_set_ps(0.0, scale, scale, scale)),
+                    _mm_set_ps(ln_length, 0.0, 0.0, 0.0),
+                )
+            } else if w < 0.0 {
+                // A negative real number, any axis will do
+                _mm_set_ps(ln_length, 0.0, 0.0, core::f32::consts::PI)
+            } else {
+                _mm_set_ps(ln_length, 0.0, 0.0, 0.0)
+            }
+        }
+    }
 }
@@ -156,21 +156,33 @@ pub trait FloatMatrix2x2<T: FloatEx, V2: FloatVector2<T>>: Matrix2x2<T, V2> {
         Self::new(cos, sin, -sin, cos)
     }
 
+    /// Returns the adjugate of `self` and the determinant of `self`. The inverse is the
+    /// adjugate divided by the determinant.
     #[inline]
-    fn inverse(&self) -> Self {
-        let inv_det = {
-            let det = self.determinant();
-            glam_assert!(det != T::ZERO);
-            det.recip()
-        };
+    fn adjugate_and_determinant(&self) -> (Self, T) {
         let x_axis = self.x_axis();
         let y_axis = self.y_axis();
-        Self::new(
-            y_axis.y() * inv_det,
-            x_axis.y() * -inv_det,
-            y_axis.x() * -inv_det,
-            x_axis.x() * inv_det,
-        )
+        let adjugate = Self::new(y_axis.y(), -x_axis.y(), -y_axis.x(), x_axis.x());
+        (adjugate, self.determinant())
+    }
+
+    #[inline]
+    fn inverse(&self) -> Self {
+        let (adjugate, det) = self.adjugate_and_determinant();
+        glam_assert!(det != T::ZERO);
+        adjugate.mul_scalar(det.recip())
+    }
+
+    #[inline]
+    fn inverse_with_epsilon(&self, epsilon: T) -> Option<Self> {
+        let (adjugate, det) = self.adjugate_and_determinant();
+        let inv_det = det.recip();
+        // `det.abs() > epsilon` is false if `det` is NaN
+        if det.abs() > epsilon && inv_det.is_finite() {
+            Some(adjugate.mul_scalar(inv_det))
+        } else {
+            None
+        }
     }
 }
 
@@ -334,6 +346,87 @@ pub trait Matrix3x3<T: NumEx, V3: Vector3<T>>: Matrix<T> {
     }
 }
 
+/// Diagonalizes the symmetric matrix `a`, given as `a[row][col]`, in place using the cyclic
+/// Jacobi method and writes the eigenvectors to the columns of `v`. Only the upper triangle of
+/// `a` is read.
+#[allow(clippy::needless_range_loop)]
+fn jacobi_eigen<T: FloatEx, const N: usize>(a: &mut [[T; N]; N], v: &mut [[T; N]; N]) {
+    // Maximum number of sweeps, convergence is quadratic so this is rarely reached
+    const MAX_SWEEPS: usize = 32;
+
+    for row in 0..N {
+        for col in 0..N {
+            if col < row {
+                // Mirror the upper triangle to the lower triangle
+                a[row][col] = a[col][row];
+            }
+            v[row][col] = if row == col { T::ONE } else { T::ZERO };
+        }
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off = T::ZERO;
+        let mut diag = T::ZERO;
+        for p in 0..N {
+            diag = diag + a[p][p] * a[p][p];
+            for q in p + 1..N {
+                off = off + a[p][q] * a[p][q];
+            }
+        }
+        if off <= T::EPSILON * T::EPSILON * (diag + off + off) || off.is_nan() {
+            break;
+        }
+
+        for p in 0..N {
+            for q in p + 1..N {
+                let apq = a[p][q];
+                if apq == T::ZERO {
+                    continue;
+                }
+
+                // Rotation angle which annihilates `a[p][q]`, using the smaller root for stability
+                let theta = (a[q][q] - a[p][p]) / (T::TWO * apq);
+                let theta_sq = theta * theta;
+                let t = if theta_sq.is_finite() {
+                    let t = T::ONE / (theta.abs() + (theta_sq + T::ONE).sqrt());
+                    if theta < T::ZERO {
+                        -t
+                    } else {
+                        t
+                    }
+                } else {
+                    T::HALF / theta
+                };
+                let c = T::ONE / (t * t + T::ONE).sqrt();
+                let s = t * c;
+
+                a[p][p] = a[p][p] - t * apq;
+                a[q][q] = a[q][q] + t * apq;
+                a[p][q] = T::ZERO;
+                a[q][p] = T::ZERO;
+
+                for r in 0..N {
+                    if r != p && r != q {
+                        let arp = a[r][p];
+                        let arq = a[r][q];
+                        a[r][p] = c * arp - s * arq;
+                        a[p][r] = a[r][p];
+                        a[r][q] = s * arp + c * arq;
+                        a[q][r] = a[r][q];
+                    }
+                }
+
+                for row in v.iter_mut() {
+                    let vkp = row[p];
+                    let vkq = row[q];
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+}
+
 pub trait FloatMatrix3x3<T: FloatEx, V3: FloatVector3<T>>: Matrix3x3<T, V3> {
     #[inline]
     fn abs_diff_eq(&self, other: &Self, max_abs_diff: T) -> bool
@@ -438,22 +531,136 @@ pub trait FloatMatrix3x3<T: FloatEx, V3: FloatVector3<T>>: Matrix3x3<T, V3> {
     fn transform_point2(&self, other: XY<T>) -> XY<T>;
     fn transform_vector2(&self, other: XY<T>) -> XY<T>;
 
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix using the cyclic Jacobi
+    /// method. Only the upper triangle of `self` is read.
+    ///
+    /// Returns the eigenvalues sorted in descending order and a rotation matrix whose columns are
+    /// the corresponding unit length eigenvectors.
+    fn symmetric_eigen(&self) -> (XYZ<T>, Self) {
+        let mut m = [T::ZERO; 9];
+        self.write_cols_to_slice(&mut m);
+        let mut a = [[m[0], m[3], m[6]], [m[3], m[4], m[7]], [m[6], m[7], m[8]]];
+        let mut v = [[T::ZERO; 3]; 3];
+        jacobi_eigen(&mut a, &mut v);
+
+        // Sort eigenvalues in descending order
+        let mut order = [0, 1, 2];
+        if a[order[0]][order[0]] < a[order[1]][order[1]] {
+            order.swap(0, 1);
+        }
+        if a[order[1]][order[1]] < a[order[2]][order[2]] {
+            order.swap(1, 2);
+        }
+        if a[order[0]][order[0]] < a[order[1]][order[1]] {
+            order.swap(0, 1);
+        }
+
+        let eigenvalues = XYZ {
+            x: a[order[0]][order[0]],
+            y: a[order[1]][order[1]],
+            z: a[order[2]][order[2]],
+        };
+        let col = |i: usize| V3::new(v[0][order[i]], v[1][order[i]], v[2][order[i]]);
+        let x_axis = col(0);
+        let y_axis = col(1);
+        // Derive the last axis from the first two so the result is always a proper rotation
+        let z_axis = x_axis.cross(y_axis);
+        (eigenvalues, Self::from_cols(x_axis, y_axis, z_axis))
+    }
+
+    /// Computes the singular value decomposition `self = u * diagonal(sigma) * v^T` where `u` and
+    /// `v` are rotation matrices.
+    ///
+    /// The singular values are sorted by descending magnitude. The last singular value is
+    /// negative if the determinant of `self` is negative so that `u` and `v` never contain a
+    /// reflection.
+    fn svd(&self) -> (Self, XYZ<T>, Self) {
+        // The right singular vectors are the eigenvectors of `self^T * self`
+        let (_, v) = self.transpose().mul_matrix(self).symmetric_eigen();
+
+        // The columns of `self * v` are orthogonal with lengths equal to the singular values
+        let b = self.mul_matrix(&v);
+        let b0 = *b.x_axis();
+        let b1 = *b.y_axis();
+        let b2 = *b.z_axis();
+
+        let sigma0 = b0.length();
+        let u0 = if sigma0 > T::ZERO {
+            b0.mul_scalar(sigma0.recip())
+        } else {
+            V3::X
+        };
+
+        // Build the remaining left singular vectors with cross products so `u` is orthonormal
+        // even when `self` is rank deficient
+        let w = u0.cross(b1);
+        let w_length = w.length();
+        let u2 = if w_length > sigma0 * T::EPSILON && w_length > T::ZERO {
+            w.mul_scalar(w_length.recip())
+        } else {
+            let (x, y, z) = (u0.x().abs(), u0.y().abs(), u0.z().abs());
+            let axis = if x <= y && x <= z {
+                V3::X
+            } else if y <= z {
+                V3::Y
+            } else {
+                V3::Z
+            };
+            u0.cross(axis).normalize()
+        };
+        let u1 = u2.cross(u0);
+
+        let sigma = XYZ {
+            x: sigma0,
+            y: NumEx::max(b1.dot(u1), T::ZERO),
+            z: b2.dot(u2),
+        };
+        (Self::from_cols(u0, u1, u2), sigma, v)
+    }
+
+    /// Computes the polar decomposition `self = rotation * stretch` where `rotation` is a
+    /// rotation matrix and `stretch` is a symmetric matrix.
     #[inline]
-    fn inverse(&self) -> Self
-    where
-        <V3 as Vector<T>>::Mask: MaskVector3,
-    {
+    fn polar(&self) -> (Self, Self) {
+        let (u, sigma, v) = self.svd();
+        let v_t = v.transpose();
+        let rotation = u.mul_matrix(&v_t);
+        let stretch = v.mul_matrix(&Self::from_diagonal(sigma)).mul_matrix(&v_t);
+        (rotation, stretch)
+    }
+
+    /// Returns the adjugate of `self` and the determinant of `self`. The inverse is the
+    /// adjugate divided by the determinant.
+    #[inline]
+    fn adjugate_and_determinant(&self) -> (Self, T) {
         let x_axis = self.x_axis();
         let y_axis = self.y_axis();
         let z_axis = self.z_axis();
         let tmp0 = y_axis.cross(*z_axis);
         let tmp1 = z_axis.cross(*x_axis);
         let tmp2 = x_axis.cross(*y_axis);
-        let det = z_axis.dot_into_vec(tmp2);
-        glam_assert!(det.cmpne(V3::ZERO).all());
-        let inv_det = det.recip();
+        let det = z_axis.dot(tmp2);
         // TODO: Work out if it's possible to get rid of the transpose
-        Self::from_cols(tmp0.mul(inv_det), tmp1.mul(inv_det), tmp2.mul(inv_det)).transpose()
+        (Self::from_cols(tmp0, tmp1, tmp2).transpose(), det)
+    }
+
+    #[inline]
+    fn inverse(&self) -> Self {
+        let (adjugate, det) = self.adjugate_and_determinant();
+        glam_assert!(det != T::ZERO);
+        adjugate.mul_scalar(det.recip())
+    }
+
+    #[inline]
+    fn inverse_with_epsilon(&self, epsilon: T) -> Option<Self> {
+        let (adjugate, det) = self.adjugate_and_determinant();
+        let inv_det = det.recip();
+        // `det.abs() > epsilon` is false if `det` is NaN
+        if det.abs() > epsilon && inv_det.is_finite() {
+            Some(adjugate.mul_scalar(inv_det))
+        } else {
+            None
+        }
     }
 
     #[inline]
@@ -682,6 +889,50 @@ pub trait FloatMatrix4x4<T: FloatEx, V4: FloatVector4<T> + Quaternion<T>>:
     // Vector3 represented by a SIMD type if available
     type SIMDVector3;
 
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix using the cyclic Jacobi
+    /// method. Only the upper triangle of `self` is read.
+    ///
+    /// Returns the eigenvalues sorted in descending order and a matrix whose columns are the
+    /// corresponding unit length eigenvectors.
+    fn symmetric_eigen(&self) -> (XYZW<T>, Self) {
+        let mut m = [T::ZERO; 16];
+        self.write_cols_to_slice(&mut m);
+        let mut a = [[T::ZERO; 4]; 4];
+        for (col, column) in m.chunks(4).enumerate() {
+            for (row, &value) in column.iter().enumerate() {
+                a[row][col] = value;
+            }
+        }
+        let mut v = [[T::ZERO; 4]; 4];
+        jacobi_eigen(&mut a, &mut v);
+
+        // Sort eigenvalues in descending order
+        let mut order = [0, 1, 2, 3];
+        for i in 1..4 {
+            let mut j = i;
+            while j > 0 && a[order[j - 1]][order[j - 1]] < a[order[j]][order[j]] {
+                order.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+
+        let eigenvalues = XYZW {
+            x: a[order[0]][order[0]],
+            y: a[order[1]][order[1]],
+            z: a[order[2]][order[2]],
+            w: a[order[3]][order[3]],
+        };
+        let col = |i: usize| {
+            V4::new(
+                v[0][order[i]],
+                v[1][order[i]],
+                v[2][order[i]],
+                v[3][order[i]],
+            )
+        };
+        (eigenvalues, Self::from_cols(col(0), col(1), col(2), col(3)))
+    }
+
     #[inline]
     fn abs_diff_eq(&self, other: &Self, max_abs_diff: T) -> bool
     where
@@ -886,7 +1137,9 @@ pub trait FloatMatrix4x4<T: FloatEx, V4: FloatVector4<T> + Quaternion<T>>:
     fn transform_float4_as_vector3(&self, other: Self::SIMDVector3) -> Self::SIMDVector3;
     fn project_float4_as_point3(&self, other: Self::SIMDVector3) -> Self::SIMDVector3;
 
-    fn inverse(&self) -> Self {
+    /// Returns the adjugate of `self` and the determinant of `self`. The inverse is the
+    /// adjugate divided by the determinant.
+    fn adjugate_and_determinant(&self) -> (Self, T) {
         let (m00, m01, m02, m03) = self.x_axis().into_tuple();
         let (m10, m11, m12, m13) = self.y_axis().into_tuple();
         let (m20, m21, m22, m23) = self.z_axis().into_tuple();
@@ -936,7 +1189,7 @@ pub trait FloatMatrix4x4<T: FloatEx, V4: FloatVector4<T> + Quaternion<T>>:
         let sign_a = Vector4::new(T::ONE, -T::ONE, T::ONE, -T::ONE);
         let sign_b = Vector4::new(-T::ONE, T::ONE, -T::ONE, T::ONE);
 
-        let inverse = Self::from_cols(
+        let adjugate = Self::from_cols(
             inv0.mul(sign_a),
             inv1.mul(sign_b),
             inv2.mul(sign_a),
@@ -944,18 +1197,34 @@ pub trait FloatMatrix4x4<T: FloatEx, V4: FloatVector4<T> + Quaternion<T>>:
         );
 
         let col0 = V4::new(
-            inverse.x_axis().x(),
-            inverse.y_axis().x(),
-            inverse.z_axis().x(),
-            inverse.w_axis().x(),
+            adjugate.x_axis().x(),
+            adjugate.y_axis().x(),
+            adjugate.z_axis().x(),
+            adjugate.w_axis().x(),
         );
 
         let dot0 = self.x_axis().mul(col0);
         let dot1 = dot0.x() + dot0.y() + dot0.z() + dot0.w();
 
-        glam_assert!(dot1 != T::ZERO);
+        (adjugate, dot1)
+    }
 
-        let rcp_det = dot1.recip();
-        inverse.mul_scalar(rcp_det)
+    #[inline]
+    fn inverse(&self) -> Self {
+        let (adjugate, det) = self.adjugate_and_determinant();
+        glam_assert!(det != T::ZERO);
+        adjugate.mul_scalar(det.recip())
+    }
+
+    #[inline]
+    fn inverse_with_epsilon(&self, epsilon: T) -> Option<Self> {
+        let (adjugate, det) = self.adjugate_and_determinant();
+        let inv_det = det.recip();
+        // `det.abs() > epsilon` is false if `det` is NaN
+        if det.abs() > epsilon && inv_det.is_finite() {
+            Some(adjugate.mul_scalar(inv_det))
+        } else {
+            None
+        }
     }
 }
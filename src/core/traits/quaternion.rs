@@ -134,7 +134,29 @@ pub trait Quaternion<T: FloatEx>: FloatVector4<T> {
     fn conjugate(self) -> Self;
     fn lerp(self, end: Self, s: T) -> Self;
     fn slerp(self, end: Self, s: T) -> Self;
-    fn mul_quaternion(self, other: Self) -> Self;
+
+    #[inline]
+    fn mul_quaternion(self, other: Self) -> Self {
+        glam_assert!(FloatVector4::is_normalized(self));
+        glam_assert!(FloatVector4::is_normalized(other));
+        self.mul_quaternion_unnormalized(other)
+    }
+
+    /// Returns the Hamilton product of `self` and `other`, which do not need to be normalized.
+    fn mul_quaternion_unnormalized(self, other: Self) -> Self;
+
     fn mul_vector3(self, other: XYZ<T>) -> XYZ<T>;
     fn mul_float4_as_vector3(self, other: Self::SIMDVector3) -> Self::SIMDVector3;
+
+    /// Returns the quaternion exponential of `self`.
+    fn exp_quaternion(self) -> Self;
+
+    /// Returns the quaternion natural logarithm of `self`.
+    fn ln_quaternion(self) -> Self;
+
+    /// Returns `self` raised to the power `n`.
+    #[inline]
+    fn powf_quaternion(self, n: T) -> Self {
+        self.ln_quaternion().mul_scalar(n).exp_quaternion()
+    }
 }
@@ -24,11 +24,13 @@ pub trait Signed: Sized + Num + core::ops::Neg<Output = Self> {
 pub trait Float: Num + Copy + core::ops::Neg<Output = Self> {
     fn asin(self) -> Self;
     fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
     fn ceil(self) -> Self;
     fn exp(self) -> Self;
     fn floor(self) -> Self;
     fn is_finite(self) -> bool;
     fn is_nan(self) -> bool;
+    fn ln(self) -> Self;
     fn powf(self, n: Self) -> Self;
     fn recip(self) -> Self;
     fn round(self) -> Self;
@@ -78,6 +80,10 @@ macro_rules! impl_float_trait {
                 $t::acos(self)
             }
             #[inline(always)]
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+            #[inline(always)]
             fn ceil(self) -> Self {
                 $t::ceil(self)
             }
@@ -98,6 +104,10 @@ macro_rules! impl_float_trait {
                 $t::is_nan(self)
             }
             #[inline(always)]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+            #[inline(always)]
             fn powf(self, n: Self) -> Self {
                 $t::powf(self, n)
             }
@@ -137,6 +147,16 @@ impl_float_trait!(f64);
 impl_signed_trait!(i32);
 #[cfg(not(feature = "libm"))]
 impl_num_trait!(u32);
+#[cfg(not(feature = "libm"))]
+impl_signed_trait!(i64);
+#[cfg(not(feature = "libm"))]
+impl_num_trait!(u64);
+#[cfg(not(feature = "libm"))]
+impl_signed_trait!(i16);
+#[cfg(not(feature = "libm"))]
+impl_num_trait!(u16);
+#[cfg(not(feature = "libm"))]
+impl_num_trait!(u8);
 
 pub trait MaskConst: Sized {
     const MASK: [Self; 2];
@@ -151,6 +171,7 @@ pub trait FloatConstEx: Sized {
     const NEG_ONE: Self;
     const TWO: Self;
     const HALF: Self;
+    const EPSILON: Self;
 }
 
 pub trait NanConstEx: Sized {
@@ -196,6 +217,7 @@ impl FloatConstEx for f32 {
     const NEG_ONE: Self = -1.0;
     const TWO: Self = 2.0;
     const HALF: Self = 0.5;
+    const EPSILON: Self = f32::EPSILON;
 }
 
 impl NumEx for f32 {
@@ -268,6 +290,7 @@ impl FloatConstEx for f64 {
     const NEG_ONE: Self = -1.0;
     const TWO: Self = 2.0;
     const HALF: Self = 0.5;
+    const EPSILON: Self = f64::EPSILON;
 }
 
 impl NumEx for f64 {
@@ -332,6 +355,90 @@ impl NumEx for u32 {
     }
 }
 
+impl NumConstEx for i64 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
+
+impl NumEx for i64 {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        core::cmp::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        core::cmp::max(self, other)
+    }
+}
+
+impl SignedEx for i64 {}
+
+impl NumConstEx for u64 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
+
+impl NumEx for u64 {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        core::cmp::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        core::cmp::max(self, other)
+    }
+}
+
+impl NumConstEx for i16 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
+
+impl NumEx for i16 {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        core::cmp::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        core::cmp::max(self, other)
+    }
+}
+
+impl SignedEx for i16 {}
+
+impl NumConstEx for u16 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
+
+impl NumEx for u16 {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        core::cmp::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        core::cmp::max(self, other)
+    }
+}
+
+impl NumConstEx for u8 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+}
+
+impl NumEx for u8 {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        core::cmp::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        core::cmp::max(self, other)
+    }
+}
+
 pub trait IntegerShiftOps<Rhs>: Sized + Shl<Rhs, Output = Self> + Shr<Rhs, Output = Self> {}
 
 pub trait IntegerBitOps:
@@ -353,8 +460,222 @@ impl IntegerShiftOps<u8> for u32 {}
 impl IntegerShiftOps<u16> for u32 {}
 impl IntegerShiftOps<u32> for u32 {}
 
+impl IntegerShiftOps<i8> for i64 {}
+impl IntegerShiftOps<i16> for i64 {}
+impl IntegerShiftOps<i32> for i64 {}
+impl IntegerShiftOps<i64> for i64 {}
+impl IntegerShiftOps<u8> for i64 {}
+impl IntegerShiftOps<u16> for i64 {}
+impl IntegerShiftOps<u32> for i64 {}
+impl IntegerShiftOps<u64> for i64 {}
+
+impl IntegerShiftOps<i8> for u64 {}
+impl IntegerShiftOps<i16> for u64 {}
+impl IntegerShiftOps<i32> for u64 {}
+impl IntegerShiftOps<i64> for u64 {}
+impl IntegerShiftOps<u8> for u64 {}
+impl IntegerShiftOps<u16> for u64 {}
+impl IntegerShiftOps<u32> for u64 {}
+impl IntegerShiftOps<u64> for u64 {}
+
+impl IntegerShiftOps<i8> for i16 {}
+impl IntegerShiftOps<i16> for i16 {}
+impl IntegerShiftOps<i32> for i16 {}
+impl IntegerShiftOps<i64> for i16 {}
+impl IntegerShiftOps<u8> for i16 {}
+impl IntegerShiftOps<u16> for i16 {}
+impl IntegerShiftOps<u32> for i16 {}
+impl IntegerShiftOps<u64> for i16 {}
+
+impl IntegerShiftOps<i8> for u16 {}
+impl IntegerShiftOps<i16> for u16 {}
+impl IntegerShiftOps<i32> for u16 {}
+impl IntegerShiftOps<i64> for u16 {}
+impl IntegerShiftOps<u8> for u16 {}
+impl IntegerShiftOps<u16> for u16 {}
+impl IntegerShiftOps<u32> for u16 {}
+impl IntegerShiftOps<u64> for u16 {}
+
+impl IntegerShiftOps<i8> for u8 {}
+impl IntegerShiftOps<i16> for u8 {}
+impl IntegerShiftOps<i32> for u8 {}
+impl IntegerShiftOps<i64> for u8 {}
+impl IntegerShiftOps<u8> for u8 {}
+impl IntegerShiftOps<u16> for u8 {}
+impl IntegerShiftOps<u32> for u8 {}
+impl IntegerShiftOps<u64> for u8 {}
+
 impl IntegerBitOps for i32 {}
 impl IntegerBitOps for u32 {}
+impl IntegerBitOps for i64 {}
+impl IntegerBitOps for u64 {}
+impl IntegerBitOps for i16 {}
+impl IntegerBitOps for u16 {}
+impl IntegerBitOps for u8 {}
+
+pub trait IntegerEx: NumEx {
+    type Unsigned: IntegerEx;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn wrapping_div(self, rhs: Self) -> Self;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn saturating_div(self, rhs: Self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+    fn overflowing_div(self, rhs: Self) -> (Self, bool);
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn abs_diff(self, rhs: Self) -> Self::Unsigned;
+    fn count_ones(self) -> Self::Unsigned;
+    fn leading_zeros(self) -> Self::Unsigned;
+    fn trailing_zeros(self) -> Self::Unsigned;
+}
+
+pub trait UnsignedIntegerEx: IntegerEx {
+    fn is_power_of_two(self) -> bool;
+    fn next_power_of_two(self) -> Self;
+}
+
+macro_rules! impl_integer_ex {
+    ($t:ident, $u:ident) => {
+        impl IntegerEx for $t {
+            type Unsigned = $u;
+            #[inline(always)]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                $t::wrapping_add(self, rhs)
+            }
+            #[inline(always)]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                $t::wrapping_sub(self, rhs)
+            }
+            #[inline(always)]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                $t::wrapping_mul(self, rhs)
+            }
+            #[inline(always)]
+            fn wrapping_div(self, rhs: Self) -> Self {
+                $t::wrapping_div(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                $t::saturating_add(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                $t::saturating_sub(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_mul(self, rhs: Self) -> Self {
+                $t::saturating_mul(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_div(self, rhs: Self) -> Self {
+                // `saturating_div` requires Rust 1.58, division can only overflow for `MIN / -1`
+                match $t::overflowing_div(self, rhs) {
+                    (_, true) => $t::MAX,
+                    (v, false) => v,
+                }
+            }
+            #[inline(always)]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                $t::checked_add(self, rhs)
+            }
+            #[inline(always)]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                $t::checked_sub(self, rhs)
+            }
+            #[inline(always)]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                $t::checked_mul(self, rhs)
+            }
+            #[inline(always)]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                $t::checked_div(self, rhs)
+            }
+            #[inline(always)]
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                $t::overflowing_add(self, rhs)
+            }
+            #[inline(always)]
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                $t::overflowing_sub(self, rhs)
+            }
+            #[inline(always)]
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                $t::overflowing_mul(self, rhs)
+            }
+            #[inline(always)]
+            fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                $t::overflowing_div(self, rhs)
+            }
+            #[inline(always)]
+            fn div_euclid(self, rhs: Self) -> Self {
+                $t::div_euclid(self, rhs)
+            }
+            #[inline(always)]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $t::rem_euclid(self, rhs)
+            }
+            #[inline(always)]
+            fn abs_diff(self, rhs: Self) -> $u {
+                // `abs_diff` requires Rust 1.60, the wrapped difference is always in range of `$u`
+                if self > rhs {
+                    (self as $u).wrapping_sub(rhs as $u)
+                } else {
+                    (rhs as $u).wrapping_sub(self as $u)
+                }
+            }
+            #[inline(always)]
+            fn count_ones(self) -> $u {
+                $t::count_ones(self) as $u
+            }
+            #[inline(always)]
+            fn leading_zeros(self) -> $u {
+                $t::leading_zeros(self) as $u
+            }
+            #[inline(always)]
+            fn trailing_zeros(self) -> $u {
+                $t::trailing_zeros(self) as $u
+            }
+        }
+    };
+}
+
+macro_rules! impl_unsigned_integer_ex {
+    ($t:ident) => {
+        impl UnsignedIntegerEx for $t {
+            #[inline(always)]
+            fn is_power_of_two(self) -> bool {
+                $t::is_power_of_two(self)
+            }
+            #[inline(always)]
+            fn next_power_of_two(self) -> Self {
+                $t::next_power_of_two(self)
+            }
+        }
+    };
+}
+
+impl_integer_ex!(i32, u32);
+impl_integer_ex!(u32, u32);
+impl_integer_ex!(i64, u64);
+impl_integer_ex!(u64, u64);
+impl_integer_ex!(i16, u16);
+impl_integer_ex!(u16, u16);
+impl_integer_ex!(u8, u8);
+
+impl_unsigned_integer_ex!(u32);
+impl_unsigned_integer_ex!(u64);
+impl_unsigned_integer_ex!(u16);
+impl_unsigned_integer_ex!(u8);
 
 #[cfg(test)]
 macro_rules! assert_approx_eq {
@@ -826,3 +826,36 @@ pub trait VectorBitOps<Rhs> {
     fn vector_bitor(self, rhs: Rhs) -> Self;
     fn vector_bitxor(self, rhs: Rhs) -> Self;
 }
+
+pub trait IntegerVector<T>: Vector<T> {
+    type Unsigned;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn wrapping_div(self, rhs: Self) -> Self;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn saturating_div(self, rhs: Self) -> Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn overflowing_add(self, rhs: Self) -> (Self, Self::Mask);
+    fn overflowing_sub(self, rhs: Self) -> (Self, Self::Mask);
+    fn overflowing_mul(self, rhs: Self) -> (Self, Self::Mask);
+    fn overflowing_div(self, rhs: Self) -> (Self, Self::Mask);
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn abs_diff(self, rhs: Self) -> Self::Unsigned;
+    fn count_ones(self) -> Self::Unsigned;
+    fn leading_zeros(self) -> Self::Unsigned;
+    fn trailing_zeros(self) -> Self::Unsigned;
+    fn element_sum(self) -> T;
+    fn element_product(self) -> T;
+}
+
+pub trait UnsignedIntegerVector<T>: IntegerVector<T> {
+    fn is_power_of_two(self) -> Self::Mask;
+    fn next_power_of_two(self) -> Self;
+}
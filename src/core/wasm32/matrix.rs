@@ -109,17 +109,14 @@ impl FloatMatrix2x2<f32, XY<f32>> for v128 {
     }
 
     #[inline]
-    fn inverse(&self) -> Self {
+    fn adjugate_and_determinant(&self) -> (Self, f32) {
         const SIGN: v128 = const_f32x4!([1.0, -1.0, -1.0, 1.0]);
         let abcd = *self;
         let dcba = i32x4_shuffle::<3, 2, 5, 4>(abcd, abcd);
         let prod = f32x4_mul(abcd, dcba);
         let sub = f32x4_sub(prod, i32x4_shuffle::<1, 1, 5, 5>(prod, prod));
-        let det = i32x4_shuffle::<0, 0, 4, 4>(sub, sub);
-        let tmp = f32x4_div(SIGN, det);
-        glam_assert!(tmp.is_finite());
         let dbca = i32x4_shuffle::<3, 1, 6, 4>(abcd, abcd);
-        f32x4_mul(dbca, tmp)
+        (f32x4_mul(dbca, SIGN), f32x4_extract_lane::<0>(sub))
     }
 }
 
@@ -264,7 +261,7 @@ impl Matrix4x4<f32, v128> for Columns4<v128> {
         let swp2a = i32x4_shuffle::<2, 1, 1, 0>(self.z_axis, self.z_axis);
         let swp3a = i32x4_shuffle::<3, 3, 2, 3>(self.w_axis, self.w_axis);
         let swp2b = i32x4_shuffle::<3, 3, 2, 3>(self.z_axis, self.z_axis);
-        let swp3b = i32x4_shuffle::<2, 1, 2, 0>(self.w_axis, self.w_axis);
+        let swp3b = i32x4_shuffle::<2, 1, 1, 0>(self.w_axis, self.w_axis);
         let swp2c = i32x4_shuffle::<2, 1, 0, 0>(self.z_axis, self.z_axis);
         let swp3c = i32x4_shuffle::<0, 0, 2, 1>(self.w_axis, self.w_axis);
 
@@ -315,7 +312,7 @@ impl Matrix4x4<f32, v128> for Columns4<v128> {
 impl FloatMatrix4x4<f32, v128> for Columns4<v128> {
     type SIMDVector3 = v128;
 
-    fn inverse(&self) -> Self {
+    fn adjugate_and_determinant(&self) -> (Self, f32) {
         // Based on https://github.com/g-truc/glm `glm_mat4_inverse`
         let fac0 = {
             let swp0a = i32x4_shuffle::<3, 3, 7, 7>(self.w_axis, self.z_axis);
@@ -443,16 +440,14 @@ impl FloatMatrix4x4<f32, v128> for Columns4<v128> {
         let row2 = i32x4_shuffle::<0, 2, 4, 6>(row0, row1);
 
         let dot0 = Vector4::dot(self.x_axis, row2);
-        glam_assert!(dot0 != 0.0);
 
-        let rcp0 = f32x4_splat(dot0.recip());
-
-        Self {
-            x_axis: f32x4_mul(inv0, rcp0),
-            y_axis: f32x4_mul(inv1, rcp0),
-            z_axis: f32x4_mul(inv2, rcp0),
-            w_axis: f32x4_mul(inv3, rcp0),
-        }
+        let adjugate = Self {
+            x_axis: inv0,
+            y_axis: inv1,
+            z_axis: inv2,
+            w_axis: inv3,
+        };
+        (adjugate, dot0)
     }
 
     #[inline(always)]
@@ -73,9 +73,7 @@ impl Quaternion<f32> for v128 {
     }
 
     #[inline]
-    fn mul_quaternion(self, other: Self) -> Self {
-        glam_assert!(FloatVector4::is_normalized(self));
-        glam_assert!(FloatVector4::is_normalized(other));
+    fn mul_quaternion_unnormalized(self, other: Self) -> Self {
         // Based on https://github.com/nfrechette/rtm `rtm::quat_mul`
         let lhs = self;
         let rhs = other;
@@ -127,4 +125,41 @@ impl Quaternion<f32> for v128 {
             .add(b.mul(Vector3::dot_into_vec(other, b).mul(TWO)))
             .add(b.cross(other).mul(w.mul(TWO)))
     }
+
+    #[inline]
+    fn exp_quaternion(self) -> Self {
+        let angle = FloatVector3::length(self);
+        let exp_w = Vector4::w(self).exp();
+        // TODO: v128_sin is broken
+        let (sin, cos) = angle.sin_cos();
+        // sin(angle) / angle rounds to 1 for very small angles
+        let scale = if angle * angle > f32::EPSILON {
+            exp_w * sin / angle
+        } else {
+            exp_w
+        };
+        f32x4_add(
+            f32x4_mul(self, f32x4(scale, scale, scale, 0.0)),
+            f32x4(0.0, 0.0, 0.0, exp_w * cos),
+        )
+    }
+
+    #[inline]
+    fn ln_quaternion(self) -> Self {
+        let v_length = FloatVector3::length(self);
+        let ln_length = FloatVector4::length(self).ln();
+        let w = Vector4::w(self);
+        if v_length > 0.0 {
+            let scale = v_length.atan2(w) / v_length;
+            f32x4_add(
+                f32x4_mul(self, f32x4(scale, scale, scale, 0.0)),
+                f32x4(0.0, 0.0, 0.0, ln_length),
+            )
+        } else if w < 0.0 {
+            // A negative real number, any axis will do
+            f32x4(core::f32::consts::PI, 0.0, 0.0, ln_length)
+        } else {
+            f32x4(0.0, 0.0, 0.0, ln_length)
+        }
+    }
 }
@@ -0,0 +1,468 @@
+// Dual quaternions for rigid transforms.
+//
+// A unit dual quaternion `real + ε dual` represents a rotation `real` followed by a translation
+// `t`, where `dual = 0.5 * t * real`. The dual part is generally not normalized, so the products
+// below use the unnormalized Hamilton product rather than the asserting quaternion `Mul`.
+use crate::core::traits::{quaternion::Quaternion, vector::VectorConst};
+use crate::{Affine3A, DAffine3, DMat4, DQuat, DVec3, Mat4, Quat, Vec3};
+use core::ops::{Add, Mul, MulAssign, Neg, Sub};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+macro_rules! impl_dual_quat {
+    ($t:ident, $quat:ident, $vec3:ident, $mat4:ident, $affine3:ident, $dual_quat:ident) => {
+        /// A dual quaternion representing a rigid transform, which is a rotation followed by a
+        /// translation.
+        ///
+        /// Unlike a matrix, blending dual quaternions with [`Self::linear_blend()`] or
+        /// [`Self::sclerp()`] always produces a rigid transform, which avoids the volume loss of
+        /// linear blend skinning.
+        #[derive(Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $dual_quat {
+            /// The rotation part.
+            pub real: $quat,
+            /// The translation part, `0.5 * translation * real`.
+            pub dual: $quat,
+        }
+
+        impl $dual_quat {
+            const ZERO: $quat = $quat(VectorConst::ZERO);
+
+            /// The identity transform.
+            pub const IDENTITY: Self = Self {
+                real: $quat::IDENTITY,
+                dual: Self::ZERO,
+            };
+
+            /// All NAN:s.
+            pub const NAN: Self = Self {
+                real: $quat::NAN,
+                dual: $quat::NAN,
+            };
+
+            /// Creates a dual quaternion from its `real` and `dual` parts.
+            ///
+            /// This should generally not be called manually unless you know what you are doing.
+            /// Use one of the other constructors instead such as
+            /// [`Self::from_rotation_translation()`].
+            #[inline(always)]
+            pub fn from_real_dual(real: $quat, dual: $quat) -> Self {
+                Self { real, dual }
+            }
+
+            /// Creates a dual quaternion from the given `rotation` and `translation`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn from_rotation_translation(rotation: $quat, translation: $vec3) -> Self {
+                glam_assert!(rotation.is_normalized());
+                let translation =
+                    $quat::from_xyzw(translation.x, translation.y, translation.z, 0.0);
+                Self {
+                    real: rotation,
+                    dual: Self::mul_quat(translation, rotation) * 0.5,
+                }
+            }
+
+            /// Creates a dual quaternion from the given `rotation` quaternion.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn from_quat(rotation: $quat) -> Self {
+                glam_assert!(rotation.is_normalized());
+                Self {
+                    real: rotation,
+                    dual: Self::ZERO,
+                }
+            }
+
+            /// Creates a dual quaternion from the given `translation`.
+            #[inline]
+            pub fn from_translation(translation: $vec3) -> Self {
+                Self {
+                    real: $quat::IDENTITY,
+                    dual: $quat::from_xyzw(
+                        translation.x * 0.5,
+                        translation.y * 0.5,
+                        translation.z * 0.5,
+                        0.0,
+                    ),
+                }
+            }
+
+            /// Creates a dual quaternion from the rotation and translation of a 3D affine
+            /// transformation matrix, ignoring any scale.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if the determinant of `mat` is zero when `glam_assert` is enabled.
+            #[inline]
+            pub fn from_mat4(mat: &$mat4) -> Self {
+                let (_, rotation, translation) = mat.to_scale_rotation_translation();
+                Self::from_rotation_translation(rotation, translation)
+            }
+
+            /// Creates a dual quaternion from the rotation and translation of an affine
+            /// transform, ignoring any scale.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if the determinant of `affine.matrix3` is zero when `glam_assert` is
+            /// enabled.
+            #[inline]
+            pub fn from_affine3(affine: &$affine3) -> Self {
+                let (_, rotation, translation) = affine.to_scale_rotation_translation();
+                Self::from_rotation_translation(rotation, translation)
+            }
+
+            /// Returns the rotation of `self`.
+            #[inline(always)]
+            pub fn rotation(&self) -> $quat {
+                self.real
+            }
+
+            /// Returns the translation of `self`.
+            #[inline]
+            pub fn translation(&self) -> $vec3 {
+                Self::mul_quat(self.dual, self.real.conjugate()).xyz() * 2.0
+            }
+
+            /// Returns the rotation and translation of `self`.
+            #[inline]
+            pub fn to_rotation_translation(&self) -> ($quat, $vec3) {
+                (self.rotation(), self.translation())
+            }
+
+            /// Returns the inverse transform of `self`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+            #[must_use]
+            #[inline]
+            pub fn inverse(&self) -> Self {
+                glam_assert!(self.is_normalized());
+                Self {
+                    real: self.real.conjugate(),
+                    dual: self.dual.conjugate(),
+                }
+            }
+
+            /// Returns `self` normalized to a unit dual quaternion.
+            ///
+            /// This divides both parts by the length of the real part and removes the component
+            /// of the dual part which is parallel to the real part.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if the real part of `self` has zero length when `glam_assert` is
+            /// enabled.
+            #[must_use]
+            #[inline]
+            pub fn normalize(&self) -> Self {
+                let length = self.real.length();
+                glam_assert!(length != 0.0);
+                let real = self.real / length;
+                let dual = self.dual / length;
+                Self {
+                    real,
+                    dual: dual - real * real.dot(dual),
+                }
+            }
+
+            /// Returns whether `self` is a unit dual quaternion, which is when the real part is
+            /// normalized and orthogonal to the dual part.
+            ///
+            /// Uses a precision threshold of `1e-4`, which is scaled by the length of the dual part
+            /// when it is greater than one so that large translations are not rejected.
+            #[inline]
+            pub fn is_normalized(&self) -> bool {
+                self.real.is_normalized()
+                    && self.real.dot(self.dual).abs() <= 1e-4 * self.dual.length().max(1.0)
+            }
+
+            /// Returns `true` if, and only if, all elements are finite.
+            /// If any element is either `NaN`, positive or negative infinity, this will return
+            /// `false`.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                self.real.is_finite() && self.dual.is_finite()
+            }
+
+            /// Returns `true` if any elements are `NaN`.
+            #[inline]
+            pub fn is_nan(&self) -> bool {
+                self.real.is_nan() || self.dual.is_nan()
+            }
+
+            /// Returns true if the absolute difference of all elements between `self` and `other`
+            /// is less than or equal to `max_abs_diff`.
+            ///
+            /// This can be used to compare if two dual quaternions contain similar elements. It
+            /// works best when comparing with a known value. The `max_abs_diff` that should be
+            /// used used depends on the values being compared against.
+            ///
+            /// For more see
+            /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+            #[inline]
+            pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+                self.real.abs_diff_eq(other.real, max_abs_diff)
+                    && self.dual.abs_diff_eq(other.dual, max_abs_diff)
+            }
+
+            /// Transforms the given 3D point, applying rotation and translation.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn transform_point3(&self, point: $vec3) -> $vec3 {
+                self.real * point + self.translation()
+            }
+
+            /// Transforms the given 3D vector, applying rotation only.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn transform_vector3(&self, vector: $vec3) -> $vec3 {
+                self.real * vector
+            }
+
+            /// Performs a screw linear interpolation (ScLERP) between `self` and `end` based on
+            /// the value `s`.
+            ///
+            /// The interpolated transform moves along the screw motion from `self` to `end`, so
+            /// the rotation and translation change at a constant rate. The shortest path is
+            /// taken regardless of the signs of `self` and `end`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn sclerp(self, end: Self, s: $t) -> Self {
+                glam_assert!(self.is_normalized() && end.is_normalized());
+                let end = if self.real.dot(end.real) < 0.0 {
+                    -end
+                } else {
+                    end
+                };
+                self * (self.inverse() * end).powf(s)
+            }
+
+            // Raises a unit dual quaternion, with a non-negative real `w`, to the power of `s` by
+            // scaling the angle and pitch of its screw motion.
+            fn powf(self, s: $t) -> Self {
+                let (real, dual) = (self.real, self.dual);
+                let sin_half = real.xyz().length();
+                if sin_half <= core::$t::EPSILON {
+                    // No rotation, only a translation
+                    return Self {
+                        real: $quat::IDENTITY,
+                        dual: dual * s,
+                    };
+                }
+
+                let half_angle = sin_half.atan2(real.w);
+                let axis = real.xyz() / sin_half;
+                let half_pitch = -dual.w / sin_half;
+                let moment = (dual.xyz() - axis * (half_pitch * real.w)) / sin_half;
+
+                let (sin_half, cos_half) = (half_angle * s).sin_cos();
+                let half_pitch = half_pitch * s;
+                let real = axis * sin_half;
+                let dual = moment * sin_half + axis * (half_pitch * cos_half);
+                Self {
+                    real: $quat::from_xyzw(real.x, real.y, real.z, cos_half),
+                    dual: $quat::from_xyzw(dual.x, dual.y, dual.z, -half_pitch * sin_half),
+                }
+            }
+
+            // The Hamilton product of two quaternions which are not necessarily normalized
+            #[inline(always)]
+            fn mul_quat(lhs: $quat, rhs: $quat) -> $quat {
+                $quat(lhs.0.mul_quaternion_unnormalized(rhs.0))
+            }
+
+            /// Blends the weighted transforms using dual quaternion linear blending (DLB) from
+            /// Kavan et al. "Skinning with Dual Quaternions" (2007).
+            ///
+            /// Each transform is flipped into the hemisphere of the first one before the
+            /// weighted sum is normalized, so the blend is independent of the signs of the
+            /// inputs. Returns [`Self::IDENTITY`] if `transforms` is empty or the weighted sum
+            /// is zero.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if any of the `transforms` are not normalized when `glam_assert` is
+            /// enabled.
+            pub fn linear_blend(transforms: &[(Self, $t)]) -> Self {
+                let mut sum = Self {
+                    real: Self::ZERO,
+                    dual: Self::ZERO,
+                };
+                if let Some(&(first, _)) = transforms.first() {
+                    for &(transform, weight) in transforms {
+                        glam_assert!(transform.is_normalized());
+                        if first.real.dot(transform.real) < 0.0 {
+                            sum = sum - transform * weight;
+                        } else {
+                            sum = sum + transform * weight;
+                        }
+                    }
+                }
+                if sum.real.length_squared() > 0.0 {
+                    sum.normalize()
+                } else {
+                    Self::IDENTITY
+                }
+            }
+        }
+
+        impl Default for $dual_quat {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Debug for $dual_quat {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.debug_struct(stringify!($dual_quat))
+                    .field("real", &self.real)
+                    .field("dual", &self.dual)
+                    .finish()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Display for $dual_quat {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "[{}, {}]", self.real, self.dual)
+            }
+        }
+
+        impl Mul for $dual_quat {
+            type Output = Self;
+            /// Multiplies two dual quaternions. If they each represent a rigid transform, the
+            /// result will represent `other` followed by `self`.
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                Self {
+                    real: Self::mul_quat(self.real, other.real),
+                    dual: Self::mul_quat(self.real, other.dual)
+                        + Self::mul_quat(self.dual, other.real),
+                }
+            }
+        }
+
+        impl MulAssign for $dual_quat {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl Mul<$t> for $dual_quat {
+            type Output = Self;
+            /// Multiplies both parts of a dual quaternion by a scalar value.
+            ///
+            /// The product is not guaranteed to be normalized.
+            #[inline]
+            fn mul(self, other: $t) -> Self {
+                Self {
+                    real: self.real * other,
+                    dual: self.dual * other,
+                }
+            }
+        }
+
+        impl Add for $dual_quat {
+            type Output = Self;
+            /// Adds two dual quaternions.
+            ///
+            /// The sum is not guaranteed to be normalized.
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self {
+                    real: self.real + other.real,
+                    dual: self.dual + other.dual,
+                }
+            }
+        }
+
+        impl Sub for $dual_quat {
+            type Output = Self;
+            /// Subtracts the other dual quaternion from self.
+            ///
+            /// The difference is not guaranteed to be normalized.
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self {
+                    real: self.real - other.real,
+                    dual: self.dual - other.dual,
+                }
+            }
+        }
+
+        impl Neg for $dual_quat {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self {
+                    real: -self.real,
+                    dual: -self.dual,
+                }
+            }
+        }
+
+        impl<'a> core::iter::Product<&'a Self> for $dual_quat {
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a Self>,
+            {
+                iter.fold(Self::IDENTITY, |a, &b| a * b)
+            }
+        }
+
+        impl AsRef<[$t; 8]> for $dual_quat {
+            #[inline(always)]
+            fn as_ref(&self) -> &[$t; 8] {
+                unsafe { &*(self as *const Self as *const [$t; 8]) }
+            }
+        }
+
+        impl AsMut<[$t; 8]> for $dual_quat {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut [$t; 8] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; 8]) }
+            }
+        }
+
+        impl From<$dual_quat> for $mat4 {
+            #[inline]
+            fn from(dq: $dual_quat) -> $mat4 {
+                $mat4::from_rotation_translation(dq.real, dq.translation())
+            }
+        }
+
+        impl From<$dual_quat> for $affine3 {
+            #[inline]
+            fn from(dq: $dual_quat) -> $affine3 {
+                $affine3::from_rotation_translation(dq.real, dq.translation())
+            }
+        }
+    };
+}
+
+impl_dual_quat!(f32, Quat, Vec3, Mat4, Affine3A, DualQuat);
+impl_dual_quat!(f64, DQuat, DVec3, DMat4, DAffine3, DDualQuat);
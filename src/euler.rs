@@ -1,7 +1,10 @@
 /*
-Conversion from quaternions to Euler rotation sequences.
+Conversion between Euler rotation sequences and quaternions or rotation matrices.
 
-From: http://bediyap.com/programming/convert-quaternion-to-euler-rotations/
+The angles are extracted from the rotation matrix using the approach described in "Euler Angle
+Conversion" by Ken Shoemake, Graphics Gems IV, with the third angle computed from the first one
+as described in "Computing Euler angles from a rotation matrix" by Mike Day, which keeps the
+angles consistent close to gimbal lock.
 */
 
 use super::quat::{DQuat, Quat};
@@ -9,12 +12,24 @@ use crate::core::traits::scalar::*;
 
 /// Euler rotation sequences.
 ///
-/// The angles are applied starting from the right.
-/// E.g. XYZ will first apply the z-axis rotation.
+/// The intrinsic sequences rotate around the axes of the rotating frame and the angles are
+/// applied starting from the right. E.g. XYZ will first apply the z-axis rotation, so
+/// `Quat::from_euler(EulerRot::XYZ, a, b, c)` is equal to
+/// `Quat::from_rotation_x(a) * Quat::from_rotation_y(b) * Quat::from_rotation_z(c)`.
+///
+/// The extrinsic sequences, with the `Ex` suffix, rotate around the fixed world axes and the
+/// angles are applied starting from the left. E.g. XYZEx will first apply the x-axis rotation,
+/// so `Quat::from_euler(EulerRot::XYZEx, a, b, c)` is equal to
+/// `Quat::from_rotation_z(c) * Quat::from_rotation_y(b) * Quat::from_rotation_x(a)`.
 ///
 /// YXZ can be used for yaw (y-axis), pitch (x-axis), roll (z-axis).
 ///
-/// The two-axis rotations (e.g. ZYZ) are not fully tested and have to be treated with caution.
+/// When converting a rotation to Euler angles the second angle is in the range `[-PI/2, PI/2]`
+/// for the three-axis sequences and in the range `[0, PI]` for the two-axis sequences (e.g.
+/// ZYZ). The first and third angles are in the range `[-PI, PI]`. In gimbal lock, when the first
+/// and third axes are aligned, the angle applied first is zero and the angle applied last
+/// contains the whole rotation around the aligned axes. That is the third angle for intrinsic
+/// sequences and the first angle for extrinsic sequences.
 #[derive(Debug, Clone, Copy)]
 pub enum EulerRot {
     /// Intrinsic three-axis rotation ZYX
@@ -31,23 +46,43 @@ pub enum EulerRot {
     XZY,
 
     /// Intrinsic two-axis rotation ZYZ
-    #[deprecated(note = "Untested! Use at own risk!")]
     ZYZ,
     /// Intrinsic two-axis rotation ZXZ
-    #[deprecated(note = "Untested! Use at own risk!")]
     ZXZ,
     /// Intrinsic two-axis rotation YXY
-    #[deprecated(note = "Untested! Use at own risk!")]
     YXY,
     /// Intrinsic two-axis rotation YZY
-    #[deprecated(note = "Untested! Use at own risk!")]
     YZY,
     /// Intrinsic two-axis rotation XYX
-    #[deprecated(note = "Untested! Use at own risk!")]
     XYX,
     /// Intrinsic two-axis rotation XZX
-    #[deprecated(note = "Untested! Use at own risk!")]
     XZX,
+
+    /// Extrinsic three-axis rotation ZYX
+    ZYXEx,
+    /// Extrinsic three-axis rotation ZXY
+    ZXYEx,
+    /// Extrinsic three-axis rotation YXZ
+    YXZEx,
+    /// Extrinsic three-axis rotation YZX
+    YZXEx,
+    /// Extrinsic three-axis rotation XYZ
+    XYZEx,
+    /// Extrinsic three-axis rotation XZY
+    XZYEx,
+
+    /// Extrinsic two-axis rotation ZYZ
+    ZYZEx,
+    /// Extrinsic two-axis rotation ZXZ
+    ZXZEx,
+    /// Extrinsic two-axis rotation YXY
+    YXYEx,
+    /// Extrinsic two-axis rotation YZY
+    YZYEx,
+    /// Extrinsic two-axis rotation XYX
+    XYXEx,
+    /// Extrinsic two-axis rotation XZX
+    XZXEx,
 }
 
 impl Default for EulerRot {
@@ -57,20 +92,112 @@ impl Default for EulerRot {
     }
 }
 
+impl EulerRot {
+    /// Returns the axis indices of the equivalent intrinsic sequence and whether the first and
+    /// third angles are swapped in it.
+    ///
+    /// An extrinsic sequence is equal to the intrinsic sequence of the reversed axes with the
+    /// first and third angles swapped, e.g. XYZEx with the angles `(a, b, c)` is ZYX with the
+    /// angles `(c, b, a)`.
+    fn intrinsic_axes(self) -> ([usize; 3], bool) {
+        use EulerRot::*;
+        const X: usize = 0;
+        const Y: usize = 1;
+        const Z: usize = 2;
+        match self {
+            ZYX => ([Z, Y, X], false),
+            ZXY => ([Z, X, Y], false),
+            YXZ => ([Y, X, Z], false),
+            YZX => ([Y, Z, X], false),
+            XYZ => ([X, Y, Z], false),
+            XZY => ([X, Z, Y], false),
+            ZYZ => ([Z, Y, Z], false),
+            ZXZ => ([Z, X, Z], false),
+            YXY => ([Y, X, Y], false),
+            YZY => ([Y, Z, Y], false),
+            XYX => ([X, Y, X], false),
+            XZX => ([X, Z, X], false),
+            ZYXEx => ([X, Y, Z], true),
+            ZXYEx => ([Y, X, Z], true),
+            YXZEx => ([Z, X, Y], true),
+            YZXEx => ([X, Z, Y], true),
+            XYZEx => ([Z, Y, X], true),
+            XZYEx => ([Y, Z, X], true),
+            ZYZEx => ([Z, Y, Z], true),
+            ZXZEx => ([Z, X, Z], true),
+            YXYEx => ([Y, X, Y], true),
+            YZYEx => ([Y, Z, Y], true),
+            XYXEx => ([X, Y, X], true),
+            XZXEx => ([X, Z, X], true),
+        }
+    }
+
+    /// Returns the angles equivalent to `angles` that are closest to `previous`.
+    ///
+    /// Each angle can be offset by a multiple of `2*PI`, and the sequence has a second solution
+    /// of `(a + PI, PI - b, c + PI)` for three-axis and `(a + PI, -b, c + PI)` for two-axis
+    /// sequences. In gimbal lock only the sum or difference of the first and third angles is
+    /// defined, so it is split to keep both angles as close as possible to `previous`.
+    pub(crate) fn closest_angles<T: FloatEx>(
+        self,
+        angles: (T, T, T),
+        previous: (T, T, T),
+    ) -> (T, T, T) {
+        let pi = T::from_f64(core::f64::consts::PI);
+        let tau = pi + pi;
+        let wrap = |angle: T, target: T| angle + tau * ((target - angle) / tau).round();
+        let (pa, pb, pc) = previous;
+        let closest = |(a, b, c): (T, T, T)| (wrap(a, pa), wrap(b, pb), wrap(c, pc));
+        let distance_squared =
+            |(a, b, c): (T, T, T)| (a - pa) * (a - pa) + (b - pb) * (b - pb) + (c - pc) * (c - pc);
+
+        let ([i, j, k], _) = self.intrinsic_axes();
+        let two_axis = i == k;
+        let (a, b, c) = angles;
+        let (sin_b, cos_b) = b.sin_cos();
+        let threshold = T::from_f32(16.0) * T::EPSILON;
+        let gimbal_lock = if two_axis { sin_b.abs() } else { cos_b.abs() } <= threshold;
+        if gimbal_lock {
+            // The first and third angles can be offset by `t` and `-sign * t`
+            let sign = if two_axis {
+                cos_b.signum()
+            } else if j == (i + 1) % 3 {
+                sin_b.signum()
+            } else {
+                -sin_b.signum()
+            };
+            let (a, b, c) = closest((a, b, c));
+            let t = ((pa - a) + sign * (c - pc)) * T::HALF;
+            closest((a + t, b, c - sign * t))
+        } else {
+            let first = closest((a, b, c));
+            let second = if two_axis {
+                closest((a + pi, -b, c + pi))
+            } else {
+                closest((a + pi, pi - b, c + pi))
+            };
+            if distance_squared(second) < distance_squared(first) {
+                second
+            } else {
+                first
+            }
+        }
+    }
+}
+
 /// Conversion from quaternion to euler angles.
 pub trait EulerFromQuaternion<Q: Copy>: Sized + Copy {
     type Output: FloatEx;
-    /// Compute the angle of the first axis (X-x-x)
-    fn first(self, q: Q) -> Self::Output;
-    /// Compute then angle of the second axis (x-X-x)
-    fn second(self, q: Q) -> Self::Output;
-    /// Compute then angle of the third axis (x-x-X)
-    fn third(self, q: Q) -> Self::Output;
 
     /// Compute all angles of a rotation in the notation order
-    fn convert_quat(self, q: Q) -> (Self::Output, Self::Output, Self::Output) {
-        (self.first(q), self.second(q), self.third(q))
-    }
+    fn convert_quat(self, q: Q) -> (Self::Output, Self::Output, Self::Output);
+}
+
+/// Conversion from rotation matrix to euler angles.
+pub trait EulerFromMatrix<T: FloatEx>: Sized + Copy {
+    /// Compute all angles of a rotation matrix, given as a column major array, in the notation
+    /// order
+    fn convert_mat(self, cols: &[[T; 3]; 3]) -> (T, T, T);
 }
 
 /// Conversion from euler angles to quaternion.
@@ -80,31 +207,52 @@ pub trait EulerToQuaternion<T>: Copy {
     fn new_quat(self, u: T, v: T, w: T) -> Self::Output;
 }
 
-/// Helper, until std::f32::clamp is stable.
-fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
-    assert!(min <= max);
-    if value < min {
-        min
-    } else if value > max {
-        max
-    } else {
-        value
-    }
-}
+impl<T: FloatEx> EulerFromMatrix<T> for EulerRot {
+    fn convert_mat(self, cols: &[[T; 3]; 3]) -> (T, T, T) {
+        let ([i, j, k], swapped) = self.intrinsic_axes();
+        let m = |row: usize, col: usize| cols[col][row];
+        let two_axis = i == k;
+        // Use the remaining axis as the third one of two-axis sequences
+        let k = if two_axis { 3 - i - j } else { k };
+        // The sign of the permutation of the axes
+        let s = if j == (i + 1) % 3 { T::ONE } else { T::NEG_ONE };
+        let threshold = T::from_f32(16.0) * T::EPSILON;
 
-/// Adds a atan2 that handles the negative zero case.
-/// Basically forces positive zero in the x-argument for atan2.
-pub trait Atan2Fixed<T = Self> {
-    fn atan2_fixed(self, other: T) -> T;
-}
-impl Atan2Fixed for f32 {
-    fn atan2_fixed(self, other: f32) -> f32 {
-        self.atan2(if other == 0.0f32 { 0.0f32 } else { other })
-    }
-}
-impl Atan2Fixed for f64 {
-    fn atan2_fixed(self, other: f64) -> f64 {
-        self.atan2(if other == 0.0f64 { 0.0f64 } else { other })
+        let (a, b, c) = if two_axis {
+            // Two-axis sequence, R = R_i(a) * R_j(b) * R_i(c)
+            let sin_b = (m(i, j) * m(i, j) + m(i, k) * m(i, k)).sqrt();
+            let b = sin_b.atan2(m(i, i));
+            if sin_b > threshold {
+                let a = m(j, i).atan2(-s * m(k, i));
+                let (sin_a, cos_a) = a.sin_cos();
+                let c = (-s * cos_a * m(j, k) - sin_a * m(k, k))
+                    .atan2(cos_a * m(j, j) + s * sin_a * m(k, j));
+                (a, b, c)
+            } else {
+                // Gimbal lock
+                ((s * m(k, j)).atan2(m(j, j)), b, T::ZERO)
+            }
+        } else {
+            // Three-axis sequence, R = R_i(a) * R_j(b) * R_k(c)
+            let cos_b = (m(i, i) * m(i, i) + m(i, j) * m(i, j)).sqrt();
+            let b = (s * m(i, k)).atan2(cos_b);
+            if cos_b > threshold {
+                let a = (-s * m(j, k)).atan2(m(k, k));
+                let (sin_a, cos_a) = a.sin_cos();
+                let c = (s * cos_a * m(j, i) + sin_a * m(k, i))
+                    .atan2(cos_a * m(j, j) + s * sin_a * m(k, j));
+                (a, b, c)
+            } else {
+                // Gimbal lock
+                ((s * m(k, j)).atan2(m(j, j)), b, T::ZERO)
+            }
+        };
+
+        if swapped {
+            (c, b, a)
+        } else {
+            (a, b, c)
+        }
     }
 }
 
@@ -112,107 +260,17 @@ macro_rules! impl_from_quat {
     ($t:ty, $quat:ident) => {
         impl EulerFromQuaternion<$quat> for EulerRot {
             type Output = $t;
-            fn first(self, q: $quat) -> $t {
-                use EulerRot::*;
-                match self {
-                    ZYX => (Self::Output::TWO * (q.x * q.y + q.w * q.z))
-                        .atan2(q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z),
-                    ZXY => (-Self::Output::TWO * (q.x * q.y - q.w * q.z))
-                        .atan2(q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z),
-                    YXZ => (Self::Output::TWO * (q.x * q.z + q.w * q.y))
-                        .atan2(q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z),
-                    YZX => (-Self::Output::TWO * (q.x * q.z - q.w * q.y))
-                        .atan2(q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z),
-                    XYZ => (-Self::Output::TWO * (q.y * q.z - q.w * q.x))
-                        .atan2(q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z),
-                    XZY => (Self::Output::TWO * (q.y * q.z + q.w * q.x))
-                        .atan2(q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z),
-                    #[allow(deprecated)]
-                    ZYZ => (Self::Output::TWO * (q.y * q.z + q.w * q.x))
-                        .atan2_fixed(-Self::Output::TWO * (q.x * q.z - q.w * q.y)),
-                    #[allow(deprecated)]
-                    ZXZ => (Self::Output::TWO * (q.x * q.z - q.w * q.y))
-                        .atan2_fixed(Self::Output::TWO * (q.y * q.z + q.w * q.x)),
-                    #[allow(deprecated)]
-                    YXY => (Self::Output::TWO * (q.x * q.y + q.w * q.z))
-                        .atan2_fixed(-Self::Output::TWO * (q.y * q.z - q.w * q.x)),
-                    #[allow(deprecated)]
-                    YZY => (Self::Output::TWO * (q.y * q.z - q.w * q.x))
-                        .atan2_fixed(Self::Output::TWO * (q.x * q.y + q.w * q.z)),
-                    #[allow(deprecated)]
-                    XYX => (Self::Output::TWO * (q.x * q.y - q.w * q.z))
-                        .atan2_fixed(Self::Output::TWO * (q.x * q.z + q.w * q.y)),
-                    #[allow(deprecated)]
-                    XZX => (Self::Output::TWO * (q.x * q.z + q.w * q.y))
-                        .atan2_fixed(-Self::Output::TWO * (q.x * q.y - q.w * q.z)),
-                }
-            }
-
-            fn second(self, q: $quat) -> $t {
-                use EulerRot::*;
-
-                /// Clamp number to range [-1,1](-1,1) for asin() and acos(), else NaN is possible.
-                #[inline(always)]
-                fn arc_clamp<T: FloatEx>(val: T) -> T {
-                    clamp(val, T::NEG_ONE, T::ONE)
-                }
-                match self {
-                    ZYX => arc_clamp(-Self::Output::TWO * (q.x * q.z - q.w * q.y)).asin(),
-                    ZXY => arc_clamp(Self::Output::TWO * (q.y * q.z + q.w * q.x)).asin(),
-                    YXZ => arc_clamp(-Self::Output::TWO * (q.y * q.z - q.w * q.x)).asin(),
-                    YZX => arc_clamp(Self::Output::TWO * (q.x * q.y + q.w * q.z)).asin(),
-                    XYZ => arc_clamp(Self::Output::TWO * (q.x * q.z + q.w * q.y)).asin(),
-                    XZY => arc_clamp(-Self::Output::TWO * (q.x * q.y - q.w * q.z)).asin(),
-                    #[allow(deprecated)]
-                    ZYZ => arc_clamp(q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z).acos(),
-                    #[allow(deprecated)]
-                    ZXZ => arc_clamp(q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z).acos(),
-                    #[allow(deprecated)]
-                    YXY => arc_clamp(q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z).acos(),
-                    #[allow(deprecated)]
-                    YZY => arc_clamp(q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z).acos(),
-                    #[allow(deprecated)]
-                    XYX => arc_clamp(q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z).acos(),
-                    #[allow(deprecated)]
-                    XZX => arc_clamp(q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z).acos(),
-                }
-            }
-
-            fn third(self, q: $quat) -> $t {
-                use EulerRot::*;
-                #[allow(deprecated)]
-                match self {
-                    ZYX => (Self::Output::TWO * (q.y * q.z + q.w * q.x))
-                        .atan2(q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z),
-                    ZXY => (-Self::Output::TWO * (q.x * q.z - q.w * q.y))
-                        .atan2(q.w * q.w - q.x * q.x - q.y * q.y + q.z * q.z),
-                    YXZ => (Self::Output::TWO * (q.x * q.y + q.w * q.z))
-                        .atan2(q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z),
-                    YZX => (-Self::Output::TWO * (q.y * q.z - q.w * q.x))
-                        .atan2(q.w * q.w - q.x * q.x + q.y * q.y - q.z * q.z),
-                    XYZ => (-Self::Output::TWO * (q.x * q.y - q.w * q.z))
-                        .atan2(q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z),
-                    XZY => (Self::Output::TWO * (q.x * q.z + q.w * q.y))
-                        .atan2(q.w * q.w + q.x * q.x - q.y * q.y - q.z * q.z),
-                    #[allow(deprecated)]
-                    ZYZ => (Self::Output::TWO * (q.y * q.z - q.w * q.x))
-                        .atan2_fixed(Self::Output::TWO * (q.x * q.z + q.w * q.y)),
-                    #[allow(deprecated)]
-                    ZXZ => (Self::Output::TWO * (q.x * q.z + q.w * q.y))
-                        .atan2_fixed(-Self::Output::TWO * (q.y * q.z - q.w * q.x)),
-                    #[allow(deprecated)]
-                    YXY => (Self::Output::TWO * (q.x * q.y - q.w * q.z))
-                        .atan2_fixed(Self::Output::TWO * (q.y * q.z + q.w * q.x)),
-                    #[allow(deprecated)]
-                    YZY => (Self::Output::TWO * (q.y * q.z + q.w * q.x))
-                        .atan2_fixed(-Self::Output::TWO * (q.x * q.y - q.w * q.z)),
-                    #[allow(deprecated)]
-                    XYX => (Self::Output::TWO * (q.x * q.y + q.w * q.z))
-                        .atan2_fixed(-Self::Output::TWO * (q.x * q.z - q.w * q.y)),
-                    #[allow(deprecated)]
-                    XZX => (Self::Output::TWO * (q.x * q.z - q.w * q.y))
-                        .atan2_fixed(Self::Output::TWO * (q.x * q.y + q.w * q.z)),
-                }
+            fn convert_quat(self, q: $quat) -> ($t, $t, $t) {
+                // The rotation matrix of `q`, without assuming that `q` is normalized
+                let (x2, y2, z2, w2) = (q.x * q.x, q.y * q.y, q.z * q.z, q.w * q.w);
+                let (xy, xz, yz) = (q.x * q.y, q.x * q.z, q.y * q.z);
+                let (wx, wy, wz) = (q.w * q.x, q.w * q.y, q.w * q.z);
+                let cols = [
+                    [w2 + x2 - y2 - z2, 2.0 * (xy + wz), 2.0 * (xz - wy)],
+                    [2.0 * (xy - wz), w2 - x2 + y2 - z2, 2.0 * (yz + wx)],
+                    [2.0 * (xz + wy), 2.0 * (yz - wx), w2 - x2 - y2 + z2],
+                ];
+                self.convert_mat(&cols)
             }
         }
         // End - impl EulerFromQuaternion
@@ -225,40 +283,17 @@ macro_rules! impl_to_quat {
             type Output = $quat;
             #[inline(always)]
             fn new_quat(self, u: $t, v: $t, w: $t) -> $quat {
-                use EulerRot::*;
                 #[inline(always)]
-                fn rot_x(a: $t) -> $quat {
-                    $quat::from_rotation_x(a)
-                }
-                #[inline(always)]
-                fn rot_y(a: $t) -> $quat {
-                    $quat::from_rotation_y(a)
-                }
-                #[inline(always)]
-                fn rot_z(a: $t) -> $quat {
-                    $quat::from_rotation_z(a)
-                }
-                match self {
-                    ZYX => rot_z(u) * rot_y(v) * rot_x(w),
-                    ZXY => rot_z(u) * rot_x(v) * rot_y(w),
-                    YXZ => rot_y(u) * rot_x(v) * rot_z(w),
-                    YZX => rot_y(u) * rot_z(v) * rot_x(w),
-                    XYZ => rot_x(u) * rot_y(v) * rot_z(w),
-                    XZY => rot_x(u) * rot_z(v) * rot_y(w),
-                    #[allow(deprecated)]
-                    ZYZ => rot_z(u) * rot_y(v) * rot_z(w),
-                    #[allow(deprecated)]
-                    ZXZ => rot_z(u) * rot_x(v) * rot_z(w),
-                    #[allow(deprecated)]
-                    YXY => rot_y(u) * rot_x(v) * rot_y(w),
-                    #[allow(deprecated)]
-                    YZY => rot_y(u) * rot_z(v) * rot_y(w),
-                    #[allow(deprecated)]
-                    XYX => rot_x(u) * rot_y(v) * rot_x(w),
-                    #[allow(deprecated)]
-                    XZX => rot_x(u) * rot_z(v) * rot_x(w),
+                fn rot(axis: usize, a: $t) -> $quat {
+                    match axis {
+                        0 => $quat::from_rotation_x(a),
+                        1 => $quat::from_rotation_y(a),
+                        _ => $quat::from_rotation_z(a),
+                    }
                 }
-                .normalize()
+                let ([i, j, k], swapped) = self.intrinsic_axes();
+                let (u, w) = if swapped { (w, u) } else { (u, w) };
+                (rot(i, u) * rot(j, v) * rot(k, w)).normalize()
             }
         }
         // End - impl EulerToQuaternion
@@ -1,6 +1,7 @@
 use crate::{
-    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2,
-    Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4,
+    Affine2, Affine3A, DAffine2, DAffine3, DDualQuat, DMat2, DMat2x3, DMat2x4, DMat3, DMat3x2,
+    DMat3x4, DMat4, DMat4x2, DMat4x3, DQuat, DVec2, DVec3, DVec4, DualQuat, Mat2, Mat2x3, Mat2x4,
+    Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x2, Mat4x3, Quat, Vec2, Vec3, Vec3A, Vec4,
 };
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
@@ -134,7 +135,14 @@ macro_rules! impl_approx_xzyw_axes {
 impl_approx_as_ref!(f32, Mat2);
 impl_approx_as_ref!(f32, Mat3);
 impl_approx_as_ref!(f32, Mat4);
+impl_approx_as_ref!(f32, Mat2x3);
+impl_approx_as_ref!(f32, Mat3x2);
+impl_approx_as_ref!(f32, Mat2x4);
+impl_approx_as_ref!(f32, Mat4x2);
+impl_approx_as_ref!(f32, Mat3x4);
+impl_approx_as_ref!(f32, Mat4x3);
 impl_approx_as_ref!(f32, Quat);
+impl_approx_as_ref!(f32, DualQuat);
 impl_approx_as_ref!(f32, Vec2);
 impl_approx_as_ref!(f32, Vec3);
 impl_approx_as_ref!(f32, Vec4);
@@ -149,7 +157,14 @@ impl_approx_xzyw_axes!(f64, DAffine3);
 impl_approx_as_ref!(f64, DMat2);
 impl_approx_as_ref!(f64, DMat3);
 impl_approx_as_ref!(f64, DMat4);
+impl_approx_as_ref!(f64, DMat2x3);
+impl_approx_as_ref!(f64, DMat3x2);
+impl_approx_as_ref!(f64, DMat2x4);
+impl_approx_as_ref!(f64, DMat4x2);
+impl_approx_as_ref!(f64, DMat3x4);
+impl_approx_as_ref!(f64, DMat4x3);
 impl_approx_as_ref!(f64, DQuat);
+impl_approx_as_ref!(f64, DDualQuat);
 impl_approx_as_ref!(f64, DVec2);
 impl_approx_as_ref!(f64, DVec3);
 impl_approx_as_ref!(f64, DVec4);
@@ -195,18 +210,40 @@ mod test {
         impl_approx_test!(f32, Vec3A);
         impl_approx_test!(f32, Vec4);
         impl_approx_test!(f32, Quat, Quat::from_slice(&ONESF32));
+        impl_approx_test!(
+            f32,
+            DualQuat,
+            DualQuat::from_real_dual(Quat::from_slice(&ONESF32), Quat::from_slice(&ONESF32))
+        );
         impl_approx_test!(f32, Mat2, Mat2::from_cols_slice(&ONESF32));
         impl_approx_test!(f32, Mat3, Mat3::from_cols_slice(&ONESF32));
         impl_approx_test!(f32, Mat3A, Mat3A::from_cols_slice(&ONESF32));
         impl_approx_test!(f32, Mat4, Mat4::from_cols_slice(&ONESF32));
+        impl_approx_test!(f32, Mat2x3, Mat2x3::from_cols_slice(&ONESF32));
+        impl_approx_test!(f32, Mat3x2, Mat3x2::from_cols_slice(&ONESF32));
+        impl_approx_test!(f32, Mat2x4, Mat2x4::from_cols_slice(&ONESF32));
+        impl_approx_test!(f32, Mat4x2, Mat4x2::from_cols_slice(&ONESF32));
+        impl_approx_test!(f32, Mat3x4, Mat3x4::from_cols_slice(&ONESF32));
+        impl_approx_test!(f32, Mat4x3, Mat4x3::from_cols_slice(&ONESF32));
 
         const ONESF64: [f64; 16] = [1.0; 16];
         impl_approx_test!(f64, DVec2);
         impl_approx_test!(f64, DVec3);
         impl_approx_test!(f64, DVec4);
         impl_approx_test!(f64, DQuat, DQuat::from_slice(&ONESF64));
+        impl_approx_test!(
+            f64,
+            DDualQuat,
+            DDualQuat::from_real_dual(DQuat::from_slice(&ONESF64), DQuat::from_slice(&ONESF64))
+        );
         impl_approx_test!(f64, DMat2, DMat2::from_cols_slice(&ONESF64));
         impl_approx_test!(f64, DMat3, DMat3::from_cols_slice(&ONESF64));
         impl_approx_test!(f64, DMat4, DMat4::from_cols_slice(&ONESF64));
+        impl_approx_test!(f64, DMat2x3, DMat2x3::from_cols_slice(&ONESF64));
+        impl_approx_test!(f64, DMat3x2, DMat3x2::from_cols_slice(&ONESF64));
+        impl_approx_test!(f64, DMat2x4, DMat2x4::from_cols_slice(&ONESF64));
+        impl_approx_test!(f64, DMat4x2, DMat4x2::from_cols_slice(&ONESF64));
+        impl_approx_test!(f64, DMat3x4, DMat3x4::from_cols_slice(&ONESF64));
+        impl_approx_test!(f64, DMat4x3, DMat4x3::from_cols_slice(&ONESF64));
     }
 }
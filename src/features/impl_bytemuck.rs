@@ -1,6 +1,9 @@
 use crate::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, Quat,
-    UVec2, UVec3, UVec4, Vec2, Vec3, Vec4,
+    DDualQuat, DMat2, DMat2x3, DMat2x4, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x2, DMat4x3, DQuat,
+    DVec2, DVec3, DVec4, DualQuat, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4, IVec2,
+    IVec3, IVec4, Mat2, Mat2x3, Mat2x4, Mat3, Mat3x2, Mat3x4, Mat4, Mat4x2, Mat4x3, Quat, U16Vec2,
+    U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec4, Vec2,
+    Vec3, Vec4,
 };
 use bytemuck::{Pod, Zeroable};
 
@@ -10,9 +13,23 @@ unsafe impl Pod for Mat3 {}
 unsafe impl Zeroable for Mat3 {}
 unsafe impl Pod for Mat4 {}
 unsafe impl Zeroable for Mat4 {}
+unsafe impl Pod for Mat2x3 {}
+unsafe impl Zeroable for Mat2x3 {}
+unsafe impl Pod for Mat3x2 {}
+unsafe impl Zeroable for Mat3x2 {}
+unsafe impl Pod for Mat2x4 {}
+unsafe impl Zeroable for Mat2x4 {}
+unsafe impl Pod for Mat4x2 {}
+unsafe impl Zeroable for Mat4x2 {}
+unsafe impl Pod for Mat3x4 {}
+unsafe impl Zeroable for Mat3x4 {}
+unsafe impl Pod for Mat4x3 {}
+unsafe impl Zeroable for Mat4x3 {}
 
 unsafe impl Pod for Quat {}
 unsafe impl Zeroable for Quat {}
+unsafe impl Pod for DualQuat {}
+unsafe impl Zeroable for DualQuat {}
 
 unsafe impl Pod for Vec2 {}
 unsafe impl Zeroable for Vec2 {}
@@ -27,9 +44,23 @@ unsafe impl Pod for DMat3 {}
 unsafe impl Zeroable for DMat3 {}
 unsafe impl Pod for DMat4 {}
 unsafe impl Zeroable for DMat4 {}
+unsafe impl Pod for DMat2x3 {}
+unsafe impl Zeroable for DMat2x3 {}
+unsafe impl Pod for DMat3x2 {}
+unsafe impl Zeroable for DMat3x2 {}
+unsafe impl Pod for DMat2x4 {}
+unsafe impl Zeroable for DMat2x4 {}
+unsafe impl Pod for DMat4x2 {}
+unsafe impl Zeroable for DMat4x2 {}
+unsafe impl Pod for DMat3x4 {}
+unsafe impl Zeroable for DMat3x4 {}
+unsafe impl Pod for DMat4x3 {}
+unsafe impl Zeroable for DMat4x3 {}
 
 unsafe impl Pod for DQuat {}
 unsafe impl Zeroable for DQuat {}
+unsafe impl Pod for DDualQuat {}
+unsafe impl Zeroable for DDualQuat {}
 
 unsafe impl Pod for DVec2 {}
 unsafe impl Zeroable for DVec2 {}
@@ -52,11 +83,62 @@ unsafe impl Zeroable for UVec3 {}
 unsafe impl Pod for UVec4 {}
 unsafe impl Zeroable for UVec4 {}
 
+unsafe impl Pod for I64Vec2 {}
+unsafe impl Zeroable for I64Vec2 {}
+unsafe impl Pod for I64Vec3 {}
+unsafe impl Zeroable for I64Vec3 {}
+unsafe impl Pod for I64Vec4 {}
+unsafe impl Zeroable for I64Vec4 {}
+
+unsafe impl Pod for U64Vec2 {}
+unsafe impl Zeroable for U64Vec2 {}
+unsafe impl Pod for U64Vec3 {}
+unsafe impl Zeroable for U64Vec3 {}
+unsafe impl Pod for U64Vec4 {}
+unsafe impl Zeroable for U64Vec4 {}
+
+unsafe impl Pod for I16Vec2 {}
+unsafe impl Zeroable for I16Vec2 {}
+unsafe impl Pod for I16Vec3 {}
+unsafe impl Zeroable for I16Vec3 {}
+unsafe impl Pod for I16Vec4 {}
+unsafe impl Zeroable for I16Vec4 {}
+
+unsafe impl Pod for U16Vec2 {}
+unsafe impl Zeroable for U16Vec2 {}
+unsafe impl Pod for U16Vec3 {}
+unsafe impl Zeroable for U16Vec3 {}
+unsafe impl Pod for U16Vec4 {}
+unsafe impl Zeroable for U16Vec4 {}
+
+unsafe impl Pod for U8Vec2 {}
+unsafe impl Zeroable for U8Vec2 {}
+unsafe impl Pod for U8Vec3 {}
+unsafe impl Zeroable for U8Vec3 {}
+unsafe impl Pod for U8Vec4 {}
+unsafe impl Zeroable for U8Vec4 {}
+
+#[cfg(feature = "half")]
+mod f16 {
+    use crate::{HVec2, HVec3, HVec4};
+    use bytemuck::{Pod, Zeroable};
+
+    unsafe impl Pod for HVec2 {}
+    unsafe impl Zeroable for HVec2 {}
+    unsafe impl Pod for HVec3 {}
+    unsafe impl Zeroable for HVec3 {}
+    unsafe impl Pod for HVec4 {}
+    unsafe impl Zeroable for HVec4 {}
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
-        DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4,
-        Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4,
+        DDualQuat, DMat2, DMat2x3, DMat2x4, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x2, DMat4x3,
+        DQuat, DVec2, DVec3, DVec4, DualQuat, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3, I64Vec4,
+        IVec2, IVec3, IVec4, Mat2, Mat2x3, Mat2x4, Mat3, Mat3x2, Mat3x4, Mat4, Mat4x2, Mat4x3,
+        Quat, U16Vec2, U16Vec3, U16Vec4, U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2,
+        UVec3, UVec4, Vec2, Vec3, Vec4,
     };
     use core::mem;
 
@@ -75,7 +157,14 @@ mod test {
     test_t!(mat2, Mat2);
     test_t!(mat3, Mat3);
     test_t!(mat4, Mat4);
+    test_t!(mat2x3, Mat2x3);
+    test_t!(mat3x2, Mat3x2);
+    test_t!(mat2x4, Mat2x4);
+    test_t!(mat4x2, Mat4x2);
+    test_t!(mat3x4, Mat3x4);
+    test_t!(mat4x3, Mat4x3);
     test_t!(quat, Quat);
+    test_t!(dual_quat, DualQuat);
     test_t!(vec2, Vec2);
     test_t!(vec3, Vec3);
     test_t!(vec4, Vec4);
@@ -83,7 +172,14 @@ mod test {
     test_t!(dmat2, DMat2);
     test_t!(dmat3, DMat3);
     test_t!(dmat4, DMat4);
+    test_t!(dmat2x3, DMat2x3);
+    test_t!(dmat3x2, DMat3x2);
+    test_t!(dmat2x4, DMat2x4);
+    test_t!(dmat4x2, DMat4x2);
+    test_t!(dmat3x4, DMat3x4);
+    test_t!(dmat4x3, DMat4x3);
     test_t!(dquat, DQuat);
+    test_t!(ddual_quat, DDualQuat);
     test_t!(dvec2, DVec2);
     test_t!(dvec3, DVec3);
     test_t!(dvec4, DVec4);
@@ -95,4 +191,34 @@ mod test {
     test_t!(uvec2, UVec2);
     test_t!(uvec3, UVec3);
     test_t!(uvec4, UVec4);
+
+    test_t!(i64vec2, I64Vec2);
+    test_t!(i64vec3, I64Vec3);
+    test_t!(i64vec4, I64Vec4);
+
+    test_t!(u64vec2, U64Vec2);
+    test_t!(u64vec3, U64Vec3);
+    test_t!(u64vec4, U64Vec4);
+
+    test_t!(i16vec2, I16Vec2);
+    test_t!(i16vec3, I16Vec3);
+    test_t!(i16vec4, I16Vec4);
+
+    test_t!(u16vec2, U16Vec2);
+    test_t!(u16vec3, U16Vec3);
+    test_t!(u16vec4, U16Vec4);
+
+    test_t!(u8vec2, U8Vec2);
+    test_t!(u8vec3, U8Vec3);
+    test_t!(u8vec4, U8Vec4);
+
+    #[cfg(feature = "half")]
+    mod f16 {
+        use super::*;
+        use crate::{HVec2, HVec3, HVec4};
+
+        test_t!(hvec2, HVec2);
+        test_t!(hvec3, HVec3);
+        test_t!(hvec4, HVec4);
+    }
 }
@@ -1,6 +1,8 @@
 use crate::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4,
-    Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, I16Vec2, I16Vec3, I16Vec4, I64Vec2, I64Vec3,
+    I64Vec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, U16Vec2, U16Vec3, U16Vec4,
+    U64Vec2, U64Vec3, U64Vec4, U8Vec2, U8Vec3, U8Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A,
+    Vec4,
 };
 
 macro_rules! impl_vec_types {
@@ -273,6 +275,11 @@ impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
 impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
 impl_vec_types!(i32, IVec2, IVec3, IVec4);
 impl_vec_types!(u32, UVec2, UVec3, UVec4);
+impl_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+impl_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+impl_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+impl_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+impl_vec_types!(u8, U8Vec2, U8Vec3, U8Vec4);
 
 #[cfg(test)]
 mod test {
@@ -487,4 +494,24 @@ mod test {
     mod u32 {
         impl_vec_tests!(u32, UVec2, UVec3, UVec4);
     }
+
+    mod i64 {
+        impl_vec_tests!(i64, I64Vec2, I64Vec3, I64Vec4);
+    }
+
+    mod u64 {
+        impl_vec_tests!(u64, U64Vec2, U64Vec3, U64Vec4);
+    }
+
+    mod i16 {
+        impl_vec_tests!(i16, I16Vec2, I16Vec3, I16Vec4);
+    }
+
+    mod u16 {
+        impl_vec_tests!(u16, U16Vec2, U16Vec3, U16Vec4);
+    }
+
+    mod u8 {
+        impl_vec_tests!(u8, U8Vec2, U8Vec3, U8Vec4);
+    }
 }
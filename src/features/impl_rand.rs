@@ -1,3 +1,92 @@
+pub use self::f32::{UniformVec2, UniformVec3, UniformVec3A, UniformVec4};
+pub use self::f64::{UniformDVec2, UniformDVec3, UniformDVec4};
+
+/// Samples unit quaternions uniformly distributed over all 3D rotations.
+///
+/// Uses the method from Ken Shoemake's *Uniform Random Rotations* in Graphics Gems III.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UniformQuat;
+
+/// Samples unit vectors uniformly distributed on the surface of the unit sphere.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitSphere;
+
+/// Samples vectors uniformly distributed inside the unit sphere.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitBall;
+
+/// Samples unit vectors uniformly distributed on the unit circle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitCircle;
+
+/// Samples vectors uniformly distributed inside the unit circle.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitDisk;
+
+/// Samples unit vectors uniformly distributed on the hemisphere around `normal`.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitHemisphere<T> {
+    /// The normalized direction at the pole of the hemisphere.
+    pub normal: T,
+}
+
+/// Samples unit vectors on the hemisphere around `normal` with a probability density
+/// proportional to the cosine of the angle between the sample and `normal`.
+///
+/// This is commonly used for importance sampling diffuse reflections.
+#[derive(Clone, Copy, Debug)]
+pub struct CosineHemisphere<T> {
+    /// The normalized direction at the pole of the hemisphere.
+    pub normal: T,
+}
+
+macro_rules! impl_uniform_vec {
+    ($t:ty, $vec:ident, $sampler:ident, $($c:ident),+) => {
+        /// A [`UniformSampler`] which samples each vector component uniformly from a range.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $sampler {
+            $($c: UniformFloat<$t>,)+
+        }
+
+        impl SampleUniform for $vec {
+            type Sampler = $sampler;
+        }
+
+        impl UniformSampler for $sampler {
+            type X = $vec;
+
+            #[inline]
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (low.borrow(), high.borrow());
+                Self {
+                    $($c: UniformFloat::<$t>::new(low.$c, high.$c),)+
+                }
+            }
+
+            #[inline]
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (low.borrow(), high.borrow());
+                Self {
+                    $($c: UniformFloat::<$t>::new_inclusive(low.$c, high.$c),)+
+                }
+            }
+
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                $vec::new($(self.$c.sample(rng)),+)
+            }
+        }
+    };
+}
+
 macro_rules! impl_vec_types {
     ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
         impl Distribution<$vec2> for Standard {
@@ -84,13 +173,122 @@ macro_rules! impl_float_types {
         impl Distribution<$quat> for Standard {
             #[inline]
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $quat {
-                let yaw = -PI + rng.gen::<$t>() * 2.0 * PI;
-                let pitch = -PI + rng.gen::<$t>() * 2.0 * PI;
-                let roll = -PI + rng.gen::<$t>() * 2.0 * PI;
-                $quat::from_euler(crate::EulerRot::YXZ, yaw, pitch, roll)
+                UniformQuat.sample(rng)
+            }
+        }
+
+        impl Distribution<$quat> for UniformQuat {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $quat {
+                let u = rng.gen::<$t>();
+                let (sin1, cos1) = (rng.gen::<$t>() * TAU).sin_cos();
+                let (sin2, cos2) = (rng.gen::<$t>() * TAU).sin_cos();
+                let r1 = (1.0 - u).sqrt();
+                let r2 = u.sqrt();
+                $quat::from_xyzw(r1 * sin1, r1 * cos1, r2 * sin2, r2 * cos2)
+            }
+        }
+
+        impl Distribution<$vec2> for UnitCircle {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec2 {
+                let (sin, cos) = (rng.gen::<$t>() * TAU).sin_cos();
+                $vec2::new(cos, sin)
+            }
+        }
+
+        impl Distribution<$vec2> for UnitDisk {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec2 {
+                // The square root compensates for the area growing with the radius.
+                let r = rng.gen::<$t>().sqrt();
+                let v: $vec2 = UnitCircle.sample(rng);
+                v * r
+            }
+        }
+
+        impl Distribution<$vec3> for UnitSphere {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                // By Archimedes' hat-box theorem `z` is uniform on the sphere.
+                let z = 1.0 - 2.0 * rng.gen::<$t>();
+                let (sin, cos) = (rng.gen::<$t>() * TAU).sin_cos();
+                let r = (1.0 - z * z).max(0.0).sqrt();
+                $vec3::new(r * cos, r * sin, z)
+            }
+        }
+
+        impl Distribution<$vec3> for UnitBall {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                // The cube root compensates for the volume growing with the radius.
+                let r = rng.gen::<$t>().cbrt();
+                let v: $vec3 = UnitSphere.sample(rng);
+                v * r
+            }
+        }
+
+        impl UnitHemisphere<$vec3> {
+            /// Creates a distribution over the hemisphere around `normal`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn new(normal: $vec3) -> Self {
+                glam_assert!(normal.is_normalized());
+                Self { normal }
             }
         }
 
+        impl Distribution<$vec3> for UnitHemisphere<$vec3> {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                let v: $vec3 = UnitSphere.sample(rng);
+                if v.dot(self.normal) < 0.0 {
+                    -v
+                } else {
+                    v
+                }
+            }
+        }
+
+        impl CosineHemisphere<$vec3> {
+            /// Creates a cosine weighted distribution over the hemisphere around `normal`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn new(normal: $vec3) -> Self {
+                glam_assert!(normal.is_normalized());
+                Self { normal }
+            }
+        }
+
+        impl Distribution<$vec3> for CosineHemisphere<$vec3> {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                // Malley's method, points uniform on the disk projected up onto the hemisphere.
+                let d: $vec2 = UnitDisk.sample(rng);
+                let z = (1.0 - d.length_squared()).max(0.0).sqrt();
+                let (tangent, bitangent) = orthonormal_pair(self.normal);
+                tangent * d.x + bitangent * d.y + self.normal * z
+            }
+        }
+
+        // From https://graphics.pixar.com/library/OrthonormalB/paper.pdf
+        #[inline]
+        fn orthonormal_pair(n: $vec3) -> ($vec3, $vec3) {
+            let sign: $t = if n.z >= 0.0 { 1.0 } else { -1.0 };
+            let a = -1.0 / (sign + n.z);
+            let b = n.x * n.y * a;
+            (
+                $vec3::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x),
+                $vec3::new(b, sign + n.y * n.y * a, -n.y),
+            )
+        }
+
         #[test]
         fn test_mat2_rand() {
             use rand::{Rng, SeedableRng};
@@ -135,19 +333,169 @@ macro_rules! impl_float_types {
             let b: $quat = rng2.gen();
             assert_eq!(a, b);
         }
+
+        #[test]
+        fn test_uniform_quat_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let mut w_squared_sum = 0.0;
+            let mut x_axis_sum = $vec3::ZERO;
+            for _ in 0..SAMPLE_COUNT {
+                let a: $quat = rng1.sample(UniformQuat);
+                assert!(a.is_normalized());
+                assert_eq!(a, rng2.sample(UniformQuat));
+                w_squared_sum += a.w * a.w;
+                x_axis_sum += a * $vec3::X;
+            }
+            // For uniform rotations E[w²] = 1/4 and rotated vectors average to zero
+            let n = SAMPLE_COUNT as $t;
+            assert!((w_squared_sum / n - 0.25).abs() < 0.02);
+            assert!((x_axis_sum / n).length() < 0.05);
+        }
+
+        #[test]
+        fn test_unit_circle_disk_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let mut circle_sum = $vec2::ZERO;
+            let mut inner_count = 0;
+            for _ in 0..SAMPLE_COUNT {
+                let a: $vec2 = rng1.sample(UnitCircle);
+                assert!(a.is_normalized());
+                assert_eq!(a, rng2.sample(UnitCircle));
+                circle_sum += a;
+
+                let b: $vec2 = rng1.sample(UnitDisk);
+                assert!(b.length() <= 1.0);
+                assert_eq!(b, rng2.sample(UnitDisk));
+                if b.length() < 0.5 {
+                    inner_count += 1;
+                }
+            }
+            // A circle of radius 0.5 covers a quarter of the unit disk
+            let n = SAMPLE_COUNT as $t;
+            assert!((circle_sum / n).length() < 0.05);
+            assert!((inner_count as $t / n - 0.25).abs() < 0.02);
+        }
+
+        #[test]
+        fn test_unit_sphere_ball_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let mut sphere_sum = $vec3::ZERO;
+            let mut inner_count = 0;
+            for _ in 0..SAMPLE_COUNT {
+                let a: $vec3 = rng1.sample(UnitSphere);
+                assert!(a.is_normalized());
+                assert_eq!(a, rng2.sample(UnitSphere));
+                sphere_sum += a;
+
+                let b: $vec3 = rng1.sample(UnitBall);
+                assert!(b.length() <= 1.0);
+                assert_eq!(b, rng2.sample(UnitBall));
+                if b.length() < 0.5 {
+                    inner_count += 1;
+                }
+            }
+            // A sphere of radius 0.5 contains an eighth of the unit ball volume
+            let n = SAMPLE_COUNT as $t;
+            assert!((sphere_sum / n).length() < 0.05);
+            assert!((inner_count as $t / n - 0.125).abs() < 0.02);
+        }
+
+        #[test]
+        fn test_hemisphere_rand() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let normal = $vec3::new(1.0, 2.0, -2.0) / 3.0;
+            let uniform = UnitHemisphere::<$vec3>::new(normal);
+            let cosine = CosineHemisphere::<$vec3>::new(normal);
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let mut uniform_sum = 0.0;
+            let mut cosine_sum = 0.0;
+            for _ in 0..SAMPLE_COUNT {
+                let a: $vec3 = rng1.sample(uniform);
+                assert!(a.is_normalized());
+                assert!(a.dot(normal) >= 0.0);
+                assert_eq!(a, rng2.sample(uniform));
+                uniform_sum += a.dot(normal);
+
+                let b: $vec3 = rng1.sample(cosine);
+                assert!(b.is_normalized());
+                assert!(b.dot(normal) >= -1e-6);
+                assert_eq!(b, rng2.sample(cosine));
+                cosine_sum += b.dot(normal);
+            }
+            // The mean cosine is 1/2 for uniform and 2/3 for cosine weighted sampling
+            let n = SAMPLE_COUNT as $t;
+            assert!((uniform_sum / n - 0.5).abs() < 0.02);
+            assert!((cosine_sum / n - 2.0 / 3.0).abs() < 0.02);
+        }
+
+        #[test]
+        fn test_uniform_vec_rand() {
+            use rand::{distributions::Uniform, Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+            let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+            let low = $vec3::new(-1.0, 2.0, 10.0);
+            let high = $vec3::new(1.0, 3.0, 20.0);
+            let range = Uniform::new(low, high);
+            let inclusive = Uniform::new_inclusive(low, high);
+            for _ in 0..100 {
+                let a: $vec3 = rng1.sample(range);
+                assert!(a.cmpge(low).all() && a.cmplt(high).all());
+                assert_eq!(a, rng2.sample(range));
+
+                let b: $vec3 = rng1.sample(inclusive);
+                assert!(b.cmpge(low).all() && b.cmple(high).all());
+                assert_eq!(b, rng2.sample(inclusive));
+            }
+
+            let range = Uniform::new($vec2::ZERO, $vec2::ONE);
+            let a: $vec2 = rng1.sample(range);
+            assert!(a.cmpge($vec2::ZERO).all() && a.cmplt($vec2::ONE).all());
+            let range = Uniform::new($vec4::ZERO, $vec4::ONE);
+            let b: $vec4 = rng1.sample(range);
+            assert!(b.cmpge($vec4::ZERO).all() && b.cmplt($vec4::ONE).all());
+        }
     };
 }
 
 mod f32 {
+    use super::{
+        CosineHemisphere, UniformQuat, UnitBall, UnitCircle, UnitDisk, UnitHemisphere, UnitSphere,
+    };
     use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
-    use core::f32::consts::PI;
+    use core::f32::consts::TAU;
     use rand::{
-        distributions::{Distribution, Standard},
+        distributions::{
+            uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler},
+            Distribution, Standard,
+        },
         Rng,
     };
 
+    #[cfg(not(feature = "std"))]
+    use num_traits::Float;
+
+    #[cfg(test)]
+    const SAMPLE_COUNT: usize = 10_000;
+
     impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
 
+    impl_uniform_vec!(f32, Vec2, UniformVec2, x, y);
+    impl_uniform_vec!(f32, Vec3, UniformVec3, x, y, z);
+    impl_uniform_vec!(f32, Vec3A, UniformVec3A, x, y, z);
+    impl_uniform_vec!(f32, Vec4, UniformVec4, x, y, z, w);
+
     impl Distribution<Vec3A> for Standard {
         #[inline]
         fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
@@ -155,6 +503,84 @@ mod f32 {
         }
     }
 
+    impl Distribution<Vec3A> for UnitSphere {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+            Vec3A::from(Distribution::<Vec3>::sample(self, rng))
+        }
+    }
+
+    impl Distribution<Vec3A> for UnitBall {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+            Vec3A::from(Distribution::<Vec3>::sample(self, rng))
+        }
+    }
+
+    impl UnitHemisphere<Vec3A> {
+        /// Creates a distribution over the hemisphere around `normal`.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+        #[inline]
+        pub fn new(normal: Vec3A) -> Self {
+            glam_assert!(normal.is_normalized());
+            Self { normal }
+        }
+    }
+
+    impl Distribution<Vec3A> for UnitHemisphere<Vec3A> {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+            let v: Vec3 = UnitHemisphere::<Vec3>::new(self.normal.into()).sample(rng);
+            Vec3A::from(v)
+        }
+    }
+
+    impl CosineHemisphere<Vec3A> {
+        /// Creates a cosine weighted distribution over the hemisphere around `normal`.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+        #[inline]
+        pub fn new(normal: Vec3A) -> Self {
+            glam_assert!(normal.is_normalized());
+            Self { normal }
+        }
+    }
+
+    impl Distribution<Vec3A> for CosineHemisphere<Vec3A> {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+            let v: Vec3 = CosineHemisphere::<Vec3>::new(self.normal.into()).sample(rng);
+            Vec3A::from(v)
+        }
+    }
+
+    #[test]
+    fn test_vec3a_distributions_rand() {
+        use rand::{distributions::Uniform, Rng, SeedableRng};
+        use rand_xoshiro::Xoshiro256Plus;
+        let normal = Vec3A::new(1.0, 2.0, -2.0) / 3.0;
+        let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+        let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+        let a: Vec3A = rng1.sample(UnitSphere);
+        assert_eq!(a, Vec3A::from(rng2.sample::<Vec3, _>(UnitSphere)));
+        let a: Vec3A = rng1.sample(UnitBall);
+        assert_eq!(a, Vec3A::from(rng2.sample::<Vec3, _>(UnitBall)));
+        let a: Vec3A = rng1.sample(UnitHemisphere::<Vec3A>::new(normal));
+        let b: Vec3 = rng2.sample(UnitHemisphere::<Vec3>::new(normal.into()));
+        assert_eq!(a, Vec3A::from(b));
+        let a: Vec3A = rng1.sample(CosineHemisphere::<Vec3A>::new(normal));
+        let b: Vec3 = rng2.sample(CosineHemisphere::<Vec3>::new(normal.into()));
+        assert_eq!(a, Vec3A::from(b));
+        let a: Vec3A = rng1.sample(Uniform::new(Vec3A::ZERO, Vec3A::ONE));
+        let b: Vec3 = rng2.sample(Uniform::new(Vec3::ZERO, Vec3::ONE));
+        assert_eq!(a, Vec3A::from(b));
+    }
+
     #[test]
     fn test_vec3a_rand() {
         use rand::{Rng, SeedableRng};
@@ -168,14 +594,30 @@ mod f32 {
 }
 
 mod f64 {
+    use super::{
+        CosineHemisphere, UniformQuat, UnitBall, UnitCircle, UnitDisk, UnitHemisphere, UnitSphere,
+    };
     use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
-    use core::f64::consts::PI;
+    use core::f64::consts::TAU;
     use rand::{
-        distributions::{Distribution, Standard},
+        distributions::{
+            uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler},
+            Distribution, Standard,
+        },
         Rng,
     };
 
+    #[cfg(not(feature = "std"))]
+    use num_traits::Float;
+
+    #[cfg(test)]
+    const SAMPLE_COUNT: usize = 10_000;
+
     impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+
+    impl_uniform_vec!(f64, DVec2, UniformDVec2, x, y);
+    impl_uniform_vec!(f64, DVec3, UniformDVec3, x, y, z);
+    impl_uniform_vec!(f64, DVec4, UniformDVec4, x, y, z, w);
 }
 
 mod i32 {
@@ -197,3 +639,53 @@ mod u32 {
 
     impl_vec_types!(u32, UVec2, UVec3, UVec4);
 }
+
+mod i64 {
+    use crate::{I64Vec2, I64Vec3, I64Vec4};
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    impl_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+}
+
+mod u64 {
+    use crate::{U64Vec2, U64Vec3, U64Vec4};
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    impl_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+}
+
+mod i16 {
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    impl_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+}
+
+mod u16 {
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    impl_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+}
+
+mod u8 {
+    use crate::{U8Vec2, U8Vec3, U8Vec4};
+    use rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    };
+
+    impl_vec_types!(u8, U8Vec2, U8Vec3, U8Vec4);
+}
@@ -108,6 +108,51 @@ mod u32 {
     impl_rkyv!(UVec4);
 }
 
+mod i64 {
+    use crate::{I64Vec2, I64Vec3, I64Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(I64Vec2);
+    impl_rkyv!(I64Vec3);
+    impl_rkyv!(I64Vec4);
+}
+
+mod u64 {
+    use crate::{U64Vec2, U64Vec3, U64Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(U64Vec2);
+    impl_rkyv!(U64Vec3);
+    impl_rkyv!(U64Vec4);
+}
+
+mod i16 {
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(I16Vec2);
+    impl_rkyv!(I16Vec3);
+    impl_rkyv!(I16Vec4);
+}
+
+mod u16 {
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(U16Vec2);
+    impl_rkyv!(U16Vec3);
+    impl_rkyv!(U16Vec4);
+}
+
+mod u8 {
+    use crate::{U8Vec2, U8Vec3, U8Vec4};
+    use rkyv::{from_archived, to_archived, Archive, Deserialize, Fallible, Serialize};
+
+    impl_rkyv!(U8Vec2);
+    impl_rkyv!(U8Vec3);
+    impl_rkyv!(U8Vec4);
+}
+
 #[cfg(test)]
 mod test {
     pub type DefaultSerializer = rkyv::ser::serializers::CoreSerializer<256, 256>;
@@ -184,5 +229,30 @@ mod test {
         test_archive(&UVec2::new(1, 2));
         test_archive(&UVec3::new(1, 2, 3));
         test_archive(&UVec4::new(1, 2, 3, 4));
+
+        use crate::{I64Vec2, I64Vec3, I64Vec4};
+        test_archive(&I64Vec2::new(-1, 2));
+        test_archive(&I64Vec3::new(-1, 2, 3));
+        test_archive(&I64Vec4::new(-1, 2, 3, 4));
+
+        use crate::{U64Vec2, U64Vec3, U64Vec4};
+        test_archive(&U64Vec2::new(1, 2));
+        test_archive(&U64Vec3::new(1, 2, 3));
+        test_archive(&U64Vec4::new(1, 2, 3, 4));
+
+        use crate::{I16Vec2, I16Vec3, I16Vec4};
+        test_archive(&I16Vec2::new(-1, 2));
+        test_archive(&I16Vec3::new(-1, 2, 3));
+        test_archive(&I16Vec4::new(-1, 2, 3, 4));
+
+        use crate::{U16Vec2, U16Vec3, U16Vec4};
+        test_archive(&U16Vec2::new(1, 2));
+        test_archive(&U16Vec3::new(1, 2, 3));
+        test_archive(&U16Vec4::new(1, 2, 3, 4));
+
+        use crate::{U8Vec2, U8Vec3, U8Vec4};
+        test_archive(&U8Vec2::new(1, 2));
+        test_archive(&U8Vec3::new(1, 2, 3));
+        test_archive(&U8Vec4::new(1, 2, 3, 4));
     }
 }
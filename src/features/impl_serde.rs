@@ -283,6 +283,86 @@ macro_rules! impl_serde_quat {
     };
 }
 
+macro_rules! impl_serde_dual_quat {
+    ($t:ty, $quat:ident, $dual_quat:ident) => {
+        impl Serialize for $dual_quat {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                // Serialize as the real part followed by the dual part:
+                let mut state = serializer.serialize_tuple_struct(stringify!($dual_quat), 8)?;
+                state.serialize_field(&self.real.x)?;
+                state.serialize_field(&self.real.y)?;
+                state.serialize_field(&self.real.z)?;
+                state.serialize_field(&self.real.w)?;
+                state.serialize_field(&self.dual.x)?;
+                state.serialize_field(&self.dual.y)?;
+                state.serialize_field(&self.dual.z)?;
+                state.serialize_field(&self.dual.w)?;
+                state.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $dual_quat {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                struct DualQuatVisitor;
+
+                impl<'de> Visitor<'de> for DualQuatVisitor {
+                    type Value = $dual_quat;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(concat!("struct ", stringify!($dual_quat)))
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<$dual_quat, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let mut f = [0.0; 8];
+                        for (i, v) in f.iter_mut().enumerate() {
+                            *v = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                        }
+                        Ok($dual_quat::from_real_dual(
+                            $quat::from_slice(&f[..4]),
+                            $quat::from_slice(&f[4..]),
+                        ))
+                    }
+                }
+
+                deserializer.deserialize_tuple_struct(stringify!($dual_quat), 8, DualQuatVisitor)
+            }
+        }
+
+        #[test]
+        fn test_dual_quat_serde() {
+            let a = $dual_quat::from_real_dual(
+                $quat::from_xyzw(1.0, 2.0, 3.0, 4.0),
+                $quat::from_xyzw(5.0, 6.0, 7.0, 8.0),
+            );
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0]");
+            let deserialized = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            let deserialized = serde_json::from_str::<$dual_quat>("[]");
+            assert!(deserialized.is_err());
+            let deserialized = serde_json::from_str::<$dual_quat>("[1.0,2.0,3.0,4.0]");
+            assert!(deserialized.is_err());
+            let deserialized =
+                serde_json::from_str::<$dual_quat>("[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
+            assert!(deserialized.is_err());
+            let deserialized =
+                serde_json::from_str::<$dual_quat>("[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0]]");
+            assert!(deserialized.is_err());
+        }
+    };
+}
+
 macro_rules! impl_serde_mat2 {
     ($t:ty, $mat2:ident) => {
         impl Serialize for $mat2 {
@@ -733,7 +813,9 @@ mod test_int {
 mod f32 {
     #[cfg(test)]
     use super::test_float::*;
-    use crate::{Affine2, Affine3A, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    use crate::{
+        Affine2, Affine3A, DualQuat, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4,
+    };
     use core::fmt;
     use serde::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -743,12 +825,13 @@ mod f32 {
     impl_serde_float_types!(f32, Affine2, Affine3A, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
     impl_serde_mat3!(f32, Mat3A, test_mat3a_serde);
     impl_serde_vec3!(f32, Vec3A, test_vec3a_serde);
+    impl_serde_dual_quat!(f32, Quat, DualQuat);
 }
 
 mod f64 {
     #[cfg(test)]
     use super::test_float::*;
-    use crate::{DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+    use crate::{DAffine2, DAffine3, DDualQuat, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
     use core::fmt;
     use serde::{
         de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
@@ -758,6 +841,7 @@ mod f64 {
     impl_serde_float_types!(
         f64, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4
     );
+    impl_serde_dual_quat!(f64, DQuat, DDualQuat);
 }
 
 mod i32 {
@@ -785,3 +869,154 @@ mod u32 {
 
     impl_serde_vec_types!(u32, UVec2, UVec3, UVec4);
 }
+
+mod i64 {
+    #[cfg(test)]
+    use super::test_int::*;
+    use crate::{I64Vec2, I64Vec3, I64Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(i64, I64Vec2, I64Vec3, I64Vec4);
+}
+
+mod u64 {
+    #[cfg(test)]
+    use super::test_int::*;
+    use crate::{U64Vec2, U64Vec3, U64Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(u64, U64Vec2, U64Vec3, U64Vec4);
+}
+
+mod i16 {
+    #[cfg(test)]
+    use super::test_int::*;
+    use crate::{I16Vec2, I16Vec3, I16Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(i16, I16Vec2, I16Vec3, I16Vec4);
+}
+
+mod u16 {
+    #[cfg(test)]
+    use super::test_int::*;
+    use crate::{U16Vec2, U16Vec3, U16Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(u16, U16Vec2, U16Vec3, U16Vec4);
+}
+
+mod u8 {
+    #[cfg(test)]
+    use super::test_int::*;
+    use crate::{U8Vec2, U8Vec3, U8Vec4};
+    use core::fmt;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    impl_serde_vec_types!(u8, U8Vec2, U8Vec3, U8Vec4);
+}
+
+#[cfg(feature = "half")]
+mod f16 {
+    #[cfg(test)]
+    use super::test_float::*;
+    use crate::{HVec2, HVec3, HVec4};
+    use core::fmt;
+    use half::f16;
+    use serde::{
+        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+        ser::{Serialize, SerializeTupleStruct, Serializer},
+    };
+
+    // `f16` elements are serialized as `f32`, which represents every `f16` value exactly.
+    macro_rules! impl_serde_hvec {
+        ($hvecn:ident, $size:literal, $test:ident, $sx:ident, $sx_short:ident, $sx_long:ident) => {
+            impl Serialize for $hvecn {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let mut state = serializer.serialize_tuple_struct(stringify!($hvecn), $size)?;
+                    for v in self.as_ref() {
+                        state.serialize_field(&v.to_f32())?;
+                    }
+                    state.end()
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $hvecn {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    struct HVecVisitor;
+
+                    impl<'de> Visitor<'de> for HVecVisitor {
+                        type Value = $hvecn;
+
+                        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                            formatter.write_str(concat!("struct ", stringify!($hvecn)))
+                        }
+
+                        fn visit_seq<V>(self, mut seq: V) -> Result<$hvecn, V::Error>
+                        where
+                            V: SeqAccess<'de>,
+                        {
+                            let mut a = [f16::ZERO; $size];
+                            for (i, v) in a.iter_mut().enumerate() {
+                                let f: f32 = seq
+                                    .next_element()?
+                                    .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                                *v = f16::from_f32(f);
+                            }
+                            Ok($hvecn::from(a))
+                        }
+                    }
+
+                    deserializer.deserialize_tuple_struct(stringify!($hvecn), $size, HVecVisitor)
+                }
+            }
+
+            #[test]
+            fn $test() {
+                let mut a = $hvecn::ZERO;
+                for (i, v) in a.as_mut().iter_mut().enumerate() {
+                    *v = f16::from_f32((i + 1) as f32);
+                }
+                let serialized = serde_json::to_string(&a).unwrap();
+                assert_eq!($sx, serialized);
+                let deserialized = serde_json::from_str(&serialized).unwrap();
+                assert_eq!(a, deserialized);
+                let deserialized = serde_json::from_str::<$hvecn>(SX0);
+                assert!(deserialized.is_err());
+                let deserialized = serde_json::from_str::<$hvecn>($sx_short);
+                assert!(deserialized.is_err());
+                let deserialized = serde_json::from_str::<$hvecn>($sx_long);
+                assert!(deserialized.is_err());
+            }
+        };
+    }
+
+    impl_serde_hvec!(HVec2, 2, test_hvec2_serde, SX2, SX1, SX3);
+    impl_serde_hvec!(HVec3, 3, test_hvec3_serde, SX3, SX2, SX4);
+    impl_serde_hvec!(HVec4, 4, test_hvec4_serde, SX4, SX3, SX5);
+}
@@ -0,0 +1,240 @@
+// View frustums for visibility culling.
+//
+// The six planes are stored transposed, the `x`, `y`, `z` and `d` of four planes per vector, so
+// that a culling test evaluates four planes at once with a handful of multiply-adds on `Vec4`.
+// The two unused lanes of the second group hold a zero normal and an infinite `d`, the same as the
+// far plane of an infinite projection, so every point is in front of them.
+use crate::{Aabb3, Aabb3A, DAabb3, DMat4, DPlane, DVec3, DVec4, Mat4, Plane, Vec3, Vec4};
+
+macro_rules! impl_frustum {
+    ($t:ident, $vec3:ident, $vec4:ident, $mat4:ident, $plane:ident, $aabb3:ident, $frustum:ident) => {
+        /// A view frustum defined by six inward facing planes.
+        ///
+        /// The planes are ordered left, right, bottom, top, near and far. A frustum created from
+        /// an infinite perspective projection has a far plane with a zero normal and an infinite
+        /// `d`, which every point is in front of.
+        ///
+        /// The culling methods are conservative: a box or sphere that is outside the frustum but
+        /// not fully behind any single plane, for example near a corner, is reported as
+        /// intersecting.
+        #[derive(Clone, Copy, PartialEq)]
+        pub struct $frustum {
+            x: [$vec4; 2],
+            y: [$vec4; 2],
+            z: [$vec4; 2],
+            d: [$vec4; 2],
+        }
+
+        impl $frustum {
+            /// Creates a frustum from inward facing planes ordered left, right, bottom, top, near
+            /// and far.
+            #[inline]
+            pub fn from_planes(planes: [$plane; 6]) -> Self {
+                let [left, right, bottom, top, near, far] = planes;
+                let lanes = |a: $plane, b: $plane, c: $plane, d: $plane| {
+                    (
+                        $vec4::new(a.normal.x, b.normal.x, c.normal.x, d.normal.x),
+                        $vec4::new(a.normal.y, b.normal.y, c.normal.y, d.normal.y),
+                        $vec4::new(a.normal.z, b.normal.z, c.normal.z, d.normal.z),
+                        $vec4::new(a.d, b.d, c.d, d.d),
+                    )
+                };
+                let padding = $plane {
+                    normal: $vec3::ZERO,
+                    d: core::$t::INFINITY,
+                };
+                let (x0, y0, z0, d0) = lanes(left, right, bottom, top);
+                let (x1, y1, z1, d1) = lanes(near, far, padding, padding);
+                Self {
+                    x: [x0, x1],
+                    y: [y0, y1],
+                    z: [z0, z1],
+                    d: [d0, d1],
+                }
+            }
+
+            /// Extracts the frustum from a view projection matrix with a `[0,1]` clip space depth
+            /// range.
+            ///
+            /// This is the convention used by `perspective_lh()`, `perspective_rh()`, their infinite
+            /// variants and the `[0,1]` orthographic projections. For reverse-Z projections use
+            /// [`Self::from_view_projection_reverse_z()`] so that the near and far planes are not
+            /// swapped.
+            #[inline]
+            pub fn from_view_projection(view_projection: &$mat4) -> Self {
+                let m = view_projection;
+                let (r2, r3) = (m.row(2), m.row(3));
+                Self::from_clip_planes(m, r2, r3 - r2)
+            }
+
+            /// Extracts the frustum from a view projection matrix with a `[-1,1]` clip space depth
+            /// range, as created by `perspective_rh_gl()` and `orthographic_rh_gl()`.
+            #[inline]
+            pub fn from_view_projection_gl(view_projection: &$mat4) -> Self {
+                let m = view_projection;
+                let (r2, r3) = (m.row(2), m.row(3));
+                Self::from_clip_planes(m, r3 + r2, r3 - r2)
+            }
+
+            /// Extracts the frustum from a reverse-Z view projection matrix, which maps the near
+            /// plane to a clip space depth of `1` and the far plane to `0`, as created by
+            /// `perspective_infinite_reverse_lh()` and `perspective_infinite_reverse_rh()`.
+            #[inline]
+            pub fn from_view_projection_reverse_z(view_projection: &$mat4) -> Self {
+                let m = view_projection;
+                let (r2, r3) = (m.row(2), m.row(3));
+                Self::from_clip_planes(m, r3 - r2, r2)
+            }
+
+            // Gribb and Hartmann plane extraction, the side planes are the same for every depth
+            // convention.
+            #[inline]
+            fn from_clip_planes(m: &$mat4, near: $vec4, far: $vec4) -> Self {
+                let (r0, r1, r3) = (m.row(0), m.row(1), m.row(3));
+                Self::from_planes([
+                    Self::clip_plane(r3 + r0),
+                    Self::clip_plane(r3 - r0),
+                    Self::clip_plane(r3 + r1),
+                    Self::clip_plane(r3 - r1),
+                    Self::clip_plane(near),
+                    Self::clip_plane(far),
+                ])
+            }
+
+            // The far plane of an infinite projection comes out with a zero normal, which can't
+            // be normalized, so it is replaced by a plane that contains everything.
+            #[inline]
+            fn clip_plane(v: $vec4) -> $plane {
+                if v.truncate().length() <= core::$t::EPSILON * v.length() {
+                    $plane {
+                        normal: $vec3::ZERO,
+                        d: core::$t::INFINITY,
+                    }
+                } else {
+                    $plane::from_vec4(v)
+                }
+            }
+
+            /// Returns the plane at `index`, ordered left, right, bottom, top, near and far.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is greater than 5.
+            #[inline]
+            pub fn plane(&self, index: usize) -> $plane {
+                assert!(index < 6);
+                let (group, lane) = (index / 4, index % 4);
+                $plane {
+                    normal: $vec3::new(
+                        self.x[group][lane],
+                        self.y[group][lane],
+                        self.z[group][lane],
+                    ),
+                    d: self.d[group][lane],
+                }
+            }
+
+            /// Returns the six planes ordered left, right, bottom, top, near and far.
+            #[inline]
+            pub fn planes(&self) -> [$plane; 6] {
+                [
+                    self.plane(0),
+                    self.plane(1),
+                    self.plane(2),
+                    self.plane(3),
+                    self.plane(4),
+                    self.plane(5),
+                ]
+            }
+
+            /// Returns the eight corners of the frustum, the near corners followed by the far
+            /// corners, each in the order bottom left, bottom right, top right and top left.
+            ///
+            /// Returns `None` if the frustum is infinite or degenerate.
+            #[inline]
+            pub fn corners(&self) -> Option<[$vec3; 8]> {
+                let [left, right, bottom, top, near, far] = self.planes();
+                let corner = |a, b, c| $plane::intersect_three_planes(a, b, c);
+                Some([
+                    corner(near, bottom, left)?,
+                    corner(near, bottom, right)?,
+                    corner(near, top, right)?,
+                    corner(near, top, left)?,
+                    corner(far, bottom, left)?,
+                    corner(far, bottom, right)?,
+                    corner(far, top, right)?,
+                    corner(far, top, left)?,
+                ])
+            }
+
+            // Returns `true` if `center` is more than `radius` behind any plane, where `radius`
+            // holds a per plane radius.
+            #[inline(always)]
+            fn is_outside(&self, center: $vec3, radius: [$vec4; 2]) -> bool {
+                let (x, y, z) = (
+                    $vec4::splat(center.x),
+                    $vec4::splat(center.y),
+                    $vec4::splat(center.z),
+                );
+                let distance0 = self.x[0] * x + self.y[0] * y + self.z[0] * z + self.d[0];
+                let distance1 = self.x[1] * x + self.y[1] * y + self.z[1] * z + self.d[1];
+                (distance0 + radius[0]).cmplt($vec4::ZERO).any()
+                    || (distance1 + radius[1]).cmplt($vec4::ZERO).any()
+            }
+
+            /// Returns `true` if `point` is inside or on the boundary of the frustum.
+            #[inline]
+            pub fn contains_point(&self, point: $vec3) -> bool {
+                !self.is_outside(point, [$vec4::ZERO; 2])
+            }
+
+            /// Returns `true` if the sphere at `center` with `radius` may intersect the frustum.
+            #[inline]
+            pub fn intersects_sphere(&self, center: $vec3, radius: $t) -> bool {
+                !self.is_outside(center, [$vec4::splat(radius); 2])
+            }
+
+            /// Returns `true` if `aabb` may intersect the frustum.
+            #[inline]
+            pub fn intersects_aabb(&self, aabb: &$aabb3) -> bool {
+                self.intersects_box(aabb.center(), aabb.half_extents())
+            }
+
+            // Tests the box against each plane using its projected radius onto the plane normal.
+            #[inline(always)]
+            fn intersects_box(&self, center: $vec3, half_extents: $vec3) -> bool {
+                let (ex, ey, ez) = (
+                    $vec4::splat(half_extents.x),
+                    $vec4::splat(half_extents.y),
+                    $vec4::splat(half_extents.z),
+                );
+                let radius =
+                    |i: usize| self.x[i].abs() * ex + self.y[i].abs() * ey + self.z[i].abs() * ez;
+                !self.is_outside(center, [radius(0), radius(1)])
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Debug for $frustum {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.debug_struct(stringify!($frustum))
+                    .field("planes", &self.planes())
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_frustum!(f32, Vec3, Vec4, Mat4, Plane, Aabb3, Frustum);
+
+impl Frustum {
+    /// Returns `true` if `aabb` may intersect the frustum.
+    ///
+    /// See [`Self::intersects_aabb()`] for details.
+    #[inline]
+    pub fn intersects_aabb3a(&self, aabb: &Aabb3A) -> bool {
+        self.intersects_box(aabb.center().into(), aabb.half_extents().into())
+    }
+}
+
+impl_frustum!(f64, DVec3, DVec4, DMat4, DPlane, DAabb3, DFrustum);
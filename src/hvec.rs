@@ -0,0 +1,413 @@
+use crate::core::storage::{XY, XYZ, XYZW};
+use crate::{Vec2, Vec3, Vec3A, Vec4};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use half::f16;
+
+// The F16C intrinsics were stabilized in Rust 1.68, see the MSRV notes in the crate docs.
+#[allow(clippy::incompatible_msrv)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f16c {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    use half::f16;
+
+    /// Returns true if the F16C conversion instructions can be used.
+    #[inline]
+    pub(super) fn is_available() -> bool {
+        #[cfg(target_feature = "f16c")]
+        {
+            true
+        }
+        #[cfg(all(not(target_feature = "f16c"), feature = "std"))]
+        {
+            std::is_x86_feature_detected!("f16c")
+        }
+        #[cfg(all(not(target_feature = "f16c"), not(feature = "std")))]
+        {
+            false
+        }
+    }
+
+    /// Converts 4 `f32` values at a time using `vcvtps2ph`, the remainder are converted in
+    /// software.
+    #[target_feature(enable = "f16c")]
+    pub(super) unsafe fn f32_to_f16_slice(src: &[f32], dst: &mut [f16]) {
+        debug_assert_eq!(src.len(), dst.len());
+        let mut src_chunks = src.chunks_exact(4);
+        let mut dst_chunks = dst.chunks_exact_mut(4);
+        for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+            let v = _mm_loadu_ps(s.as_ptr());
+            let h = _mm_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(v);
+            _mm_storel_epi64(d.as_mut_ptr() as *mut __m128i, h);
+        }
+        super::f32_to_f16_slice_soft(src_chunks.remainder(), dst_chunks.into_remainder());
+    }
+
+    /// Converts 4 `f16` values at a time using `vcvtph2ps`, the remainder are converted in
+    /// software.
+    #[target_feature(enable = "f16c")]
+    pub(super) unsafe fn f16_to_f32_slice(src: &[f16], dst: &mut [f32]) {
+        debug_assert_eq!(src.len(), dst.len());
+        let mut src_chunks = src.chunks_exact(4);
+        let mut dst_chunks = dst.chunks_exact_mut(4);
+        for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+            let h = _mm_loadl_epi64(s.as_ptr() as *const __m128i);
+            let v = _mm_cvtph_ps(h);
+            _mm_storeu_ps(d.as_mut_ptr(), v);
+        }
+        super::f16_to_f32_slice_soft(src_chunks.remainder(), dst_chunks.into_remainder());
+    }
+}
+
+#[inline]
+fn f32_to_f16_slice_soft(src: &[f32], dst: &mut [f16]) {
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = f16::from_f32(*s);
+    }
+}
+
+#[inline]
+fn f16_to_f32_slice_soft(src: &[f16], dst: &mut [f32]) {
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = s.to_f32();
+    }
+}
+
+/// Converts `src` to half precision, rounding to nearest even.
+fn f32_to_f16_slice(src: &[f32], dst: &mut [f16]) {
+    assert_eq!(src.len(), dst.len());
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if f16c::is_available() {
+            unsafe { f16c::f32_to_f16_slice(src, dst) };
+            return;
+        }
+    }
+    f32_to_f16_slice_soft(src, dst)
+}
+
+/// Converts `src` to single precision, this conversion is exact.
+fn f16_to_f32_slice(src: &[f16], dst: &mut [f32]) {
+    assert_eq!(src.len(), dst.len());
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if f16c::is_available() {
+            unsafe { f16c::f16_to_f32_slice(src, dst) };
+            return;
+        }
+    }
+    f16_to_f32_slice_soft(src, dst)
+}
+
+macro_rules! impl_hvecn {
+    ($new:ident, $hvecn:ident, $vecn:ident, $inner:ident, $size:literal,
+     $as_hvecn:ident, $as_vecn:ident, $from_slice:ident, $to_slice:ident, $($c:ident),+) => {
+        /// Creates a new vector.
+        #[inline(always)]
+        pub fn $new($($c: f16),+) -> $hvecn {
+            $hvecn::new($($c),+)
+        }
+
+        impl $hvecn {
+            /// All zeroes.
+            pub const ZERO: Self = Self($inner { $($c: f16::ZERO),+ });
+
+            /// All ones.
+            pub const ONE: Self = Self($inner { $($c: f16::ONE),+ });
+
+            /// All NAN.
+            pub const NAN: Self = Self($inner { $($c: f16::NAN),+ });
+
+            /// Creates a new vector.
+            #[inline(always)]
+            pub fn new($($c: f16),+) -> Self {
+                Self($inner { $($c),+ })
+            }
+
+            /// Creates a vector with all elements set to `v`.
+            #[inline(always)]
+            pub fn splat(v: f16) -> Self {
+                Self($inner { $($c: v),+ })
+            }
+
+            /// Returns the elements of `self` as an array.
+            #[inline(always)]
+            pub fn to_array(&self) -> [f16; $size] {
+                [$(self.$c),+]
+            }
+
+            /// Casts all elements of `self` to `f32`.
+            ///
+            /// Every `f16` value is exactly representable as an `f32` so this conversion is
+            /// lossless.
+            #[inline]
+            pub fn $as_vecn(&self) -> $vecn {
+                $vecn::new($(self.$c.to_f32()),+)
+            }
+
+            /// Converts each element of `src` to half precision, writing the results to `dst`.
+            ///
+            /// Values are rounded to nearest even. On `x86` and `x86_64` the F16C instructions
+            /// are used when they are available, otherwise a software conversion is used.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `src` and `dst` are not the same length.
+            pub fn $from_slice(src: &[$vecn], dst: &mut [$hvecn]) {
+                assert_eq!(src.len(), dst.len());
+                // Safety: both vector types are tightly packed arrays of their element type.
+                let (src, dst) = unsafe {
+                    (
+                        core::slice::from_raw_parts(src.as_ptr() as *const f32, src.len() * $size),
+                        core::slice::from_raw_parts_mut(
+                            dst.as_mut_ptr() as *mut f16,
+                            dst.len() * $size,
+                        ),
+                    )
+                };
+                f32_to_f16_slice(src, dst);
+            }
+
+            /// Converts each element of `src` to single precision, writing the results to `dst`.
+            ///
+            /// On `x86` and `x86_64` the F16C instructions are used when they are available,
+            /// otherwise a software conversion is used.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `src` and `dst` are not the same length.
+            pub fn $to_slice(src: &[$hvecn], dst: &mut [$vecn]) {
+                assert_eq!(src.len(), dst.len());
+                // Safety: both vector types are tightly packed arrays of their element type.
+                let (src, dst) = unsafe {
+                    (
+                        core::slice::from_raw_parts(src.as_ptr() as *const f16, src.len() * $size),
+                        core::slice::from_raw_parts_mut(
+                            dst.as_mut_ptr() as *mut f32,
+                            dst.len() * $size,
+                        ),
+                    )
+                };
+                f16_to_f32_slice(src, dst);
+            }
+        }
+
+        impl $vecn {
+            /// Casts all elements of `self` to `f16`, rounding to nearest even.
+            #[inline]
+            pub fn $as_hvecn(&self) -> $hvecn {
+                $hvecn::new($(f16::from_f32(self.$c)),+)
+            }
+        }
+
+        impl Default for $hvecn {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl PartialEq for $hvecn {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl From<$vecn> for $hvecn {
+            #[inline(always)]
+            fn from(v: $vecn) -> Self {
+                v.$as_hvecn()
+            }
+        }
+
+        impl From<$hvecn> for $vecn {
+            #[inline(always)]
+            fn from(v: $hvecn) -> Self {
+                v.$as_vecn()
+            }
+        }
+
+        impl From<[f16; $size]> for $hvecn {
+            #[inline(always)]
+            fn from(a: [f16; $size]) -> Self {
+                let [$($c),+] = a;
+                Self::new($($c),+)
+            }
+        }
+
+        impl From<$hvecn> for [f16; $size] {
+            #[inline(always)]
+            fn from(v: $hvecn) -> Self {
+                v.to_array()
+            }
+        }
+
+        impl AsRef<[f16; $size]> for $hvecn {
+            #[inline(always)]
+            fn as_ref(&self) -> &[f16; $size] {
+                unsafe { &*(self as *const $hvecn as *const [f16; $size]) }
+            }
+        }
+
+        impl AsMut<[f16; $size]> for $hvecn {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut [f16; $size] {
+                unsafe { &mut *(self as *mut $hvecn as *mut [f16; $size]) }
+            }
+        }
+
+        impl Deref for $hvecn {
+            type Target = $inner<f16>;
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl DerefMut for $hvecn {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Display for $hvecn {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_array(), f)
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $hvecn {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut t = fmt.debug_tuple(stringify!($hvecn));
+                $(t.field(&self.$c);)+
+                t.finish()
+            }
+        }
+    };
+}
+
+/// A 2-dimensional vector of half precision floats.
+///
+/// This is a storage type, convert to a [`Vec2`] to perform arithmetic.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct HVec2(pub(crate) XY<f16>);
+
+impl_hvecn!(
+    hvec2,
+    HVec2,
+    Vec2,
+    XY,
+    2,
+    as_hvec2,
+    as_vec2,
+    from_vec2_slice,
+    to_vec2_slice,
+    x,
+    y
+);
+
+/// A 3-dimensional vector of half precision floats.
+///
+/// This is a storage type, convert to a [`Vec3`] or [`Vec3A`] to perform arithmetic.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct HVec3(pub(crate) XYZ<f16>);
+
+impl_hvecn!(
+    hvec3,
+    HVec3,
+    Vec3,
+    XYZ,
+    3,
+    as_hvec3,
+    as_vec3,
+    from_vec3_slice,
+    to_vec3_slice,
+    x,
+    y,
+    z
+);
+
+impl HVec3 {
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    pub fn as_vec3a(&self) -> Vec3A {
+        Vec3A::new(self.x.to_f32(), self.y.to_f32(), self.z.to_f32())
+    }
+}
+
+impl Vec3A {
+    /// Casts all elements of `self` to `f16`, rounding to nearest even.
+    #[inline]
+    pub fn as_hvec3(&self) -> HVec3 {
+        HVec3::new(
+            f16::from_f32(self.x),
+            f16::from_f32(self.y),
+            f16::from_f32(self.z),
+        )
+    }
+}
+
+impl From<Vec3A> for HVec3 {
+    #[inline(always)]
+    fn from(v: Vec3A) -> Self {
+        v.as_hvec3()
+    }
+}
+
+impl From<HVec3> for Vec3A {
+    #[inline(always)]
+    fn from(v: HVec3) -> Self {
+        v.as_vec3a()
+    }
+}
+
+/// A 4-dimensional vector of half precision floats.
+///
+/// This is a storage type, convert to a [`Vec4`] to perform arithmetic.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct HVec4(pub(crate) XYZW<f16>);
+
+impl_hvecn!(
+    hvec4,
+    HVec4,
+    Vec4,
+    XYZW,
+    4,
+    as_hvec4,
+    as_vec4,
+    from_vec4_slice,
+    to_vec4_slice,
+    x,
+    y,
+    z,
+    w
+);
+
+mod const_test_hvec2 {
+    const_assert_eq!(2, core::mem::align_of::<super::HVec2>());
+    const_assert_eq!(4, core::mem::size_of::<super::HVec2>());
+    const_assert_eq!(8, core::mem::size_of::<crate::Vec2>());
+}
+
+mod const_test_hvec3 {
+    const_assert_eq!(2, core::mem::align_of::<super::HVec3>());
+    const_assert_eq!(6, core::mem::size_of::<super::HVec3>());
+    const_assert_eq!(12, core::mem::size_of::<crate::Vec3>());
+}
+
+mod const_test_hvec4 {
+    const_assert_eq!(2, core::mem::align_of::<super::HVec4>());
+    const_assert_eq!(8, core::mem::size_of::<super::HVec4>());
+    const_assert_eq!(16, core::mem::size_of::<crate::Vec4>());
+}
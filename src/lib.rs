@@ -8,17 +8,52 @@
 * [`f32`](mod@f32) types
   * vectors: [`Vec2`], [`Vec3`], [`Vec3A`] and [`Vec4`]
   * square matrices: [`Mat2`], [`Mat3`], [`Mat3A`] and [`Mat4`]
-  * a quaternion type: [`Quat`]
+  * non-square matrices: [`Mat2x3`], [`Mat3x2`], [`Mat2x4`], [`Mat4x2`], [`Mat3x4`] and
+    [`Mat4x3`]
+  * matrix factorizations: [`Mat3Lu`], [`Mat3Qr`], [`Mat3Cholesky`] and their 2x2 and 4x4
+    equivalents
+  * a 2D rotation type: [`Rot2`]
+  * a quaternion type: [`Quat`] and the [`QuatAccumulator`] rotation blender
   * affine transformation types: [`Affine2`] and [`Affine3A`]
+  * a dual quaternion rigid transformation type: [`DualQuat`]
+  * axis-aligned bounding box types: [`Aabb2`], [`Aabb3`] and [`Aabb3A`]
+  * ray types: [`Ray2`] and [`Ray3`]
+  * a plane type: [`Plane`]
+  * a view frustum type: [`Frustum`]
+  * bounding volume types: [`Sphere`] and [`Obb3`]
 * [`f64`](mod@f64) types
   * vectors: [`DVec2`], [`DVec3`] and [`DVec4`]
   * square matrices: [`DMat2`], [`DMat3`] and [`DMat4`]
-  * a quaternion type: [`DQuat`]
+  * non-square matrices: [`DMat2x3`], [`DMat3x2`], [`DMat2x4`], [`DMat4x2`], [`DMat3x4`] and
+    [`DMat4x3`]
+  * matrix factorizations: [`DMat3Lu`], [`DMat3Qr`], [`DMat3Cholesky`] and their 2x2 and 4x4
+    equivalents
+  * a 2D rotation type: [`DRot2`]
+  * a quaternion type: [`DQuat`] and the [`DQuatAccumulator`] rotation blender
   * affine transformation types: [`DAffine2`] and [`DAffine3`]
+  * a dual quaternion rigid transformation type: [`DDualQuat`]
+  * an axis-aligned bounding box type: [`DAabb3`]
+  * a ray type: [`DRay3`]
+  * a plane type: [`DPlane`]
+  * a view frustum type: [`DFrustum`]
+  * bounding volume types: [`DSphere`] and [`DObb3`]
 * [`i32`](mod@i32) types
   * vectors: [`IVec2`], [`IVec3`] and [`IVec4`]
+  * an axis-aligned bounding box type: [`IAabb3`]
 * [`u32`](mod@u32) types
   * vectors: [`UVec2`], [`UVec3`] and [`UVec4`]
+* [`i64`](mod@i64) types
+  * vectors: [`I64Vec2`], [`I64Vec3`] and [`I64Vec4`]
+* [`u64`](mod@u64) types
+  * vectors: [`U64Vec2`], [`U64Vec3`] and [`U64Vec4`]
+* [`i16`](mod@i16) types
+  * vectors: [`I16Vec2`], [`I16Vec3`] and [`I16Vec4`]
+* [`u16`](mod@u16) types
+  * vectors: [`U16Vec2`], [`U16Vec3`] and [`U16Vec4`]
+* [`u8`](mod@u8) types
+  * vectors: [`U8Vec2`], [`U8Vec3`] and [`U8Vec4`]
+* `f16` types, when the `half` feature is enabled
+  * vectors: `HVec2`, `HVec3` and `HVec4`
 * [`bool`](mod@bool) types
   * vectors: [`BVec2`], [`BVec3`] and [`BVec4`]
 
@@ -214,11 +249,14 @@ and benchmarks.
 * `std` - the default feature, has no dependencies.
 * `approx` - traits and macros for approximate float comparisons
 * `bytemuck` - for casting into slices of bytes
+* `half` - half precision `HVec2`, `HVec3` and `HVec4` storage types using the `f16` type from
+  the `half` crate
 * `libm` - required to compile with `no_std`
 * `mint` - for interoperating with other 3D math libraries
 * `num-traits` - required to compile `no_std`, will be included when enabling
   the `libm` feature
-* `rand` - implementations of `Distribution` trait for all `glam` types.
+* `rand` - implementations of `Distribution` trait for all `glam` types, and uniform rotation
+  and direction distributions in the `distributions` module.
 * `serde` - implementations of `Serialize` and `Deserialize` for all `glam`
   types. Note that serialization should work between builds of `glam` with and without SIMD enabled
 * `scalar-math` - disables SIMD support and uses native alignment for all types.
@@ -231,6 +269,8 @@ and benchmarks.
 
 The minimum supported Rust version is `1.51.0`.
 
+The `half` feature requires Rust `1.68.0` on `x86` and `x86_64` for F16C support.
+
 */
 #![doc(html_root_url = "https://docs.rs/glam/0.19.0")]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -258,15 +298,28 @@ mod vec;
 #[doc(hidden)]
 pub mod cast;
 
+mod aabb;
 mod affine2;
 mod affine3;
 mod core;
+mod dual_quat;
 mod euler;
 mod features;
+mod frustum;
+#[cfg(feature = "half")]
+mod hvec;
 mod mat2;
 mod mat3;
 mod mat4;
+mod mat_factor;
+mod mat_nonsquare;
+mod obb;
+mod plane;
 mod quat;
+mod quat_accumulator;
+mod ray;
+mod rot2;
+mod sphere;
 mod vec2;
 mod vec3;
 mod vec4;
@@ -289,12 +342,28 @@ pub use self::bool::*;
 
 /** `f32` vector, quaternion and matrix types. */
 pub mod f32 {
+    pub use super::aabb::{Aabb2, Aabb3, Aabb3A};
     pub use super::affine2::Affine2;
     pub use super::affine3::Affine3A;
+    pub use super::dual_quat::DualQuat;
+    pub use super::frustum::Frustum;
     pub use super::mat2::{mat2, Mat2};
     pub use super::mat3::{mat3, mat3a, Mat3, Mat3A};
     pub use super::mat4::{mat4, Mat4};
+    pub use super::mat_factor::{
+        Mat2Cholesky, Mat2Lu, Mat2Qr, Mat3Cholesky, Mat3Lu, Mat3Qr, Mat4Cholesky, Mat4Lu, Mat4Qr,
+    };
+    pub use super::mat_nonsquare::{
+        mat2x3, mat2x4, mat3x2, mat3x4, mat4x2, mat4x3, Mat2x3, Mat2x4, Mat3x2, Mat3x4, Mat4x2,
+        Mat4x3,
+    };
+    pub use super::obb::Obb3;
+    pub use super::plane::Plane;
     pub use super::quat::{quat, Quat};
+    pub use super::quat_accumulator::QuatAccumulator;
+    pub use super::ray::{Ray2, Ray3, TriangleHit};
+    pub use super::rot2::Rot2;
+    pub use super::sphere::Sphere;
     pub use super::vec2::{vec2, Vec2};
     pub use super::vec3::{vec3, vec3a, Vec3, Vec3A};
     pub use super::vec4::{vec4, Vec4};
@@ -307,12 +376,29 @@ pub use self::f32::*;
 
 /** `f64` vector, quaternion and matrix types. */
 pub mod f64 {
+    pub use super::aabb::DAabb3;
     pub use super::affine2::DAffine2;
     pub use super::affine3::DAffine3;
+    pub use super::dual_quat::DDualQuat;
+    pub use super::frustum::DFrustum;
     pub use super::mat2::{dmat2, DMat2};
     pub use super::mat3::{dmat3, DMat3};
     pub use super::mat4::{dmat4, DMat4};
+    pub use super::mat_factor::{
+        DMat2Cholesky, DMat2Lu, DMat2Qr, DMat3Cholesky, DMat3Lu, DMat3Qr, DMat4Cholesky, DMat4Lu,
+        DMat4Qr,
+    };
+    pub use super::mat_nonsquare::{
+        dmat2x3, dmat2x4, dmat3x2, dmat3x4, dmat4x2, dmat4x3, DMat2x3, DMat2x4, DMat3x2, DMat3x4,
+        DMat4x2, DMat4x3,
+    };
+    pub use super::obb::DObb3;
+    pub use super::plane::DPlane;
     pub use super::quat::{dquat, DQuat};
+    pub use super::quat_accumulator::DQuatAccumulator;
+    pub use super::ray::{DRay3, DTriangleHit};
+    pub use super::rot2::DRot2;
+    pub use super::sphere::DSphere;
     pub use super::vec2::{dvec2, DVec2};
     pub use super::vec3::{dvec3, DVec3};
     pub use super::vec4::{dvec4, DVec4};
@@ -321,6 +407,7 @@ pub use self::f64::*;
 
 /** `i32` vector types. */
 pub mod i32 {
+    pub use super::aabb::IAabb3;
     pub use super::vec2::{ivec2, IVec2};
     pub use super::vec3::{ivec3, IVec3};
     pub use super::vec4::{ivec4, IVec4};
@@ -335,6 +422,64 @@ pub mod u32 {
 }
 pub use self::u32::*;
 
+/** `i64` vector types. */
+pub mod i64 {
+    pub use super::vec2::{i64vec2, I64Vec2};
+    pub use super::vec3::{i64vec3, I64Vec3};
+    pub use super::vec4::{i64vec4, I64Vec4};
+}
+pub use self::i64::*;
+
+/** `u64` vector types. */
+pub mod u64 {
+    pub use super::vec2::{u64vec2, U64Vec2};
+    pub use super::vec3::{u64vec3, U64Vec3};
+    pub use super::vec4::{u64vec4, U64Vec4};
+}
+pub use self::u64::*;
+
+/** `i16` vector types. */
+pub mod i16 {
+    pub use super::vec2::{i16vec2, I16Vec2};
+    pub use super::vec3::{i16vec3, I16Vec3};
+    pub use super::vec4::{i16vec4, I16Vec4};
+}
+pub use self::i16::*;
+
+/** `u16` vector types. */
+pub mod u16 {
+    pub use super::vec2::{u16vec2, U16Vec2};
+    pub use super::vec3::{u16vec3, U16Vec3};
+    pub use super::vec4::{u16vec4, U16Vec4};
+}
+pub use self::u16::*;
+
+/** `u8` vector types. */
+pub mod u8 {
+    pub use super::vec2::{u8vec2, U8Vec2};
+    pub use super::vec3::{u8vec3, U8Vec3};
+    pub use super::vec4::{u8vec4, U8Vec4};
+}
+pub use self::u8::*;
+
+/** `f16` vector types. */
+#[cfg(feature = "half")]
+pub mod f16 {
+    pub use super::hvec::{hvec2, hvec3, hvec4, HVec2, HVec3, HVec4};
+}
+#[cfg(feature = "half")]
+pub use self::f16::*;
+
+/** Random distributions for `glam` types, when the `rand` feature is enabled. */
+#[cfg(feature = "rand")]
+pub mod distributions {
+    pub use super::features::impl_rand::{
+        CosineHemisphere, UniformDVec2, UniformDVec3, UniformDVec4, UniformQuat, UniformVec2,
+        UniformVec3, UniformVec3A, UniformVec4, UnitBall, UnitCircle, UnitDisk, UnitHemisphere,
+        UnitSphere,
+    };
+}
+
 /** Traits adding swizzle methods to all vector types. */
 pub mod swizzles;
 
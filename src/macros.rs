@@ -482,3 +482,213 @@ macro_rules! const_uvec4 {
         unsafe { $crate::cast::UVec4Cast { ux4: $ux4 }.v4 }
     };
 }
+
+/// Creates a `I64Vec2` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_i64vec2, I64Vec2};
+/// const ONE: I64Vec2 = const_i64vec2!([1; 2]);
+/// const X: I64Vec2 = const_i64vec2!([1, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_i64vec2 {
+    ($ix2:expr) => {
+        unsafe { $crate::cast::I64Vec2Cast { ix2: $ix2 }.v2 }
+    };
+}
+
+/// Creates a `I64Vec3` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_i64vec3, I64Vec3};
+/// const ONE: I64Vec3 = const_i64vec3!([1; 3]);
+/// const X: I64Vec3 = const_i64vec3!([1, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_i64vec3 {
+    ($ix3:expr) => {
+        unsafe { $crate::cast::I64Vec3Cast { ix3: $ix3 }.v3 }
+    };
+}
+
+/// Creates a `I64Vec4` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_i64vec4, I64Vec4};
+/// const ONE: I64Vec4 = const_i64vec4!([1; 4]);
+/// const X: I64Vec4 = const_i64vec4!([1, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_i64vec4 {
+    ($ix4:expr) => {
+        unsafe { $crate::cast::I64Vec4Cast { ix4: $ix4 }.v4 }
+    };
+}
+
+/// Creates a `U64Vec2` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u64vec2, U64Vec2};
+/// const ONE: U64Vec2 = const_u64vec2!([1; 2]);
+/// const X: U64Vec2 = const_u64vec2!([1, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u64vec2 {
+    ($ux2:expr) => {
+        unsafe { $crate::cast::U64Vec2Cast { ux2: $ux2 }.v2 }
+    };
+}
+
+/// Creates a `U64Vec3` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u64vec3, U64Vec3};
+/// const ONE: U64Vec3 = const_u64vec3!([1; 3]);
+/// const X: U64Vec3 = const_u64vec3!([1, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u64vec3 {
+    ($ux3:expr) => {
+        unsafe { $crate::cast::U64Vec3Cast { ux3: $ux3 }.v3 }
+    };
+}
+
+/// Creates a `U64Vec4` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u64vec4, U64Vec4};
+/// const ONE: U64Vec4 = const_u64vec4!([1; 4]);
+/// const X: U64Vec4 = const_u64vec4!([1, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u64vec4 {
+    ($ux4:expr) => {
+        unsafe { $crate::cast::U64Vec4Cast { ux4: $ux4 }.v4 }
+    };
+}
+
+/// Creates a `I16Vec2` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_i16vec2, I16Vec2};
+/// const ONE: I16Vec2 = const_i16vec2!([1; 2]);
+/// const X: I16Vec2 = const_i16vec2!([1, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_i16vec2 {
+    ($ix2:expr) => {
+        unsafe { $crate::cast::I16Vec2Cast { ix2: $ix2 }.v2 }
+    };
+}
+
+/// Creates a `I16Vec3` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_i16vec3, I16Vec3};
+/// const ONE: I16Vec3 = const_i16vec3!([1; 3]);
+/// const X: I16Vec3 = const_i16vec3!([1, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_i16vec3 {
+    ($ix3:expr) => {
+        unsafe { $crate::cast::I16Vec3Cast { ix3: $ix3 }.v3 }
+    };
+}
+
+/// Creates a `I16Vec4` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_i16vec4, I16Vec4};
+/// const ONE: I16Vec4 = const_i16vec4!([1; 4]);
+/// const X: I16Vec4 = const_i16vec4!([1, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_i16vec4 {
+    ($ix4:expr) => {
+        unsafe { $crate::cast::I16Vec4Cast { ix4: $ix4 }.v4 }
+    };
+}
+
+/// Creates a `U16Vec2` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u16vec2, U16Vec2};
+/// const ONE: U16Vec2 = const_u16vec2!([1; 2]);
+/// const X: U16Vec2 = const_u16vec2!([1, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u16vec2 {
+    ($ux2:expr) => {
+        unsafe { $crate::cast::U16Vec2Cast { ux2: $ux2 }.v2 }
+    };
+}
+
+/// Creates a `U16Vec3` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u16vec3, U16Vec3};
+/// const ONE: U16Vec3 = const_u16vec3!([1; 3]);
+/// const X: U16Vec3 = const_u16vec3!([1, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u16vec3 {
+    ($ux3:expr) => {
+        unsafe { $crate::cast::U16Vec3Cast { ux3: $ux3 }.v3 }
+    };
+}
+
+/// Creates a `U16Vec4` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u16vec4, U16Vec4};
+/// const ONE: U16Vec4 = const_u16vec4!([1; 4]);
+/// const X: U16Vec4 = const_u16vec4!([1, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u16vec4 {
+    ($ux4:expr) => {
+        unsafe { $crate::cast::U16Vec4Cast { ux4: $ux4 }.v4 }
+    };
+}
+
+/// Creates a `U8Vec2` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u8vec2, U8Vec2};
+/// const ONE: U8Vec2 = const_u8vec2!([1; 2]);
+/// const X: U8Vec2 = const_u8vec2!([1, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u8vec2 {
+    ($ux2:expr) => {
+        unsafe { $crate::cast::U8Vec2Cast { ux2: $ux2 }.v2 }
+    };
+}
+
+/// Creates a `U8Vec3` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u8vec3, U8Vec3};
+/// const ONE: U8Vec3 = const_u8vec3!([1; 3]);
+/// const X: U8Vec3 = const_u8vec3!([1, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u8vec3 {
+    ($ux3:expr) => {
+        unsafe { $crate::cast::U8Vec3Cast { ux3: $ux3 }.v3 }
+    };
+}
+
+/// Creates a `U8Vec4` that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_u8vec4, U8Vec4};
+/// const ONE: U8Vec4 = const_u8vec4!([1; 4]);
+/// const X: U8Vec4 = const_u8vec4!([1, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! const_u8vec4 {
+    ($ux4:expr) => {
+        unsafe { $crate::cast::U8Vec4Cast { ux4: $ux4 }.v4 }
+    };
+}
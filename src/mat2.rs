@@ -2,7 +2,7 @@ use crate::core::{
     storage::{Columns2, XY},
     traits::matrix::{FloatMatrix2x2, Matrix2x2, MatrixConst},
 };
-use crate::{DMat3, DVec2, Mat3, Vec2};
+use crate::{DMat3, DRot2, DVec2, Mat3, Rot2, Vec2};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -25,7 +25,7 @@ use core::arch::x86_64::*;
 use core::arch::wasm32::v128;
 
 macro_rules! impl_mat2_methods {
-    ($t:ty, $vec2:ident, $mat3:ident, $inner:ident) => {
+    ($t:ty, $vec2:ident, $rot2:ident, $mat3:ident, $inner:ident) => {
         /// A 2x2 matrix with all elements set to `0.0`.
         pub const ZERO: Self = Self($inner::ZERO);
 
@@ -91,6 +91,20 @@ macro_rules! impl_mat2_methods {
             Self($inner::from_angle(angle))
         }
 
+        /// Creates a 2x2 rotation matrix from the given 2D rotation.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+        #[inline]
+        pub fn from_rot2(rotation: $rot2) -> Self {
+            glam_assert!(rotation.is_normalized());
+            Self::from_cols(
+                $vec2::new(rotation.cos, rotation.sin),
+                $vec2::new(-rotation.sin, rotation.cos),
+            )
+        }
+
         /// Creates a 2x2 matrix from a 3x3 matrix, discarding the 2nd row and column.
         #[inline(always)]
         pub fn from_mat3(m: $mat3) -> Self {
@@ -199,6 +213,34 @@ macro_rules! impl_mat2_methods {
             Self(self.0.inverse())
         }
 
+        /// Returns the inverse of `self`, or `None` if `self` is not invertible.
+        ///
+        /// A matrix is considered not invertible if its determinant is zero or if the reciprocal of
+        /// the determinant is not finite.
+        #[must_use]
+        #[inline(always)]
+        pub fn try_inverse(&self) -> Option<Self> {
+            self.0.inverse_with_epsilon(0.0).map(Self)
+        }
+
+        /// Returns the inverse of `self`, or a zero matrix if `self` is not invertible.
+        #[must_use]
+        #[inline]
+        pub fn inverse_or_zero(&self) -> Self {
+            self.try_inverse().unwrap_or(Self::ZERO)
+        }
+
+        /// Returns the inverse of `self`, or `None` if the absolute value of the determinant of
+        /// `self` is less than or equal to `epsilon`.
+        ///
+        /// This is useful for rejecting matrices that are close to singular, whose inverse would be
+        /// numerically unreliable.
+        #[must_use]
+        #[inline(always)]
+        pub fn inverse_with_epsilon(&self, epsilon: $t) -> Option<Self> {
+            self.0.inverse_with_epsilon(epsilon).map(Self)
+        }
+
         /// Transforms a 2D vector.
         #[inline(always)]
         pub fn mul_vec2(&self, other: $vec2) -> $vec2 {
@@ -334,7 +376,7 @@ type InnerF32 = crate::core::storage::Columns2<XY<f32>>;
 pub struct Mat2(pub(crate) InnerF32);
 
 impl Mat2 {
-    impl_mat2_methods!(f32, Vec2, Mat3, InnerF32);
+    impl_mat2_methods!(f32, Vec2, Rot2, Mat3, InnerF32);
 
     #[deprecated(since = "0.18.0", note = "please use `as_dmat2()` instead")]
     #[inline(always)]
@@ -357,7 +399,7 @@ type InnerF64 = crate::core::storage::Columns2<XY<f64>>;
 pub struct DMat2(pub(crate) InnerF64);
 
 impl DMat2 {
-    impl_mat2_methods!(f64, DVec2, DMat3, InnerF64);
+    impl_mat2_methods!(f64, DVec2, DRot2, DMat3, InnerF64);
 
     #[deprecated(since = "0.18.0", note = "please use `as_mat2()` instead")]
     #[inline(always)]
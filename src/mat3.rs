@@ -2,7 +2,10 @@ use crate::core::{
     storage::{Columns3, XYZ},
     traits::matrix::{FloatMatrix3x3, Matrix3x3, MatrixConst},
 };
-use crate::{DMat2, DMat4, DQuat, DVec2, DVec3, EulerRot, Mat2, Mat4, Quat, Vec2, Vec3, Vec3A};
+use crate::euler::EulerFromMatrix;
+use crate::{
+    DMat2, DMat4, DQuat, DRot2, DVec2, DVec3, EulerRot, Mat2, Mat4, Quat, Rot2, Vec2, Vec3, Vec3A,
+};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -57,7 +60,7 @@ macro_rules! define_mat3_struct {
 }
 
 macro_rules! impl_mat3_methods {
-    ($t:ty, $vec3:ident, $vec3a:ident, $vec2:ident, $quat:ident, $mat2:ident, $mat4:ident, $inner:ident) => {
+    ($t:ty, $vec3:ident, $vec3a:ident, $vec2:ident, $quat:ident, $rot2:ident, $mat2:ident, $mat4:ident, $inner:ident) => {
         /// A 3x3 matrix with all elements set to `0.0`.
         pub const ZERO: Self = Self($inner::ZERO);
 
@@ -151,6 +154,38 @@ macro_rules! impl_mat3_methods {
             Self::from_quat(quat)
         }
 
+        /// Returns the angles (in radians) of the given euler rotation sequence for the rotation
+        /// matrix `self`.
+        ///
+        /// If `self` contains scales, shears or other non-rotation transformations the resulting
+        /// angles will be ill-defined.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if any column of `self` is not normalized when `glam_assert` is enabled.
+        pub fn to_euler(&self, order: EulerRot) -> ($t, $t, $t) {
+            glam_assert!(
+                self.x_axis.is_normalized()
+                    && self.y_axis.is_normalized()
+                    && self.z_axis.is_normalized()
+            );
+            order.convert_mat(&self.to_cols_array_2d())
+        }
+
+        /// Returns the angles (in radians) of the given euler rotation sequence for the rotation
+        /// matrix `self` that are closest to the `previous` angles.
+        ///
+        /// Unlike [`Self::to_euler()`] the angles are not limited to a range, any multiple of
+        /// `2*PI` may be added and the equivalent solution of the sequence is picked if it is
+        /// closer.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if any column of `self` is not normalized when `glam_assert` is enabled.
+        pub fn to_euler_closest(&self, order: EulerRot, previous: ($t, $t, $t)) -> ($t, $t, $t) {
+            order.closest_angles(self.to_euler(order), previous)
+        }
+
         /// Creates a 3D rotation matrix from `angle` (in radians) around the x axis.
         #[inline(always)]
         pub fn from_rotation_x(angle: $t) -> Self {
@@ -188,6 +223,19 @@ macro_rules! impl_mat3_methods {
             Self(FloatMatrix3x3::from_angle(angle))
         }
 
+        /// Creates an affine transformation matrix from the given 2D `rotation`.
+        ///
+        /// The resulting matrix can be used to transform 2D points and vectors. See
+        /// [`Self::transform_point2()`] and [`Self::transform_vector2()`].
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+        #[inline]
+        pub fn from_rot2(rotation: $rot2) -> Self {
+            Self::from_mat2($mat2::from_rot2(rotation))
+        }
+
         /// Creates an affine transformation matrix from the given 2D `scale`, rotation `angle` (in
         /// radians) and `translation`.
         ///
@@ -328,6 +376,96 @@ macro_rules! impl_mat3_methods {
             Self(self.0.inverse())
         }
 
+        /// Returns the inverse of `self`, or `None` if `self` is not invertible.
+        ///
+        /// A matrix is considered not invertible if its determinant is zero or if the reciprocal of
+        /// the determinant is not finite.
+        #[must_use]
+        #[inline(always)]
+        pub fn try_inverse(&self) -> Option<Self> {
+            self.0.inverse_with_epsilon(0.0).map(Self)
+        }
+
+        /// Returns the inverse of `self`, or a zero matrix if `self` is not invertible.
+        #[must_use]
+        #[inline]
+        pub fn inverse_or_zero(&self) -> Self {
+            self.try_inverse().unwrap_or(Self::ZERO)
+        }
+
+        /// Returns the inverse of `self`, or `None` if the absolute value of the determinant of
+        /// `self` is less than or equal to `epsilon`.
+        ///
+        /// This is useful for rejecting matrices that are close to singular, whose inverse would be
+        /// numerically unreliable.
+        #[must_use]
+        #[inline(always)]
+        pub fn inverse_with_epsilon(&self, epsilon: $t) -> Option<Self> {
+            self.0.inverse_with_epsilon(epsilon).map(Self)
+        }
+
+        /// Computes the eigen-decomposition of the symmetric matrix `self`, such as an inertia
+        /// tensor or a covariance matrix.
+        ///
+        /// Returns the eigenvalues in descending order and a rotation matrix whose columns are
+        /// the corresponding unit length eigenvectors, so that `self` is equal to
+        /// `v * Self::from_diagonal(eigenvalues) * v.transpose()`. The rotation can be converted
+        /// to a quaternion using `from_mat3` if required.
+        ///
+        /// Only the upper triangle of `self` is read, the lower triangle is assumed to mirror it.
+        ///
+        /// The decomposition uses the cyclic Jacobi method which iterates until the off-diagonal
+        /// elements are negligible. Each eigenvalue is accurate to within a small multiple of
+        /// `EPSILON` times the Frobenius norm of `self`. The error in the direction of each
+        /// eigenvector is proportional to that bound divided by the gap to the closest other
+        /// eigenvalue, so when eigenvalues are repeated any orthonormal basis of their eigenspace
+        /// may be returned.
+        #[must_use]
+        #[inline]
+        pub fn symmetric_eigen(&self) -> ($vec3, Self) {
+            let (eigenvalues, eigenvectors) = self.0.symmetric_eigen();
+            ($vec3(eigenvalues.into()), Self(eigenvectors))
+        }
+
+        /// Computes the singular value decomposition of `self`.
+        ///
+        /// Returns `(u, sigma, v)` such that `self` is equal to
+        /// `u * Self::from_diagonal(sigma) * v.transpose()`, where `u` and `v` are rotation
+        /// matrices and `sigma` contains the singular values sorted by descending magnitude.
+        ///
+        /// If the determinant of `self` is negative the last singular value is negative, so `u`
+        /// and `v` never contain a reflection and can always be converted to quaternions using
+        /// `from_mat3`. Rank deficient input is supported, in which case the singular vectors of
+        /// the zero singular values are an arbitrary orthonormal completion.
+        ///
+        /// The decomposition is computed from the eigenvectors of `self^T * self` using
+        /// [`Self::symmetric_eigen`]. Singular values are accurate to within a small multiple of
+        /// `EPSILON` times the largest singular value, so very small singular values have a large
+        /// relative error.
+        #[must_use]
+        #[inline]
+        pub fn svd(&self) -> (Self, $vec3, Self) {
+            let (u, sigma, v) = self.0.svd();
+            (Self(u), $vec3(sigma.into()), Self(v))
+        }
+
+        /// Computes the polar decomposition of `self`.
+        ///
+        /// Returns `(rotation, stretch)` such that `self` is equal to `rotation * stretch`, where
+        /// `rotation` is a rotation matrix and `stretch` is a symmetric matrix.
+        ///
+        /// `rotation` is never a reflection. If the determinant of `self` is negative the
+        /// reflection is contained in `stretch`, which then has one negative eigenvalue. This is
+        /// the behaviour wanted for shape matching and for handling inverted elements in FEM.
+        ///
+        /// The decomposition is derived from [`Self::svd`] and has the same accuracy.
+        #[must_use]
+        #[inline]
+        pub fn polar(&self) -> (Self, Self) {
+            let (rotation, stretch) = self.0.polar();
+            (Self(rotation), Self(stretch))
+        }
+
         /// Transforms a 3D vector.
         #[inline(always)]
         pub fn mul_vec3(&self, other: $vec3) -> $vec3 {
@@ -473,7 +611,7 @@ type InnerF32 = Columns3<XYZ<f32>>;
 define_mat3_struct!(Mat3, InnerF32);
 
 impl Mat3 {
-    impl_mat3_methods!(f32, Vec3, Vec3, Vec2, Quat, Mat2, Mat4, InnerF32);
+    impl_mat3_methods!(f32, Vec3, Vec3, Vec2, Quat, Rot2, Mat2, Mat4, InnerF32);
 
     /// Transforms a `Vec3A`.
     #[inline]
@@ -521,7 +659,7 @@ type InnerF32A = Columns3<crate::core::storage::XYZF32A16>;
 define_mat3_struct!(Mat3A, InnerF32A);
 
 impl Mat3A {
-    impl_mat3_methods!(f32, Vec3, Vec3A, Vec2, Quat, Mat2, Mat4, InnerF32A);
+    impl_mat3_methods!(f32, Vec3, Vec3A, Vec2, Quat, Rot2, Mat2, Mat4, InnerF32A);
 
     /// Transforms a `Vec3A`.
     #[inline]
@@ -572,7 +710,7 @@ type InnerF64 = Columns3<XYZ<f64>>;
 define_mat3_struct!(DMat3, InnerF64);
 
 impl DMat3 {
-    impl_mat3_methods!(f64, DVec3, DVec3, DVec2, DQuat, DMat2, DMat4, InnerF64);
+    impl_mat3_methods!(f64, DVec3, DVec3, DVec2, DQuat, DRot2, DMat2, DMat4, InnerF64);
 
     #[deprecated(since = "0.18.0", note = "please use `as_mat3()` instead")]
     #[inline(always)]
@@ -5,6 +5,7 @@ use crate::core::{
         projection::ProjectionMatrix,
     },
 };
+use crate::euler::EulerFromMatrix;
 use crate::{DMat3, DQuat, DVec3, DVec4, EulerRot, Mat3, Quat, Vec3, Vec3A, Vec4};
 
 #[cfg(all(
@@ -230,6 +231,26 @@ macro_rules! impl_mat4_methods {
             Self::from_quat(quat)
         }
 
+        /// Returns the angles (in radians) of the given euler rotation sequence for the rotation
+        /// in the upper 3x3 matrix of `self`.
+        ///
+        /// If the upper 3x3 matrix contains scales, shears or other non-rotation transformations
+        /// the resulting angles will be ill-defined.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if any column of the upper 3x3 matrix is not normalized when `glam_assert`
+        /// is enabled.
+        pub fn to_euler(&self, order: EulerRot) -> ($t, $t, $t) {
+            let x_axis = self.x_axis.truncate();
+            let y_axis = self.y_axis.truncate();
+            let z_axis = self.z_axis.truncate();
+            glam_assert!(
+                x_axis.is_normalized() && y_axis.is_normalized() && z_axis.is_normalized()
+            );
+            order.convert_mat(&[x_axis.into(), y_axis.into(), z_axis.into()])
+        }
+
         /// Creates an affine transformation matrix containing a 3D rotation around the x axis of
         /// `angle` (in radians).
         ///
@@ -386,6 +407,34 @@ macro_rules! impl_mat4_methods {
             Self(self.0.inverse())
         }
 
+        /// Returns the inverse of `self`, or `None` if `self` is not invertible.
+        ///
+        /// A matrix is considered not invertible if its determinant is zero or if the reciprocal of
+        /// the determinant is not finite.
+        #[must_use]
+        #[inline(always)]
+        pub fn try_inverse(&self) -> Option<Self> {
+            self.0.inverse_with_epsilon(0.0).map(Self)
+        }
+
+        /// Returns the inverse of `self`, or a zero matrix if `self` is not invertible.
+        #[must_use]
+        #[inline]
+        pub fn inverse_or_zero(&self) -> Self {
+            self.try_inverse().unwrap_or(Self::ZERO)
+        }
+
+        /// Returns the inverse of `self`, or `None` if the absolute value of the determinant of
+        /// `self` is less than or equal to `epsilon`.
+        ///
+        /// This is useful for rejecting matrices that are close to singular, whose inverse would be
+        /// numerically unreliable.
+        #[must_use]
+        #[inline(always)]
+        pub fn inverse_with_epsilon(&self, epsilon: $t) -> Option<Self> {
+            self.0.inverse_with_epsilon(epsilon).map(Self)
+        }
+
         /// Creates a left-handed view matrix using a camera position, an up direction, and a focal
         /// point.
         /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=forward`.
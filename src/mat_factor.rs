@@ -0,0 +1,464 @@
+// Matrix factorizations and linear system solvers for the square matrix types.
+//
+// The factorizations work on row major copies of the matrix elements, `a[row][col]`, which keeps
+// the row operations used by the algorithms below straightforward.
+use crate::{DMat2, DMat3, DMat4, DVec2, DVec3, DVec4, Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+macro_rules! impl_mat_factor {
+    ($t:ty, $n:tt, $mat:ident, $vec:ident,
+     $lu_doc:expr, $lu:ident, $qr_doc:expr, $qr:ident, $cholesky_doc:expr, $cholesky:ident) => {
+        impl $mat {
+            /// Solves the linear system `self * x = rhs` for `x`.
+            ///
+            /// This uses an LU decomposition with partial pivoting which is faster and more
+            /// accurate than multiplying `rhs` by the inverse of `self`. If you need to solve
+            /// several systems with the same matrix use [`Self::lu()`] and reuse the result.
+            ///
+            /// Returns `None` if `self` is singular or so close to singular that the result would be
+            /// dominated by rounding error.
+            #[must_use]
+            #[inline]
+            pub fn solve(&self, rhs: $vec) -> Option<$vec> {
+                self.lu().map(|lu| lu.solve(rhs))
+            }
+
+            /// Computes the LU decomposition of `self` with partial pivoting.
+            ///
+            /// Returns `None` if `self` is singular or so close to singular that the result would be
+            /// dominated by rounding error.
+            #[must_use]
+            #[allow(clippy::needless_range_loop)]
+            pub fn lu(&self) -> Option<$lu> {
+                let mut lu = self.transpose().to_cols_array_2d();
+                let mut perm = [0; $n];
+                for (i, p) in perm.iter_mut().enumerate() {
+                    *p = i;
+                }
+                let mut sign = 1.0;
+                // Pivots this small relative to the elements of `self` are round off from a
+                // singular matrix
+                let tolerance = $n as $t
+                    * <$t>::EPSILON
+                    * lu.iter().flatten().fold(0.0, |max: $t, e| max.max(e.abs()));
+                for k in 0..$n {
+                    // Pick the row with the largest magnitude in column `k` as the pivot
+                    let mut pivot = k;
+                    let mut max = lu[k][k].abs();
+                    for i in k + 1..$n {
+                        if lu[i][k].abs() > max {
+                            pivot = i;
+                            max = lu[i][k].abs();
+                        }
+                    }
+                    if max <= tolerance || max.is_nan() {
+                        return None;
+                    }
+                    if pivot != k {
+                        lu.swap(pivot, k);
+                        perm.swap(pivot, k);
+                        sign = -sign;
+                    }
+                    for i in k + 1..$n {
+                        let factor = lu[i][k] / lu[k][k];
+                        lu[i][k] = factor;
+                        for j in k + 1..$n {
+                            lu[i][j] -= factor * lu[k][j];
+                        }
+                    }
+                }
+                Some($lu { lu, perm, sign })
+            }
+
+            /// Computes the QR decomposition of `self` using Householder reflections.
+            ///
+            /// Returns `None` if `self` is singular or so close to singular that the result would be
+            /// dominated by rounding error.
+            #[must_use]
+            #[allow(clippy::needless_range_loop)]
+            pub fn qr(&self) -> Option<$qr> {
+                let mut r = self.transpose().to_cols_array_2d();
+                // Diagonal elements this small relative to the elements of `self` are round off
+                // from a singular matrix
+                let tolerance = $n as $t
+                    * <$t>::EPSILON
+                    * r.iter().flatten().fold(0.0, |max: $t, e| max.max(e.abs()));
+                let mut q = $mat::IDENTITY.to_cols_array_2d();
+                let mut q_det = 1.0;
+                for k in 0..$n - 1 {
+                    let mut norm_sq = 0.0;
+                    for i in k..$n {
+                        norm_sq += r[i][k] * r[i][k];
+                    }
+                    let norm = norm_sq.sqrt();
+                    // Reflect onto the axis with the opposite sign to avoid cancellation
+                    let alpha = if r[k][k] < 0.0 { norm } else { -norm };
+                    let mut v = [0.0; $n];
+                    for i in k..$n {
+                        v[i] = r[i][k];
+                    }
+                    v[k] -= alpha;
+                    let mut v_len_sq = 0.0;
+                    for i in k..$n {
+                        v_len_sq += v[i] * v[i];
+                    }
+                    if v_len_sq == 0.0 {
+                        continue;
+                    }
+                    let scale = 2.0 / v_len_sq;
+
+                    // r = h * r where h = I - scale * v * v^T
+                    for j in 0..$n {
+                        let mut dot = 0.0;
+                        for i in k..$n {
+                            dot += v[i] * r[i][j];
+                        }
+                        for i in k..$n {
+                            r[i][j] -= scale * dot * v[i];
+                        }
+                    }
+                    // q = q * h
+                    for row in q.iter_mut() {
+                        let mut dot = 0.0;
+                        for i in k..$n {
+                            dot += row[i] * v[i];
+                        }
+                        for i in k..$n {
+                            row[i] -= scale * dot * v[i];
+                        }
+                    }
+                    q_det = -q_det;
+                }
+                // Clean up the round off below the diagonal
+                for i in 1..$n {
+                    for j in 0..i {
+                        r[i][j] = 0.0;
+                    }
+                }
+                for k in 0..$n {
+                    if r[k][k].abs() <= tolerance || r[k][k].is_nan() {
+                        return None;
+                    }
+                }
+                Some($qr { q, r, q_det })
+            }
+
+            /// Computes the Cholesky decomposition of `self`, which must be symmetric positive
+            /// definite. Only the upper triangle of `self` is read.
+            ///
+            /// Returns `None` if `self` is not positive definite.
+            #[must_use]
+            #[allow(clippy::needless_range_loop)]
+            pub fn cholesky(&self) -> Option<$cholesky> {
+                let a = self.transpose().to_cols_array_2d();
+                let mut l = [[0.0; $n]; $n];
+                for j in 0..$n {
+                    let mut d = a[j][j];
+                    for k in 0..j {
+                        d -= l[j][k] * l[j][k];
+                    }
+                    if d <= 0.0 || d.is_nan() {
+                        return None;
+                    }
+                    let l_jj = d.sqrt();
+                    l[j][j] = l_jj;
+                    for i in j + 1..$n {
+                        let mut s = a[j][i];
+                        for k in 0..j {
+                            s -= l[i][k] * l[j][k];
+                        }
+                        l[i][j] = s / l_jj;
+                    }
+                }
+                Some($cholesky { l })
+            }
+        }
+
+        #[doc = $lu_doc]
+        ///
+        /// The decomposition satisfies `p * a = l * u` where `p` is a permutation matrix, `l` is
+        /// a unit lower triangular matrix and `u` is an upper triangular matrix.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $lu {
+            // `l` below the diagonal and `u` on and above the diagonal, row major
+            lu: [[$t; $n]; $n],
+            // `perm[i]` is the row of the original matrix that ended up in row `i`
+            perm: [usize; $n],
+            // The determinant of the permutation
+            sign: $t,
+        }
+
+        impl $lu {
+            /// Solves the linear system `a * x = rhs` for `x`.
+            #[must_use]
+            #[allow(clippy::needless_range_loop)]
+            pub fn solve(&self, rhs: $vec) -> $vec {
+                let b = rhs.to_array();
+                let mut x = [0.0; $n];
+                // Forward substitution with the permuted right hand side
+                for i in 0..$n {
+                    let mut s = b[self.perm[i]];
+                    for j in 0..i {
+                        s -= self.lu[i][j] * x[j];
+                    }
+                    x[i] = s;
+                }
+                // Back substitution
+                for i in (0..$n).rev() {
+                    let mut s = x[i];
+                    for j in i + 1..$n {
+                        s -= self.lu[i][j] * x[j];
+                    }
+                    x[i] = s / self.lu[i][i];
+                }
+                $vec::from(x)
+            }
+
+            /// Returns the determinant of the decomposed matrix.
+            #[must_use]
+            pub fn determinant(&self) -> $t {
+                let mut det = self.sign;
+                for (i, row) in self.lu.iter().enumerate() {
+                    det *= row[i];
+                }
+                det
+            }
+
+            /// Returns the inverse of the decomposed matrix.
+            #[must_use]
+            pub fn inverse(&self) -> $mat {
+                let mut cols = $mat::IDENTITY.to_cols_array_2d();
+                for col in cols.iter_mut() {
+                    *col = self.solve($vec::from(*col)).to_array();
+                }
+                $mat::from_cols_array_2d(&cols)
+            }
+
+            /// Returns the unit lower triangular matrix `l`.
+            #[must_use]
+            pub fn l(&self) -> $mat {
+                let mut l = $mat::IDENTITY.to_cols_array_2d();
+                for (i, row) in self.lu.iter().enumerate() {
+                    l[i][..i].copy_from_slice(&row[..i]);
+                }
+                $mat::from_cols_array_2d(&l).transpose()
+            }
+
+            /// Returns the upper triangular matrix `u`.
+            #[must_use]
+            pub fn u(&self) -> $mat {
+                let mut u = [[0.0; $n]; $n];
+                for (i, row) in self.lu.iter().enumerate() {
+                    u[i][i..].copy_from_slice(&row[i..]);
+                }
+                $mat::from_cols_array_2d(&u).transpose()
+            }
+
+            /// Returns the permutation matrix `p`.
+            #[must_use]
+            pub fn p(&self) -> $mat {
+                let mut p = [[0.0; $n]; $n];
+                for (i, &j) in self.perm.iter().enumerate() {
+                    p[i][j] = 1.0;
+                }
+                $mat::from_cols_array_2d(&p).transpose()
+            }
+        }
+
+        #[doc = $qr_doc]
+        ///
+        /// The decomposition satisfies `a = q * r` where `q` is an orthogonal matrix and `r` is an
+        /// upper triangular matrix.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $qr {
+            // Row major
+            q: [[$t; $n]; $n],
+            // Row major
+            r: [[$t; $n]; $n],
+            // The determinant of `q`, which is `-1` for an odd number of reflections
+            q_det: $t,
+        }
+
+        impl $qr {
+            /// Solves the linear system `a * x = rhs` for `x`.
+            #[must_use]
+            #[allow(clippy::needless_range_loop)]
+            pub fn solve(&self, rhs: $vec) -> $vec {
+                let b = rhs.to_array();
+                // x = r^-1 * q^T * b
+                let mut x = [0.0; $n];
+                for i in 0..$n {
+                    for j in 0..$n {
+                        x[i] += self.q[j][i] * b[j];
+                    }
+                }
+                for i in (0..$n).rev() {
+                    let mut s = x[i];
+                    for j in i + 1..$n {
+                        s -= self.r[i][j] * x[j];
+                    }
+                    x[i] = s / self.r[i][i];
+                }
+                $vec::from(x)
+            }
+
+            /// Returns the determinant of the decomposed matrix.
+            #[must_use]
+            pub fn determinant(&self) -> $t {
+                let mut det = self.q_det;
+                for (i, row) in self.r.iter().enumerate() {
+                    det *= row[i];
+                }
+                det
+            }
+
+            /// Returns the orthogonal matrix `q`.
+            #[must_use]
+            pub fn q(&self) -> $mat {
+                $mat::from_cols_array_2d(&self.q).transpose()
+            }
+
+            /// Returns the upper triangular matrix `r`.
+            #[must_use]
+            pub fn r(&self) -> $mat {
+                $mat::from_cols_array_2d(&self.r).transpose()
+            }
+        }
+
+        #[doc = $cholesky_doc]
+        ///
+        /// The decomposition satisfies `a = l * l^T` where `l` is a lower triangular matrix with
+        /// a positive diagonal.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $cholesky {
+            // Row major
+            l: [[$t; $n]; $n],
+        }
+
+        impl $cholesky {
+            /// Solves the linear system `a * x = rhs` for `x`.
+            #[must_use]
+            #[allow(clippy::needless_range_loop)]
+            pub fn solve(&self, rhs: $vec) -> $vec {
+                let mut x = rhs.to_array();
+                // Forward substitution with `l`
+                for i in 0..$n {
+                    for j in 0..i {
+                        x[i] -= self.l[i][j] * x[j];
+                    }
+                    x[i] /= self.l[i][i];
+                }
+                // Back substitution with `l^T`
+                for i in (0..$n).rev() {
+                    for j in i + 1..$n {
+                        x[i] -= self.l[j][i] * x[j];
+                    }
+                    x[i] /= self.l[i][i];
+                }
+                $vec::from(x)
+            }
+
+            /// Returns the determinant of the decomposed matrix.
+            #[must_use]
+            pub fn determinant(&self) -> $t {
+                let mut det = 1.0;
+                for (i, row) in self.l.iter().enumerate() {
+                    det *= row[i] * row[i];
+                }
+                det
+            }
+
+            /// Returns the inverse of the decomposed matrix.
+            #[must_use]
+            pub fn inverse(&self) -> $mat {
+                let mut cols = $mat::IDENTITY.to_cols_array_2d();
+                for col in cols.iter_mut() {
+                    *col = self.solve($vec::from(*col)).to_array();
+                }
+                $mat::from_cols_array_2d(&cols)
+            }
+
+            /// Returns the lower triangular matrix `l`.
+            #[must_use]
+            pub fn l(&self) -> $mat {
+                $mat::from_cols_array_2d(&self.l).transpose()
+            }
+        }
+    };
+}
+
+impl_mat_factor!(
+    f32,
+    2,
+    Mat2,
+    Vec2,
+    "The LU decomposition of a [`Mat2`], created by [`Mat2::lu()`].",
+    Mat2Lu,
+    "The QR decomposition of a [`Mat2`], created by [`Mat2::qr()`].",
+    Mat2Qr,
+    "The Cholesky decomposition of a [`Mat2`], created by [`Mat2::cholesky()`].",
+    Mat2Cholesky
+);
+impl_mat_factor!(
+    f32,
+    3,
+    Mat3,
+    Vec3,
+    "The LU decomposition of a [`Mat3`], created by [`Mat3::lu()`].",
+    Mat3Lu,
+    "The QR decomposition of a [`Mat3`], created by [`Mat3::qr()`].",
+    Mat3Qr,
+    "The Cholesky decomposition of a [`Mat3`], created by [`Mat3::cholesky()`].",
+    Mat3Cholesky
+);
+impl_mat_factor!(
+    f32,
+    4,
+    Mat4,
+    Vec4,
+    "The LU decomposition of a [`Mat4`], created by [`Mat4::lu()`].",
+    Mat4Lu,
+    "The QR decomposition of a [`Mat4`], created by [`Mat4::qr()`].",
+    Mat4Qr,
+    "The Cholesky decomposition of a [`Mat4`], created by [`Mat4::cholesky()`].",
+    Mat4Cholesky
+);
+
+impl_mat_factor!(
+    f64,
+    2,
+    DMat2,
+    DVec2,
+    "The LU decomposition of a [`DMat2`], created by [`DMat2::lu()`].",
+    DMat2Lu,
+    "The QR decomposition of a [`DMat2`], created by [`DMat2::qr()`].",
+    DMat2Qr,
+    "The Cholesky decomposition of a [`DMat2`], created by [`DMat2::cholesky()`].",
+    DMat2Cholesky
+);
+impl_mat_factor!(
+    f64,
+    3,
+    DMat3,
+    DVec3,
+    "The LU decomposition of a [`DMat3`], created by [`DMat3::lu()`].",
+    DMat3Lu,
+    "The QR decomposition of a [`DMat3`], created by [`DMat3::qr()`].",
+    DMat3Qr,
+    "The Cholesky decomposition of a [`DMat3`], created by [`DMat3::cholesky()`].",
+    DMat3Cholesky
+);
+impl_mat_factor!(
+    f64,
+    4,
+    DMat4,
+    DVec4,
+    "The LU decomposition of a [`DMat4`], created by [`DMat4::lu()`].",
+    DMat4Lu,
+    "The QR decomposition of a [`DMat4`], created by [`DMat4::qr()`].",
+    DMat4Qr,
+    "The Cholesky decomposition of a [`DMat4`], created by [`DMat4::cholesky()`].",
+    DMat4Cholesky
+);
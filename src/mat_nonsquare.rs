@@ -0,0 +1,825 @@
+use crate::{
+    Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DVec2, DVec3, DVec4, Mat2, Mat3,
+    Mat4, Vec2, Vec3, Vec3A, Vec4,
+};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+macro_rules! define_matrc_struct {
+    ($doc:expr, $matrc:ident, $colvec:ident, $($col:ident),+) => {
+        #[doc = $doc]
+        ///
+        /// Non-square matrices are mostly useful for packing data more tightly than a square
+        /// matrix would allow, for example when uploading transforms to a shader. Multiplying two
+        /// matrices produces a matrix with the number of rows of the left hand side and the number
+        /// of columns of the right hand side, so a `Mat4x3` multiplied by a `Mat3x4` is a `Mat4`.
+        #[derive(Clone, Copy)]
+        #[cfg_attr(not(target_arch = "spirv"), repr(C))]
+        pub struct $matrc {
+            $(pub $col: $colvec,)+
+        }
+    };
+}
+
+// Returns the row `$r` of `$m` as a `$rowvec`.
+macro_rules! matrc_row {
+    ($m:ident, $rowvec:ident, $r:ident, [$(($col:ident, $idx:tt, $c:ident)),+]) => {
+        $rowvec::new($($m.$col.$r),+)
+    };
+}
+
+macro_rules! impl_matrc_methods {
+    ($t:ty, $matrc:ident, $transpose:ident, $colvec:ident, $rowvec:ident, $mul_vec:ident,
+     $rows:tt, $cols:tt, $len:tt,
+     $cols_tt:tt, [$(($col:ident, $idx:tt, $c:ident)),+], [$(($ridx:tt, $r:ident)),+]) => {
+        impl $matrc {
+            /// A matrix with all elements set to `0.0`.
+            pub const ZERO: Self = Self {
+                $($col: $colvec::ZERO,)+
+            };
+
+            /// A matrix where all main diagonal elements are `1`, and all other elements are `0`.
+            pub const IDENTITY: Self = Self {
+                $($col: matrc_identity_col!($colvec, $idx),)+
+            };
+
+            /// All NAN:s.
+            pub const NAN: Self = Self {
+                $($col: $colvec::NAN,)+
+            };
+
+            /// Creates a matrix from column vectors.
+            #[inline(always)]
+            pub fn from_cols($($col: $colvec),+) -> Self {
+                Self { $($col),+ }
+            }
+
+            /// Creates a matrix from an array stored in column major order.
+            /// If your data is stored in row major you will need to `transpose` the returned
+            /// matrix.
+            #[inline(always)]
+            pub fn from_cols_array(m: &[$t; $len]) -> Self {
+                Self::from_cols_slice(m)
+            }
+
+            /// Creates an array storing data in column major order.
+            /// If you require data in row major order `transpose` the matrix first.
+            #[inline]
+            pub fn to_cols_array(&self) -> [$t; $len] {
+                let mut m = [0.0; $len];
+                self.write_cols_to_slice(&mut m);
+                m
+            }
+
+            /// Creates a matrix from a 2D array stored in column major order.
+            /// If your data is in row major order you will need to `transpose` the returned
+            /// matrix.
+            #[inline(always)]
+            pub fn from_cols_array_2d(m: &[[$t; $rows]; $cols]) -> Self {
+                Self {
+                    $($col: $colvec::from(m[$idx]),)+
+                }
+            }
+
+            /// Creates a 2D array storing data in column major order.
+            /// If you require data in row major order `transpose` the matrix first.
+            #[inline(always)]
+            pub fn to_cols_array_2d(&self) -> [[$t; $rows]; $cols] {
+                [$(self.$col.to_array()),+]
+            }
+
+            /// Creates a matrix from the first rows * columns values in `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is shorter than the number of elements in the matrix.
+            #[inline]
+            pub fn from_cols_slice(slice: &[$t]) -> Self {
+                assert!(slice.len() >= $len);
+                Self {
+                    $($col: $colvec::from_slice(&slice[$idx * $rows..]),)+
+                }
+            }
+
+            /// Writes the columns of `self` to the first rows * columns elements in `slice`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice` is shorter than the number of elements in the matrix.
+            #[inline]
+            pub fn write_cols_to_slice(self, slice: &mut [$t]) {
+                assert!(slice.len() >= $len);
+                $(self.$col.write_to_slice(&mut slice[$idx * $rows..]);)+
+            }
+
+            /// Returns the matrix column for the given `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than the number of columns.
+            #[inline]
+            pub fn col(&self, index: usize) -> $colvec {
+                match index {
+                    $($idx => self.$col,)+
+                    _ => panic!("index out of bounds"),
+                }
+            }
+
+            /// Returns a mutable reference to the matrix column for the given `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than the number of columns.
+            #[inline]
+            pub fn col_mut(&mut self, index: usize) -> &mut $colvec {
+                match index {
+                    $($idx => &mut self.$col,)+
+                    _ => panic!("index out of bounds"),
+                }
+            }
+
+            /// Returns the matrix row for the given `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is not less than the number of rows.
+            #[inline]
+            pub fn row(&self, index: usize) -> $rowvec {
+                match index {
+                    $($ridx => matrc_row!(self, $rowvec, $r, $cols_tt),)+
+                    _ => panic!("index out of bounds"),
+                }
+            }
+
+            /// Returns `true` if, and only if, all elements are finite.
+            /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                $(self.$col.is_finite())&&+
+            }
+
+            /// Returns `true` if any elements are `NaN`.
+            #[inline]
+            pub fn is_nan(&self) -> bool {
+                $(self.$col.is_nan())||+
+            }
+
+            /// Returns the transpose of `self`, swapping the number of rows and columns.
+            #[must_use]
+            #[inline]
+            pub fn transpose(&self) -> $transpose {
+                $transpose::from_cols($(matrc_row!(self, $rowvec, $r, $cols_tt)),+)
+            }
+
+            /// Transforms a vector with as many elements as `self` has columns, returning a vector
+            /// with as many elements as `self` has rows.
+            #[inline]
+            pub fn $mul_vec(&self, other: $rowvec) -> $colvec {
+                let mut res = $colvec::ZERO;
+                $(res += self.$col * other.$c;)+
+                res
+            }
+
+            /// Multiplies a matrix by a scalar.
+            #[inline]
+            pub fn mul_scalar(&self, other: $t) -> Self {
+                Self {
+                    $($col: self.$col * other,)+
+                }
+            }
+
+            /// Returns true if the absolute difference of all elements between `self` and `other`
+            /// is less than or equal to `max_abs_diff`.
+            ///
+            /// This can be used to compare if two matrices contain similar elements. It works best
+            /// when comparing with a known value. The `max_abs_diff` that should be used used
+            /// depends on the values being compared against.
+            ///
+            /// For more see
+            /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+            #[inline]
+            pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+                $(self.$col.abs_diff_eq(other.$col, max_abs_diff))&&+
+            }
+        }
+    };
+}
+
+// Returns column `$idx` of a matrix with ones on the main diagonal.
+macro_rules! matrc_identity_col {
+    ($colvec:ident, 0) => {
+        $colvec::X
+    };
+    ($colvec:ident, 1) => {
+        $colvec::Y
+    };
+    ($colvec:ident, 2) => {
+        matrc_identity_col!(@z $colvec)
+    };
+    ($colvec:ident, 3) => {
+        matrc_identity_col!(@w $colvec)
+    };
+    (@z Vec2) => { Vec2::ZERO };
+    (@z DVec2) => { DVec2::ZERO };
+    (@z $colvec:ident) => { $colvec::Z };
+    (@w Vec2) => { Vec2::ZERO };
+    (@w DVec2) => { DVec2::ZERO };
+    (@w Vec3) => { Vec3::ZERO };
+    (@w DVec3) => { DVec3::ZERO };
+    (@w $colvec:ident) => { $colvec::W };
+}
+
+macro_rules! impl_matrc_traits {
+    ($t:ty, $new:ident, $matrc:ident, $colvec:ident, $rowvec:ident, $mul_vec:ident, $len:tt,
+     [$(($col:ident, $idx:tt, $c:ident)),+]) => {
+        /// Creates a matrix from column vectors.
+        #[inline(always)]
+        pub fn $new($($col: $colvec),+) -> $matrc {
+            $matrc::from_cols($($col),+)
+        }
+
+        impl Default for $matrc {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl PartialEq for $matrc {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$col.eq(&other.$col))&&+
+            }
+        }
+
+        impl Add<$matrc> for $matrc {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self {
+                    $($col: self.$col + other.$col,)+
+                }
+            }
+        }
+
+        impl AddAssign<$matrc> for $matrc {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = self.add(other);
+            }
+        }
+
+        impl Sub<$matrc> for $matrc {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self {
+                    $($col: self.$col - other.$col,)+
+                }
+            }
+        }
+
+        impl SubAssign<$matrc> for $matrc {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = self.sub(other);
+            }
+        }
+
+        impl Mul<$rowvec> for $matrc {
+            type Output = $colvec;
+            #[inline(always)]
+            fn mul(self, other: $rowvec) -> $colvec {
+                self.$mul_vec(other)
+            }
+        }
+
+        impl Mul<$matrc> for $t {
+            type Output = $matrc;
+            #[inline(always)]
+            fn mul(self, other: $matrc) -> $matrc {
+                other.mul_scalar(self)
+            }
+        }
+
+        impl Mul<$t> for $matrc {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $t) -> Self {
+                self.mul_scalar(other)
+            }
+        }
+
+        impl MulAssign<$t> for $matrc {
+            #[inline(always)]
+            fn mul_assign(&mut self, other: $t) {
+                *self = self.mul_scalar(other);
+            }
+        }
+
+        impl<'a> Sum<&'a Self> for $matrc {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a Self>,
+            {
+                iter.fold(Self::ZERO, |a, &b| Self::add(a, b))
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl AsRef<[$t; $len]> for $matrc {
+            #[inline(always)]
+            fn as_ref(&self) -> &[$t; $len] {
+                unsafe { &*(self as *const Self as *const [$t; $len]) }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl AsMut<[$t; $len]> for $matrc {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut [$t; $len] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; $len]) }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Display for $matrc {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let cols = [$(&self.$col as &dyn fmt::Display),+];
+                write!(f, "[")?;
+                for (i, col) in cols.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", col)?;
+                }
+                write!(f, "]")
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $matrc {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt.debug_struct(stringify!($matrc))
+                    $(.field(stringify!($col), &self.$col))+
+                    .finish()
+            }
+        }
+    };
+}
+
+// Implements `$lhs * $rhs = $out` by multiplying each column of `$rhs` by `$lhs`.
+macro_rules! impl_matrc_mul {
+    ($lhs:ident * $rhs:ident = $out:ident, [$($col:ident),+]) => {
+        impl Mul<$rhs> for $lhs {
+            type Output = $out;
+            #[inline]
+            fn mul(self, other: $rhs) -> $out {
+                $out::from_cols($(self * other.$col),+)
+            }
+        }
+    };
+}
+
+// Implements `as_*` conversions between the `f32` and `f64` versions of a matrix.
+macro_rules! impl_matrc_cast {
+    (f32, $($tt:tt)+) => {
+        impl_matrc_cast!(@impl "Casts all elements of `self` to `f32`.", $($tt)+);
+    };
+    (f64, $($tt:tt)+) => {
+        impl_matrc_cast!(@impl "Casts all elements of `self` to `f64`.", $($tt)+);
+    };
+    (@impl $doc:expr, $from:ident, $to:ident, $as_to:ident, $as_col:ident, [$($col:ident),+]) => {
+        impl $from {
+            #[doc = $doc]
+            #[inline]
+            pub fn $as_to(&self) -> $to {
+                $to::from_cols($(self.$col.$as_col()),+)
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrc {
+    ($doc:expr, $t:ty, $new:ident, $matrc:ident, $transpose:ident, $colvec:ident, $rowvec:ident,
+     $mul_vec:ident, $rows:tt, $cols:tt, $len:tt,
+     $cols_tt:tt, $rows_tt:tt) => {
+        impl_matrc!(@expand $doc, $t, $new, $matrc, $transpose, $colvec, $rowvec, $mul_vec, $rows,
+            $cols, $len, $cols_tt, $cols_tt, $rows_tt);
+    };
+    (@expand $doc:expr, $t:ty, $new:ident, $matrc:ident, $transpose:ident, $colvec:ident,
+     $rowvec:ident, $mul_vec:ident, $rows:tt, $cols:tt, $len:tt,
+     $cols_tt:tt, [$(($col:ident, $idx:tt, $c:ident)),+], $rows_tt:tt) => {
+        define_matrc_struct!($doc, $matrc, $colvec, $($col),+);
+        impl_matrc_methods!($t, $matrc, $transpose, $colvec, $rowvec, $mul_vec, $rows, $cols,
+            $len, $cols_tt, $cols_tt, $rows_tt);
+        impl_matrc_traits!($t, $new, $matrc, $colvec, $rowvec, $mul_vec, $len, $cols_tt);
+    };
+}
+
+macro_rules! impl_matrc_types {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident,
+     $mat2:ident, $mat3:ident, $mat4:ident,
+     $mat2x3:ident, $mat3x2:ident, $mat3x4:ident, $mat4x3:ident, $mat2x4:ident, $mat4x2:ident,
+     $new2x3:ident, $new3x2:ident, $new3x4:ident, $new4x3:ident, $new2x4:ident, $new4x2:ident) => {
+        impl_matrc!(
+            "A 2x3 column major matrix with 2 rows and 3 columns.",
+            $t,
+            $new2x3,
+            $mat2x3,
+            $mat3x2,
+            $vec2,
+            $vec3,
+            mul_vec3,
+            2,
+            3,
+            6,
+            [(x_axis, 0, x), (y_axis, 1, y), (z_axis, 2, z)],
+            [(0, x), (1, y)]
+        );
+        impl_matrc!(
+            "A 3x2 column major matrix with 3 rows and 2 columns.",
+            $t,
+            $new3x2,
+            $mat3x2,
+            $mat2x3,
+            $vec3,
+            $vec2,
+            mul_vec2,
+            3,
+            2,
+            6,
+            [(x_axis, 0, x), (y_axis, 1, y)],
+            [(0, x), (1, y), (2, z)]
+        );
+        impl_matrc!(
+            "A 3x4 column major matrix with 3 rows and 4 columns.",
+            $t,
+            $new3x4,
+            $mat3x4,
+            $mat4x3,
+            $vec3,
+            $vec4,
+            mul_vec4,
+            3,
+            4,
+            12,
+            [
+                (x_axis, 0, x),
+                (y_axis, 1, y),
+                (z_axis, 2, z),
+                (w_axis, 3, w)
+            ],
+            [(0, x), (1, y), (2, z)]
+        );
+        impl_matrc!(
+            "A 4x3 column major matrix with 4 rows and 3 columns.",
+            $t,
+            $new4x3,
+            $mat4x3,
+            $mat3x4,
+            $vec4,
+            $vec3,
+            mul_vec3,
+            4,
+            3,
+            12,
+            [(x_axis, 0, x), (y_axis, 1, y), (z_axis, 2, z)],
+            [(0, x), (1, y), (2, z), (3, w)]
+        );
+        impl_matrc!(
+            "A 2x4 column major matrix with 2 rows and 4 columns.",
+            $t,
+            $new2x4,
+            $mat2x4,
+            $mat4x2,
+            $vec2,
+            $vec4,
+            mul_vec4,
+            2,
+            4,
+            8,
+            [
+                (x_axis, 0, x),
+                (y_axis, 1, y),
+                (z_axis, 2, z),
+                (w_axis, 3, w)
+            ],
+            [(0, x), (1, y)]
+        );
+        impl_matrc!(
+            "A 4x2 column major matrix with 4 rows and 2 columns.",
+            $t,
+            $new4x2,
+            $mat4x2,
+            $mat2x4,
+            $vec4,
+            $vec2,
+            mul_vec2,
+            4,
+            2,
+            8,
+            [(x_axis, 0, x), (y_axis, 1, y)],
+            [(0, x), (1, y), (2, z), (3, w)]
+        );
+
+        // products with a 2 column left hand side
+        impl_matrc_mul!($mat2 * $mat2x3 = $mat2x3, [x_axis, y_axis, z_axis]);
+        impl_matrc_mul!($mat2 * $mat2x4 = $mat2x4, [x_axis, y_axis, z_axis, w_axis]);
+        impl_matrc_mul!($mat3x2 * $mat2 = $mat3x2, [x_axis, y_axis]);
+        impl_matrc_mul!($mat3x2 * $mat2x3 = $mat3, [x_axis, y_axis, z_axis]);
+        impl_matrc_mul!(
+            $mat3x2 * $mat2x4 = $mat3x4,
+            [x_axis, y_axis, z_axis, w_axis]
+        );
+        impl_matrc_mul!($mat4x2 * $mat2 = $mat4x2, [x_axis, y_axis]);
+        impl_matrc_mul!($mat4x2 * $mat2x3 = $mat4x3, [x_axis, y_axis, z_axis]);
+        impl_matrc_mul!($mat4x2 * $mat2x4 = $mat4, [x_axis, y_axis, z_axis, w_axis]);
+
+        // products with a 3 column left hand side
+        impl_matrc_mul!($mat2x3 * $mat3 = $mat2x3, [x_axis, y_axis, z_axis]);
+        impl_matrc_mul!($mat2x3 * $mat3x2 = $mat2, [x_axis, y_axis]);
+        impl_matrc_mul!(
+            $mat2x3 * $mat3x4 = $mat2x4,
+            [x_axis, y_axis, z_axis, w_axis]
+        );
+        impl_matrc_mul!($mat3 * $mat3x2 = $mat3x2, [x_axis, y_axis]);
+        impl_matrc_mul!($mat3 * $mat3x4 = $mat3x4, [x_axis, y_axis, z_axis, w_axis]);
+        impl_matrc_mul!($mat4x3 * $mat3 = $mat4x3, [x_axis, y_axis, z_axis]);
+        impl_matrc_mul!($mat4x3 * $mat3x2 = $mat4x2, [x_axis, y_axis]);
+        impl_matrc_mul!($mat4x3 * $mat3x4 = $mat4, [x_axis, y_axis, z_axis, w_axis]);
+
+        // products with a 4 column left hand side
+        impl_matrc_mul!($mat2x4 * $mat4 = $mat2x4, [x_axis, y_axis, z_axis, w_axis]);
+        impl_matrc_mul!($mat2x4 * $mat4x2 = $mat2, [x_axis, y_axis]);
+        impl_matrc_mul!($mat2x4 * $mat4x3 = $mat2x3, [x_axis, y_axis, z_axis]);
+        impl_matrc_mul!($mat3x4 * $mat4 = $mat3x4, [x_axis, y_axis, z_axis, w_axis]);
+        impl_matrc_mul!($mat3x4 * $mat4x2 = $mat3x2, [x_axis, y_axis]);
+        impl_matrc_mul!($mat3x4 * $mat4x3 = $mat3, [x_axis, y_axis, z_axis]);
+        impl_matrc_mul!($mat4 * $mat4x2 = $mat4x2, [x_axis, y_axis]);
+        impl_matrc_mul!($mat4 * $mat4x3 = $mat4x3, [x_axis, y_axis, z_axis]);
+
+        impl $mat2x3 {
+            /// Creates a 2x3 matrix from a 3x3 matrix, discarding the last row.
+            #[inline]
+            pub fn from_mat3(m: $mat3) -> Self {
+                Self::from_cols(
+                    m.x_axis.truncate(),
+                    m.y_axis.truncate(),
+                    m.z_axis.truncate(),
+                )
+            }
+        }
+
+        impl From<$mat2x3> for $mat3 {
+            /// Extends a 2x3 matrix to a 3x3 matrix with a last row of `[0, 0, 1]`.
+            #[inline]
+            fn from(m: $mat2x3) -> $mat3 {
+                $mat3::from_cols(
+                    m.x_axis.extend(0.0),
+                    m.y_axis.extend(0.0),
+                    m.z_axis.extend(1.0),
+                )
+            }
+        }
+
+        impl $mat3x2 {
+            /// Creates a 3x2 matrix from a 3x3 matrix, discarding the last column.
+            #[inline]
+            pub fn from_mat3(m: $mat3) -> Self {
+                Self::from_cols(m.x_axis, m.y_axis)
+            }
+        }
+
+        impl From<$mat3x2> for $mat3 {
+            /// Extends a 3x2 matrix to a 3x3 matrix with a last column of `[0, 0, 1]`.
+            #[inline]
+            fn from(m: $mat3x2) -> $mat3 {
+                $mat3::from_cols(m.x_axis, m.y_axis, $vec3::Z)
+            }
+        }
+
+        impl $mat3x4 {
+            /// Creates a 3x4 matrix from a 4x4 matrix, discarding the last row.
+            #[inline]
+            pub fn from_mat4(m: $mat4) -> Self {
+                Self::from_cols(
+                    m.x_axis.truncate(),
+                    m.y_axis.truncate(),
+                    m.z_axis.truncate(),
+                    m.w_axis.truncate(),
+                )
+            }
+        }
+
+        impl From<$mat3x4> for $mat4 {
+            /// Extends a 3x4 matrix to a 4x4 matrix with a last row of `[0, 0, 0, 1]`.
+            #[inline]
+            fn from(m: $mat3x4) -> $mat4 {
+                $mat4::from_cols(
+                    m.x_axis.extend(0.0),
+                    m.y_axis.extend(0.0),
+                    m.z_axis.extend(0.0),
+                    m.w_axis.extend(1.0),
+                )
+            }
+        }
+
+        impl $mat4x3 {
+            /// Creates a 4x3 matrix from a 4x4 matrix, discarding the last column.
+            #[inline]
+            pub fn from_mat4(m: $mat4) -> Self {
+                Self::from_cols(m.x_axis, m.y_axis, m.z_axis)
+            }
+        }
+
+        impl From<$mat4x3> for $mat4 {
+            /// Extends a 4x3 matrix to a 4x4 matrix with a last column of `[0, 0, 0, 1]`.
+            #[inline]
+            fn from(m: $mat4x3) -> $mat4 {
+                $mat4::from_cols(m.x_axis, m.y_axis, m.z_axis, $vec4::W)
+            }
+        }
+    };
+}
+
+impl_matrc_types!(
+    f32, Vec2, Vec3, Vec4, Mat2, Mat3, Mat4, Mat2x3, Mat3x2, Mat3x4, Mat4x3, Mat2x4, Mat4x2,
+    mat2x3, mat3x2, mat3x4, mat4x3, mat2x4, mat4x2
+);
+
+impl_matrc_types!(
+    f64, DVec2, DVec3, DVec4, DMat2, DMat3, DMat4, DMat2x3, DMat3x2, DMat3x4, DMat4x3, DMat2x4,
+    DMat4x2, dmat2x3, dmat3x2, dmat3x4, dmat4x3, dmat2x4, dmat4x2
+);
+
+impl_matrc_cast!(
+    f64,
+    Mat2x3,
+    DMat2x3,
+    as_dmat2x3,
+    as_dvec2,
+    [x_axis, y_axis, z_axis]
+);
+impl_matrc_cast!(f64, Mat3x2, DMat3x2, as_dmat3x2, as_dvec3, [x_axis, y_axis]);
+impl_matrc_cast!(
+    f64,
+    Mat3x4,
+    DMat3x4,
+    as_dmat3x4,
+    as_dvec3,
+    [x_axis, y_axis, z_axis, w_axis]
+);
+impl_matrc_cast!(
+    f64,
+    Mat4x3,
+    DMat4x3,
+    as_dmat4x3,
+    as_dvec4,
+    [x_axis, y_axis, z_axis]
+);
+impl_matrc_cast!(
+    f64,
+    Mat2x4,
+    DMat2x4,
+    as_dmat2x4,
+    as_dvec2,
+    [x_axis, y_axis, z_axis, w_axis]
+);
+impl_matrc_cast!(f64, Mat4x2, DMat4x2, as_dmat4x2, as_dvec4, [x_axis, y_axis]);
+impl_matrc_cast!(
+    f32,
+    DMat2x3,
+    Mat2x3,
+    as_mat2x3,
+    as_vec2,
+    [x_axis, y_axis, z_axis]
+);
+impl_matrc_cast!(f32, DMat3x2, Mat3x2, as_mat3x2, as_vec3, [x_axis, y_axis]);
+impl_matrc_cast!(
+    f32,
+    DMat3x4,
+    Mat3x4,
+    as_mat3x4,
+    as_vec3,
+    [x_axis, y_axis, z_axis, w_axis]
+);
+impl_matrc_cast!(
+    f32,
+    DMat4x3,
+    Mat4x3,
+    as_mat4x3,
+    as_vec4,
+    [x_axis, y_axis, z_axis]
+);
+impl_matrc_cast!(
+    f32,
+    DMat2x4,
+    Mat2x4,
+    as_mat2x4,
+    as_vec2,
+    [x_axis, y_axis, z_axis, w_axis]
+);
+impl_matrc_cast!(f32, DMat4x2, Mat4x2, as_mat4x2, as_vec4, [x_axis, y_axis]);
+
+impl From<Affine2> for Mat2x3 {
+    #[inline]
+    fn from(a: Affine2) -> Self {
+        Self::from_cols(a.matrix2.x_axis, a.matrix2.y_axis, a.translation)
+    }
+}
+
+impl From<Mat2x3> for Affine2 {
+    #[inline]
+    fn from(m: Mat2x3) -> Self {
+        Self::from_cols(m.x_axis, m.y_axis, m.z_axis)
+    }
+}
+
+impl From<DAffine2> for DMat2x3 {
+    #[inline]
+    fn from(a: DAffine2) -> Self {
+        Self::from_cols(a.matrix2.x_axis, a.matrix2.y_axis, a.translation)
+    }
+}
+
+impl From<DMat2x3> for DAffine2 {
+    #[inline]
+    fn from(m: DMat2x3) -> Self {
+        Self::from_cols(m.x_axis, m.y_axis, m.z_axis)
+    }
+}
+
+impl From<Affine3A> for Mat3x4 {
+    #[inline]
+    fn from(a: Affine3A) -> Self {
+        Self::from_cols(
+            a.matrix3.x_axis.into(),
+            a.matrix3.y_axis.into(),
+            a.matrix3.z_axis.into(),
+            a.translation.into(),
+        )
+    }
+}
+
+impl From<Mat3x4> for Affine3A {
+    #[inline]
+    fn from(m: Mat3x4) -> Self {
+        Self::from_cols(
+            Vec3A::from(m.x_axis),
+            Vec3A::from(m.y_axis),
+            Vec3A::from(m.z_axis),
+            Vec3A::from(m.w_axis),
+        )
+    }
+}
+
+impl From<DAffine3> for DMat3x4 {
+    #[inline]
+    fn from(a: DAffine3) -> Self {
+        Self::from_cols(
+            a.matrix3.x_axis,
+            a.matrix3.y_axis,
+            a.matrix3.z_axis,
+            a.translation,
+        )
+    }
+}
+
+impl From<DMat3x4> for DAffine3 {
+    #[inline]
+    fn from(m: DMat3x4) -> Self {
+        Self::from_cols(m.x_axis, m.y_axis, m.z_axis, m.w_axis)
+    }
+}
+
+mod const_test_mat2x3 {
+    const_assert_eq!(
+        core::mem::align_of::<f32>(),
+        core::mem::align_of::<super::Mat2x3>()
+    );
+    const_assert_eq!(24, core::mem::size_of::<super::Mat2x3>());
+    const_assert_eq!(24, core::mem::size_of::<super::Mat3x2>());
+    const_assert_eq!(48, core::mem::size_of::<super::DMat2x3>());
+    const_assert_eq!(48, core::mem::size_of::<super::DMat3x2>());
+}
+
+mod const_test_mat3x4 {
+    const_assert_eq!(
+        core::mem::align_of::<f32>(),
+        core::mem::align_of::<super::Mat3x4>()
+    );
+    const_assert_eq!(48, core::mem::size_of::<super::Mat3x4>());
+    const_assert_eq!(48, core::mem::size_of::<super::Mat4x3>());
+    const_assert_eq!(96, core::mem::size_of::<super::DMat3x4>());
+    const_assert_eq!(96, core::mem::size_of::<super::DMat4x3>());
+}
+
+mod const_test_mat2x4 {
+    const_assert_eq!(32, core::mem::size_of::<super::Mat2x4>());
+    const_assert_eq!(32, core::mem::size_of::<super::Mat4x2>());
+    const_assert_eq!(64, core::mem::size_of::<super::DMat2x4>());
+    const_assert_eq!(64, core::mem::size_of::<super::DMat4x2>());
+}
@@ -0,0 +1,293 @@
+// Oriented bounding boxes.
+//
+// A box is stored as its `center`, `half_extents` along its local axes and a `rotation` matrix
+// whose columns are those axes. Queries transform into the local space of the box where it is an
+// axis-aligned box centered on the origin. Box against box tests use the separating axis theorem
+// as described in Real-Time Collision Detection by Christer Ericson.
+use crate::{Aabb3, DAabb3, DMat3, DQuat, DSphere, DVec3, Mat3A, Quat, Sphere, Vec3};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+macro_rules! impl_obb3 {
+    ($t:ident, $vec3:ident, $quat:ident, $mat3:ident, $aabb3:ident, $sphere:ident, $obb3:ident) => {
+        /// An oriented bounding box stored as its `center`, `half_extents` and `rotation`.
+        #[derive(Clone, Copy, PartialEq)]
+        pub struct $obb3 {
+            /// The center of the box.
+            pub center: $vec3,
+            /// Half the size of the box along each of its local axes.
+            pub half_extents: $vec3,
+            /// The rotation of the box, the columns are its local axes.
+            pub rotation: $mat3,
+        }
+
+        impl $obb3 {
+            /// Creates a box from its `center`, `half_extents` and `rotation`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if any element of `half_extents` is negative or `rotation` is not
+            /// normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn new(center: $vec3, half_extents: $vec3, rotation: $quat) -> Self {
+                glam_assert!(half_extents.cmpge($vec3::ZERO).all());
+                Self {
+                    center,
+                    half_extents,
+                    rotation: $mat3::from_quat(rotation),
+                }
+            }
+
+            /// Creates a box with the same bounds as `aabb`.
+            #[inline]
+            pub fn from_aabb(aabb: &$aabb3) -> Self {
+                Self {
+                    center: aabb.center(),
+                    half_extents: aabb.half_extents(),
+                    rotation: $mat3::IDENTITY,
+                }
+            }
+
+            /// Creates a box containing all of the given `points`, aligned to their principal
+            /// axes.
+            ///
+            /// The axes are the eigenvectors of the covariance matrix of the points, which fits
+            /// elongated point sets well but is not guaranteed to give the minimal volume box.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `points` is empty.
+            pub fn from_points_pca(points: &[$vec3]) -> Self {
+                let inv_count = (points.len() as $t).recip();
+                let mean = points.iter().fold($vec3::ZERO, |sum, &point| sum + point) * inv_count;
+                let (mut xx, mut xy, mut xz, mut yy, mut yz, mut zz) =
+                    (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+                for &point in points {
+                    let p = point - mean;
+                    xx += p.x * p.x;
+                    xy += p.x * p.y;
+                    xz += p.x * p.z;
+                    yy += p.y * p.y;
+                    yz += p.y * p.z;
+                    zz += p.z * p.z;
+                }
+                let covariance =
+                    $mat3::from_cols_array(&[xx, xy, xz, xy, yy, yz, xz, yz, zz]) * inv_count;
+                let (_, rotation) = covariance.symmetric_eigen();
+
+                let inverse = rotation.transpose();
+                let first = inverse.mul_vec3(points[0] - mean);
+                let (min, max) = points[1..]
+                    .iter()
+                    .fold((first, first), |(min, max), &point| {
+                        let local = inverse.mul_vec3(point - mean);
+                        (min.min(local), max.max(local))
+                    });
+                Self {
+                    center: mean + rotation.mul_vec3((min + max) * 0.5),
+                    half_extents: (max - min) * 0.5,
+                    rotation,
+                }
+            }
+
+            // Returns `point` in the local space of `self`.
+            #[inline]
+            fn to_local(&self, point: $vec3) -> $vec3 {
+                self.rotation.transpose().mul_vec3(point - self.center)
+            }
+
+            /// Returns the eight corners of `self`.
+            ///
+            /// Bits 0, 1 and 2 of the index select the positive side of the box along the local
+            /// x, y and z axes respectively.
+            #[inline]
+            pub fn corners(&self) -> [$vec3; 8] {
+                let x = $vec3::from(self.rotation.x_axis) * self.half_extents.x;
+                let y = $vec3::from(self.rotation.y_axis) * self.half_extents.y;
+                let z = $vec3::from(self.rotation.z_axis) * self.half_extents.z;
+                let c = self.center;
+                [
+                    c - x - y - z,
+                    c + x - y - z,
+                    c - x + y - z,
+                    c + x + y - z,
+                    c - x - y + z,
+                    c + x - y + z,
+                    c - x + y + z,
+                    c + x + y + z,
+                ]
+            }
+
+            /// Returns the smallest axis-aligned box containing `self`.
+            #[inline]
+            pub fn to_aabb(&self) -> $aabb3 {
+                let half_extents = $vec3::from(self.rotation.x_axis).abs() * self.half_extents.x
+                    + $vec3::from(self.rotation.y_axis).abs() * self.half_extents.y
+                    + $vec3::from(self.rotation.z_axis).abs() * self.half_extents.z;
+                $aabb3::from_center_half_extents(self.center, half_extents)
+            }
+
+            /// Returns the point inside or on the boundary of `self` which is closest to
+            /// `point`.
+            #[inline]
+            pub fn closest_point(&self, point: $vec3) -> $vec3 {
+                let local = self
+                    .to_local(point)
+                    .clamp(-self.half_extents, self.half_extents);
+                self.center + self.rotation.mul_vec3(local)
+            }
+
+            /// Returns `true` if `point` is inside `self` or on its boundary.
+            #[inline]
+            pub fn contains_point(&self, point: $vec3) -> bool {
+                self.to_local(point).abs().cmple(self.half_extents).all()
+            }
+
+            /// Returns `true` if `sphere` is entirely inside `self`.
+            #[inline]
+            pub fn contains_sphere(&self, sphere: &$sphere) -> bool {
+                (self.to_local(sphere.center).abs() + $vec3::splat(sphere.radius))
+                    .cmple(self.half_extents)
+                    .all()
+            }
+
+            /// Returns `true` if `other` is entirely inside `self`.
+            #[inline]
+            pub fn contains_obb(&self, other: &Self) -> bool {
+                // The half extents of `other` projected onto the axes of `self`.
+                let r = (self.rotation.transpose() * other.rotation).to_cols_array_2d();
+                let extents = $vec3::new(r[0][0], r[0][1], r[0][2]).abs() * other.half_extents.x
+                    + $vec3::new(r[1][0], r[1][1], r[1][2]).abs() * other.half_extents.y
+                    + $vec3::new(r[2][0], r[2][1], r[2][2]).abs() * other.half_extents.z;
+                (self.to_local(other.center).abs() + extents)
+                    .cmple(self.half_extents)
+                    .all()
+            }
+
+            /// Returns `true` if `self` and `sphere` overlap or touch.
+            #[inline]
+            pub fn intersects_sphere(&self, sphere: &$sphere) -> bool {
+                sphere.contains_point(self.closest_point(sphere.center))
+            }
+
+            /// Returns `true` if `self` and `other` overlap or touch.
+            ///
+            /// Tests the 15 potential separating axes, the face normals of both boxes and the
+            /// cross products of each pair of their axes.
+            pub fn intersects_obb(&self, other: &Self) -> bool {
+                let (a, b) = (self.half_extents.to_array(), other.half_extents.to_array());
+                // `r[i][j]` is the dot product of axis `i` of `self` and axis `j` of `other`.
+                let r = (other.rotation.transpose() * self.rotation).to_cols_array_2d();
+                let t = self.to_local(other.center).to_array();
+                // Adding epsilon avoids false negatives when two axes are nearly parallel and
+                // their cross product is close to zero.
+                let mut abs_r = [[0.0; 3]; 3];
+                for i in 0..3 {
+                    for j in 0..3 {
+                        abs_r[i][j] = r[i][j].abs() + core::$t::EPSILON;
+                    }
+                }
+
+                // The axes of `self`.
+                for i in 0..3 {
+                    let rb = b[0] * abs_r[i][0] + b[1] * abs_r[i][1] + b[2] * abs_r[i][2];
+                    if t[i].abs() > a[i] + rb {
+                        return false;
+                    }
+                }
+
+                // The axes of `other`.
+                for j in 0..3 {
+                    let ra = a[0] * abs_r[0][j] + a[1] * abs_r[1][j] + a[2] * abs_r[2][j];
+                    let tj = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+                    if tj.abs() > ra + b[j] {
+                        return false;
+                    }
+                }
+
+                // The cross products of axis `i` of `self` and axis `j` of `other`.
+                for i in 0..3 {
+                    let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+                    for j in 0..3 {
+                        let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                        let ra = a[i1] * abs_r[i2][j] + a[i2] * abs_r[i1][j];
+                        let rb = b[j1] * abs_r[i][j2] + b[j2] * abs_r[i][j1];
+                        let tij = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                        if tij.abs() > ra + rb {
+                            return false;
+                        }
+                    }
+                }
+
+                true
+            }
+
+            /// Returns the volume of `self`.
+            #[inline]
+            pub fn volume(&self) -> $t {
+                8.0 * self.half_extents.x * self.half_extents.y * self.half_extents.z
+            }
+
+            /// Returns `true` if, and only if, all elements are finite.
+            #[inline]
+            pub fn is_finite(&self) -> bool {
+                self.center.is_finite()
+                    && self.half_extents.is_finite()
+                    && self.rotation.is_finite()
+            }
+
+            /// Returns true if the absolute difference of all elements between `self` and `other`
+            /// is less than or equal to `max_abs_diff`.
+            #[inline]
+            pub fn abs_diff_eq(&self, other: &Self, max_abs_diff: $t) -> bool {
+                self.center.abs_diff_eq(other.center, max_abs_diff)
+                    && self
+                        .half_extents
+                        .abs_diff_eq(other.half_extents, max_abs_diff)
+                    && self.rotation.abs_diff_eq(other.rotation, max_abs_diff)
+            }
+        }
+
+        impl $sphere {
+            /// Returns `true` if `obb` is entirely inside `self`.
+            #[inline]
+            pub fn contains_obb(self, obb: &$obb3) -> bool {
+                // The corner of `obb` farthest from the center of `self`.
+                let farthest = obb.to_local(self.center).abs() + obb.half_extents;
+                farthest.length_squared() <= self.radius * self.radius
+            }
+
+            /// Returns `true` if `self` and `obb` overlap or touch.
+            #[inline]
+            pub fn intersects_obb(self, obb: &$obb3) -> bool {
+                obb.intersects_sphere(&self)
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Debug for $obb3 {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.debug_struct(stringify!($obb3))
+                    .field("center", &self.center)
+                    .field("half_extents", &self.half_extents)
+                    .field("rotation", &self.rotation)
+                    .finish()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Display for $obb3 {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "[{}, {}, {}]",
+                    self.center, self.half_extents, self.rotation
+                )
+            }
+        }
+    };
+}
+
+impl_obb3!(f32, Vec3, Quat, Mat3A, Aabb3, Sphere, Obb3);
+impl_obb3!(f64, DVec3, DQuat, DMat3, DAabb3, DSphere, DObb3);
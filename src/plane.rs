@@ -0,0 +1,223 @@
+// Planes in Hessian normal form.
+//
+// A plane is stored as a unit `normal` and a distance `d` so that points on the plane satisfy
+// `normal.dot(point) + d = 0`. This matches the `Vec4` layout `(a, b, c, d)` commonly used for
+// planes extracted from projection matrices.
+use crate::{DMat4, DRay3, DVec3, DVec4, Mat4, Ray3, Vec3, Vec4};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+macro_rules! impl_plane {
+    ($t:ident, $vec3:ident, $vec4:ident, $mat4:ident, $ray3:ident, $plane:ident) => {
+        /// A plane defined by a unit `normal` and distance `d` such that points on the plane
+        /// satisfy `normal.dot(point) + d = 0`.
+        ///
+        /// Points on the side the normal points towards have a positive signed distance.
+        #[derive(Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $plane {
+            /// The unit normal of the plane.
+            pub normal: $vec3,
+            /// The negated distance of the plane from the origin along `normal`.
+            pub d: $t,
+        }
+
+        impl $plane {
+            /// Creates a plane from a unit `normal` and distance `d`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+            #[inline(always)]
+            pub fn new(normal: $vec3, d: $t) -> Self {
+                glam_assert!(normal.is_normalized());
+                Self { normal, d }
+            }
+
+            /// Creates a plane through `point` with the given unit `normal`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn from_point_normal(point: $vec3, normal: $vec3) -> Self {
+                Self::new(normal, -normal.dot(point))
+            }
+
+            /// Creates a plane through the points `a`, `b` and `c`.
+            ///
+            /// The normal points towards the side from which the points appear counter-clockwise.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if the points are colinear when `glam_assert` is enabled.
+            #[inline]
+            pub fn from_points(a: $vec3, b: $vec3, c: $vec3) -> Self {
+                let normal = (b - a).cross(c - a).normalize();
+                Self::from_point_normal(a, normal)
+            }
+
+            /// Creates a plane from the coefficients `(a, b, c, d)` of the plane equation
+            /// `ax + by + cz + d = 0`, normalizing the result.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `(a, b, c)` is zero when `glam_assert` is enabled.
+            #[inline]
+            pub fn from_vec4(v: $vec4) -> Self {
+                Self {
+                    normal: v.truncate(),
+                    d: v.w,
+                }
+                .normalize()
+            }
+
+            /// Returns the plane coefficients `(a, b, c, d)` as a `Vec4`.
+            #[inline]
+            pub fn to_vec4(self) -> $vec4 {
+                self.normal.extend(self.d)
+            }
+
+            /// Returns `self` scaled so that the normal is of length `1.0`.
+            ///
+            /// This is needed for planes where `normal` is not a unit vector, such as those
+            /// extracted from the rows of a projection matrix.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `normal` is zero when `glam_assert` is enabled.
+            #[must_use]
+            #[inline]
+            pub fn normalize(self) -> Self {
+                let length = self.normal.length();
+                glam_assert!(length != 0.0);
+                let inv_length = length.recip();
+                Self {
+                    normal: self.normal * inv_length,
+                    d: self.d * inv_length,
+                }
+            }
+
+            /// Returns whether the normal of `self` is of length `1.0` or not.
+            #[inline]
+            pub fn is_normalized(self) -> bool {
+                self.normal.is_normalized()
+            }
+
+            /// Returns the signed distance from `self` to `point`, positive on the side the
+            /// normal points towards.
+            #[inline]
+            pub fn signed_distance(self, point: $vec3) -> $t {
+                self.normal.dot(point) + self.d
+            }
+
+            /// Returns the closest point on `self` to `point`.
+            #[inline]
+            pub fn project_point(self, point: $vec3) -> $vec3 {
+                point - self.normal * self.signed_distance(point)
+            }
+
+            /// Returns `point` mirrored across `self`.
+            #[inline]
+            pub fn reflect_point(self, point: $vec3) -> $vec3 {
+                point - self.normal * (2.0 * self.signed_distance(point))
+            }
+
+            /// Returns the line where `self` and `other` intersect, as a ray through the point on
+            /// the line closest to the origin with direction `self.normal.cross(other.normal)`.
+            ///
+            /// Returns `None` if the planes are parallel.
+            #[inline]
+            pub fn intersect_plane(self, other: Self) -> Option<$ray3> {
+                let direction = self.normal.cross(other.normal);
+                let length_squared = direction.length_squared();
+                if length_squared <= core::$t::EPSILON * core::$t::EPSILON {
+                    return None;
+                }
+                let origin = (other.normal.cross(direction) * -self.d
+                    + direction.cross(self.normal) * -other.d)
+                    / length_squared;
+                Some($ray3::new(origin, direction / length_squared.sqrt()))
+            }
+
+            /// Returns the single point where the planes `a`, `b` and `c` meet.
+            ///
+            /// Returns `None` if any two of the planes are parallel or all three share a line.
+            #[inline]
+            pub fn intersect_three_planes(a: Self, b: Self, c: Self) -> Option<$vec3> {
+                let bc = b.normal.cross(c.normal);
+                let det = a.normal.dot(bc);
+                if det.abs() <= core::$t::EPSILON {
+                    return None;
+                }
+                let ca = c.normal.cross(a.normal);
+                let ab = a.normal.cross(b.normal);
+                Some((bc * -a.d + ca * -b.d + ab * -c.d) / det)
+            }
+
+            /// Returns `self` transformed by `mat`.
+            ///
+            /// Planes transform by the inverse transpose of the matrix used for points. When
+            /// transforming many planes by the same matrix use
+            /// [`Self::transform_inverse_transpose()`] to compute it once.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if the determinant of `mat` is zero when `glam_assert` is enabled.
+            #[inline]
+            pub fn transform_mat4(self, mat: &$mat4) -> Self {
+                self.transform_inverse_transpose(&mat.inverse().transpose())
+            }
+
+            /// Returns `self` transformed by the point transform whose inverse transpose is
+            /// `inverse_transpose`.
+            ///
+            /// The result is normalized.
+            #[inline]
+            pub fn transform_inverse_transpose(self, inverse_transpose: &$mat4) -> Self {
+                Self::from_vec4(*inverse_transpose * self.to_vec4())
+            }
+
+            /// Returns `true` if, and only if, all elements are finite.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                self.normal.is_finite() && self.d.is_finite()
+            }
+
+            /// Returns true if the absolute difference of all elements between `self` and `other`
+            /// is less than or equal to `max_abs_diff`.
+            #[inline]
+            pub fn abs_diff_eq(self, other: Self, max_abs_diff: $t) -> bool {
+                self.to_vec4().abs_diff_eq(other.to_vec4(), max_abs_diff)
+            }
+        }
+
+        impl From<$plane> for $vec4 {
+            #[inline(always)]
+            fn from(plane: $plane) -> Self {
+                plane.to_vec4()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Debug for $plane {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.debug_struct(stringify!($plane))
+                    .field("normal", &self.normal)
+                    .field("d", &self.d)
+                    .finish()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Display for $plane {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "[{}, {}]", self.normal, self.d)
+            }
+        }
+    };
+}
+
+impl_plane!(f32, Vec3, Vec4, Mat4, Ray3, Plane);
+impl_plane!(f64, DVec3, DVec4, DMat4, DRay3, DPlane);
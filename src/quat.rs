@@ -1,4 +1,5 @@
 use crate::core::traits::{
+    matrix::FloatMatrix4x4,
     quaternion::Quaternion,
     vector::{FloatVector4, MaskVector4, Vector, Vector4, Vector4Const},
 };
@@ -226,6 +227,53 @@ macro_rules! impl_quat_methods {
             }
         }
 
+        /// Creates a left-handed look rotation which rotates `+Z` to `forward` and `+Y` towards
+        /// `up`.
+        ///
+        /// For a coordinate system with `+X=right`, `+Y=up` and `+Z=forward`. If `forward` and
+        /// `up` are colinear the result is the minimal rotation from `+Z` to `forward`.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `forward` or `up` are not normalized when `glam_assert` is enabled.
+        #[inline]
+        #[cfg(feature = "std")]
+        pub fn from_look_rotation_lh(forward: $vec3, up: $vec3) -> Self {
+            Self::look_rotation($vec3::Z, forward, up)
+        }
+
+        /// Creates a right-handed look rotation which rotates `-Z` to `forward` and `+Y` towards
+        /// `up`.
+        ///
+        /// For a coordinate system with `+X=right`, `+Y=up` and `+Z=back`. If `forward` and `up`
+        /// are colinear the result is the minimal rotation from `-Z` to `forward`.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `forward` or `up` are not normalized when `glam_assert` is enabled.
+        #[inline]
+        #[cfg(feature = "std")]
+        pub fn from_look_rotation_rh(forward: $vec3, up: $vec3) -> Self {
+            Self::look_rotation(-$vec3::Z, forward, up)
+        }
+
+        #[cfg(feature = "std")]
+        fn look_rotation(local_forward: $vec3, forward: $vec3, up: $vec3) -> Self {
+            glam_assert!(forward.is_normalized());
+            glam_assert!(up.is_normalized());
+
+            let z_axis = forward * local_forward.z;
+            let x_axis = up.cross(z_axis);
+            let length_sq = x_axis.length_squared();
+            if length_sq < core::$t::EPSILON {
+                // `forward` and `up` are colinear so `up` does not constrain the roll
+                return Self::from_rotation_arc(local_forward, forward);
+            }
+            let x_axis = x_axis / length_sq.sqrt();
+            let y_axis = z_axis.cross(x_axis);
+            Self::from_mat3(&$mat3::from_cols(x_axis, y_axis, z_axis))
+        }
+
         /// Returns the rotation axis and angle (in radians) of `self`.
         #[inline(always)]
         pub fn to_axis_angle(self) -> ($vec3, $t) {
@@ -240,12 +288,168 @@ macro_rules! impl_quat_methods {
             $vec3(axis) * angle
         }
 
+        /// Returns the quaternion exponential of `self`.
+        ///
+        /// For a quaternion with a zero `w` this is the unit quaternion rotating by twice the
+        /// length of `xyz` radians around `xyz`, making it the inverse of [`Self::ln()`] for unit
+        /// quaternions. Other quaternions are supported, in which case the result is scaled by
+        /// `exp(w)` and will not be normalized.
+        #[must_use]
+        #[inline(always)]
+        pub fn exp(self) -> Self {
+            Self(self.0.exp_quaternion())
+        }
+
+        /// Returns the quaternion natural logarithm of `self`.
+        ///
+        /// For a unit quaternion the result has a zero `w` and an `xyz` of the rotation axis
+        /// scaled by half the rotation angle in radians. For other quaternions `w` is the natural
+        /// logarithm of the length of `self`.
+        ///
+        /// A quaternion with a zero `xyz` and a negative `w` has no unique logarithm, in this case
+        /// the x axis is used.
+        #[must_use]
+        #[inline(always)]
+        pub fn ln(self) -> Self {
+            Self(self.0.ln_quaternion())
+        }
+
+        /// Returns `self` raised to the power `n`, computed as `(self.ln() * n).exp()`.
+        ///
+        /// For a unit quaternion this scales the rotation angle by `n` around the same axis,
+        /// which is the same as slerping from the identity to `self` by `n` without taking the
+        /// shortest path.
+        #[must_use]
+        #[inline(always)]
+        pub fn powf(self, n: $t) -> Self {
+            Self(self.0.powf_quaternion(n))
+        }
+
+        /// Creates a quaternion from a `swing` and a `twist` rotation, applying the twist
+        /// first. This is the inverse of [`Self::to_swing_twist()`].
+        #[inline(always)]
+        pub fn from_swing_twist(swing: Self, twist: Self) -> Self {
+            swing * twist
+        }
+
+        /// Decomposes `self` into a swing rotation and a twist rotation around the normalized
+        /// `axis`, such that `self == swing * twist`.
+        ///
+        /// The twist is the rotation of `self` around `axis` and the swing is the remaining
+        /// rotation around an axis perpendicular to `axis`. The twist has a non-negative `w`, so
+        /// its angle is in the range `[-PI, PI]`, and the swing has the sign required to give
+        /// back `self`.
+        ///
+        /// When `self` is a half turn around an axis perpendicular to `axis` the twist is
+        /// undefined, in this case the twist is the identity and the swing is `self`.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` or `axis` are not normalized when `glam_assert` is enabled.
+        pub fn to_swing_twist(self, axis: $vec3) -> (Self, Self) {
+            glam_assert!(self.is_normalized());
+            glam_assert!(axis.is_normalized());
+            // The projection of the rotation axis onto the twist axis
+            let projected = axis * self.xyz().dot(axis);
+            let twist = Self::from_xyzw(projected.x, projected.y, projected.z, self.w);
+            let length_squared = twist.length_squared();
+            if length_squared < core::$t::EPSILON * core::$t::EPSILON {
+                (self, Self::IDENTITY)
+            } else {
+                let twist = twist * length_squared.sqrt().recip();
+                let twist = if twist.w < 0.0 { -twist } else { twist };
+                (self * twist.conjugate(), twist)
+            }
+        }
+
+        /// Limits the twist of `self` around the normalized `axis` to the range
+        /// `[min_angle, max_angle]` (in radians), keeping the swing unchanged.
+        ///
+        /// The twist angle is measured in the range `[-PI, PI]`, see
+        /// [`Self::to_swing_twist()`].
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` or `axis` are not normalized or `min_angle` is greater than
+        /// `max_angle` when `glam_assert` is enabled.
+        pub fn clamp_twist(self, axis: $vec3, min_angle: $t, max_angle: $t) -> Self {
+            glam_assert!(min_angle <= max_angle);
+            let (swing, twist) = self.to_swing_twist(axis);
+            let angle = 2.0 * twist.xyz().dot(axis).atan2(twist.w);
+            if angle < min_angle {
+                swing * Self::from_axis_angle(axis, min_angle)
+            } else if angle > max_angle {
+                swing * Self::from_axis_angle(axis, max_angle)
+            } else {
+                self
+            }
+        }
+
+        /// Limits the swing of `self` away from the normalized `axis` to a cone with a half
+        /// angle of `max_angle` (in radians), keeping the twist around `axis` unchanged.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` or `axis` are not normalized or `max_angle` is negative when
+        /// `glam_assert` is enabled.
+        pub fn clamp_swing_cone(self, axis: $vec3, max_angle: $t) -> Self {
+            glam_assert!(max_angle >= 0.0);
+            let (swing, twist) = self.to_swing_twist(axis);
+            let swing = if swing.w < 0.0 { -swing } else { swing };
+            let sin_half_angle = swing.xyz().length();
+            let angle = 2.0 * sin_half_angle.atan2(swing.w);
+            if angle > max_angle {
+                let swing_axis = swing.xyz() / sin_half_angle;
+                let clamped = Self::from_axis_angle(swing_axis, max_angle) * twist;
+                // Keep the sign of `self`
+                if clamped.dot(self) < 0.0 {
+                    -clamped
+                } else {
+                    clamped
+                }
+            } else {
+                self
+            }
+        }
+
         /// Returns the rotation angles for the given euler rotation sequence.
         #[inline(always)]
         pub fn to_euler(self, euler: EulerRot) -> ($t, $t, $t) {
             euler.convert_quat(self)
         }
 
+        /// Returns the rotation angles for the given euler rotation sequence that are closest
+        /// to the `previous` angles.
+        ///
+        /// Unlike [`Self::to_euler()`] the angles are not limited to a range, any multiple of
+        /// `2*PI` may be added and the equivalent solution of the sequence is picked if it is
+        /// closer. This avoids discontinuities when converting consecutive rotations, e.g. the
+        /// keys of an animation curve.
+        pub fn to_euler_closest(self, euler: EulerRot, previous: ($t, $t, $t)) -> ($t, $t, $t) {
+            euler.closest_angles(self.to_euler(euler), previous)
+        }
+
+        /// Converts a slice of rotations into continuous angles for the given euler rotation
+        /// sequence, writing the result to `curve`.
+        ///
+        /// The first rotation is converted with [`Self::to_euler()`] and each following one with
+        /// [`Self::to_euler_closest()`] using the angles of the rotation before it.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `rotations` and `curve` have different lengths.
+        pub fn to_euler_curve(euler: EulerRot, rotations: &[Self], curve: &mut [($t, $t, $t)]) {
+            assert_eq!(rotations.len(), curve.len());
+            let mut previous = None;
+            for (angles, rotation) in curve.iter_mut().zip(rotations) {
+                *angles = match previous {
+                    Some(previous) => rotation.to_euler_closest(euler, previous),
+                    None => rotation.to_euler(euler),
+                };
+                previous = Some(*angles);
+            }
+        }
+
         /// Returns the vector part of the quaternion.
         #[inline(always)]
         pub fn xyz(self) -> $vec3 {
@@ -360,6 +564,67 @@ macro_rules! impl_quat_methods {
             self.dot(other).abs().acos_approx() * 2.0
         }
 
+        /// Rotates `self` towards `target` by at most `max_angle` (in radians).
+        ///
+        /// Returns `target` when the angle between the rotations is less than or equal to
+        /// `max_angle`, otherwise the rotation along the shortest path from `self` to `target`
+        /// which is `max_angle` away from `self`.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` or `target` are not normalized or if `max_angle` is negative
+        /// when `glam_assert` is enabled.
+        #[inline]
+        pub fn rotate_towards(self, target: Self, max_angle: $t) -> Self {
+            glam_assert!(max_angle >= 0.0);
+            let angle = self.angle_between(target);
+            if angle <= max_angle {
+                target
+            } else {
+                // Slerp does not take the shortest path so flip `target` into our hemisphere
+                let target = if self.dot(target) < 0.0 {
+                    -target
+                } else {
+                    target
+                };
+                self.slerp(target, max_angle / angle)
+            }
+        }
+
+        /// Returns the constant angular velocity, in radians per unit of time, which rotates `q0`
+        /// to `q1` along the shortest path over a time step of `dt`.
+        ///
+        /// The angular velocity is in the world frame, so [`Self::integrate()`] undoes this:
+        /// `q0.integrate(Self::angular_velocity_between(q0, q1, dt), dt) ≈ q1`.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `q0` or `q1` are not normalized or if `dt` is zero when `glam_assert` is
+        /// enabled.
+        #[inline]
+        pub fn angular_velocity_between(q0: Self, q1: Self, dt: $t) -> $vec3 {
+            glam_assert!(q0.is_normalized() && q1.is_normalized());
+            glam_assert!(dt != 0.0);
+            let delta = q1 * q0.conjugate();
+            let delta = if delta.w < 0.0 { -delta } else { delta };
+            delta.to_scaled_axis() / dt
+        }
+
+        /// Integrates the world frame `angular_velocity`, in radians per unit of time, over a
+        /// time step of `dt` starting from `self`.
+        ///
+        /// The angular velocity is assumed to be constant over the time step, so the result is
+        /// exact rather than a first order approximation.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+        #[inline]
+        pub fn integrate(self, angular_velocity: $vec3, dt: $t) -> Self {
+            glam_assert!(self.is_normalized());
+            Self::from_scaled_axis(angular_velocity * dt) * self
+        }
+
         /// Returns true if the absolute difference of all elements between `self` and `other`
         /// is less than or equal to `max_abs_diff`.
         ///
@@ -410,6 +675,164 @@ macro_rules! impl_quat_methods {
             Self(self.0.slerp(end.0, s))
         }
 
+        /// Performs a spherical quadrangle interpolation between `self` and `end` based on the
+        /// value `s`, using the intermediate control points `a` and `b`.
+        ///
+        /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the result
+        /// will be equal to `end`.
+        ///
+        /// Interpolating a sequence of keyframes with `squad` gives a curve with continuous
+        /// angular velocity when `a` and `b` are computed with [`Self::squad_control_point()`],
+        /// where `a` is the control point of `self` and `b` is the control point of `end`.
+        ///
+        /// As with [`Self::slerp()`] the `dot` product between consecutive keys should be
+        /// positive.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if any input is not normalized when `glam_assert` is enabled.
+        #[inline]
+        pub fn squad(self, a: Self, b: Self, end: Self, s: $t) -> Self {
+            let outer = self.slerp(end, s);
+            let inner = a.slerp(b, s);
+            outer.slerp(inner, 2.0 * s * (1.0 - s))
+        }
+
+        /// Computes the intermediate control point of the key `current` for use with
+        /// [`Self::squad()`], given the neighbouring keys `prev` and `next`.
+        ///
+        /// For the first and last keys of a sequence pass the key itself as the missing
+        /// neighbour. `prev` and `next` are negated if required so that the shortest rotation to
+        /// `current` is used.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if any input is not normalized when `glam_assert` is enabled.
+        pub fn squad_control_point(prev: Self, current: Self, next: Self) -> Self {
+            let prev = if current.dot(prev) < 0.0 { -prev } else { prev };
+            let next = if current.dot(next) < 0.0 { -next } else { next };
+            let inv_current = current.conjugate();
+            let ln_next = (inv_current * next).ln();
+            let ln_prev = (inv_current * prev).ln();
+            current * ((ln_next + ln_prev) * -0.25).exp()
+        }
+
+        /// Performs a cubic Hermite interpolation between `self` and `end` based on the value
+        /// `s`.
+        ///
+        /// `angular_velocity_start` and `angular_velocity_end` are the angular velocities at
+        /// `self` and `end` in radians per unit of `s`, expressed in the same frame as the
+        /// rotations, i.e. as world space axes scaled by the rate of rotation. The curve is the
+        /// spherical cubic Bezier curve with the same end points and derivatives.
+        ///
+        /// `end` is negated if required so that the shortest rotation from `self` is used.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+        pub fn hermite(
+            self,
+            angular_velocity_start: $vec3,
+            end: Self,
+            angular_velocity_end: $vec3,
+            s: $t,
+        ) -> Self {
+            let end = if self.dot(end) < 0.0 { -end } else { end };
+            let b1 = Self::from_scaled_axis(angular_velocity_start * (1.0 / 3.0)) * self;
+            let b2 = Self::from_scaled_axis(angular_velocity_end * (-1.0 / 3.0)) * end;
+            // De Casteljau's algorithm with slerp in place of lerp
+            let p01 = self.slerp(b1, s);
+            let p12 = b1.slerp(b2, s);
+            let p23 = b2.slerp(end, s);
+            let p012 = p01.slerp(p12, s);
+            let p123 = p12.slerp(p23, s);
+            p012.slerp(p123, s)
+        }
+
+        /// Performs a Catmull-Rom spline interpolation between `start` and `end` based on the
+        /// value `s`, using the neighbouring keys `prev` and `next` to compute the angular
+        /// velocities at `start` and `end`.
+        ///
+        /// When `s` is `0.0`, the result will be equal to `start`. When `s` is `1.0`, the result
+        /// will be equal to `end`. Interpolating a sequence of evenly spaced keyframes this way
+        /// gives a curve with continuous angular velocity. For the first and last keys of a
+        /// sequence pass the key itself as the missing neighbour.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if any input is not normalized when `glam_assert` is enabled.
+        pub fn catmull_rom(prev: Self, start: Self, end: Self, next: Self, s: $t) -> Self {
+            // Half of the shortest rotation between the neighbours of each key
+            let tangent = |prev: Self, next: Self| {
+                let delta = next * prev.conjugate();
+                let delta = if delta.w < 0.0 { -delta } else { delta };
+                delta.to_scaled_axis() * 0.5
+            };
+            start.hermite(tangent(prev, end), end, tangent(start, next), s)
+        }
+
+        /// Returns the average of the given rotations.
+        ///
+        /// See [`Self::weighted_average`] for details.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if any of the `rotations` are not normalized when `glam_assert` is enabled.
+        #[inline]
+        pub fn average(rotations: &[Self]) -> Self {
+            Self::weighted_average_iter(rotations.iter().map(|&q| (q, 1.0)))
+        }
+
+        /// Returns the weighted average of the given rotations.
+        ///
+        /// This uses the eigenvector method from Markley et al. "Averaging Quaternions" (2007),
+        /// the result is the rotation which minimizes the weighted sum of squared chordal
+        /// distances to the inputs. Unlike summing quaternions it does not depend on the sign of
+        /// the inputs or on their order. The returned quaternion is in the same hemisphere as the
+        /// first rotation.
+        ///
+        /// Returns [`Self::IDENTITY`] if `rotations` is empty or the total weight is not
+        /// positive. For a cheaper approximation when the rotations are close together, see
+        /// `QuatAccumulator` and `DQuatAccumulator`.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if any of the `rotations` are not normalized when `glam_assert` is enabled.
+        #[inline]
+        pub fn weighted_average(rotations: &[(Self, $t)]) -> Self {
+            Self::weighted_average_iter(rotations.iter().copied())
+        }
+
+        fn weighted_average_iter(rotations: impl Iterator<Item = (Self, $t)>) -> Self {
+            let mut first = None;
+            let mut total = 0.0;
+            let mut m = $mat4::ZERO;
+            for (q, weight) in rotations {
+                glam_assert!(q.is_normalized());
+                first.get_or_insert(q);
+                total += weight;
+                // Accumulate the weighted outer product `q * q^T`
+                let v = $vec4::from(q) * weight;
+                m.x_axis += v * q.x;
+                m.y_axis += v * q.y;
+                m.z_axis += v * q.z;
+                m.w_axis += v * q.w;
+            }
+
+            match first {
+                Some(first) if total > 0.0 => {
+                    let (_, eigenvectors) = m.0.symmetric_eigen();
+                    let average = Self(eigenvectors.x_axis).normalize();
+                    if average.dot(first) < 0.0 {
+                        -average
+                    } else {
+                        average
+                    }
+                }
+                _ => Self::IDENTITY,
+            }
+        }
+
         /// Multiplies a quaternion and a 3D vector, returning the rotated vector.
         ///
         /// # Panics
@@ -0,0 +1,87 @@
+// Normalized lerp accumulators for blending many rotations.
+//
+// Summing quaternions and normalizing the result is a good approximation of the weighted average
+// rotation when the inputs are close together. Each input is flipped into the hemisphere of the
+// running sum first, since `q` and `-q` represent the same rotation but cancel when added.
+use crate::{DQuat, DVec4, Quat, Vec4};
+
+macro_rules! impl_quat_accumulator {
+    ($t:ty, $quat:ident, $vec4:ident, $doc:expr, $accumulator:ident) => {
+        #[doc = $doc]
+        ///
+        /// This is much cheaper than the eigenvector method used by the quaternion
+        /// `weighted_average` function but is only accurate when the accumulated rotations are
+        /// close to each other.
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $accumulator {
+            sum: $vec4,
+            total_weight: $t,
+        }
+
+        impl $accumulator {
+            /// Creates an empty accumulator.
+            #[inline(always)]
+            pub fn new() -> Self {
+                Self {
+                    sum: $vec4::ZERO,
+                    total_weight: 0.0,
+                }
+            }
+
+            /// Adds `rotation` with the given `weight` to the accumulator.
+            ///
+            /// The rotation is negated if it is not in the same hemisphere as the rotations
+            /// accumulated so far.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn add(&mut self, rotation: $quat, weight: $t) {
+                glam_assert!(rotation.is_normalized());
+                let v = $vec4::from(rotation) * weight;
+                if self.sum.dot(v) < 0.0 {
+                    self.sum -= v;
+                } else {
+                    self.sum += v;
+                }
+                self.total_weight += weight;
+            }
+
+            /// Returns the sum of the weights added to the accumulator.
+            #[inline(always)]
+            pub fn total_weight(&self) -> $t {
+                self.total_weight
+            }
+
+            /// Returns the normalized blend of the accumulated rotations.
+            ///
+            /// Returns the identity quaternion if nothing has been accumulated or the
+            /// accumulated rotations cancel out.
+            #[inline]
+            pub fn result(&self) -> $quat {
+                let length = self.sum.length();
+                if length > 0.0 {
+                    $quat::from_vec4(self.sum / length)
+                } else {
+                    $quat::IDENTITY
+                }
+            }
+        }
+    };
+}
+
+impl_quat_accumulator!(
+    f32,
+    Quat,
+    Vec4,
+    "Accumulates weighted [`Quat`] rotations using normalized lerp.",
+    QuatAccumulator
+);
+impl_quat_accumulator!(
+    f64,
+    DQuat,
+    DVec4,
+    "Accumulates weighted [`DQuat`] rotations using normalized lerp.",
+    DQuatAccumulator
+);
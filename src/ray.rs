@@ -0,0 +1,365 @@
+// Rays and ray intersection tests.
+//
+// All intersection tests return the distance along the ray to the first hit at or after the ray
+// origin. Rays are half-lines, so hits behind the origin are ignored. Surfaces are two-sided.
+use crate::{Aabb2, Aabb3, Aabb3A, DAabb3, DQuat, DVec3, Quat, Vec2, Vec3, Vec3A};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+// The slab test for rays against axis-aligned boxes, evaluated for all axes at once.
+//
+// A zero direction component gives infinite slab distances from the reciprocal. If the origin is
+// also exactly on a boundary of that slab `0 * inf` is NaN, in which case the ray runs along the
+// boundary and the slab places no limit on the hit distance.
+macro_rules! slab_intersection {
+    ($t:ident, $vec:ident, $origin:expr, $direction:expr, $min:expr, $max:expr) => {{
+        let inv_direction = $direction.recip();
+        let t1 = ($min - $origin) * inv_direction;
+        let t2 = ($max - $origin) * inv_direction;
+        let parallel = t1.is_nan_mask() | t2.is_nan_mask();
+        let t_near = $vec::select(parallel, $vec::splat(core::$t::NEG_INFINITY), t1.min(t2));
+        let t_far = $vec::select(parallel, $vec::splat(core::$t::INFINITY), t1.max(t2));
+        let t_near = t_near.max_element().max(0.0);
+        if t_near <= t_far.min_element() {
+            Some(t_near)
+        } else {
+            None
+        }
+    }};
+}
+
+macro_rules! impl_ray_common {
+    ($t:ident, $vec:ident, $ray:ident) => {
+        impl $ray {
+            /// Creates a ray starting at `origin` and pointing in the `direction`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `direction` is not normalized when `glam_assert` is enabled.
+            #[inline(always)]
+            pub fn new(origin: $vec, direction: $vec) -> Self {
+                glam_assert!(direction.is_normalized());
+                Self { origin, direction }
+            }
+
+            /// Returns the point at the given `distance` along the ray.
+            #[inline]
+            pub fn at(self, distance: $t) -> $vec {
+                self.origin + self.direction * distance
+            }
+
+            /// Returns the distance along the ray to the plane, or line in 2D, with the given
+            /// `normal` and distance `d` satisfying `normal.dot(point) + d = 0`.
+            ///
+            /// Returns `None` if the ray is parallel to the plane or points away from it.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn intersect_plane(self, normal: $vec, d: $t) -> Option<$t> {
+                glam_assert!(normal.is_normalized());
+                let denom = normal.dot(self.direction);
+                if denom.abs() <= core::$t::EPSILON {
+                    return None;
+                }
+                let distance = -(normal.dot(self.origin) + d) / denom;
+                if distance >= 0.0 {
+                    Some(distance)
+                } else {
+                    None
+                }
+            }
+
+            // Distance to a sphere or circle, using the numerically stable form of the quadratic
+            // from Ray Tracing Gems chapter 7.
+            #[inline]
+            fn intersect_ball(self, center: $vec, radius: $t) -> Option<$t> {
+                let offset = self.origin - center;
+                let b = offset.dot(self.direction);
+                let c = offset.length_squared() - radius * radius;
+                if c > 0.0 && b > 0.0 {
+                    // Outside and pointing away
+                    return None;
+                }
+                let f = offset - self.direction * b;
+                let discriminant = radius * radius - f.length_squared();
+                if discriminant < 0.0 {
+                    return None;
+                }
+                let root = discriminant.sqrt();
+                if c > 0.0 {
+                    Some(-b - root)
+                } else {
+                    // The origin is inside, the hit is where the ray exits
+                    Some(-b + root)
+                }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Debug for $ray {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.debug_struct(stringify!($ray))
+                    .field("origin", &self.origin)
+                    .field("direction", &self.direction)
+                    .finish()
+            }
+        }
+    };
+}
+
+macro_rules! impl_ray3 {
+    ($t:ident, $vec3:ident, $vec3a:ident, $quat:ident, $aabb3:ident, $hit:ident, $ray3:ident) => {
+        /// The result of a ray triangle intersection test.
+        ///
+        /// The hit point is `(1 - u - v) * v0 + u * v1 + v * v2` for the triangle vertices
+        /// `v0`, `v1` and `v2`.
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $hit {
+            /// The distance along the ray to the hit point.
+            pub distance: $t,
+            /// The barycentric coordinate of the hit point for the second vertex.
+            pub u: $t,
+            /// The barycentric coordinate of the hit point for the third vertex.
+            pub v: $t,
+        }
+
+        /// A 3D ray, a half-line starting at `origin` and extending in `direction`.
+        ///
+        /// The `direction` is expected to be normalized so that intersection distances are in
+        /// the same units as the ray.
+        #[derive(Clone, Copy, Default, PartialEq)]
+        pub struct $ray3 {
+            /// The start point of the ray.
+            pub origin: $vec3,
+            /// The normalized direction of the ray.
+            pub direction: $vec3,
+        }
+
+        impl_ray_common!($t, $vec3, $ray3);
+
+        impl $ray3 {
+            /// Returns the distance along the ray to the sphere with the given `center` and
+            /// `radius`.
+            ///
+            /// If the ray origin is inside the sphere the distance to the exit point is returned.
+            #[inline]
+            pub fn intersect_sphere(self, center: $vec3, radius: $t) -> Option<$t> {
+                self.intersect_ball(center, radius)
+            }
+
+            /// Returns the distance along the ray to `aabb` using the slab test.
+            ///
+            /// Returns `Some(0.0)` if the ray origin is inside the box. Hits on the boundary of
+            /// the box, including rays grazing along a face, are included.
+            #[inline]
+            pub fn intersect_aabb(self, aabb: &$aabb3) -> Option<$t> {
+                slab_intersection!(
+                    $t,
+                    $vec3a,
+                    $vec3a::from(self.origin),
+                    $vec3a::from(self.direction),
+                    $vec3a::from(aabb.min),
+                    $vec3a::from(aabb.max)
+                )
+            }
+
+            /// Returns the distance along the ray to the oriented box with the given `center`,
+            /// `half_extents` and `rotation`.
+            ///
+            /// See [`Self::intersect_aabb()`] for details.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `rotation` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn intersect_obb(
+                self,
+                center: $vec3,
+                half_extents: $vec3,
+                rotation: $quat,
+            ) -> Option<$t> {
+                let inv_rotation = rotation.conjugate();
+                let local = Self {
+                    origin: inv_rotation * (self.origin - center),
+                    direction: inv_rotation * self.direction,
+                };
+                local.intersect_aabb(&$aabb3::new(-half_extents, half_extents))
+            }
+
+            /// Returns the hit distance and barycentric coordinates of the intersection with the
+            /// triangle `v0`, `v1`, `v2` using the Möller–Trumbore algorithm.
+            ///
+            /// This is fast but rays passing exactly through a shared edge or vertex can miss both
+            /// of the adjacent triangles. Use [`Self::intersect_triangle_watertight()`] where that
+            /// matters.
+            #[inline]
+            pub fn intersect_triangle(self, v0: $vec3, v1: $vec3, v2: $vec3) -> Option<$hit> {
+                let v0 = $vec3a::from(v0);
+                let direction = $vec3a::from(self.direction);
+                let edge1 = $vec3a::from(v1) - v0;
+                let edge2 = $vec3a::from(v2) - v0;
+                let p = direction.cross(edge2);
+                let det = edge1.dot(p);
+                if det.abs() <= core::$t::EPSILON * edge1.length() * edge2.length() {
+                    // The ray is parallel to the triangle, relative to its size
+                    return None;
+                }
+                let inv_det = det.recip();
+                let s = $vec3a::from(self.origin) - v0;
+                let u = s.dot(p) * inv_det;
+                if !(0.0..=1.0).contains(&u) {
+                    return None;
+                }
+                let q = s.cross(edge1);
+                let v = direction.dot(q) * inv_det;
+                if v < 0.0 || u + v > 1.0 {
+                    return None;
+                }
+                let distance = edge2.dot(q) * inv_det;
+                if distance >= 0.0 {
+                    Some($hit { distance, u, v })
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the hit distance and barycentric coordinates of the intersection with the
+            /// triangle `v0`, `v1`, `v2` using the watertight algorithm of Woop, Benthin and
+            /// Wald.
+            ///
+            /// A ray hitting an edge or vertex shared by a closed mesh is guaranteed to hit at
+            /// least one of the adjacent triangles.
+            pub fn intersect_triangle_watertight(
+                self,
+                v0: $vec3,
+                v1: $vec3,
+                v2: $vec3,
+            ) -> Option<$hit> {
+                // Permute the axes so that the largest direction component is z
+                let direction = self.direction.to_array();
+                let abs_direction = self.direction.abs();
+                let kz = if abs_direction.x > abs_direction.y {
+                    if abs_direction.x > abs_direction.z {
+                        0
+                    } else {
+                        2
+                    }
+                } else if abs_direction.y > abs_direction.z {
+                    1
+                } else {
+                    2
+                };
+                let mut kx = (kz + 1) % 3;
+                let mut ky = (kx + 1) % 3;
+                if direction[kz] < 0.0 {
+                    // Preserve the winding of the triangle
+                    core::mem::swap(&mut kx, &mut ky);
+                }
+
+                // Shear and scale so the ray points along +z
+                let sx = direction[kx] / direction[kz];
+                let sy = direction[ky] / direction[kz];
+                let sz = 1.0 / direction[kz];
+                let a = (v0 - self.origin).to_array();
+                let b = (v1 - self.origin).to_array();
+                let c = (v2 - self.origin).to_array();
+                let ax = a[kx] - sx * a[kz];
+                let ay = a[ky] - sy * a[kz];
+                let bx = b[kx] - sx * b[kz];
+                let by = b[ky] - sy * b[kz];
+                let cx = c[kx] - sx * c[kz];
+                let cy = c[ky] - sy * c[kz];
+
+                // Scaled barycentric coordinates, recomputed in double precision if any are zero
+                let mut u = cx * by - cy * bx;
+                let mut v = ax * cy - ay * cx;
+                let mut w = bx * ay - by * ax;
+                #[allow(clippy::unnecessary_cast)]
+                if u == 0.0 || v == 0.0 || w == 0.0 {
+                    u = (cx as f64 * by as f64 - cy as f64 * bx as f64) as $t;
+                    v = (ax as f64 * cy as f64 - ay as f64 * cx as f64) as $t;
+                    w = (bx as f64 * ay as f64 - by as f64 * ax as f64) as $t;
+                }
+                if (u < 0.0 || v < 0.0 || w < 0.0) && (u > 0.0 || v > 0.0 || w > 0.0) {
+                    return None;
+                }
+                let det = u + v + w;
+                if det == 0.0 {
+                    return None;
+                }
+
+                // The scaled hit distance has the same sign as `det` for hits in front
+                let az = sz * a[kz];
+                let bz = sz * b[kz];
+                let cz = sz * c[kz];
+                let distance = u * az + v * bz + w * cz;
+                if (det < 0.0 && distance > 0.0) || (det > 0.0 && distance < 0.0) {
+                    return None;
+                }
+                let inv_det = det.recip();
+                Some($hit {
+                    distance: distance * inv_det,
+                    u: v * inv_det,
+                    v: w * inv_det,
+                })
+            }
+        }
+    };
+}
+
+/// A 2D ray, a half-line starting at `origin` and extending in `direction`.
+///
+/// The `direction` is expected to be normalized so that intersection distances are in the same
+/// units as the ray.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Ray2 {
+    /// The start point of the ray.
+    pub origin: Vec2,
+    /// The normalized direction of the ray.
+    pub direction: Vec2,
+}
+
+impl_ray_common!(f32, Vec2, Ray2);
+
+impl Ray2 {
+    /// Returns the distance along the ray to the circle with the given `center` and `radius`.
+    ///
+    /// If the ray origin is inside the circle the distance to the exit point is returned.
+    #[inline]
+    pub fn intersect_circle(self, center: Vec2, radius: f32) -> Option<f32> {
+        self.intersect_ball(center, radius)
+    }
+
+    /// Returns the distance along the ray to `aabb` using the slab test.
+    ///
+    /// Returns `Some(0.0)` if the ray origin is inside the box. Hits on the boundary of the box,
+    /// including rays grazing along an edge, are included.
+    #[inline]
+    pub fn intersect_aabb(self, aabb: &Aabb2) -> Option<f32> {
+        slab_intersection!(f32, Vec2, self.origin, self.direction, aabb.min, aabb.max)
+    }
+}
+
+impl_ray3!(f32, Vec3, Vec3A, Quat, Aabb3, TriangleHit, Ray3);
+
+impl Ray3 {
+    /// Returns the distance along the ray to `aabb` using the slab test.
+    ///
+    /// See [`Self::intersect_aabb()`] for details.
+    #[inline]
+    pub fn intersect_aabb3a(self, aabb: &Aabb3A) -> Option<f32> {
+        slab_intersection!(
+            f32,
+            Vec3A,
+            Vec3A::from(self.origin),
+            Vec3A::from(self.direction),
+            aabb.min,
+            aabb.max
+        )
+    }
+}
+
+impl_ray3!(f64, DVec3, DVec3, DQuat, DAabb3, DTriangleHit, DRay3);
@@ -0,0 +1,313 @@
+// 2D rotations stored as unit complex numbers.
+//
+// Rotating a vector is a complex multiplication, which is cheaper than building a rotation
+// matrix from an angle and composes without the angle wrapping issues of storing raw angles.
+use crate::{DMat2, DVec2, Mat2, Vec2};
+use core::ops::{Mul, MulAssign};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+macro_rules! impl_rot2 {
+    ($t:ident, $vec2:ident, $mat2:ident, $rot2:ident) => {
+        /// A 2D rotation stored as a unit complex number, `cos + i sin`.
+        ///
+        /// Rotations are composed by multiplication and applied to vectors with `*`, similar to
+        /// how quaternions are used for 3D rotations.
+        #[derive(Clone, Copy, PartialEq)]
+        #[repr(C)]
+        pub struct $rot2 {
+            /// The cosine of the rotation angle, the real part.
+            pub cos: $t,
+            /// The sine of the rotation angle, the imaginary part.
+            pub sin: $t,
+        }
+
+        impl $rot2 {
+            /// The identity rotation. Corresponds to no rotation.
+            pub const IDENTITY: Self = Self { cos: 1.0, sin: 0.0 };
+
+            /// All NAN:s.
+            pub const NAN: Self = Self {
+                cos: core::$t::NAN,
+                sin: core::$t::NAN,
+            };
+
+            /// Creates a rotation from the `sin` and `cos` of an angle.
+            ///
+            /// This should generally not be called manually unless you know what you are doing.
+            /// Use one of the other constructors instead such as [`Self::from_angle()`].
+            #[inline(always)]
+            pub fn from_sin_cos(sin: $t, cos: $t) -> Self {
+                Self { cos, sin }
+            }
+
+            /// Creates a rotation of `angle` (in radians).
+            #[inline]
+            pub fn from_angle(angle: $t) -> Self {
+                let (sin, cos) = angle.sin_cos();
+                Self { cos, sin }
+            }
+
+            /// Gets the minimal rotation for transforming `from` to `to`.
+            ///
+            /// `from_rotation_arc(from, to) * from ≈ to`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `from` or `to` are not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn from_rotation_arc(from: $vec2, to: $vec2) -> Self {
+                glam_assert!(from.is_normalized());
+                glam_assert!(to.is_normalized());
+                Self {
+                    cos: from.dot(to),
+                    sin: from.perp_dot(to),
+                }
+                .normalize()
+            }
+
+            /// Creates a rotation from the rotation part of a 2x2 matrix.
+            ///
+            /// The matrix is expected to contain a rotation and positive scale only, the
+            /// normalized first column is used.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if the first column of `mat` is zero when `glam_assert` is enabled.
+            #[inline]
+            pub fn from_mat2(mat: &$mat2) -> Self {
+                Self {
+                    cos: mat.x_axis.x,
+                    sin: mat.x_axis.y,
+                }
+                .normalize()
+            }
+
+            /// Returns the rotation angle (in radians) in the range `[-π, +π]`.
+            #[inline]
+            pub fn to_angle(self) -> $t {
+                self.sin.atan2(self.cos)
+            }
+
+            /// Returns the inverse of a normalized rotation, which rotates by the negated angle.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+            #[must_use]
+            #[inline]
+            pub fn inverse(self) -> Self {
+                glam_assert!(self.is_normalized());
+                Self {
+                    cos: self.cos,
+                    sin: -self.sin,
+                }
+            }
+
+            /// Computes the length of `self`.
+            #[inline]
+            pub fn length(self) -> $t {
+                self.length_squared().sqrt()
+            }
+
+            /// Computes the squared length of `self`.
+            #[inline]
+            pub fn length_squared(self) -> $t {
+                self.cos * self.cos + self.sin * self.sin
+            }
+
+            /// Returns `self` normalized to length 1.0.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` is zero length when `glam_assert` is enabled.
+            #[must_use]
+            #[inline]
+            pub fn normalize(self) -> Self {
+                let length = self.length();
+                glam_assert!(length != 0.0);
+                Self {
+                    cos: self.cos / length,
+                    sin: self.sin / length,
+                }
+            }
+
+            /// Returns whether `self` is of length `1.0` or not.
+            ///
+            /// Uses a precision threshold of `1e-4`.
+            #[inline]
+            pub fn is_normalized(self) -> bool {
+                (self.length_squared() - 1.0).abs() <= 1e-4
+            }
+
+            /// Returns `true` if, and only if, all elements are finite.
+            /// If any element is either `NaN`, positive or negative infinity, this will return
+            /// `false`.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                self.cos.is_finite() && self.sin.is_finite()
+            }
+
+            /// Returns `true` if any elements are `NaN`.
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                self.cos.is_nan() || self.sin.is_nan()
+            }
+
+            /// Returns true if the absolute difference of all elements between `self` and `other`
+            /// is less than or equal to `max_abs_diff`.
+            ///
+            /// This can be used to compare if two rotations contain similar elements. It works
+            /// best when comparing with a known value. The `max_abs_diff` that should be used used
+            /// depends on the values being compared against.
+            ///
+            /// For more see
+            /// [comparing floating point numbers](https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/).
+            #[inline]
+            pub fn abs_diff_eq(self, other: Self, max_abs_diff: $t) -> bool {
+                (self.cos - other.cos).abs() <= max_abs_diff
+                    && (self.sin - other.sin).abs() <= max_abs_diff
+            }
+
+            /// Returns the angle (in radians) of the rotation between `self` and `other`, in the
+            /// range `[0, π]`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` or `other` are not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn angle_between(self, other: Self) -> $t {
+                (other * self.inverse()).to_angle().abs()
+            }
+
+            /// Performs a spherical linear interpolation between `self` and `end` based on the
+            /// value `s`, rotating at a constant rate along the shortest path.
+            ///
+            /// When `s` is `0.0`, the result will be equal to `self`. When `s` is `1.0`, the
+            /// result will be equal to `end`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn slerp(self, end: Self, s: $t) -> Self {
+                let angle = (end * self.inverse()).to_angle();
+                Self::from_angle(angle * s) * self
+            }
+
+            /// Performs a normalized linear interpolation between `self` and `end` based on the
+            /// value `s`.
+            ///
+            /// This is cheaper than [`Self::slerp()`] but the rotation rate is not constant. The
+            /// result is undefined when `self` and `end` are half a turn apart and `s` is `0.5`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn nlerp(self, end: Self, s: $t) -> Self {
+                glam_assert!(self.is_normalized() && end.is_normalized());
+                Self {
+                    cos: self.cos + (end.cos - self.cos) * s,
+                    sin: self.sin + (end.sin - self.sin) * s,
+                }
+                .normalize()
+            }
+
+            /// Rotates the given 2D vector by `self`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            pub fn mul_vec2(self, other: $vec2) -> $vec2 {
+                glam_assert!(self.is_normalized());
+                $vec2::new(self.cos, self.sin).rotate(other)
+            }
+        }
+
+        impl Default for $rot2 {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Debug for $rot2 {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.debug_struct(stringify!($rot2))
+                    .field("cos", &self.cos)
+                    .field("sin", &self.sin)
+                    .finish()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Display for $rot2 {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "[{}, {}]", self.cos, self.sin)
+            }
+        }
+
+        impl Mul for $rot2 {
+            type Output = Self;
+            /// Multiplies two rotations, the result rotates by the sum of their angles.
+            ///
+            /// Note that due to floating point rounding the result may not be perfectly
+            /// normalized.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` or `other` are not normalized when `glam_assert` is enabled.
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                glam_assert!(self.is_normalized() && other.is_normalized());
+                Self {
+                    cos: self.cos * other.cos - self.sin * other.sin,
+                    sin: self.sin * other.cos + self.cos * other.sin,
+                }
+            }
+        }
+
+        impl MulAssign for $rot2 {
+            #[inline]
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl Mul<$vec2> for $rot2 {
+            type Output = $vec2;
+            /// Rotates the given 2D vector by `self`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+            #[inline]
+            fn mul(self, other: $vec2) -> $vec2 {
+                self.mul_vec2(other)
+            }
+        }
+
+        impl<'a> core::iter::Product<&'a Self> for $rot2 {
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a Self>,
+            {
+                iter.fold(Self::IDENTITY, |a, &b| a * b)
+            }
+        }
+
+        impl From<$rot2> for $vec2 {
+            #[inline(always)]
+            fn from(r: $rot2) -> Self {
+                $vec2::new(r.cos, r.sin)
+            }
+        }
+    };
+}
+
+impl_rot2!(f32, Vec2, Mat2, Rot2);
+impl_rot2!(f64, DVec2, DMat2, DRot2);
@@ -0,0 +1,263 @@
+// Bounding spheres.
+//
+// Two fitting algorithms are provided. Ritter's is a fast two pass approximation, typically within
+// 5-20% of the optimal radius. Welzl's computes the minimal sphere and is written as the iterative
+// form of the move-to-front recursion so that it needs neither allocation nor deep recursion.
+use crate::{DVec3, Vec3};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+macro_rules! impl_sphere {
+    ($t:ident, $vec3:ident, $sphere:ident) => {
+        /// A sphere stored as its `center` and `radius`.
+        #[derive(Clone, Copy, PartialEq)]
+        pub struct $sphere {
+            /// The center of the sphere.
+            pub center: $vec3,
+            /// The radius of the sphere.
+            pub radius: $t,
+        }
+
+        impl $sphere {
+            /// Creates a sphere from its `center` and `radius`.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `radius` is negative when `glam_assert` is enabled.
+            #[inline(always)]
+            pub fn new(center: $vec3, radius: $t) -> Self {
+                glam_assert!(radius >= 0.0);
+                Self { center, radius }
+            }
+
+            /// Creates a sphere containing all of the given `points` using Ritter's algorithm.
+            ///
+            /// The sphere starts as the one spanning two approximately diametrically opposite
+            /// points and is grown to include any points outside of it. This takes two passes over
+            /// the points but the result is not minimal, use [`Self::from_points_welzl()`] when a
+            /// tight fit matters more than speed.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `points` is empty.
+            #[inline]
+            pub fn from_points_ritter(points: &[$vec3]) -> Self {
+                let farthest = |from: $vec3| {
+                    points.iter().fold(from, |farthest, &point| {
+                        if from.distance_squared(point) > from.distance_squared(farthest) {
+                            point
+                        } else {
+                            farthest
+                        }
+                    })
+                };
+                let a = farthest(points[0]);
+                let b = farthest(a);
+                points
+                    .iter()
+                    .fold(Self::from_two_points(a, b), |sphere, &point| {
+                        sphere.grow(point)
+                    })
+            }
+
+            /// Creates the smallest sphere containing all of the given `points` using Welzl's
+            /// algorithm.
+            ///
+            /// The expected running time is linear when the points are in random order. Sorted or
+            /// otherwise structured input should be shuffled first as the worst case is quartic.
+            /// Points may lie outside the result by a small multiple of `EPSILON` relative to the
+            /// radius.
+            ///
+            /// # Panics
+            ///
+            /// Will panic if `points` is empty.
+            pub fn from_points_welzl(points: &[$vec3]) -> Self {
+                let mut sphere = Self::new(points[0], 0.0);
+                for (i, &pi) in points.iter().enumerate().skip(1) {
+                    if sphere.encloses(pi) {
+                        continue;
+                    }
+                    // The smallest sphere containing the first `i` points with `pi` on its
+                    // boundary.
+                    sphere = Self::new(pi, 0.0);
+                    for (j, &pj) in points[..i].iter().enumerate() {
+                        if sphere.encloses(pj) {
+                            continue;
+                        }
+                        sphere = Self::from_two_points(pi, pj);
+                        for (k, &pk) in points[..j].iter().enumerate() {
+                            if sphere.encloses(pk) {
+                                continue;
+                            }
+                            sphere = Self::from_three_points(pi, pj, pk);
+                            for &pl in &points[..k] {
+                                if !sphere.encloses(pl) {
+                                    sphere = Self::from_four_points(pi, pj, pk, pl);
+                                }
+                            }
+                        }
+                    }
+                }
+                sphere
+            }
+
+            // Returns `true` if `point` is inside `self` allowing for rounding error in the
+            // fitting algorithms.
+            #[inline]
+            fn encloses(self, point: $vec3) -> bool {
+                self.center.distance(point) <= self.radius * (1.0 + 4.0 * core::$t::EPSILON)
+            }
+
+            // The smallest sphere with `a` and `b` on its boundary.
+            #[inline]
+            fn from_two_points(a: $vec3, b: $vec3) -> Self {
+                Self::new((a + b) * 0.5, a.distance(b) * 0.5)
+            }
+
+            // The smallest sphere with `a`, `b` and `c` on its boundary, which is centered on
+            // their plane. Colinear points fall back to the sphere spanning the farthest pair.
+            fn from_three_points(a: $vec3, b: $vec3, c: $vec3) -> Self {
+                let ab = b - a;
+                let ac = c - a;
+                let normal = ab.cross(ac);
+                let length_squared = normal.length_squared();
+                if length_squared <= core::$t::EPSILON * ab.length_squared() * ac.length_squared() {
+                    let bc = Self::from_two_points(b, c);
+                    let pair = if ab.length_squared() > ac.length_squared() {
+                        Self::from_two_points(a, b)
+                    } else {
+                        Self::from_two_points(a, c)
+                    };
+                    return if bc.radius > pair.radius { bc } else { pair };
+                }
+                let offset = (normal.cross(ab) * ac.length_squared()
+                    + ac.cross(normal) * ab.length_squared())
+                    / (2.0 * length_squared);
+                Self::new(a + offset, offset.length())
+            }
+
+            // The sphere with `a`, `b`, `c` and `d` on its boundary. Coplanar points fall back to
+            // the smallest sphere through three of them which contains the fourth.
+            fn from_four_points(a: $vec3, b: $vec3, c: $vec3, d: $vec3) -> Self {
+                let ab = b - a;
+                let ac = c - a;
+                let ad = d - a;
+                let det = ab.dot(ac.cross(ad));
+                if det.abs() <= core::$t::EPSILON * ab.length() * ac.length() * ad.length() {
+                    let candidates = [
+                        (Self::from_three_points(a, b, c), d),
+                        (Self::from_three_points(a, b, d), c),
+                        (Self::from_three_points(a, c, d), b),
+                        (Self::from_three_points(b, c, d), a),
+                    ];
+                    return candidates
+                        .iter()
+                        .filter(|(sphere, other)| sphere.encloses(*other))
+                        .map(|&(sphere, _)| sphere)
+                        .fold(None, |best: Option<Self>, sphere| match best {
+                            Some(best) if best.radius <= sphere.radius => Some(best),
+                            _ => Some(sphere),
+                        })
+                        .unwrap_or_else(|| Self::from_three_points(a, b, c).grow(d));
+                }
+                let offset = (ac.cross(ad) * ab.length_squared()
+                    + ad.cross(ab) * ac.length_squared()
+                    + ab.cross(ac) * ad.length_squared())
+                    / (2.0 * det);
+                Self::new(a + offset, offset.length())
+            }
+
+            /// Returns the smallest sphere containing both `self` and `point`.
+            #[inline]
+            pub fn grow(self, point: $vec3) -> Self {
+                let distance = self.center.distance(point);
+                if distance <= self.radius {
+                    return self;
+                }
+                let radius = (self.radius + distance) * 0.5;
+                let center =
+                    self.center + (point - self.center) * ((radius - self.radius) / distance);
+                Self::new(center, radius)
+            }
+
+            /// Returns `true` if `point` is inside `self` or on its boundary.
+            #[inline]
+            pub fn contains_point(self, point: $vec3) -> bool {
+                self.center.distance_squared(point) <= self.radius * self.radius
+            }
+
+            /// Returns `true` if `other` is entirely inside `self`.
+            #[inline]
+            pub fn contains_sphere(self, other: Self) -> bool {
+                self.center.distance(other.center) + other.radius <= self.radius
+            }
+
+            /// Returns `true` if `self` and `other` overlap or touch.
+            #[inline]
+            pub fn intersects_sphere(self, other: Self) -> bool {
+                let radius = self.radius + other.radius;
+                self.center.distance_squared(other.center) <= radius * radius
+            }
+
+            /// Returns the point inside or on the boundary of `self` which is closest to
+            /// `point`.
+            #[inline]
+            pub fn closest_point(self, point: $vec3) -> $vec3 {
+                let offset = point - self.center;
+                let distance = offset.length();
+                if distance <= self.radius {
+                    point
+                } else {
+                    self.center + offset * (self.radius / distance)
+                }
+            }
+
+            /// Returns the volume of `self`.
+            #[inline]
+            pub fn volume(self) -> $t {
+                (4.0 / 3.0) * core::$t::consts::PI * self.radius * self.radius * self.radius
+            }
+
+            /// Returns the surface area of `self`.
+            #[inline]
+            pub fn surface_area(self) -> $t {
+                4.0 * core::$t::consts::PI * self.radius * self.radius
+            }
+
+            /// Returns `true` if, and only if, all elements are finite.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                self.center.is_finite() && self.radius.is_finite()
+            }
+
+            /// Returns true if the absolute difference of all elements between `self` and `other`
+            /// is less than or equal to `max_abs_diff`.
+            #[inline]
+            pub fn abs_diff_eq(self, other: Self, max_abs_diff: $t) -> bool {
+                self.center.abs_diff_eq(other.center, max_abs_diff)
+                    && (self.radius - other.radius).abs() <= max_abs_diff
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Debug for $sphere {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                fmt.debug_struct(stringify!($sphere))
+                    .field("center", &self.center)
+                    .field("radius", &self.radius)
+                    .finish()
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::fmt::Display for $sphere {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "[{}, {}]", self.center, self.radius)
+            }
+        }
+    };
+}
+
+impl_sphere!(f32, Vec3, Sphere);
+impl_sphere!(f64, DVec3, DSphere);
@@ -10,6 +10,26 @@ mod uvec2_impl_scalar;
 mod uvec3_impl_scalar;
 mod uvec4_impl_scalar;
 
+mod i64vec2_impl_scalar;
+mod i64vec3_impl_scalar;
+mod i64vec4_impl_scalar;
+
+mod u64vec2_impl_scalar;
+mod u64vec3_impl_scalar;
+mod u64vec4_impl_scalar;
+
+mod i16vec2_impl_scalar;
+mod i16vec3_impl_scalar;
+mod i16vec4_impl_scalar;
+
+mod u16vec2_impl_scalar;
+mod u16vec3_impl_scalar;
+mod u16vec4_impl_scalar;
+
+mod u8vec2_impl_scalar;
+mod u8vec3_impl_scalar;
+mod u8vec4_impl_scalar;
+
 mod vec2_impl_scalar;
 mod vec3_impl_scalar;
 #[cfg(any(
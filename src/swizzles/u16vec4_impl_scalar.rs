@@ -0,0 +1,1350 @@
+// Generated by swizzlegen. Do not edit.
+
+use super::Vec4Swizzles;
+use crate::{U16Vec2, U16Vec3, U16Vec4};
+
+impl Vec4Swizzles for U16Vec4 {
+    type Vec2 = U16Vec2;
+    type Vec3 = U16Vec3;
+
+    #[inline]
+    fn xxxx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn xxxy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn xxxz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn xxxw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.x, self.w)
+    }
+    #[inline]
+    fn xxyx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn xxyy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn xxyz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn xxyw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.y, self.w)
+    }
+    #[inline]
+    fn xxzx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn xxzy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn xxzz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn xxzw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.z, self.w)
+    }
+    #[inline]
+    fn xxwx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.w, self.x)
+    }
+    #[inline]
+    fn xxwy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.w, self.y)
+    }
+    #[inline]
+    fn xxwz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.w, self.z)
+    }
+    #[inline]
+    fn xxww(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.x, self.w, self.w)
+    }
+    #[inline]
+    fn xyxx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn xyxy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn xyxz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn xyxw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.x, self.w)
+    }
+    #[inline]
+    fn xyyx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn xyyy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn xyyz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn xyyw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.y, self.w)
+    }
+    #[inline]
+    fn xyzx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn xyzy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn xyzz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn xywx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.w, self.x)
+    }
+    #[inline]
+    fn xywy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.w, self.y)
+    }
+    #[inline]
+    fn xywz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.w, self.z)
+    }
+    #[inline]
+    fn xyww(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.y, self.w, self.w)
+    }
+    #[inline]
+    fn xzxx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn xzxy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn xzxz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn xzxw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.x, self.w)
+    }
+    #[inline]
+    fn xzyx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn xzyy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn xzyz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn xzyw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.y, self.w)
+    }
+    #[inline]
+    fn xzzx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn xzzy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn xzzz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn xzzw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.z, self.w)
+    }
+    #[inline]
+    fn xzwx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.w, self.x)
+    }
+    #[inline]
+    fn xzwy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.w, self.y)
+    }
+    #[inline]
+    fn xzwz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.w, self.z)
+    }
+    #[inline]
+    fn xzww(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.z, self.w, self.w)
+    }
+    #[inline]
+    fn xwxx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.x, self.x)
+    }
+    #[inline]
+    fn xwxy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.x, self.y)
+    }
+    #[inline]
+    fn xwxz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.x, self.z)
+    }
+    #[inline]
+    fn xwxw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.x, self.w)
+    }
+    #[inline]
+    fn xwyx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.y, self.x)
+    }
+    #[inline]
+    fn xwyy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.y, self.y)
+    }
+    #[inline]
+    fn xwyz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.y, self.z)
+    }
+    #[inline]
+    fn xwyw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.y, self.w)
+    }
+    #[inline]
+    fn xwzx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.z, self.x)
+    }
+    #[inline]
+    fn xwzy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.z, self.y)
+    }
+    #[inline]
+    fn xwzz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.z, self.z)
+    }
+    #[inline]
+    fn xwzw(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.z, self.w)
+    }
+    #[inline]
+    fn xwwx(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.w, self.x)
+    }
+    #[inline]
+    fn xwwy(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.w, self.y)
+    }
+    #[inline]
+    fn xwwz(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.w, self.z)
+    }
+    #[inline]
+    fn xwww(self) -> U16Vec4 {
+        U16Vec4::new(self.x, self.w, self.w, self.w)
+    }
+    #[inline]
+    fn yxxx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn yxxy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn yxxz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn yxxw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.x, self.w)
+    }
+    #[inline]
+    fn yxyx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn yxyy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn yxyz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn yxyw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.y, self.w)
+    }
+    #[inline]
+    fn yxzx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn yxzy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn yxzz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn yxzw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.z, self.w)
+    }
+    #[inline]
+    fn yxwx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.w, self.x)
+    }
+    #[inline]
+    fn yxwy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.w, self.y)
+    }
+    #[inline]
+    fn yxwz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.w, self.z)
+    }
+    #[inline]
+    fn yxww(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.x, self.w, self.w)
+    }
+    #[inline]
+    fn yyxx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn yyxy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn yyxz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn yyxw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.x, self.w)
+    }
+    #[inline]
+    fn yyyx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn yyyy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn yyyz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn yyyw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.y, self.w)
+    }
+    #[inline]
+    fn yyzx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn yyzy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn yyzz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn yyzw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.z, self.w)
+    }
+    #[inline]
+    fn yywx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.w, self.x)
+    }
+    #[inline]
+    fn yywy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.w, self.y)
+    }
+    #[inline]
+    fn yywz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.w, self.z)
+    }
+    #[inline]
+    fn yyww(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.y, self.w, self.w)
+    }
+    #[inline]
+    fn yzxx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn yzxy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn yzxz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn yzxw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.x, self.w)
+    }
+    #[inline]
+    fn yzyx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn yzyy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn yzyz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn yzyw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.y, self.w)
+    }
+    #[inline]
+    fn yzzx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn yzzy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn yzzz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn yzzw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.z, self.w)
+    }
+    #[inline]
+    fn yzwx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.w, self.x)
+    }
+    #[inline]
+    fn yzwy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.w, self.y)
+    }
+    #[inline]
+    fn yzwz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.w, self.z)
+    }
+    #[inline]
+    fn yzww(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.z, self.w, self.w)
+    }
+    #[inline]
+    fn ywxx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.x, self.x)
+    }
+    #[inline]
+    fn ywxy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.x, self.y)
+    }
+    #[inline]
+    fn ywxz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.x, self.z)
+    }
+    #[inline]
+    fn ywxw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.x, self.w)
+    }
+    #[inline]
+    fn ywyx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.y, self.x)
+    }
+    #[inline]
+    fn ywyy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.y, self.y)
+    }
+    #[inline]
+    fn ywyz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.y, self.z)
+    }
+    #[inline]
+    fn ywyw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.y, self.w)
+    }
+    #[inline]
+    fn ywzx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.z, self.x)
+    }
+    #[inline]
+    fn ywzy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.z, self.y)
+    }
+    #[inline]
+    fn ywzz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.z, self.z)
+    }
+    #[inline]
+    fn ywzw(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.z, self.w)
+    }
+    #[inline]
+    fn ywwx(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.w, self.x)
+    }
+    #[inline]
+    fn ywwy(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.w, self.y)
+    }
+    #[inline]
+    fn ywwz(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.w, self.z)
+    }
+    #[inline]
+    fn ywww(self) -> U16Vec4 {
+        U16Vec4::new(self.y, self.w, self.w, self.w)
+    }
+    #[inline]
+    fn zxxx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn zxxy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn zxxz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn zxxw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.x, self.w)
+    }
+    #[inline]
+    fn zxyx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn zxyy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn zxyz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn zxyw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.y, self.w)
+    }
+    #[inline]
+    fn zxzx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn zxzy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn zxzz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn zxzw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.z, self.w)
+    }
+    #[inline]
+    fn zxwx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.w, self.x)
+    }
+    #[inline]
+    fn zxwy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.w, self.y)
+    }
+    #[inline]
+    fn zxwz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.w, self.z)
+    }
+    #[inline]
+    fn zxww(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.x, self.w, self.w)
+    }
+    #[inline]
+    fn zyxx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn zyxy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn zyxz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn zyxw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.x, self.w)
+    }
+    #[inline]
+    fn zyyx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn zyyy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn zyyz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn zyyw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.y, self.w)
+    }
+    #[inline]
+    fn zyzx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn zyzy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn zyzz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn zyzw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.z, self.w)
+    }
+    #[inline]
+    fn zywx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.w, self.x)
+    }
+    #[inline]
+    fn zywy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.w, self.y)
+    }
+    #[inline]
+    fn zywz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.w, self.z)
+    }
+    #[inline]
+    fn zyww(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.y, self.w, self.w)
+    }
+    #[inline]
+    fn zzxx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn zzxy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn zzxz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn zzxw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.x, self.w)
+    }
+    #[inline]
+    fn zzyx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn zzyy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn zzyz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn zzyw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.y, self.w)
+    }
+    #[inline]
+    fn zzzx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn zzzy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn zzzz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn zzzw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.z, self.w)
+    }
+    #[inline]
+    fn zzwx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.w, self.x)
+    }
+    #[inline]
+    fn zzwy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.w, self.y)
+    }
+    #[inline]
+    fn zzwz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.w, self.z)
+    }
+    #[inline]
+    fn zzww(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.z, self.w, self.w)
+    }
+    #[inline]
+    fn zwxx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.x, self.x)
+    }
+    #[inline]
+    fn zwxy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.x, self.y)
+    }
+    #[inline]
+    fn zwxz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.x, self.z)
+    }
+    #[inline]
+    fn zwxw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.x, self.w)
+    }
+    #[inline]
+    fn zwyx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.y, self.x)
+    }
+    #[inline]
+    fn zwyy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.y, self.y)
+    }
+    #[inline]
+    fn zwyz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.y, self.z)
+    }
+    #[inline]
+    fn zwyw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.y, self.w)
+    }
+    #[inline]
+    fn zwzx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.z, self.x)
+    }
+    #[inline]
+    fn zwzy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.z, self.y)
+    }
+    #[inline]
+    fn zwzz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.z, self.z)
+    }
+    #[inline]
+    fn zwzw(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.z, self.w)
+    }
+    #[inline]
+    fn zwwx(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.w, self.x)
+    }
+    #[inline]
+    fn zwwy(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.w, self.y)
+    }
+    #[inline]
+    fn zwwz(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.w, self.z)
+    }
+    #[inline]
+    fn zwww(self) -> U16Vec4 {
+        U16Vec4::new(self.z, self.w, self.w, self.w)
+    }
+    #[inline]
+    fn wxxx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.x, self.x)
+    }
+    #[inline]
+    fn wxxy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.x, self.y)
+    }
+    #[inline]
+    fn wxxz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.x, self.z)
+    }
+    #[inline]
+    fn wxxw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.x, self.w)
+    }
+    #[inline]
+    fn wxyx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.y, self.x)
+    }
+    #[inline]
+    fn wxyy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.y, self.y)
+    }
+    #[inline]
+    fn wxyz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.y, self.z)
+    }
+    #[inline]
+    fn wxyw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.y, self.w)
+    }
+    #[inline]
+    fn wxzx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.z, self.x)
+    }
+    #[inline]
+    fn wxzy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.z, self.y)
+    }
+    #[inline]
+    fn wxzz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.z, self.z)
+    }
+    #[inline]
+    fn wxzw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.z, self.w)
+    }
+    #[inline]
+    fn wxwx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.w, self.x)
+    }
+    #[inline]
+    fn wxwy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.w, self.y)
+    }
+    #[inline]
+    fn wxwz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.w, self.z)
+    }
+    #[inline]
+    fn wxww(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.x, self.w, self.w)
+    }
+    #[inline]
+    fn wyxx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.x, self.x)
+    }
+    #[inline]
+    fn wyxy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.x, self.y)
+    }
+    #[inline]
+    fn wyxz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.x, self.z)
+    }
+    #[inline]
+    fn wyxw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.x, self.w)
+    }
+    #[inline]
+    fn wyyx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.y, self.x)
+    }
+    #[inline]
+    fn wyyy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.y, self.y)
+    }
+    #[inline]
+    fn wyyz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.y, self.z)
+    }
+    #[inline]
+    fn wyyw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.y, self.w)
+    }
+    #[inline]
+    fn wyzx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.z, self.x)
+    }
+    #[inline]
+    fn wyzy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.z, self.y)
+    }
+    #[inline]
+    fn wyzz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.z, self.z)
+    }
+    #[inline]
+    fn wyzw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.z, self.w)
+    }
+    #[inline]
+    fn wywx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.w, self.x)
+    }
+    #[inline]
+    fn wywy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.w, self.y)
+    }
+    #[inline]
+    fn wywz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.w, self.z)
+    }
+    #[inline]
+    fn wyww(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.y, self.w, self.w)
+    }
+    #[inline]
+    fn wzxx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.x, self.x)
+    }
+    #[inline]
+    fn wzxy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.x, self.y)
+    }
+    #[inline]
+    fn wzxz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.x, self.z)
+    }
+    #[inline]
+    fn wzxw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.x, self.w)
+    }
+    #[inline]
+    fn wzyx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.y, self.x)
+    }
+    #[inline]
+    fn wzyy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.y, self.y)
+    }
+    #[inline]
+    fn wzyz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.y, self.z)
+    }
+    #[inline]
+    fn wzyw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.y, self.w)
+    }
+    #[inline]
+    fn wzzx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.z, self.x)
+    }
+    #[inline]
+    fn wzzy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.z, self.y)
+    }
+    #[inline]
+    fn wzzz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.z, self.z)
+    }
+    #[inline]
+    fn wzzw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.z, self.w)
+    }
+    #[inline]
+    fn wzwx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.w, self.x)
+    }
+    #[inline]
+    fn wzwy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.w, self.y)
+    }
+    #[inline]
+    fn wzwz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.w, self.z)
+    }
+    #[inline]
+    fn wzww(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.z, self.w, self.w)
+    }
+    #[inline]
+    fn wwxx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.x, self.x)
+    }
+    #[inline]
+    fn wwxy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.x, self.y)
+    }
+    #[inline]
+    fn wwxz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.x, self.z)
+    }
+    #[inline]
+    fn wwxw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.x, self.w)
+    }
+    #[inline]
+    fn wwyx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.y, self.x)
+    }
+    #[inline]
+    fn wwyy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.y, self.y)
+    }
+    #[inline]
+    fn wwyz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.y, self.z)
+    }
+    #[inline]
+    fn wwyw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.y, self.w)
+    }
+    #[inline]
+    fn wwzx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.z, self.x)
+    }
+    #[inline]
+    fn wwzy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.z, self.y)
+    }
+    #[inline]
+    fn wwzz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.z, self.z)
+    }
+    #[inline]
+    fn wwzw(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.z, self.w)
+    }
+    #[inline]
+    fn wwwx(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.w, self.x)
+    }
+    #[inline]
+    fn wwwy(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.w, self.y)
+    }
+    #[inline]
+    fn wwwz(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.w, self.z)
+    }
+    #[inline]
+    fn wwww(self) -> U16Vec4 {
+        U16Vec4::new(self.w, self.w, self.w, self.w)
+    }
+    #[inline]
+    fn xxx(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.x, self.x)
+    }
+    #[inline]
+    fn xxy(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.x, self.y)
+    }
+    #[inline]
+    fn xxz(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.x, self.z)
+    }
+    #[inline]
+    fn xxw(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.x, self.w)
+    }
+    #[inline]
+    fn xyx(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.y, self.x)
+    }
+    #[inline]
+    fn xyy(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.y, self.y)
+    }
+    #[inline]
+    fn xyz(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.y, self.z)
+    }
+    #[inline]
+    fn xyw(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.y, self.w)
+    }
+    #[inline]
+    fn xzx(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.z, self.x)
+    }
+    #[inline]
+    fn xzy(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.z, self.y)
+    }
+    #[inline]
+    fn xzz(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.z, self.z)
+    }
+    #[inline]
+    fn xzw(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.z, self.w)
+    }
+    #[inline]
+    fn xwx(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.w, self.x)
+    }
+    #[inline]
+    fn xwy(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.w, self.y)
+    }
+    #[inline]
+    fn xwz(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.w, self.z)
+    }
+    #[inline]
+    fn xww(self) -> U16Vec3 {
+        U16Vec3::new(self.x, self.w, self.w)
+    }
+    #[inline]
+    fn yxx(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.x, self.x)
+    }
+    #[inline]
+    fn yxy(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.x, self.y)
+    }
+    #[inline]
+    fn yxz(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.x, self.z)
+    }
+    #[inline]
+    fn yxw(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.x, self.w)
+    }
+    #[inline]
+    fn yyx(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.y, self.x)
+    }
+    #[inline]
+    fn yyy(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.y, self.y)
+    }
+    #[inline]
+    fn yyz(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.y, self.z)
+    }
+    #[inline]
+    fn yyw(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.y, self.w)
+    }
+    #[inline]
+    fn yzx(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.z, self.x)
+    }
+    #[inline]
+    fn yzy(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.z, self.y)
+    }
+    #[inline]
+    fn yzz(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.z, self.z)
+    }
+    #[inline]
+    fn yzw(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.z, self.w)
+    }
+    #[inline]
+    fn ywx(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.w, self.x)
+    }
+    #[inline]
+    fn ywy(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.w, self.y)
+    }
+    #[inline]
+    fn ywz(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.w, self.z)
+    }
+    #[inline]
+    fn yww(self) -> U16Vec3 {
+        U16Vec3::new(self.y, self.w, self.w)
+    }
+    #[inline]
+    fn zxx(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.x, self.x)
+    }
+    #[inline]
+    fn zxy(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.x, self.y)
+    }
+    #[inline]
+    fn zxz(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.x, self.z)
+    }
+    #[inline]
+    fn zxw(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.x, self.w)
+    }
+    #[inline]
+    fn zyx(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.y, self.x)
+    }
+    #[inline]
+    fn zyy(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.y, self.y)
+    }
+    #[inline]
+    fn zyz(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.y, self.z)
+    }
+    #[inline]
+    fn zyw(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.y, self.w)
+    }
+    #[inline]
+    fn zzx(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.z, self.x)
+    }
+    #[inline]
+    fn zzy(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.z, self.y)
+    }
+    #[inline]
+    fn zzz(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.z, self.z)
+    }
+    #[inline]
+    fn zzw(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.z, self.w)
+    }
+    #[inline]
+    fn zwx(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.w, self.x)
+    }
+    #[inline]
+    fn zwy(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.w, self.y)
+    }
+    #[inline]
+    fn zwz(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.w, self.z)
+    }
+    #[inline]
+    fn zww(self) -> U16Vec3 {
+        U16Vec3::new(self.z, self.w, self.w)
+    }
+    #[inline]
+    fn wxx(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.x, self.x)
+    }
+    #[inline]
+    fn wxy(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.x, self.y)
+    }
+    #[inline]
+    fn wxz(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.x, self.z)
+    }
+    #[inline]
+    fn wxw(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.x, self.w)
+    }
+    #[inline]
+    fn wyx(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.y, self.x)
+    }
+    #[inline]
+    fn wyy(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.y, self.y)
+    }
+    #[inline]
+    fn wyz(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.y, self.z)
+    }
+    #[inline]
+    fn wyw(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.y, self.w)
+    }
+    #[inline]
+    fn wzx(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.z, self.x)
+    }
+    #[inline]
+    fn wzy(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.z, self.y)
+    }
+    #[inline]
+    fn wzz(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.z, self.z)
+    }
+    #[inline]
+    fn wzw(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.z, self.w)
+    }
+    #[inline]
+    fn wwx(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.w, self.x)
+    }
+    #[inline]
+    fn wwy(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.w, self.y)
+    }
+    #[inline]
+    fn wwz(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.w, self.z)
+    }
+    #[inline]
+    fn www(self) -> U16Vec3 {
+        U16Vec3::new(self.w, self.w, self.w)
+    }
+    #[inline]
+    fn xx(self) -> U16Vec2 {
+        U16Vec2::new(self.x, self.x)
+    }
+    #[inline]
+    fn xy(self) -> U16Vec2 {
+        U16Vec2::new(self.x, self.y)
+    }
+    #[inline]
+    fn xz(self) -> U16Vec2 {
+        U16Vec2::new(self.x, self.z)
+    }
+    #[inline]
+    fn xw(self) -> U16Vec2 {
+        U16Vec2::new(self.x, self.w)
+    }
+    #[inline]
+    fn yx(self) -> U16Vec2 {
+        U16Vec2::new(self.y, self.x)
+    }
+    #[inline]
+    fn yy(self) -> U16Vec2 {
+        U16Vec2::new(self.y, self.y)
+    }
+    #[inline]
+    fn yz(self) -> U16Vec2 {
+        U16Vec2::new(self.y, self.z)
+    }
+    #[inline]
+    fn yw(self) -> U16Vec2 {
+        U16Vec2::new(self.y, self.w)
+    }
+    #[inline]
+    fn zx(self) -> U16Vec2 {
+        U16Vec2::new(self.z, self.x)
+    }
+    #[inline]
+    fn zy(self) -> U16Vec2 {
+        U16Vec2::new(self.z, self.y)
+    }
+    #[inline]
+    fn zz(self) -> U16Vec2 {
+        U16Vec2::new(self.z, self.z)
+    }
+    #[inline]
+    fn zw(self) -> U16Vec2 {
+        U16Vec2::new(self.z, self.w)
+    }
+    #[inline]
+    fn wx(self) -> U16Vec2 {
+        U16Vec2::new(self.w, self.x)
+    }
+    #[inline]
+    fn wy(self) -> U16Vec2 {
+        U16Vec2::new(self.w, self.y)
+    }
+    #[inline]
+    fn wz(self) -> U16Vec2 {
+        U16Vec2::new(self.w, self.z)
+    }
+    #[inline]
+    fn ww(self) -> U16Vec2 {
+        U16Vec2::new(self.w, self.w)
+    }
+}
@@ -182,6 +182,258 @@ macro_rules! impl_vecn_signed_methods {
     };
 }
 
+// Adds integer type vector arithmetic methods to an impl.
+// The methods here should be supported for all integer types of $t and all sizes of vector.
+macro_rules! impl_vecn_integer_methods {
+    ($t:ty, $vecn:ident, $uvecn:ident, $mask:ident, $inner:ident) => {
+        /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+        ///
+        /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn wrapping_add(self, rhs: Self) -> Self {
+            Self(IntegerVector::wrapping_add(self.0, rhs.0))
+        }
+
+        /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+        ///
+        /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn wrapping_sub(self, rhs: Self) -> Self {
+            Self(IntegerVector::wrapping_sub(self.0, rhs.0))
+        }
+
+        /// Returns a vector containing the wrapping multiplication of `self` and `rhs`.
+        ///
+        /// In other words this computes `[self.x.wrapping_mul(rhs.x), self.y.wrapping_mul(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn wrapping_mul(self, rhs: Self) -> Self {
+            Self(IntegerVector::wrapping_mul(self.0, rhs.0))
+        }
+
+        /// Returns a vector containing the wrapping division of `self` and `rhs`.
+        ///
+        /// In other words this computes `[self.x.wrapping_div(rhs.x), self.y.wrapping_div(rhs.y), ..]`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if any element of `rhs` is zero.
+        #[inline(always)]
+        pub fn wrapping_div(self, rhs: Self) -> Self {
+            Self(IntegerVector::wrapping_div(self.0, rhs.0))
+        }
+
+        /// Returns a vector containing the saturating addition of `self` and `rhs`.
+        ///
+        /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn saturating_add(self, rhs: Self) -> Self {
+            Self(IntegerVector::saturating_add(self.0, rhs.0))
+        }
+
+        /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+        ///
+        /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn saturating_sub(self, rhs: Self) -> Self {
+            Self(IntegerVector::saturating_sub(self.0, rhs.0))
+        }
+
+        /// Returns a vector containing the saturating multiplication of `self` and `rhs`.
+        ///
+        /// In other words this computes `[self.x.saturating_mul(rhs.x), self.y.saturating_mul(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn saturating_mul(self, rhs: Self) -> Self {
+            Self(IntegerVector::saturating_mul(self.0, rhs.0))
+        }
+
+        /// Returns a vector containing the saturating division of `self` and `rhs`.
+        ///
+        /// In other words this computes `[self.x.saturating_div(rhs.x), self.y.saturating_div(rhs.y), ..]`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if any element of `rhs` is zero.
+        #[inline(always)]
+        pub fn saturating_div(self, rhs: Self) -> Self {
+            Self(IntegerVector::saturating_div(self.0, rhs.0))
+        }
+
+        /// Returns the checked addition of `self` and `rhs`.
+        ///
+        /// Returns `None` if the addition of any element overflows.
+        #[inline(always)]
+        pub fn checked_add(self, rhs: Self) -> Option<Self> {
+            IntegerVector::checked_add(self.0, rhs.0).map(Self)
+        }
+
+        /// Returns the checked subtraction of `self` and `rhs`.
+        ///
+        /// Returns `None` if the subtraction of any element overflows.
+        #[inline(always)]
+        pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+            IntegerVector::checked_sub(self.0, rhs.0).map(Self)
+        }
+
+        /// Returns the checked multiplication of `self` and `rhs`.
+        ///
+        /// Returns `None` if the multiplication of any element overflows.
+        #[inline(always)]
+        pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+            IntegerVector::checked_mul(self.0, rhs.0).map(Self)
+        }
+
+        /// Returns the checked division of `self` and `rhs`.
+        ///
+        /// Returns `None` if the division of any element overflows or if any element of `rhs` is zero.
+        #[inline(always)]
+        pub fn checked_div(self, rhs: Self) -> Option<Self> {
+            IntegerVector::checked_div(self.0, rhs.0).map(Self)
+        }
+
+        /// Returns the wrapping addition of `self` and `rhs` along with a mask of the
+        /// elements where an overflow occurred.
+        ///
+        /// In other words this computes `[self.x.overflowing_add(rhs.x), self.y.overflowing_add(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn overflowing_add(self, rhs: Self) -> (Self, $mask) {
+            let (v, mask) = IntegerVector::overflowing_add(self.0, rhs.0);
+            (Self(v), $mask(mask))
+        }
+
+        /// Returns the wrapping subtraction of `self` and `rhs` along with a mask of the
+        /// elements where an overflow occurred.
+        ///
+        /// In other words this computes `[self.x.overflowing_sub(rhs.x), self.y.overflowing_sub(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn overflowing_sub(self, rhs: Self) -> (Self, $mask) {
+            let (v, mask) = IntegerVector::overflowing_sub(self.0, rhs.0);
+            (Self(v), $mask(mask))
+        }
+
+        /// Returns the wrapping multiplication of `self` and `rhs` along with a mask of the
+        /// elements where an overflow occurred.
+        ///
+        /// In other words this computes `[self.x.overflowing_mul(rhs.x), self.y.overflowing_mul(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn overflowing_mul(self, rhs: Self) -> (Self, $mask) {
+            let (v, mask) = IntegerVector::overflowing_mul(self.0, rhs.0);
+            (Self(v), $mask(mask))
+        }
+
+        /// Returns the wrapping division of `self` and `rhs` along with a mask of the
+        /// elements where an overflow occurred.
+        ///
+        /// In other words this computes `[self.x.overflowing_div(rhs.x), self.y.overflowing_div(rhs.y), ..]`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if any element of `rhs` is zero.
+        #[inline(always)]
+        pub fn overflowing_div(self, rhs: Self) -> (Self, $mask) {
+            let (v, mask) = IntegerVector::overflowing_div(self.0, rhs.0);
+            (Self(v), $mask(mask))
+        }
+
+        /// Returns the element-wise Euclidean quotient of `self` and `rhs`.
+        ///
+        /// Unlike `/` the quotient is rounded towards negative infinity for positive `rhs`, so
+        /// the matching [`Self::rem_euclid()`] is never negative.
+        ///
+        /// In other words this computes `[self.x.div_euclid(rhs.x), self.y.div_euclid(rhs.y), ..]`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if any element of `rhs` is zero, or if the division overflows.
+        #[inline(always)]
+        pub fn div_euclid(self, rhs: Self) -> Self {
+            Self(IntegerVector::div_euclid(self.0, rhs.0))
+        }
+
+        /// Returns the element-wise least non-negative remainder of `self` divided by `rhs`.
+        ///
+        /// In other words this computes `[self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y), ..]`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if any element of `rhs` is zero, or if the division overflows.
+        #[inline(always)]
+        pub fn rem_euclid(self, rhs: Self) -> Self {
+            Self(IntegerVector::rem_euclid(self.0, rhs.0))
+        }
+
+        /// Returns a vector containing the absolute difference of each element of `self` and
+        /// `rhs`.
+        ///
+        /// The result is unsigned so it can represent the difference between any two values.
+        ///
+        /// In other words this computes `[self.x.abs_diff(rhs.x), self.y.abs_diff(rhs.y), ..]`.
+        #[inline(always)]
+        pub fn abs_diff(self, rhs: Self) -> $uvecn {
+            $uvecn(IntegerVector::abs_diff(self.0, rhs.0))
+        }
+
+        /// Returns the sum of all elements of `self`.
+        ///
+        /// In other words, this computes `self.x + self.y + ..`.
+        #[inline(always)]
+        pub fn element_sum(self) -> $t {
+            IntegerVector::element_sum(self.0)
+        }
+
+        /// Returns the product of all elements of `self`.
+        ///
+        /// In other words, this computes `self.x * self.y * ..`.
+        #[inline(always)]
+        pub fn element_product(self) -> $t {
+            IntegerVector::element_product(self.0)
+        }
+
+        /// Returns a vector containing the number of ones in the binary representation of each
+        /// element of `self`.
+        #[inline(always)]
+        pub fn count_ones(self) -> $uvecn {
+            $uvecn(IntegerVector::count_ones(self.0))
+        }
+
+        /// Returns a vector containing the number of leading zeros in the binary representation
+        /// of each element of `self`.
+        #[inline(always)]
+        pub fn leading_zeros(self) -> $uvecn {
+            $uvecn(IntegerVector::leading_zeros(self.0))
+        }
+
+        /// Returns a vector containing the number of trailing zeros in the binary representation
+        /// of each element of `self`.
+        #[inline(always)]
+        pub fn trailing_zeros(self) -> $uvecn {
+            $uvecn(IntegerVector::trailing_zeros(self.0))
+        }
+    };
+}
+
+// Adds unsigned integer type vector methods to an impl.
+// The methods here should be supported for unsigned integer types of $t and all sizes of vector.
+macro_rules! impl_vecn_unsigned_integer_methods {
+    ($t:ty, $vecn:ident, $mask:ident, $inner:ident) => {
+        /// Returns a vector mask containing `true` for each element of `self` that is a power of
+        /// two.
+        #[inline(always)]
+        pub fn is_power_of_two(self) -> $mask {
+            $mask(UnsignedIntegerVector::is_power_of_two(self.0))
+        }
+
+        /// Returns a vector containing the smallest power of two greater than or equal to each
+        /// element of `self`.
+        ///
+        /// When an element would overflow it panics in debug mode and wraps to zero in release
+        /// mode, like the primitive `next_power_of_two`.
+        #[inline(always)]
+        pub fn next_power_of_two(self) -> Self {
+            Self(UnsignedIntegerVector::next_power_of_two(self.0))
+        }
+    };
+}
+
 // Adds float type vector methods to an impl.
 // The methods here should be supported for float types of $t and all sizes of vector.
 macro_rules! impl_vecn_float_methods {
@@ -896,6 +1148,17 @@ macro_rules! impl_vecn_scalar_bit_op_traits {
     };
 }
 
+/// Converts an integer `$v` to the integer type `$t`, saturating at the bounds of `$t`.
+macro_rules! saturating_cast {
+    ($v:expr, $t:ty) => {
+        match <$t as core::convert::TryFrom<_>>::try_from($v) {
+            Ok(v) => v,
+            Err(_) if $v > 0 => <$t>::MAX,
+            Err(_) => <$t>::MIN,
+        }
+    };
+}
+
 macro_rules! impl_as_vec2 {
     () => {
         #[deprecated(since = "0.18.0", note = "please use `as_vec2()` instead")]
@@ -904,6 +1167,9 @@ macro_rules! impl_as_vec2 {
             self.as_vec2()
         }
 
+        impl_as_vec2!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `f32`.
         #[inline(always)]
         pub fn as_vec2(&self) -> Vec2 {
@@ -920,6 +1186,9 @@ macro_rules! impl_as_vec3 {
             self.as_vec3()
         }
 
+        impl_as_vec3!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `f32`.
         #[inline(always)]
         pub fn as_vec3(&self) -> Vec3 {
@@ -936,6 +1205,9 @@ macro_rules! impl_as_vec4 {
             self.as_vec4()
         }
 
+        impl_as_vec4!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `f32`.
         #[inline(always)]
         pub fn as_vec4(&self) -> Vec4 {
@@ -952,6 +1224,9 @@ macro_rules! impl_as_dvec2 {
             self.as_dvec2()
         }
 
+        impl_as_dvec2!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `f64`.
         #[inline(always)]
         pub fn as_dvec2(&self) -> DVec2 {
@@ -968,6 +1243,9 @@ macro_rules! impl_as_dvec3 {
             self.as_dvec3()
         }
 
+        impl_as_dvec3!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `f64`.
         #[inline(always)]
         pub fn as_dvec3(&self) -> DVec3 {
@@ -984,6 +1262,9 @@ macro_rules! impl_as_dvec4 {
             self.as_dvec4()
         }
 
+        impl_as_dvec4!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `f64`.
         #[inline(always)]
         pub fn as_dvec4(&self) -> DVec4 {
@@ -1000,12 +1281,23 @@ macro_rules! impl_as_ivec2 {
             self.as_ivec2()
         }
 
+        impl_as_ivec2!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `i32`.
         #[inline(always)]
         pub fn as_ivec2(&self) -> IVec2 {
             IVec2::new(self.x as i32, self.y as i32)
         }
     };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i32`, clamping values that are out of range to
+        /// `i32::MIN` and `i32::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_ivec2(&self) -> IVec2 {
+            IVec2::new(saturating_cast!(self.x, i32), saturating_cast!(self.y, i32))
+        }
+    };
 }
 
 macro_rules! impl_as_ivec3 {
@@ -1016,12 +1308,23 @@ macro_rules! impl_as_ivec3 {
             self.as_ivec3()
         }
 
+        impl_as_ivec3!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `i32`.
         #[inline(always)]
         pub fn as_ivec3(&self) -> IVec3 {
             IVec3::new(self.x as i32, self.y as i32, self.z as i32)
         }
     };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i32`, clamping values that are out of range to
+        /// `i32::MIN` and `i32::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_ivec3(&self) -> IVec3 {
+            IVec3::new(saturating_cast!(self.x, i32), saturating_cast!(self.y, i32), saturating_cast!(self.z, i32))
+        }
+    };
 }
 
 macro_rules! impl_as_ivec4 {
@@ -1032,12 +1335,23 @@ macro_rules! impl_as_ivec4 {
             self.as_ivec4()
         }
 
+        impl_as_ivec4!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `i32`.
         #[inline(always)]
         pub fn as_ivec4(&self) -> IVec4 {
             IVec4::new(self.x as i32, self.y as i32, self.z as i32, self.w as i32)
         }
     };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i32`, clamping values that are out of range to
+        /// `i32::MIN` and `i32::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_ivec4(&self) -> IVec4 {
+            IVec4::new(saturating_cast!(self.x, i32), saturating_cast!(self.y, i32), saturating_cast!(self.z, i32), saturating_cast!(self.w, i32))
+        }
+    };
 }
 
 macro_rules! impl_as_uvec2 {
@@ -1048,12 +1362,23 @@ macro_rules! impl_as_uvec2 {
             self.as_uvec2()
         }
 
+        impl_as_uvec2!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `u32`.
         #[inline(always)]
         pub fn as_uvec2(&self) -> UVec2 {
             UVec2::new(self.x as u32, self.y as u32)
         }
     };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u32`, clamping values that are out of range to
+        /// `u32::MIN` and `u32::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_uvec2(&self) -> UVec2 {
+            UVec2::new(saturating_cast!(self.x, u32), saturating_cast!(self.y, u32))
+        }
+    };
 }
 
 macro_rules! impl_as_uvec3 {
@@ -1064,12 +1389,23 @@ macro_rules! impl_as_uvec3 {
             self.as_uvec3()
         }
 
+        impl_as_uvec3!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `u32`.
         #[inline(always)]
         pub fn as_uvec3(&self) -> UVec3 {
             UVec3::new(self.x as u32, self.y as u32, self.z as u32)
         }
     };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u32`, clamping values that are out of range to
+        /// `u32::MIN` and `u32::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_uvec3(&self) -> UVec3 {
+            UVec3::new(saturating_cast!(self.x, u32), saturating_cast!(self.y, u32), saturating_cast!(self.z, u32))
+        }
+    };
 }
 
 macro_rules! impl_as_uvec4 {
@@ -1080,10 +1416,336 @@ macro_rules! impl_as_uvec4 {
             self.as_uvec4()
         }
 
+        impl_as_uvec4!(@cast);
+    };
+    (@cast) => {
         /// Casts all elements of `self` to `u32`.
         #[inline(always)]
         pub fn as_uvec4(&self) -> UVec4 {
             UVec4::new(self.x as u32, self.y as u32, self.z as u32, self.w as u32)
         }
     };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u32`, clamping values that are out of range to
+        /// `u32::MIN` and `u32::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_uvec4(&self) -> UVec4 {
+            UVec4::new(saturating_cast!(self.x, u32), saturating_cast!(self.y, u32), saturating_cast!(self.z, u32), saturating_cast!(self.w, u32))
+        }
+    };
+}
+
+macro_rules! impl_as_i64vec2 {
+    () => {
+        /// Casts all elements of `self` to `i64`.
+        #[inline(always)]
+        pub fn as_i64vec2(&self) -> I64Vec2 {
+            I64Vec2::new(self.x as i64, self.y as i64)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i64`, clamping values that are out of range to
+        /// `i64::MIN` and `i64::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_i64vec2(&self) -> I64Vec2 {
+            I64Vec2::new(saturating_cast!(self.x, i64), saturating_cast!(self.y, i64))
+        }
+    };
+}
+
+macro_rules! impl_as_i64vec3 {
+    () => {
+        /// Casts all elements of `self` to `i64`.
+        #[inline(always)]
+        pub fn as_i64vec3(&self) -> I64Vec3 {
+            I64Vec3::new(self.x as i64, self.y as i64, self.z as i64)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i64`, clamping values that are out of range to
+        /// `i64::MIN` and `i64::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_i64vec3(&self) -> I64Vec3 {
+            I64Vec3::new(
+                saturating_cast!(self.x, i64),
+                saturating_cast!(self.y, i64),
+                saturating_cast!(self.z, i64),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_i64vec4 {
+    () => {
+        /// Casts all elements of `self` to `i64`.
+        #[inline(always)]
+        pub fn as_i64vec4(&self) -> I64Vec4 {
+            I64Vec4::new(self.x as i64, self.y as i64, self.z as i64, self.w as i64)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i64`, clamping values that are out of range to
+        /// `i64::MIN` and `i64::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_i64vec4(&self) -> I64Vec4 {
+            I64Vec4::new(
+                saturating_cast!(self.x, i64),
+                saturating_cast!(self.y, i64),
+                saturating_cast!(self.z, i64),
+                saturating_cast!(self.w, i64),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_u64vec2 {
+    () => {
+        /// Casts all elements of `self` to `u64`.
+        #[inline(always)]
+        pub fn as_u64vec2(&self) -> U64Vec2 {
+            U64Vec2::new(self.x as u64, self.y as u64)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u64`, clamping values that are out of range to
+        /// `u64::MIN` and `u64::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u64vec2(&self) -> U64Vec2 {
+            U64Vec2::new(saturating_cast!(self.x, u64), saturating_cast!(self.y, u64))
+        }
+    };
+}
+
+macro_rules! impl_as_u64vec3 {
+    () => {
+        /// Casts all elements of `self` to `u64`.
+        #[inline(always)]
+        pub fn as_u64vec3(&self) -> U64Vec3 {
+            U64Vec3::new(self.x as u64, self.y as u64, self.z as u64)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u64`, clamping values that are out of range to
+        /// `u64::MIN` and `u64::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u64vec3(&self) -> U64Vec3 {
+            U64Vec3::new(
+                saturating_cast!(self.x, u64),
+                saturating_cast!(self.y, u64),
+                saturating_cast!(self.z, u64),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_u64vec4 {
+    () => {
+        /// Casts all elements of `self` to `u64`.
+        #[inline(always)]
+        pub fn as_u64vec4(&self) -> U64Vec4 {
+            U64Vec4::new(self.x as u64, self.y as u64, self.z as u64, self.w as u64)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u64`, clamping values that are out of range to
+        /// `u64::MIN` and `u64::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u64vec4(&self) -> U64Vec4 {
+            U64Vec4::new(
+                saturating_cast!(self.x, u64),
+                saturating_cast!(self.y, u64),
+                saturating_cast!(self.z, u64),
+                saturating_cast!(self.w, u64),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_i16vec2 {
+    () => {
+        /// Casts all elements of `self` to `i16`.
+        #[inline(always)]
+        pub fn as_i16vec2(&self) -> I16Vec2 {
+            I16Vec2::new(self.x as i16, self.y as i16)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i16`, clamping values that are out of range to
+        /// `i16::MIN` and `i16::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_i16vec2(&self) -> I16Vec2 {
+            I16Vec2::new(saturating_cast!(self.x, i16), saturating_cast!(self.y, i16))
+        }
+    };
+}
+
+macro_rules! impl_as_i16vec3 {
+    () => {
+        /// Casts all elements of `self` to `i16`.
+        #[inline(always)]
+        pub fn as_i16vec3(&self) -> I16Vec3 {
+            I16Vec3::new(self.x as i16, self.y as i16, self.z as i16)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i16`, clamping values that are out of range to
+        /// `i16::MIN` and `i16::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_i16vec3(&self) -> I16Vec3 {
+            I16Vec3::new(
+                saturating_cast!(self.x, i16),
+                saturating_cast!(self.y, i16),
+                saturating_cast!(self.z, i16),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_i16vec4 {
+    () => {
+        /// Casts all elements of `self` to `i16`.
+        #[inline(always)]
+        pub fn as_i16vec4(&self) -> I16Vec4 {
+            I16Vec4::new(self.x as i16, self.y as i16, self.z as i16, self.w as i16)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `i16`, clamping values that are out of range to
+        /// `i16::MIN` and `i16::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_i16vec4(&self) -> I16Vec4 {
+            I16Vec4::new(
+                saturating_cast!(self.x, i16),
+                saturating_cast!(self.y, i16),
+                saturating_cast!(self.z, i16),
+                saturating_cast!(self.w, i16),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_u16vec2 {
+    () => {
+        /// Casts all elements of `self` to `u16`.
+        #[inline(always)]
+        pub fn as_u16vec2(&self) -> U16Vec2 {
+            U16Vec2::new(self.x as u16, self.y as u16)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u16`, clamping values that are out of range to
+        /// `u16::MIN` and `u16::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u16vec2(&self) -> U16Vec2 {
+            U16Vec2::new(saturating_cast!(self.x, u16), saturating_cast!(self.y, u16))
+        }
+    };
+}
+
+macro_rules! impl_as_u16vec3 {
+    () => {
+        /// Casts all elements of `self` to `u16`.
+        #[inline(always)]
+        pub fn as_u16vec3(&self) -> U16Vec3 {
+            U16Vec3::new(self.x as u16, self.y as u16, self.z as u16)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u16`, clamping values that are out of range to
+        /// `u16::MIN` and `u16::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u16vec3(&self) -> U16Vec3 {
+            U16Vec3::new(
+                saturating_cast!(self.x, u16),
+                saturating_cast!(self.y, u16),
+                saturating_cast!(self.z, u16),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_u16vec4 {
+    () => {
+        /// Casts all elements of `self` to `u16`.
+        #[inline(always)]
+        pub fn as_u16vec4(&self) -> U16Vec4 {
+            U16Vec4::new(self.x as u16, self.y as u16, self.z as u16, self.w as u16)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u16`, clamping values that are out of range to
+        /// `u16::MIN` and `u16::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u16vec4(&self) -> U16Vec4 {
+            U16Vec4::new(
+                saturating_cast!(self.x, u16),
+                saturating_cast!(self.y, u16),
+                saturating_cast!(self.z, u16),
+                saturating_cast!(self.w, u16),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_u8vec2 {
+    () => {
+        /// Casts all elements of `self` to `u8`.
+        #[inline(always)]
+        pub fn as_u8vec2(&self) -> U8Vec2 {
+            U8Vec2::new(self.x as u8, self.y as u8)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u8`, clamping values that are out of range to
+        /// `u8::MIN` and `u8::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u8vec2(&self) -> U8Vec2 {
+            U8Vec2::new(saturating_cast!(self.x, u8), saturating_cast!(self.y, u8))
+        }
+    };
+}
+
+macro_rules! impl_as_u8vec3 {
+    () => {
+        /// Casts all elements of `self` to `u8`.
+        #[inline(always)]
+        pub fn as_u8vec3(&self) -> U8Vec3 {
+            U8Vec3::new(self.x as u8, self.y as u8, self.z as u8)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u8`, clamping values that are out of range to
+        /// `u8::MIN` and `u8::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u8vec3(&self) -> U8Vec3 {
+            U8Vec3::new(
+                saturating_cast!(self.x, u8),
+                saturating_cast!(self.y, u8),
+                saturating_cast!(self.z, u8),
+            )
+        }
+    };
+}
+
+macro_rules! impl_as_u8vec4 {
+    () => {
+        /// Casts all elements of `self` to `u8`.
+        #[inline(always)]
+        pub fn as_u8vec4(&self) -> U8Vec4 {
+            U8Vec4::new(self.x as u8, self.y as u8, self.z as u8, self.w as u8)
+        }
+    };
+    (@saturating) => {
+        /// Casts all elements of `self` to `u8`, clamping values that are out of range to
+        /// `u8::MIN` and `u8::MAX` instead of truncating them.
+        #[inline]
+        pub fn saturating_as_u8vec4(&self) -> U8Vec4 {
+            U8Vec4::new(
+                saturating_cast!(self.x, u8),
+                saturating_cast!(self.y, u8),
+                saturating_cast!(self.z, u8),
+                saturating_cast!(self.w, u8),
+            )
+        }
+    };
 }
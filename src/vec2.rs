@@ -1,5 +1,5 @@
 use crate::core::traits::vector::*;
-use crate::{BVec2, DVec3, IVec3, UVec3, Vec3, XY};
+use crate::{BVec2, DVec3, I16Vec3, I64Vec3, IVec3, U16Vec3, U64Vec3, U8Vec3, UVec3, Vec3, XY};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -82,6 +82,20 @@ macro_rules! impl_vec2_float_methods {
         pub fn angle_between(self, other: Self) -> $t {
             self.0.angle_between(other.0)
         }
+
+        /// Returns `rhs` rotated by the angle of `self`, treating both vectors as complex
+        /// numbers and multiplying them.
+        ///
+        /// If `self` is normalized this is a pure rotation, otherwise the result is also scaled
+        /// by the length of `self`.
+        #[must_use]
+        #[inline]
+        pub fn rotate(self, rhs: Self) -> Self {
+            Self::new(
+                self.x * rhs.x - self.y * rhs.y,
+                self.y * rhs.x + self.x * rhs.y,
+            )
+        }
     };
 }
 
@@ -190,6 +204,11 @@ impl Vec2 {
     impl_as_dvec2!();
     impl_as_ivec2!();
     impl_as_uvec2!();
+    impl_as_i64vec2!();
+    impl_as_u64vec2!();
+    impl_as_i16vec2!();
+    impl_as_u16vec2!();
+    impl_as_u8vec2!();
 }
 impl_vec2_signed_traits!(f32, vec2, Vec2, Vec3, BVec2, XYF32);
 
@@ -205,6 +224,11 @@ impl DVec2 {
     impl_as_vec2!();
     impl_as_ivec2!();
     impl_as_uvec2!();
+    impl_as_i64vec2!();
+    impl_as_u64vec2!();
+    impl_as_i16vec2!();
+    impl_as_u16vec2!();
+    impl_as_u8vec2!();
 }
 impl_vec2_signed_traits!(f64, dvec2, DVec2, DVec3, BVec2, XYF64);
 
@@ -217,9 +241,21 @@ pub struct IVec2(pub(crate) XYI32);
 
 impl IVec2 {
     impl_vec2_signed_methods!(i32, IVec2, IVec3, BVec2, XYI32);
+    impl_vecn_integer_methods!(i32, IVec2, UVec2, BVec2, XYI32);
     impl_as_vec2!();
     impl_as_dvec2!();
     impl_as_uvec2!();
+    impl_as_i64vec2!();
+    impl_as_u64vec2!();
+    impl_as_i16vec2!();
+    impl_as_u16vec2!();
+    impl_as_u8vec2!();
+    impl_as_uvec2!(@saturating);
+    impl_as_i64vec2!(@saturating);
+    impl_as_u64vec2!(@saturating);
+    impl_as_i16vec2!(@saturating);
+    impl_as_u16vec2!(@saturating);
+    impl_as_u8vec2!(@saturating);
 }
 impl_vec2_signed_traits!(i32, ivec2, IVec2, IVec3, BVec2, XYI32);
 impl_vecn_eq_hash_traits!(i32, 2, IVec2);
@@ -247,9 +283,22 @@ pub struct UVec2(pub(crate) XYU32);
 
 impl UVec2 {
     impl_vec2_common_methods!(u32, UVec2, UVec3, BVec2, XYU32);
+    impl_vecn_integer_methods!(u32, UVec2, UVec2, BVec2, XYU32);
+    impl_vecn_unsigned_integer_methods!(u32, UVec2, BVec2, XYU32);
     impl_as_vec2!();
     impl_as_dvec2!();
     impl_as_ivec2!();
+    impl_as_i64vec2!();
+    impl_as_u64vec2!();
+    impl_as_i16vec2!();
+    impl_as_u16vec2!();
+    impl_as_u8vec2!();
+    impl_as_ivec2!(@saturating);
+    impl_as_i64vec2!(@saturating);
+    impl_as_u64vec2!(@saturating);
+    impl_as_i16vec2!(@saturating);
+    impl_as_u16vec2!(@saturating);
+    impl_as_u8vec2!(@saturating);
 }
 impl_vec2_unsigned_traits!(u32, uvec2, UVec2, UVec3, BVec2, XYU32);
 impl_vecn_eq_hash_traits!(u32, 2, UVec2);
@@ -268,6 +317,228 @@ impl_vecn_scalar_bit_op_traits!(UVec2, u32, XYU32);
 
 impl_vecn_bit_op_traits!(UVec2, XYU32);
 
+type XYI64 = XY<i64>;
+
+/// A 2-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct I64Vec2(pub(crate) XYI64);
+
+impl I64Vec2 {
+    impl_vec2_signed_methods!(i64, I64Vec2, I64Vec3, BVec2, XYI64);
+    impl_vecn_integer_methods!(i64, I64Vec2, U64Vec2, BVec2, XYI64);
+    impl_as_vec2!(@cast);
+    impl_as_dvec2!(@cast);
+    impl_as_ivec2!(@cast);
+    impl_as_uvec2!(@cast);
+    impl_as_u64vec2!();
+    impl_as_i16vec2!();
+    impl_as_u16vec2!();
+    impl_as_u8vec2!();
+    impl_as_ivec2!(@saturating);
+    impl_as_uvec2!(@saturating);
+    impl_as_u64vec2!(@saturating);
+    impl_as_i16vec2!(@saturating);
+    impl_as_u16vec2!(@saturating);
+    impl_as_u8vec2!(@saturating);
+}
+impl_vec2_signed_traits!(i64, i64vec2, I64Vec2, I64Vec3, BVec2, XYI64);
+impl_vecn_eq_hash_traits!(i64, 2, I64Vec2);
+
+impl_vecn_scalar_shift_op_traits!(I64Vec2, i8, XYI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec2, i16, XYI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec2, i32, XYI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec2, i64, XYI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec2, u8, XYI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec2, u16, XYI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec2, u32, XYI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec2, u64, XYI64);
+
+impl_vecn_shift_op_traits!(I64Vec2, I64Vec2, XYI64);
+impl_vecn_shift_op_traits!(I64Vec2, U64Vec2, XYI64);
+
+impl_vecn_scalar_bit_op_traits!(I64Vec2, i64, XYI64);
+
+impl_vecn_bit_op_traits!(I64Vec2, XYI64);
+
+type XYU64 = XY<u64>;
+
+/// A 2-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U64Vec2(pub(crate) XYU64);
+
+impl U64Vec2 {
+    impl_vec2_common_methods!(u64, U64Vec2, U64Vec3, BVec2, XYU64);
+    impl_vecn_integer_methods!(u64, U64Vec2, U64Vec2, BVec2, XYU64);
+    impl_vecn_unsigned_integer_methods!(u64, U64Vec2, BVec2, XYU64);
+    impl_as_vec2!(@cast);
+    impl_as_dvec2!(@cast);
+    impl_as_ivec2!(@cast);
+    impl_as_uvec2!(@cast);
+    impl_as_i64vec2!();
+    impl_as_i16vec2!();
+    impl_as_u16vec2!();
+    impl_as_u8vec2!();
+    impl_as_ivec2!(@saturating);
+    impl_as_uvec2!(@saturating);
+    impl_as_i64vec2!(@saturating);
+    impl_as_i16vec2!(@saturating);
+    impl_as_u16vec2!(@saturating);
+    impl_as_u8vec2!(@saturating);
+}
+impl_vec2_unsigned_traits!(u64, u64vec2, U64Vec2, U64Vec3, BVec2, XYU64);
+impl_vecn_eq_hash_traits!(u64, 2, U64Vec2);
+
+impl_vecn_scalar_shift_op_traits!(U64Vec2, i8, XYU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec2, i16, XYU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec2, i32, XYU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec2, i64, XYU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec2, u8, XYU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec2, u16, XYU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec2, u32, XYU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec2, u64, XYU64);
+
+impl_vecn_shift_op_traits!(U64Vec2, I64Vec2, XYU64);
+impl_vecn_shift_op_traits!(U64Vec2, U64Vec2, XYU64);
+
+impl_vecn_scalar_bit_op_traits!(U64Vec2, u64, XYU64);
+
+impl_vecn_bit_op_traits!(U64Vec2, XYU64);
+
+type XYI16 = XY<i16>;
+
+/// A 2-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct I16Vec2(pub(crate) XYI16);
+
+impl I16Vec2 {
+    impl_vec2_signed_methods!(i16, I16Vec2, I16Vec3, BVec2, XYI16);
+    impl_vecn_integer_methods!(i16, I16Vec2, U16Vec2, BVec2, XYI16);
+    impl_as_vec2!(@cast);
+    impl_as_dvec2!(@cast);
+    impl_as_ivec2!(@cast);
+    impl_as_uvec2!(@cast);
+    impl_as_i64vec2!();
+    impl_as_u64vec2!();
+    impl_as_u16vec2!();
+    impl_as_u8vec2!();
+    impl_as_ivec2!(@saturating);
+    impl_as_uvec2!(@saturating);
+    impl_as_i64vec2!(@saturating);
+    impl_as_u64vec2!(@saturating);
+    impl_as_u16vec2!(@saturating);
+    impl_as_u8vec2!(@saturating);
+}
+impl_vec2_signed_traits!(i16, i16vec2, I16Vec2, I16Vec3, BVec2, XYI16);
+impl_vecn_eq_hash_traits!(i16, 2, I16Vec2);
+
+impl_vecn_scalar_shift_op_traits!(I16Vec2, i8, XYI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec2, i16, XYI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec2, i32, XYI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec2, i64, XYI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec2, u8, XYI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec2, u16, XYI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec2, u32, XYI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec2, u64, XYI16);
+
+impl_vecn_shift_op_traits!(I16Vec2, I16Vec2, XYI16);
+impl_vecn_shift_op_traits!(I16Vec2, U16Vec2, XYI16);
+
+impl_vecn_scalar_bit_op_traits!(I16Vec2, i16, XYI16);
+
+impl_vecn_bit_op_traits!(I16Vec2, XYI16);
+
+type XYU16 = XY<u16>;
+
+/// A 2-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U16Vec2(pub(crate) XYU16);
+
+impl U16Vec2 {
+    impl_vec2_common_methods!(u16, U16Vec2, U16Vec3, BVec2, XYU16);
+    impl_vecn_integer_methods!(u16, U16Vec2, U16Vec2, BVec2, XYU16);
+    impl_vecn_unsigned_integer_methods!(u16, U16Vec2, BVec2, XYU16);
+    impl_as_vec2!(@cast);
+    impl_as_dvec2!(@cast);
+    impl_as_ivec2!(@cast);
+    impl_as_uvec2!(@cast);
+    impl_as_i64vec2!();
+    impl_as_u64vec2!();
+    impl_as_i16vec2!();
+    impl_as_u8vec2!();
+    impl_as_ivec2!(@saturating);
+    impl_as_uvec2!(@saturating);
+    impl_as_i64vec2!(@saturating);
+    impl_as_u64vec2!(@saturating);
+    impl_as_i16vec2!(@saturating);
+    impl_as_u8vec2!(@saturating);
+}
+impl_vec2_unsigned_traits!(u16, u16vec2, U16Vec2, U16Vec3, BVec2, XYU16);
+impl_vecn_eq_hash_traits!(u16, 2, U16Vec2);
+
+impl_vecn_scalar_shift_op_traits!(U16Vec2, i8, XYU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec2, i16, XYU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec2, i32, XYU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec2, i64, XYU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec2, u8, XYU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec2, u16, XYU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec2, u32, XYU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec2, u64, XYU16);
+
+impl_vecn_shift_op_traits!(U16Vec2, I16Vec2, XYU16);
+impl_vecn_shift_op_traits!(U16Vec2, U16Vec2, XYU16);
+
+impl_vecn_scalar_bit_op_traits!(U16Vec2, u16, XYU16);
+
+impl_vecn_bit_op_traits!(U16Vec2, XYU16);
+
+type XYU8 = XY<u8>;
+
+/// A 2-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U8Vec2(pub(crate) XYU8);
+
+impl U8Vec2 {
+    impl_vec2_common_methods!(u8, U8Vec2, U8Vec3, BVec2, XYU8);
+    impl_vecn_integer_methods!(u8, U8Vec2, U8Vec2, BVec2, XYU8);
+    impl_vecn_unsigned_integer_methods!(u8, U8Vec2, BVec2, XYU8);
+    impl_as_vec2!(@cast);
+    impl_as_dvec2!(@cast);
+    impl_as_ivec2!(@cast);
+    impl_as_uvec2!(@cast);
+    impl_as_i64vec2!();
+    impl_as_u64vec2!();
+    impl_as_i16vec2!();
+    impl_as_u16vec2!();
+    impl_as_ivec2!(@saturating);
+    impl_as_uvec2!(@saturating);
+    impl_as_i64vec2!(@saturating);
+    impl_as_u64vec2!(@saturating);
+    impl_as_i16vec2!(@saturating);
+    impl_as_u16vec2!(@saturating);
+}
+impl_vec2_unsigned_traits!(u8, u8vec2, U8Vec2, U8Vec3, BVec2, XYU8);
+impl_vecn_eq_hash_traits!(u8, 2, U8Vec2);
+
+impl_vecn_scalar_shift_op_traits!(U8Vec2, i8, XYU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec2, i16, XYU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec2, i32, XYU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec2, i64, XYU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec2, u8, XYU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec2, u16, XYU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec2, u32, XYU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec2, u64, XYU8);
+
+impl_vecn_shift_op_traits!(U8Vec2, U8Vec2, XYU8);
+
+impl_vecn_scalar_bit_op_traits!(U8Vec2, u8, XYU8);
+
+impl_vecn_bit_op_traits!(U8Vec2, XYU8);
+
 mod const_test_vec2 {
     const_assert_eq!(
         core::mem::align_of::<f32>(),
@@ -299,3 +570,43 @@ mod const_test_uvec2 {
     );
     const_assert_eq!(8, core::mem::size_of::<super::UVec2>());
 }
+
+mod const_test_i64vec2 {
+    const_assert_eq!(
+        core::mem::align_of::<i64>(),
+        core::mem::align_of::<super::I64Vec2>()
+    );
+    const_assert_eq!(16, core::mem::size_of::<super::I64Vec2>());
+}
+
+mod const_test_u64vec2 {
+    const_assert_eq!(
+        core::mem::align_of::<u64>(),
+        core::mem::align_of::<super::U64Vec2>()
+    );
+    const_assert_eq!(16, core::mem::size_of::<super::U64Vec2>());
+}
+
+mod const_test_i16vec2 {
+    const_assert_eq!(
+        core::mem::align_of::<i16>(),
+        core::mem::align_of::<super::I16Vec2>()
+    );
+    const_assert_eq!(4, core::mem::size_of::<super::I16Vec2>());
+}
+
+mod const_test_u16vec2 {
+    const_assert_eq!(
+        core::mem::align_of::<u16>(),
+        core::mem::align_of::<super::U16Vec2>()
+    );
+    const_assert_eq!(4, core::mem::size_of::<super::U16Vec2>());
+}
+
+mod const_test_u8vec2 {
+    const_assert_eq!(
+        core::mem::align_of::<u8>(),
+        core::mem::align_of::<super::U8Vec2>()
+    );
+    const_assert_eq!(2, core::mem::size_of::<super::U8Vec2>());
+}
@@ -4,7 +4,10 @@ use crate::core::traits::vector::*;
     not(feature = "scalar-math")
 ))]
 use crate::BVec3A;
-use crate::{BVec3, DVec2, DVec4, IVec2, IVec4, UVec2, UVec4, Vec2, Vec4, XYZ};
+use crate::{
+    BVec3, DVec2, DVec4, I16Vec2, I16Vec4, I64Vec2, I64Vec4, IVec2, IVec4, U16Vec2, U16Vec4,
+    U64Vec2, U64Vec4, U8Vec2, U8Vec4, UVec2, UVec4, Vec2, Vec4, XYZ,
+};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
@@ -276,6 +279,11 @@ macro_rules! impl_f32_vec3 {
             impl_as_dvec3!();
             impl_as_ivec3!();
             impl_as_uvec3!();
+            impl_as_i64vec3!();
+            impl_as_u64vec3!();
+            impl_as_i16vec3!();
+            impl_as_u16vec3!();
+            impl_as_u8vec3!();
         }
         impl_vec3_float_traits!(f32, $new, $vec2, $vec3, $vec4, $inner);
     };
@@ -348,6 +356,11 @@ impl DVec3 {
     impl_as_vec3!();
     impl_as_ivec3!();
     impl_as_uvec3!();
+    impl_as_i64vec3!();
+    impl_as_u64vec3!();
+    impl_as_i16vec3!();
+    impl_as_u16vec3!();
+    impl_as_u8vec3!();
 }
 impl_vec3_float_traits!(f64, dvec3, DVec2, DVec3, DVec4, XYZF64);
 
@@ -361,9 +374,21 @@ pub struct IVec3(pub(crate) XYZI32);
 impl IVec3 {
     impl_vec3_common_methods!(i32, IVec2, IVec3, IVec4, BVec3, XYZI32);
     impl_vecn_signed_methods!(i32, IVec3, BVec3, XYZI32, SignedVector3);
+    impl_vecn_integer_methods!(i32, IVec3, UVec3, BVec3, XYZI32);
     impl_as_vec3!();
     impl_as_dvec3!();
     impl_as_uvec3!();
+    impl_as_i64vec3!();
+    impl_as_u64vec3!();
+    impl_as_i16vec3!();
+    impl_as_u16vec3!();
+    impl_as_u8vec3!();
+    impl_as_uvec3!(@saturating);
+    impl_as_i64vec3!(@saturating);
+    impl_as_u64vec3!(@saturating);
+    impl_as_i16vec3!(@saturating);
+    impl_as_u16vec3!(@saturating);
+    impl_as_u8vec3!(@saturating);
 }
 impl_vec3_common_traits!(i32, ivec3, IVec2, IVec3, IVec4, XYZI32);
 impl_vecn_signed_traits!(i32, 3, IVec3, XYZI32, SignedVector3);
@@ -392,9 +417,22 @@ pub struct UVec3(pub(crate) XYZU32);
 
 impl UVec3 {
     impl_vec3_common_methods!(u32, UVec2, UVec3, UVec4, BVec3, XYZU32);
+    impl_vecn_integer_methods!(u32, UVec3, UVec3, BVec3, XYZU32);
+    impl_vecn_unsigned_integer_methods!(u32, UVec3, BVec3, XYZU32);
     impl_as_vec3!();
     impl_as_dvec3!();
     impl_as_ivec3!();
+    impl_as_i64vec3!();
+    impl_as_u64vec3!();
+    impl_as_i16vec3!();
+    impl_as_u16vec3!();
+    impl_as_u8vec3!();
+    impl_as_ivec3!(@saturating);
+    impl_as_i64vec3!(@saturating);
+    impl_as_u64vec3!(@saturating);
+    impl_as_i16vec3!(@saturating);
+    impl_as_u16vec3!(@saturating);
+    impl_as_u8vec3!(@saturating);
 }
 impl_vec3_common_traits!(u32, uvec3, UVec2, UVec3, UVec4, XYZU32);
 impl_vecn_eq_hash_traits!(u32, 3, UVec3);
@@ -413,6 +451,232 @@ impl_vecn_scalar_bit_op_traits!(UVec3, u32, XYZU32);
 
 impl_vecn_bit_op_traits!(UVec3, XYZU32);
 
+type XYZI64 = XYZ<i64>;
+
+/// A 3-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct I64Vec3(pub(crate) XYZI64);
+
+impl I64Vec3 {
+    impl_vec3_common_methods!(i64, I64Vec2, I64Vec3, I64Vec4, BVec3, XYZI64);
+    impl_vecn_signed_methods!(i64, I64Vec3, BVec3, XYZI64, SignedVector3);
+    impl_vecn_integer_methods!(i64, I64Vec3, U64Vec3, BVec3, XYZI64);
+    impl_as_vec3!(@cast);
+    impl_as_dvec3!(@cast);
+    impl_as_ivec3!(@cast);
+    impl_as_uvec3!(@cast);
+    impl_as_u64vec3!();
+    impl_as_i16vec3!();
+    impl_as_u16vec3!();
+    impl_as_u8vec3!();
+    impl_as_ivec3!(@saturating);
+    impl_as_uvec3!(@saturating);
+    impl_as_u64vec3!(@saturating);
+    impl_as_i16vec3!(@saturating);
+    impl_as_u16vec3!(@saturating);
+    impl_as_u8vec3!(@saturating);
+}
+impl_vec3_common_traits!(i64, i64vec3, I64Vec2, I64Vec3, I64Vec4, XYZI64);
+impl_vecn_signed_traits!(i64, 3, I64Vec3, XYZI64, SignedVector3);
+impl_vecn_eq_hash_traits!(i64, 3, I64Vec3);
+
+impl_vecn_scalar_shift_op_traits!(I64Vec3, i8, XYZI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec3, i16, XYZI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec3, i32, XYZI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec3, i64, XYZI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec3, u8, XYZI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec3, u16, XYZI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec3, u32, XYZI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec3, u64, XYZI64);
+
+impl_vecn_shift_op_traits!(I64Vec3, I64Vec3, XYZI64);
+impl_vecn_shift_op_traits!(I64Vec3, U64Vec3, XYZI64);
+
+impl_vecn_scalar_bit_op_traits!(I64Vec3, i64, XYZI64);
+
+impl_vecn_bit_op_traits!(I64Vec3, XYZI64);
+
+type XYZU64 = XYZ<u64>;
+
+/// A 3-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U64Vec3(pub(crate) XYZU64);
+
+impl U64Vec3 {
+    impl_vec3_common_methods!(u64, U64Vec2, U64Vec3, U64Vec4, BVec3, XYZU64);
+    impl_vecn_integer_methods!(u64, U64Vec3, U64Vec3, BVec3, XYZU64);
+    impl_vecn_unsigned_integer_methods!(u64, U64Vec3, BVec3, XYZU64);
+    impl_as_vec3!(@cast);
+    impl_as_dvec3!(@cast);
+    impl_as_ivec3!(@cast);
+    impl_as_uvec3!(@cast);
+    impl_as_i64vec3!();
+    impl_as_i16vec3!();
+    impl_as_u16vec3!();
+    impl_as_u8vec3!();
+    impl_as_ivec3!(@saturating);
+    impl_as_uvec3!(@saturating);
+    impl_as_i64vec3!(@saturating);
+    impl_as_i16vec3!(@saturating);
+    impl_as_u16vec3!(@saturating);
+    impl_as_u8vec3!(@saturating);
+}
+impl_vec3_common_traits!(u64, u64vec3, U64Vec2, U64Vec3, U64Vec4, XYZU64);
+impl_vecn_eq_hash_traits!(u64, 3, U64Vec3);
+
+impl_vecn_scalar_shift_op_traits!(U64Vec3, i8, XYZU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec3, i16, XYZU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec3, i32, XYZU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec3, i64, XYZU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec3, u8, XYZU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec3, u16, XYZU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec3, u32, XYZU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec3, u64, XYZU64);
+
+impl_vecn_shift_op_traits!(U64Vec3, I64Vec3, XYZU64);
+impl_vecn_shift_op_traits!(U64Vec3, U64Vec3, XYZU64);
+
+impl_vecn_scalar_bit_op_traits!(U64Vec3, u64, XYZU64);
+
+impl_vecn_bit_op_traits!(U64Vec3, XYZU64);
+
+type XYZI16 = XYZ<i16>;
+
+/// A 3-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct I16Vec3(pub(crate) XYZI16);
+
+impl I16Vec3 {
+    impl_vec3_common_methods!(i16, I16Vec2, I16Vec3, I16Vec4, BVec3, XYZI16);
+    impl_vecn_signed_methods!(i16, I16Vec3, BVec3, XYZI16, SignedVector3);
+    impl_vecn_integer_methods!(i16, I16Vec3, U16Vec3, BVec3, XYZI16);
+    impl_as_vec3!(@cast);
+    impl_as_dvec3!(@cast);
+    impl_as_ivec3!(@cast);
+    impl_as_uvec3!(@cast);
+    impl_as_i64vec3!();
+    impl_as_u64vec3!();
+    impl_as_u16vec3!();
+    impl_as_u8vec3!();
+    impl_as_ivec3!(@saturating);
+    impl_as_uvec3!(@saturating);
+    impl_as_i64vec3!(@saturating);
+    impl_as_u64vec3!(@saturating);
+    impl_as_u16vec3!(@saturating);
+    impl_as_u8vec3!(@saturating);
+}
+impl_vec3_common_traits!(i16, i16vec3, I16Vec2, I16Vec3, I16Vec4, XYZI16);
+impl_vecn_signed_traits!(i16, 3, I16Vec3, XYZI16, SignedVector3);
+impl_vecn_eq_hash_traits!(i16, 3, I16Vec3);
+
+impl_vecn_scalar_shift_op_traits!(I16Vec3, i8, XYZI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec3, i16, XYZI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec3, i32, XYZI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec3, i64, XYZI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec3, u8, XYZI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec3, u16, XYZI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec3, u32, XYZI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec3, u64, XYZI16);
+
+impl_vecn_shift_op_traits!(I16Vec3, I16Vec3, XYZI16);
+impl_vecn_shift_op_traits!(I16Vec3, U16Vec3, XYZI16);
+
+impl_vecn_scalar_bit_op_traits!(I16Vec3, i16, XYZI16);
+
+impl_vecn_bit_op_traits!(I16Vec3, XYZI16);
+
+type XYZU16 = XYZ<u16>;
+
+/// A 3-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U16Vec3(pub(crate) XYZU16);
+
+impl U16Vec3 {
+    impl_vec3_common_methods!(u16, U16Vec2, U16Vec3, U16Vec4, BVec3, XYZU16);
+    impl_vecn_integer_methods!(u16, U16Vec3, U16Vec3, BVec3, XYZU16);
+    impl_vecn_unsigned_integer_methods!(u16, U16Vec3, BVec3, XYZU16);
+    impl_as_vec3!(@cast);
+    impl_as_dvec3!(@cast);
+    impl_as_ivec3!(@cast);
+    impl_as_uvec3!(@cast);
+    impl_as_i64vec3!();
+    impl_as_u64vec3!();
+    impl_as_i16vec3!();
+    impl_as_u8vec3!();
+    impl_as_ivec3!(@saturating);
+    impl_as_uvec3!(@saturating);
+    impl_as_i64vec3!(@saturating);
+    impl_as_u64vec3!(@saturating);
+    impl_as_i16vec3!(@saturating);
+    impl_as_u8vec3!(@saturating);
+}
+impl_vec3_common_traits!(u16, u16vec3, U16Vec2, U16Vec3, U16Vec4, XYZU16);
+impl_vecn_eq_hash_traits!(u16, 3, U16Vec3);
+
+impl_vecn_scalar_shift_op_traits!(U16Vec3, i8, XYZU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec3, i16, XYZU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec3, i32, XYZU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec3, i64, XYZU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec3, u8, XYZU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec3, u16, XYZU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec3, u32, XYZU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec3, u64, XYZU16);
+
+impl_vecn_shift_op_traits!(U16Vec3, I16Vec3, XYZU16);
+impl_vecn_shift_op_traits!(U16Vec3, U16Vec3, XYZU16);
+
+impl_vecn_scalar_bit_op_traits!(U16Vec3, u16, XYZU16);
+
+impl_vecn_bit_op_traits!(U16Vec3, XYZU16);
+
+type XYZU8 = XYZ<u8>;
+
+/// A 3-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U8Vec3(pub(crate) XYZU8);
+
+impl U8Vec3 {
+    impl_vec3_common_methods!(u8, U8Vec2, U8Vec3, U8Vec4, BVec3, XYZU8);
+    impl_vecn_integer_methods!(u8, U8Vec3, U8Vec3, BVec3, XYZU8);
+    impl_vecn_unsigned_integer_methods!(u8, U8Vec3, BVec3, XYZU8);
+    impl_as_vec3!(@cast);
+    impl_as_dvec3!(@cast);
+    impl_as_ivec3!(@cast);
+    impl_as_uvec3!(@cast);
+    impl_as_i64vec3!();
+    impl_as_u64vec3!();
+    impl_as_i16vec3!();
+    impl_as_u16vec3!();
+    impl_as_ivec3!(@saturating);
+    impl_as_uvec3!(@saturating);
+    impl_as_i64vec3!(@saturating);
+    impl_as_u64vec3!(@saturating);
+    impl_as_i16vec3!(@saturating);
+    impl_as_u16vec3!(@saturating);
+}
+impl_vec3_common_traits!(u8, u8vec3, U8Vec2, U8Vec3, U8Vec4, XYZU8);
+impl_vecn_eq_hash_traits!(u8, 3, U8Vec3);
+
+impl_vecn_scalar_shift_op_traits!(U8Vec3, i8, XYZU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec3, i16, XYZU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec3, i32, XYZU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec3, i64, XYZU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec3, u8, XYZU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec3, u16, XYZU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec3, u32, XYZU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec3, u64, XYZU8);
+
+impl_vecn_shift_op_traits!(U8Vec3, U8Vec3, XYZU8);
+
+impl_vecn_scalar_bit_op_traits!(U8Vec3, u8, XYZU8);
+
+impl_vecn_bit_op_traits!(U8Vec3, XYZU8);
+
 #[test]
 fn test_vec3_private() {
     assert_eq!(
@@ -457,3 +721,43 @@ mod const_test_uvec3 {
     );
     const_assert_eq!(12, core::mem::size_of::<super::UVec3>());
 }
+
+mod const_test_i64vec3 {
+    const_assert_eq!(
+        core::mem::align_of::<i64>(),
+        core::mem::align_of::<super::I64Vec3>()
+    );
+    const_assert_eq!(24, core::mem::size_of::<super::I64Vec3>());
+}
+
+mod const_test_u64vec3 {
+    const_assert_eq!(
+        core::mem::align_of::<u64>(),
+        core::mem::align_of::<super::U64Vec3>()
+    );
+    const_assert_eq!(24, core::mem::size_of::<super::U64Vec3>());
+}
+
+mod const_test_i16vec3 {
+    const_assert_eq!(
+        core::mem::align_of::<i16>(),
+        core::mem::align_of::<super::I16Vec3>()
+    );
+    const_assert_eq!(6, core::mem::size_of::<super::I16Vec3>());
+}
+
+mod const_test_u16vec3 {
+    const_assert_eq!(
+        core::mem::align_of::<u16>(),
+        core::mem::align_of::<super::U16Vec3>()
+    );
+    const_assert_eq!(6, core::mem::size_of::<super::U16Vec3>());
+}
+
+mod const_test_u8vec3 {
+    const_assert_eq!(
+        core::mem::align_of::<u8>(),
+        core::mem::align_of::<super::U8Vec3>()
+    );
+    const_assert_eq!(3, core::mem::size_of::<super::U8Vec3>());
+}
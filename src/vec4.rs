@@ -5,7 +5,10 @@ use crate::core::traits::vector::*;
     not(feature = "scalar-math")
 ))]
 use crate::BVec4A;
-use crate::{BVec4, DVec2, DVec3, IVec2, IVec3, UVec2, UVec3, Vec2, Vec3, Vec3A, XYZW};
+use crate::{
+    BVec4, DVec2, DVec3, I16Vec2, I16Vec3, I64Vec2, I64Vec3, IVec2, IVec3, U16Vec2, U16Vec3,
+    U64Vec2, U64Vec3, U8Vec2, U8Vec3, UVec2, UVec3, Vec2, Vec3, Vec3A, XYZW,
+};
 use core::f32;
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
@@ -224,6 +227,11 @@ macro_rules! impl_f32_vec4 {
             impl_as_dvec4!();
             impl_as_ivec4!();
             impl_as_uvec4!();
+            impl_as_i64vec4!();
+            impl_as_u64vec4!();
+            impl_as_i16vec4!();
+            impl_as_u16vec4!();
+            impl_as_u8vec4!();
         }
         impl_vec4_signed_traits!(f32, $new, $vec2, $vec3, $vec4, $mask, $inner);
     };
@@ -301,6 +309,11 @@ impl DVec4 {
     impl_as_vec4!();
     impl_as_ivec4!();
     impl_as_uvec4!();
+    impl_as_i64vec4!();
+    impl_as_u64vec4!();
+    impl_as_i16vec4!();
+    impl_as_u16vec4!();
+    impl_as_u8vec4!();
 }
 impl_vec4_signed_traits!(f64, dvec4, DVec2, DVec3, DVec4, BVec4, XYZWF64);
 
@@ -313,9 +326,21 @@ pub struct IVec4(pub(crate) XYZWI32);
 
 impl IVec4 {
     impl_vec4_signed_methods!(i32, IVec2, IVec3, IVec4, BVec4, XYZWI32);
+    impl_vecn_integer_methods!(i32, IVec4, UVec4, BVec4, XYZWI32);
     impl_as_vec4!();
     impl_as_dvec4!();
     impl_as_uvec4!();
+    impl_as_i64vec4!();
+    impl_as_u64vec4!();
+    impl_as_i16vec4!();
+    impl_as_u16vec4!();
+    impl_as_u8vec4!();
+    impl_as_uvec4!(@saturating);
+    impl_as_i64vec4!(@saturating);
+    impl_as_u64vec4!(@saturating);
+    impl_as_i16vec4!(@saturating);
+    impl_as_u16vec4!(@saturating);
+    impl_as_u8vec4!(@saturating);
 }
 impl_vec4_signed_traits!(i32, ivec4, IVec2, IVec3, IVec4, BVec4, XYZWI32);
 impl_vecn_eq_hash_traits!(i32, 4, IVec4);
@@ -343,9 +368,22 @@ pub struct UVec4(pub(crate) XYZWU32);
 
 impl UVec4 {
     impl_vec4_common_methods!(u32, UVec2, UVec3, UVec4, BVec4, XYZWU32);
+    impl_vecn_integer_methods!(u32, UVec4, UVec4, BVec4, XYZWU32);
+    impl_vecn_unsigned_integer_methods!(u32, UVec4, BVec4, XYZWU32);
     impl_as_vec4!();
     impl_as_dvec4!();
     impl_as_ivec4!();
+    impl_as_i64vec4!();
+    impl_as_u64vec4!();
+    impl_as_i16vec4!();
+    impl_as_u16vec4!();
+    impl_as_u8vec4!();
+    impl_as_ivec4!(@saturating);
+    impl_as_i64vec4!(@saturating);
+    impl_as_u64vec4!(@saturating);
+    impl_as_i16vec4!(@saturating);
+    impl_as_u16vec4!(@saturating);
+    impl_as_u8vec4!(@saturating);
 }
 impl_vec4_common_traits!(u32, uvec4, UVec2, UVec3, UVec4, BVec4, XYZWU32);
 impl_vecn_eq_hash_traits!(u32, 4, UVec4);
@@ -364,6 +402,228 @@ impl_vecn_scalar_bit_op_traits!(UVec4, u32, XYZWU32);
 
 impl_vecn_bit_op_traits!(UVec4, XYZWU32);
 
+type XYZWI64 = XYZW<i64>;
+
+/// A 4-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct I64Vec4(pub(crate) XYZWI64);
+
+impl I64Vec4 {
+    impl_vec4_signed_methods!(i64, I64Vec2, I64Vec3, I64Vec4, BVec4, XYZWI64);
+    impl_vecn_integer_methods!(i64, I64Vec4, U64Vec4, BVec4, XYZWI64);
+    impl_as_vec4!(@cast);
+    impl_as_dvec4!(@cast);
+    impl_as_ivec4!(@cast);
+    impl_as_uvec4!(@cast);
+    impl_as_u64vec4!();
+    impl_as_i16vec4!();
+    impl_as_u16vec4!();
+    impl_as_u8vec4!();
+    impl_as_ivec4!(@saturating);
+    impl_as_uvec4!(@saturating);
+    impl_as_u64vec4!(@saturating);
+    impl_as_i16vec4!(@saturating);
+    impl_as_u16vec4!(@saturating);
+    impl_as_u8vec4!(@saturating);
+}
+impl_vec4_signed_traits!(i64, i64vec4, I64Vec2, I64Vec3, I64Vec4, BVec4, XYZWI64);
+impl_vecn_eq_hash_traits!(i64, 4, I64Vec4);
+
+impl_vecn_scalar_shift_op_traits!(I64Vec4, i8, XYZWI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec4, i16, XYZWI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec4, i32, XYZWI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec4, i64, XYZWI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec4, u8, XYZWI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec4, u16, XYZWI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec4, u32, XYZWI64);
+impl_vecn_scalar_shift_op_traits!(I64Vec4, u64, XYZWI64);
+
+impl_vecn_shift_op_traits!(I64Vec4, I64Vec4, XYZWI64);
+impl_vecn_shift_op_traits!(I64Vec4, U64Vec4, XYZWI64);
+
+impl_vecn_scalar_bit_op_traits!(I64Vec4, i64, XYZWI64);
+
+impl_vecn_bit_op_traits!(I64Vec4, XYZWI64);
+
+type XYZWU64 = XYZW<u64>;
+
+/// A 4-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U64Vec4(pub(crate) XYZWU64);
+
+impl U64Vec4 {
+    impl_vec4_common_methods!(u64, U64Vec2, U64Vec3, U64Vec4, BVec4, XYZWU64);
+    impl_vecn_integer_methods!(u64, U64Vec4, U64Vec4, BVec4, XYZWU64);
+    impl_vecn_unsigned_integer_methods!(u64, U64Vec4, BVec4, XYZWU64);
+    impl_as_vec4!(@cast);
+    impl_as_dvec4!(@cast);
+    impl_as_ivec4!(@cast);
+    impl_as_uvec4!(@cast);
+    impl_as_i64vec4!();
+    impl_as_i16vec4!();
+    impl_as_u16vec4!();
+    impl_as_u8vec4!();
+    impl_as_ivec4!(@saturating);
+    impl_as_uvec4!(@saturating);
+    impl_as_i64vec4!(@saturating);
+    impl_as_i16vec4!(@saturating);
+    impl_as_u16vec4!(@saturating);
+    impl_as_u8vec4!(@saturating);
+}
+impl_vec4_common_traits!(u64, u64vec4, U64Vec2, U64Vec3, U64Vec4, BVec4, XYZWU64);
+impl_vecn_eq_hash_traits!(u64, 4, U64Vec4);
+
+impl_vecn_scalar_shift_op_traits!(U64Vec4, i8, XYZWU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec4, i16, XYZWU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec4, i32, XYZWU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec4, i64, XYZWU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec4, u8, XYZWU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec4, u16, XYZWU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec4, u32, XYZWU64);
+impl_vecn_scalar_shift_op_traits!(U64Vec4, u64, XYZWU64);
+
+impl_vecn_shift_op_traits!(U64Vec4, I64Vec4, XYZWU64);
+impl_vecn_shift_op_traits!(U64Vec4, U64Vec4, XYZWU64);
+
+impl_vecn_scalar_bit_op_traits!(U64Vec4, u64, XYZWU64);
+
+impl_vecn_bit_op_traits!(U64Vec4, XYZWU64);
+
+type XYZWI16 = XYZW<i16>;
+
+/// A 4-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct I16Vec4(pub(crate) XYZWI16);
+
+impl I16Vec4 {
+    impl_vec4_signed_methods!(i16, I16Vec2, I16Vec3, I16Vec4, BVec4, XYZWI16);
+    impl_vecn_integer_methods!(i16, I16Vec4, U16Vec4, BVec4, XYZWI16);
+    impl_as_vec4!(@cast);
+    impl_as_dvec4!(@cast);
+    impl_as_ivec4!(@cast);
+    impl_as_uvec4!(@cast);
+    impl_as_i64vec4!();
+    impl_as_u64vec4!();
+    impl_as_u16vec4!();
+    impl_as_u8vec4!();
+    impl_as_ivec4!(@saturating);
+    impl_as_uvec4!(@saturating);
+    impl_as_i64vec4!(@saturating);
+    impl_as_u64vec4!(@saturating);
+    impl_as_u16vec4!(@saturating);
+    impl_as_u8vec4!(@saturating);
+}
+impl_vec4_signed_traits!(i16, i16vec4, I16Vec2, I16Vec3, I16Vec4, BVec4, XYZWI16);
+impl_vecn_eq_hash_traits!(i16, 4, I16Vec4);
+
+impl_vecn_scalar_shift_op_traits!(I16Vec4, i8, XYZWI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec4, i16, XYZWI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec4, i32, XYZWI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec4, i64, XYZWI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec4, u8, XYZWI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec4, u16, XYZWI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec4, u32, XYZWI16);
+impl_vecn_scalar_shift_op_traits!(I16Vec4, u64, XYZWI16);
+
+impl_vecn_shift_op_traits!(I16Vec4, I16Vec4, XYZWI16);
+impl_vecn_shift_op_traits!(I16Vec4, U16Vec4, XYZWI16);
+
+impl_vecn_scalar_bit_op_traits!(I16Vec4, i16, XYZWI16);
+
+impl_vecn_bit_op_traits!(I16Vec4, XYZWI16);
+
+type XYZWU16 = XYZW<u16>;
+
+/// A 4-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U16Vec4(pub(crate) XYZWU16);
+
+impl U16Vec4 {
+    impl_vec4_common_methods!(u16, U16Vec2, U16Vec3, U16Vec4, BVec4, XYZWU16);
+    impl_vecn_integer_methods!(u16, U16Vec4, U16Vec4, BVec4, XYZWU16);
+    impl_vecn_unsigned_integer_methods!(u16, U16Vec4, BVec4, XYZWU16);
+    impl_as_vec4!(@cast);
+    impl_as_dvec4!(@cast);
+    impl_as_ivec4!(@cast);
+    impl_as_uvec4!(@cast);
+    impl_as_i64vec4!();
+    impl_as_u64vec4!();
+    impl_as_i16vec4!();
+    impl_as_u8vec4!();
+    impl_as_ivec4!(@saturating);
+    impl_as_uvec4!(@saturating);
+    impl_as_i64vec4!(@saturating);
+    impl_as_u64vec4!(@saturating);
+    impl_as_i16vec4!(@saturating);
+    impl_as_u8vec4!(@saturating);
+}
+impl_vec4_common_traits!(u16, u16vec4, U16Vec2, U16Vec3, U16Vec4, BVec4, XYZWU16);
+impl_vecn_eq_hash_traits!(u16, 4, U16Vec4);
+
+impl_vecn_scalar_shift_op_traits!(U16Vec4, i8, XYZWU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec4, i16, XYZWU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec4, i32, XYZWU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec4, i64, XYZWU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec4, u8, XYZWU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec4, u16, XYZWU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec4, u32, XYZWU16);
+impl_vecn_scalar_shift_op_traits!(U16Vec4, u64, XYZWU16);
+
+impl_vecn_shift_op_traits!(U16Vec4, I16Vec4, XYZWU16);
+impl_vecn_shift_op_traits!(U16Vec4, U16Vec4, XYZWU16);
+
+impl_vecn_scalar_bit_op_traits!(U16Vec4, u16, XYZWU16);
+
+impl_vecn_bit_op_traits!(U16Vec4, XYZWU16);
+
+type XYZWU8 = XYZW<u8>;
+
+/// A 4-dimensional vector.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct U8Vec4(pub(crate) XYZWU8);
+
+impl U8Vec4 {
+    impl_vec4_common_methods!(u8, U8Vec2, U8Vec3, U8Vec4, BVec4, XYZWU8);
+    impl_vecn_integer_methods!(u8, U8Vec4, U8Vec4, BVec4, XYZWU8);
+    impl_vecn_unsigned_integer_methods!(u8, U8Vec4, BVec4, XYZWU8);
+    impl_as_vec4!(@cast);
+    impl_as_dvec4!(@cast);
+    impl_as_ivec4!(@cast);
+    impl_as_uvec4!(@cast);
+    impl_as_i64vec4!();
+    impl_as_u64vec4!();
+    impl_as_i16vec4!();
+    impl_as_u16vec4!();
+    impl_as_ivec4!(@saturating);
+    impl_as_uvec4!(@saturating);
+    impl_as_i64vec4!(@saturating);
+    impl_as_u64vec4!(@saturating);
+    impl_as_i16vec4!(@saturating);
+    impl_as_u16vec4!(@saturating);
+}
+impl_vec4_common_traits!(u8, u8vec4, U8Vec2, U8Vec3, U8Vec4, BVec4, XYZWU8);
+impl_vecn_eq_hash_traits!(u8, 4, U8Vec4);
+
+impl_vecn_scalar_shift_op_traits!(U8Vec4, i8, XYZWU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec4, i16, XYZWU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec4, i32, XYZWU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec4, i64, XYZWU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec4, u8, XYZWU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec4, u16, XYZWU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec4, u32, XYZWU8);
+impl_vecn_scalar_shift_op_traits!(U8Vec4, u64, XYZWU8);
+
+impl_vecn_shift_op_traits!(U8Vec4, U8Vec4, XYZWU8);
+
+impl_vecn_scalar_bit_op_traits!(U8Vec4, u8, XYZWU8);
+
+impl_vecn_bit_op_traits!(U8Vec4, XYZWU8);
+
 #[test]
 fn test_vec4_private() {
     assert_eq!(
@@ -423,3 +683,43 @@ mod const_test_uvec4 {
     );
     const_assert_eq!(16, core::mem::size_of::<super::UVec4>());
 }
+
+mod const_test_i64vec4 {
+    const_assert_eq!(
+        core::mem::align_of::<i64>(),
+        core::mem::align_of::<super::I64Vec4>()
+    );
+    const_assert_eq!(32, core::mem::size_of::<super::I64Vec4>());
+}
+
+mod const_test_u64vec4 {
+    const_assert_eq!(
+        core::mem::align_of::<u64>(),
+        core::mem::align_of::<super::U64Vec4>()
+    );
+    const_assert_eq!(32, core::mem::size_of::<super::U64Vec4>());
+}
+
+mod const_test_i16vec4 {
+    const_assert_eq!(
+        core::mem::align_of::<i16>(),
+        core::mem::align_of::<super::I16Vec4>()
+    );
+    const_assert_eq!(8, core::mem::size_of::<super::I16Vec4>());
+}
+
+mod const_test_u16vec4 {
+    const_assert_eq!(
+        core::mem::align_of::<u16>(),
+        core::mem::align_of::<super::U16Vec4>()
+    );
+    const_assert_eq!(8, core::mem::size_of::<super::U16Vec4>());
+}
+
+mod const_test_u8vec4 {
+    const_assert_eq!(
+        core::mem::align_of::<u8>(),
+        core::mem::align_of::<super::U8Vec4>()
+    );
+    const_assert_eq!(4, core::mem::size_of::<super::U8Vec4>());
+}
@@ -777,6 +777,71 @@ fn write_swizzle_impls_u32() -> Result<()> {
     Ok(())
 }
 
+fn write_swizzle_impls_i64() -> Result<()> {
+    let mut out = File::create("../src/swizzles/i64vec4_impl_scalar.rs")?;
+    write_vec4_impl_scalar(&mut out, "I64Vec4", "I64Vec3", "I64Vec2")?;
+
+    let mut out = File::create("../src/swizzles/i64vec3_impl_scalar.rs")?;
+    write_vec3_impl_scalar(&mut out, "I64Vec4", "I64Vec3", "I64Vec2")?;
+
+    let mut out = File::create("../src/swizzles/i64vec2_impl_scalar.rs")?;
+    write_vec2_impl_scalar(&mut out, "I64Vec4", "I64Vec3", "I64Vec2")?;
+
+    Ok(())
+}
+
+fn write_swizzle_impls_u64() -> Result<()> {
+    let mut out = File::create("../src/swizzles/u64vec4_impl_scalar.rs")?;
+    write_vec4_impl_scalar(&mut out, "U64Vec4", "U64Vec3", "U64Vec2")?;
+
+    let mut out = File::create("../src/swizzles/u64vec3_impl_scalar.rs")?;
+    write_vec3_impl_scalar(&mut out, "U64Vec4", "U64Vec3", "U64Vec2")?;
+
+    let mut out = File::create("../src/swizzles/u64vec2_impl_scalar.rs")?;
+    write_vec2_impl_scalar(&mut out, "U64Vec4", "U64Vec3", "U64Vec2")?;
+
+    Ok(())
+}
+
+fn write_swizzle_impls_i16() -> Result<()> {
+    let mut out = File::create("../src/swizzles/i16vec4_impl_scalar.rs")?;
+    write_vec4_impl_scalar(&mut out, "I16Vec4", "I16Vec3", "I16Vec2")?;
+
+    let mut out = File::create("../src/swizzles/i16vec3_impl_scalar.rs")?;
+    write_vec3_impl_scalar(&mut out, "I16Vec4", "I16Vec3", "I16Vec2")?;
+
+    let mut out = File::create("../src/swizzles/i16vec2_impl_scalar.rs")?;
+    write_vec2_impl_scalar(&mut out, "I16Vec4", "I16Vec3", "I16Vec2")?;
+
+    Ok(())
+}
+
+fn write_swizzle_impls_u16() -> Result<()> {
+    let mut out = File::create("../src/swizzles/u16vec4_impl_scalar.rs")?;
+    write_vec4_impl_scalar(&mut out, "U16Vec4", "U16Vec3", "U16Vec2")?;
+
+    let mut out = File::create("../src/swizzles/u16vec3_impl_scalar.rs")?;
+    write_vec3_impl_scalar(&mut out, "U16Vec4", "U16Vec3", "U16Vec2")?;
+
+    let mut out = File::create("../src/swizzles/u16vec2_impl_scalar.rs")?;
+    write_vec2_impl_scalar(&mut out, "U16Vec4", "U16Vec3", "U16Vec2")?;
+
+    Ok(())
+}
+
+fn write_swizzle_impls_u8() -> Result<()> {
+    let mut out = File::create("../src/swizzles/u8vec4_impl_scalar.rs")?;
+    write_vec4_impl_scalar(&mut out, "U8Vec4", "U8Vec3", "U8Vec2")?;
+
+    let mut out = File::create("../src/swizzles/u8vec3_impl_scalar.rs")?;
+    write_vec3_impl_scalar(&mut out, "U8Vec4", "U8Vec3", "U8Vec2")?;
+
+    let mut out = File::create("../src/swizzles/u8vec2_impl_scalar.rs")?;
+    write_vec2_impl_scalar(&mut out, "U8Vec4", "U8Vec3", "U8Vec2")?;
+
+    Ok(())
+}
+
 fn write_test_vec4(
     out: &mut impl Write,
     t: &str,
@@ -934,6 +999,41 @@ fn write_swizzle_tests() -> Result<()> {
         write_test_vec2(&mut out, "u32", "uvec4", "uvec3", "uvec2")?;
     }
 
+    {
+        let mut out = write_swizzle_tests_preamble("../tests/swizzles_i64.rs")?;
+        write_test_vec4(&mut out, "i64", "i64vec4", "i64vec3", "i64vec2")?;
+        write_test_vec3(&mut out, "i64", "i64vec4", "i64vec3", "i64vec2")?;
+        write_test_vec2(&mut out, "i64", "i64vec4", "i64vec3", "i64vec2")?;
+    }
+
+    {
+        let mut out = write_swizzle_tests_preamble("../tests/swizzles_u64.rs")?;
+        write_test_vec4(&mut out, "u64", "u64vec4", "u64vec3", "u64vec2")?;
+        write_test_vec3(&mut out, "u64", "u64vec4", "u64vec3", "u64vec2")?;
+        write_test_vec2(&mut out, "u64", "u64vec4", "u64vec3", "u64vec2")?;
+    }
+
+    {
+        let mut out = write_swizzle_tests_preamble("../tests/swizzles_i16.rs")?;
+        write_test_vec4(&mut out, "i16", "i16vec4", "i16vec3", "i16vec2")?;
+        write_test_vec3(&mut out, "i16", "i16vec4", "i16vec3", "i16vec2")?;
+        write_test_vec2(&mut out, "i16", "i16vec4", "i16vec3", "i16vec2")?;
+    }
+
+    {
+        let mut out = write_swizzle_tests_preamble("../tests/swizzles_u16.rs")?;
+        write_test_vec4(&mut out, "u16", "u16vec4", "u16vec3", "u16vec2")?;
+        write_test_vec3(&mut out, "u16", "u16vec4", "u16vec3", "u16vec2")?;
+        write_test_vec2(&mut out, "u16", "u16vec4", "u16vec3", "u16vec2")?;
+    }
+
+    {
+        let mut out = write_swizzle_tests_preamble("../tests/swizzles_u8.rs")?;
+        write_test_vec4(&mut out, "u8", "u8vec4", "u8vec3", "u8vec2")?;
+        write_test_vec3(&mut out, "u8", "u8vec4", "u8vec3", "u8vec2")?;
+        write_test_vec2(&mut out, "u8", "u8vec4", "u8vec3", "u8vec2")?;
+    }
+
     Ok(())
 }
 
@@ -943,6 +1043,11 @@ fn main() -> Result<()> {
     write_swizzle_impls_f64()?;
     write_swizzle_impls_i32()?;
     write_swizzle_impls_u32()?;
+    write_swizzle_impls_i64()?;
+    write_swizzle_impls_u64()?;
+    write_swizzle_impls_i16()?;
+    write_swizzle_impls_u16()?;
+    write_swizzle_impls_u8()?;
     write_swizzle_tests()?;
     Ok(())
 }
@@ -0,0 +1,323 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_aabb_common_tests {
+    ($t:ident, $aabb:ident, $vec:ident) => {
+        glam_test!(test_aabb_new, {
+            let min = $vec::splat(1 as $t);
+            let max = $vec::splat(3 as $t);
+            let aabb = $aabb::new(min, max);
+            assert_eq!(min, aabb.min);
+            assert_eq!(max, aabb.max);
+            assert_eq!($vec::splat(2 as $t), aabb.size());
+            assert_eq!($aabb::new($vec::ZERO, $vec::ZERO), $aabb::default());
+
+            should_glam_assert!({ $aabb::new(max, min) });
+        });
+
+        glam_test!(test_aabb_from_points, {
+            let a = $vec::splat(2 as $t);
+            let aabb = $aabb::from_points(&[a]);
+            assert_eq!(a, aabb.min);
+            assert_eq!(a, aabb.max);
+
+            let mut points = [$vec::ZERO; 3];
+            points[0] = $vec::X * (4 as $t);
+            points[1] = -$vec::Y;
+            points[2] = $vec::ONE;
+            let aabb = $aabb::from_points(&points);
+            assert_eq!(-$vec::Y, aabb.min);
+            assert_eq!($vec::ONE + $vec::X * (3 as $t), aabb.max);
+            for &point in &points {
+                assert!(aabb.contains_point(point));
+            }
+        });
+
+        glam_test!(test_aabb_union_intersection, {
+            let a = $aabb::new($vec::ZERO, $vec::splat(2 as $t));
+            let b = $aabb::new($vec::ONE, $vec::splat(3 as $t));
+            let c = $aabb::new($vec::splat(5 as $t), $vec::splat(6 as $t));
+            assert_eq!($aabb::new($vec::ZERO, $vec::splat(3 as $t)), a.union(b));
+            assert_eq!(a.union(b), b.union(a));
+            assert_eq!($aabb::new($vec::ZERO, $vec::splat(6 as $t)), a.union(c));
+
+            assert_eq!(
+                Some($aabb::new($vec::ONE, $vec::splat(2 as $t))),
+                a.intersection(b)
+            );
+            assert_eq!(a.intersection(b), b.intersection(a));
+            assert_eq!(None, a.intersection(c));
+            assert_eq!(Some(a), a.intersection(a));
+
+            // Touching boxes have a degenerate intersection
+            let d = $aabb::new($vec::splat(2 as $t), $vec::splat(4 as $t));
+            let touching = a.intersection(d).unwrap();
+            assert_eq!($vec::ZERO, touching.size());
+
+            assert!(a.intersects(b));
+            assert!(b.intersects(a));
+            assert!(a.intersects(d));
+            assert!(!a.intersects(c));
+            assert!(!c.intersects(a));
+            // Overlapping on one axis only is not an intersection
+            let e = $aabb::new($vec::X * (5 as $t), $vec::X * (6 as $t) + $vec::ONE);
+            assert!(!a.intersects(e));
+            assert!(!e.intersects(a));
+        });
+
+        glam_test!(test_aabb_grow_expand, {
+            let a = $aabb::new($vec::ZERO, $vec::ONE);
+            assert_eq!(a, a.grow($vec::ONE));
+            assert_eq!($aabb::new(-$vec::ONE, $vec::ONE), a.grow(-$vec::ONE));
+            assert_eq!(
+                $aabb::new($vec::ZERO, $vec::ONE + $vec::X),
+                a.grow($vec::X * (2 as $t))
+            );
+            assert_eq!(
+                $aabb::new(-$vec::ONE, $vec::splat(2 as $t)),
+                a.expand(1 as $t)
+            );
+            let b = $aabb::new($vec::ZERO, $vec::splat(4 as $t));
+            assert_eq!(
+                $aabb::new($vec::ONE, $vec::splat(3 as $t)),
+                b.expand(-1 as $t)
+            );
+
+            should_glam_assert!({ a.expand(-1 as $t) });
+        });
+
+        glam_test!(test_aabb_contains_closest_point, {
+            let a = $aabb::new(-$vec::ONE, $vec::ONE);
+            assert!(a.contains_point($vec::ZERO));
+            assert!(a.contains_point($vec::ONE));
+            assert!(a.contains_point(-$vec::ONE));
+            assert!(!a.contains_point($vec::X * (2 as $t)));
+            assert!(!a.contains_point(-$vec::ONE - $vec::Y));
+
+            assert_eq!($vec::ZERO, a.closest_point($vec::ZERO));
+            assert_eq!($vec::X, a.closest_point($vec::X * (5 as $t)));
+            assert_eq!(-$vec::ONE, a.closest_point($vec::splat(-3 as $t)));
+            assert_eq!(
+                $vec::X - $vec::Y,
+                a.closest_point($vec::X * (2 as $t) - $vec::Y)
+            );
+        });
+
+        glam_test!(test_aabb_fmt, {
+            let a = $aabb::new($vec::ZERO, $vec::ONE);
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    "{} {{ min: {:?}, max: {:?} }}",
+                    stringify!($aabb),
+                    $vec::ZERO,
+                    $vec::ONE
+                )
+            );
+            assert_eq!(format!("{}", a), format!("[{}, {}]", $vec::ZERO, $vec::ONE));
+        });
+    };
+}
+
+macro_rules! impl_aabb_float_tests {
+    ($t:ident, $aabb:ident, $vec:ident) => {
+        impl_aabb_common_tests!($t, $aabb, $vec);
+
+        glam_test!(test_aabb_center_half_extents, {
+            let center = $vec::ONE;
+            let half_extents = $vec::splat(0.5);
+            let a = $aabb::from_center_half_extents(center, half_extents);
+            assert_eq!($aabb::new($vec::splat(0.5), $vec::splat(1.5)), a);
+            assert_eq!(center, a.center());
+            assert_eq!(half_extents, a.half_extents());
+            assert_eq!($vec::ONE, a.size());
+            assert!(a.is_finite());
+            assert!(!$aabb::new($vec::ZERO, $vec::splat($t::INFINITY)).is_finite());
+            assert!(a.abs_diff_eq(a.expand(1e-6), 1e-5));
+            assert!(!a.abs_diff_eq(a.expand(1e-3), 1e-5));
+
+            should_glam_assert!({ $aabb::from_center_half_extents(center, -half_extents) });
+        });
+    };
+}
+
+macro_rules! impl_aabb3_tests {
+    ($t:ident, $aabb3:ident, $vec3:ident) => {
+        glam_test!(test_aabb3_volume_surface_area, {
+            let a = $aabb3::new($vec3::ZERO, $vec3::new(1 as $t, 2 as $t, 3 as $t));
+            assert_eq!(6 as $t, a.volume());
+            assert_eq!(22 as $t, a.surface_area());
+            assert_eq!(0 as $t, $aabb3::new($vec3::ZERO, $vec3::X).volume());
+        });
+    };
+}
+
+macro_rules! impl_aabb3_transform_tests {
+    (
+        $t:ident, $aabb3:ident, $vec3:ident, $mat4:ident, $affine3:ident, $quat:ident, $v3:ident
+    ) => {
+        fn corners(aabb: $aabb3) -> [$vec3; 8] {
+            let mut corners = [$vec3::ZERO; 8];
+            for (i, corner) in corners.iter_mut().enumerate() {
+                *corner = $vec3::new(
+                    if i & 1 == 0 { aabb.min.x } else { aabb.max.x },
+                    if i & 2 == 0 { aabb.min.y } else { aabb.max.y },
+                    if i & 4 == 0 { aabb.min.z } else { aabb.max.z },
+                );
+            }
+            corners
+        }
+
+        glam_test!(test_aabb3_transform, {
+            let a = $aabb3::new($vec3::new(-1.0, 0.0, 2.0), $vec3::new(2.0, 1.0, 5.0));
+            let scale = $v3::new(2.0, 0.5, 1.5);
+            let rotation = $quat::from_euler(glam::EulerRot::YXZ, 0.3, -0.7, 1.1);
+            let translation = $v3::new(3.0, -4.0, 5.0);
+            let mat = $mat4::from_scale_rotation_translation(scale, rotation, translation);
+            let affine = $affine3::from_scale_rotation_translation(scale, rotation, translation);
+
+            // The result is as tight as the box around the transformed corners
+            let mut expected = corners(a);
+            for corner in expected.iter_mut() {
+                *corner = mat.transform_point3((*corner).into()).into();
+            }
+            let expected = $aabb3::from_points(&expected);
+            assert_approx_eq!(expected, a.transform_mat4(&mat), 1e-5);
+            assert_approx_eq!(expected, a.transform_affine3(&affine), 1e-5);
+
+            assert_approx_eq!(a, a.transform_mat4(&$mat4::IDENTITY));
+            let moved = a.transform_affine3(&$affine3::from_translation(translation));
+            let translation = $vec3::from(translation);
+            assert_approx_eq!($aabb3::new(a.min + translation, a.max + translation), moved);
+
+            should_glam_assert!({ a.transform_mat4(&$mat4::ZERO) });
+        });
+    };
+}
+
+mod aabb2 {
+    use super::support::FloatCompare;
+    use glam::{Aabb2, Affine2, Mat3, Vec2};
+
+    impl FloatCompare for Aabb2 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                min: (self.min - other.min).abs(),
+                max: (self.max - other.max).abs(),
+            }
+        }
+    }
+
+    glam_test!(test_aabb2_area_perimeter, {
+        let a = Aabb2::new(Vec2::ZERO, Vec2::new(2.0, 3.0));
+        assert_eq!(6.0, a.area());
+        assert_eq!(10.0, a.perimeter());
+    });
+
+    glam_test!(test_aabb2_transform, {
+        let a = Aabb2::new(Vec2::new(-1.0, 2.0), Vec2::new(3.0, 3.0));
+        let affine =
+            Affine2::from_scale_angle_translation(Vec2::new(2.0, 0.5), 0.6, Vec2::new(-3.0, 1.0));
+        let mat =
+            Mat3::from_scale_angle_translation(Vec2::new(2.0, 0.5), 0.6, Vec2::new(-3.0, 1.0));
+        let expected = Aabb2::from_points(&[
+            affine.transform_point2(a.min),
+            affine.transform_point2(a.max),
+            affine.transform_point2(Vec2::new(a.min.x, a.max.y)),
+            affine.transform_point2(Vec2::new(a.max.x, a.min.y)),
+        ]);
+        assert_approx_eq!(expected, a.transform_affine2(&affine), 1e-5);
+        assert_approx_eq!(expected, a.transform_mat3(&mat), 1e-5);
+    });
+
+    impl_aabb_float_tests!(f32, Aabb2, Vec2);
+}
+
+mod aabb3 {
+    use super::support::FloatCompare;
+    use glam::{Aabb3, Aabb3A, Affine3A, Mat4, Quat, Vec3, Vec3A};
+
+    impl FloatCompare for Aabb3 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                min: (self.min - other.min).abs(),
+                max: (self.max - other.max).abs(),
+            }
+        }
+    }
+
+    glam_test!(test_aabb3a_conversion, {
+        let a = Aabb3::new(Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0));
+        let b = Aabb3A::from(a);
+        assert_eq!(Vec3A::new(1.0, 2.0, 3.0), b.min);
+        assert_eq!(Vec3A::new(4.0, 5.0, 6.0), b.max);
+        assert_eq!(a, Aabb3::from(b));
+    });
+
+    impl_aabb_float_tests!(f32, Aabb3, Vec3);
+    impl_aabb3_tests!(f32, Aabb3, Vec3);
+    impl_aabb3_transform_tests!(f32, Aabb3, Vec3, Mat4, Affine3A, Quat, Vec3);
+}
+
+mod aabb3a {
+    use super::support::FloatCompare;
+    use glam::{Aabb3A, Affine3A, Mat4, Quat, Vec3, Vec3A};
+
+    impl FloatCompare for Aabb3A {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                min: (self.min - other.min).abs(),
+                max: (self.max - other.max).abs(),
+            }
+        }
+    }
+
+    impl_aabb_float_tests!(f32, Aabb3A, Vec3A);
+    impl_aabb3_tests!(f32, Aabb3A, Vec3A);
+    impl_aabb3_transform_tests!(f32, Aabb3A, Vec3A, Mat4, Affine3A, Quat, Vec3);
+}
+
+mod daabb3 {
+    use super::support::FloatCompare;
+    use glam::{DAabb3, DAffine3, DMat4, DQuat, DVec3};
+
+    impl FloatCompare for DAabb3 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff as f64)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                min: (self.min - other.min).abs(),
+                max: (self.max - other.max).abs(),
+            }
+        }
+    }
+
+    impl_aabb_float_tests!(f64, DAabb3, DVec3);
+    impl_aabb3_tests!(f64, DAabb3, DVec3);
+    impl_aabb3_transform_tests!(f64, DAabb3, DVec3, DMat4, DAffine3, DQuat, DVec3);
+}
+
+mod iaabb3 {
+    use glam::{IAabb3, IVec3};
+
+    impl_aabb_common_tests!(i32, IAabb3, IVec3);
+    impl_aabb3_tests!(i32, IAabb3, IVec3);
+}
@@ -95,6 +95,30 @@ macro_rules! impl_affine2_tests {
             should_glam_assert!({ $affine2::ZERO.inverse() });
         });
 
+        glam_test!(test_affine2_try_inverse, {
+            assert_eq!(None, $affine2::ZERO.try_inverse());
+            assert_eq!(None, $affine2::NAN.try_inverse());
+            let singular = $affine2::from_scale_angle_translation(
+                $vec2::new(1.0, 0.0),
+                deg(30.0),
+                $vec2::new(1.0, 2.0),
+            );
+            assert_eq!(None, singular.try_inverse());
+            assert_eq!($affine2::ZERO, singular.inverse_or_zero());
+
+            let m = $affine2::from_scale($vec2::new(4.0, 5.0))
+                * $affine2::from_angle(deg(90.0))
+                * $affine2::from_translation($vec2::new(1.0, 2.0));
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+            assert_eq!(m.inverse(), m.inverse_or_zero());
+            assert_eq!(Some(m.inverse()), m.inverse_with_epsilon(1.0e-6));
+
+            let near_singular = $affine2::from_scale($vec2::new(1.0e-4, 1.0e-4));
+            assert_eq!(None, near_singular.inverse_with_epsilon(1.0e-6));
+            let inv = near_singular.try_inverse().unwrap();
+            assert_approx_eq!($affine2::IDENTITY, near_singular * inv);
+        });
+
         glam_test!(test_affine2_ops, {
             let m0 = $affine2::from_cols_array_2d(&MATRIX2D);
             let m0x2 = $affine2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0], [10.0, 12.0]]);
@@ -116,6 +116,26 @@ macro_rules! impl_affine3_tests {
             should_glam_assert!({ $affine3::ZERO.inverse() });
         });
 
+        glam_test!(test_affine3_try_inverse, {
+            assert_eq!(None, $affine3::ZERO.try_inverse());
+            assert_eq!(None, $affine3::NAN.try_inverse());
+            let singular = $affine3::from_scale($vec3::new(1.0, 2.0, 0.0));
+            assert_eq!(None, singular.try_inverse());
+            assert_eq!($affine3::ZERO, singular.inverse_or_zero());
+
+            let m = $affine3::from_scale($vec3::new(4.0, 5.0, 6.0))
+                * $affine3::from_rotation_z(deg(90.0))
+                * $affine3::from_translation($vec3::new(1.0, 2.0, 3.0));
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+            assert_eq!(m.inverse(), m.inverse_or_zero());
+            assert_eq!(Some(m.inverse()), m.inverse_with_epsilon(1.0e-6));
+
+            let near_singular = $affine3::from_scale($vec3::new(1.0e-3, 1.0e-3, 1.0e-3));
+            assert_eq!(None, near_singular.inverse_with_epsilon(1.0e-6));
+            let inv = near_singular.try_inverse().unwrap();
+            assert_approx_eq!($affine3::IDENTITY, near_singular * inv);
+        });
+
         glam_test!(test_affine3_decompose, {
             // identity
             let (out_scale, out_rotation, out_translation) =
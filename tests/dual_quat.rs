@@ -0,0 +1,328 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_dual_quat_tests {
+    ($t:ident, $dual_quat:ident, $quat:ident, $vec3:ident, $mat4:ident, $affine3:ident) => {
+        use core::$t::NAN;
+
+        fn rigid() -> $dual_quat {
+            $dual_quat::from_rotation_translation(
+                $quat::from_euler(EulerRot::YXZ, deg(30.0), deg(-45.0), deg(60.0)),
+                $vec3::new(1.0, -2.0, 3.0),
+            )
+        }
+
+        fn other() -> $dual_quat {
+            $dual_quat::from_rotation_translation(
+                $quat::from_axis_angle($vec3::new(1.0, 1.0, 0.0).normalize(), deg(120.0)),
+                $vec3::new(-0.5, 4.0, 2.0),
+            )
+        }
+
+        glam_test!(test_dual_quat_identity, {
+            assert_eq!($dual_quat::IDENTITY, $dual_quat::default());
+            assert_eq!(
+                $dual_quat::IDENTITY,
+                $dual_quat::IDENTITY * $dual_quat::IDENTITY
+            );
+            assert!($dual_quat::IDENTITY.is_normalized());
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_eq!(p, $dual_quat::IDENTITY.transform_point3(p));
+            assert_eq!(p, $dual_quat::IDENTITY.transform_vector3(p));
+        });
+
+        glam_test!(test_dual_quat_nan, {
+            assert!($dual_quat::NAN.is_nan());
+            assert!(!$dual_quat::NAN.is_finite());
+            assert!($dual_quat::IDENTITY.is_finite());
+            let mut dq = $dual_quat::IDENTITY;
+            dq.dual = $quat::from_xyzw(NAN, 0.0, 0.0, 0.0);
+            assert!(dq.is_nan());
+        });
+
+        glam_test!(test_dual_quat_fmt, {
+            let dq = $dual_quat::IDENTITY;
+            assert_eq!(
+                format!("{:?}", dq),
+                format!(
+                    "{} {{ real: {}(0.0, 0.0, 0.0, 1.0), dual: {}(0.0, 0.0, 0.0, 0.0) }}",
+                    stringify!($dual_quat),
+                    stringify!($quat),
+                    stringify!($quat)
+                )
+            );
+            assert_eq!(format!("{}", dq), "[[0, 0, 0, 1], [0, 0, 0, 0]]");
+        });
+
+        glam_test!(test_dual_quat_rotation_translation, {
+            let rotation = $quat::from_rotation_y(deg(90.0));
+            let translation = $vec3::new(1.0, 2.0, 3.0);
+            let dq = $dual_quat::from_rotation_translation(rotation, translation);
+            assert!(dq.is_normalized());
+            assert_eq!(rotation, dq.rotation());
+            assert_approx_eq!(translation, dq.translation(), 1.0e-6);
+            let (r, t) = dq.to_rotation_translation();
+            assert_eq!(rotation, r);
+            assert_approx_eq!(translation, t, 1.0e-6);
+
+            let p = $vec3::new(4.0, -5.0, 6.0);
+            assert_approx_eq!($vec3::new(7.0, -3.0, -1.0), dq.transform_point3(p), 1.0e-6);
+            assert_approx_eq!($vec3::new(6.0, -5.0, -4.0), dq.transform_vector3(p), 1.0e-6);
+
+            assert_eq!($dual_quat::from_quat(rotation).real, rotation);
+            assert_eq!($vec3::ZERO, $dual_quat::from_quat(rotation).translation());
+            let dq = $dual_quat::from_translation(translation);
+            assert_eq!($quat::IDENTITY, dq.rotation());
+            assert_eq!(translation, dq.translation());
+            assert_eq!(p + translation, dq.transform_point3(p));
+            assert_eq!(p, dq.transform_vector3(p));
+
+            should_glam_assert!({
+                $dual_quat::from_rotation_translation(
+                    $quat::from_xyzw(0.0, 0.0, 0.0, 2.0),
+                    translation,
+                )
+            });
+        });
+
+        glam_test!(test_dual_quat_matrix, {
+            let dq = rigid();
+            let (rotation, translation) = dq.to_rotation_translation();
+            let mat4 = $mat4::from_rotation_translation(rotation, translation);
+            let affine = $affine3::from_rotation_translation(rotation, translation);
+            assert_approx_eq!(mat4, $mat4::from(dq), 1.0e-6);
+            assert!($affine3::from(dq).abs_diff_eq(affine, 1.0e-6));
+            assert_approx_eq!(dq, $dual_quat::from_mat4(&mat4), 1.0e-6);
+            assert_approx_eq!(dq, $dual_quat::from_affine3(&affine), 1.0e-6);
+
+            // Only the rigid part of the matrix is kept
+            let scale = $vec3::new(2.0, 3.0, 0.5);
+            let mat4 = $mat4::from_scale_rotation_translation(scale, rotation, translation);
+            let affine = $affine3::from_scale_rotation_translation(scale, rotation, translation);
+            assert_approx_eq!(dq, $dual_quat::from_mat4(&mat4), 1.0e-5);
+            assert_approx_eq!(dq, $dual_quat::from_affine3(&affine), 1.0e-5);
+
+            let p = $vec3::new(4.0, -5.0, 6.0);
+            assert_approx_eq!(
+                $mat4::from(dq).transform_point3(p),
+                dq.transform_point3(p),
+                1.0e-5
+            );
+        });
+
+        glam_test!(test_dual_quat_mul, {
+            let (a, b) = (rigid(), other());
+            let ab = a * b;
+            assert!(ab.is_normalized());
+            let p = $vec3::new(4.0, -5.0, 6.0);
+            assert_approx_eq!(
+                a.transform_point3(b.transform_point3(p)),
+                ab.transform_point3(p),
+                1.0e-5
+            );
+            assert_approx_eq!($mat4::from(a) * $mat4::from(b), $mat4::from(ab), 1.0e-5);
+
+            let mut c = a;
+            c *= b;
+            assert_eq!(ab, c);
+            assert_eq!(ab, [a, b].iter().product());
+        });
+
+        glam_test!(test_dual_quat_inverse, {
+            let dq = rigid();
+            let inverse = dq.inverse();
+            assert_approx_eq!($dual_quat::IDENTITY, dq * inverse);
+            assert_approx_eq!($dual_quat::IDENTITY, inverse * dq);
+            assert_approx_eq!($mat4::from(dq).inverse(), $mat4::from(inverse), 1.0e-5);
+            let p = $vec3::new(4.0, -5.0, 6.0);
+            assert_approx_eq!(p, inverse.transform_point3(dq.transform_point3(p)), 1.0e-5);
+
+            should_glam_assert!({ (dq * 2.0).inverse() });
+        });
+
+        glam_test!(test_dual_quat_normalize, {
+            let dq = rigid();
+            assert!(dq.is_normalized());
+            assert!(!(dq * 2.0).is_normalized());
+            assert_approx_eq!(dq, (dq * 2.0).normalize());
+            assert_approx_eq!(-dq, (dq * -0.5).normalize());
+
+            // The dual part is made orthogonal to the real part
+            let mut skewed = dq;
+            skewed.dual = skewed.dual + dq.real * 0.5;
+            assert!(!skewed.is_normalized());
+            assert!(skewed.normalize().is_normalized());
+            assert_approx_eq!(dq, skewed.normalize());
+
+            assert_eq!(dq + dq, dq * 2.0);
+            assert_eq!(dq - dq, dq * 0.0);
+
+            should_glam_assert!({ (dq * 0.0).normalize() });
+        });
+
+        glam_test!(test_dual_quat_large_translation, {
+            // The dual part grows with the translation so rounding error in it must not be
+            // mistaken for denormalization
+            let translation = $vec3::new(1.0e4, -2.0e4, 3.0e4);
+            let dq = $dual_quat::from_rotation_translation(rigid().rotation(), translation);
+            assert!(dq.is_normalized());
+
+            let inverse = dq.inverse();
+            assert!(inverse.is_normalized());
+            let p = $vec3::new(4.0, -5.0, 6.0);
+            assert_approx_eq!(p, inverse.transform_point3(dq.transform_point3(p)), 1.0e-2);
+
+            let (a, b) = (dq, dq * other());
+            let half = a.sclerp(b, 0.5);
+            assert!(half.is_normalized());
+            assert!(half.sclerp(b, 1.0).is_normalized());
+
+            let blend = $dual_quat::linear_blend(&[(a, 0.25), (b, 0.75)]);
+            assert!(blend.is_normalized());
+            assert!(!(dq * 1.01).is_normalized());
+        });
+
+        glam_test!(test_dual_quat_sclerp, {
+            let (a, b) = (rigid(), other());
+            assert_approx_eq!(a, a.sclerp(b, 0.0), 1.0e-5);
+            assert_approx_eq!(b, a.sclerp(b, 1.0), 1.0e-5);
+            assert_approx_eq!(b, a.sclerp(-b, 1.0), 1.0e-5);
+            assert_approx_eq!(a.sclerp(b, 0.3), a.sclerp(-b, 0.3), 1.0e-5);
+            assert!(a.sclerp(b, 0.3).is_normalized());
+
+            // A pure translation is interpolated linearly
+            let start = $dual_quat::from_translation($vec3::new(1.0, 2.0, 3.0));
+            let end = $dual_quat::from_translation($vec3::new(3.0, -2.0, 5.0));
+            assert_approx_eq!(
+                $dual_quat::from_translation($vec3::new(2.0, 0.0, 4.0)),
+                start.sclerp(end, 0.5)
+            );
+
+            // A screw along the z axis
+            let end = $dual_quat::from_rotation_translation(
+                $quat::from_rotation_z(deg(90.0)),
+                $vec3::new(0.0, 0.0, 2.0),
+            );
+            let half = $dual_quat::from_rotation_translation(
+                $quat::from_rotation_z(deg(45.0)),
+                $vec3::new(0.0, 0.0, 1.0),
+            );
+            assert_approx_eq!(half, $dual_quat::IDENTITY.sclerp(end, 0.5));
+
+            // A rotation around a pivot keeps the pivot fixed along the whole path
+            let pivot = $vec3::new(1.0, 2.0, 0.0);
+            let around_pivot = |angle| {
+                $dual_quat::from_translation(pivot)
+                    * $dual_quat::from_quat($quat::from_rotation_z(deg(angle)))
+                    * $dual_quat::from_translation(-pivot)
+            };
+            let path = |s| $dual_quat::IDENTITY.sclerp(around_pivot(120.0), s);
+            for &s in &[0.25, 0.5, 0.75] {
+                assert_approx_eq!(pivot, path(s).transform_point3(pivot), 1.0e-5);
+                assert_approx_eq!(around_pivot(120.0 * s), path(s), 1.0e-5);
+            }
+        });
+
+        glam_test!(test_dual_quat_linear_blend, {
+            let (a, b) = (rigid(), other());
+            assert_eq!($dual_quat::IDENTITY, $dual_quat::linear_blend(&[]));
+            assert_approx_eq!(a, $dual_quat::linear_blend(&[(a, 1.0)]));
+            assert_approx_eq!(a, $dual_quat::linear_blend(&[(a, 0.5), (b, 0.0)]));
+
+            let blend = $dual_quat::linear_blend(&[(a, 0.25), (b, 0.75)]);
+            assert!(blend.is_normalized());
+            assert_approx_eq!(
+                blend,
+                $dual_quat::linear_blend(&[(a, 0.25), (-b, 0.75)]),
+                1.0e-6
+            );
+            assert_approx_eq!(
+                -blend,
+                $dual_quat::linear_blend(&[(-a, 0.25), (b, 0.75)]),
+                1.0e-6
+            );
+
+            // Translations with the same rotation are averaged
+            let rotation = a.rotation();
+            let blend = $dual_quat::linear_blend(&[
+                (
+                    $dual_quat::from_rotation_translation(rotation, $vec3::new(1.0, 0.0, 0.0)),
+                    1.0,
+                ),
+                (
+                    $dual_quat::from_rotation_translation(rotation, $vec3::new(3.0, 2.0, 0.0)),
+                    1.0,
+                ),
+            ]);
+            assert_approx_eq!(rotation, blend.rotation());
+            assert_approx_eq!($vec3::new(2.0, 1.0, 0.0), blend.translation(), 1.0e-5);
+
+            // Opposite twists blend to no rotation instead of collapsing like a matrix blend
+            let twist = |angle| $dual_quat::from_quat($quat::from_rotation_x(deg(angle)));
+            let blend = $dual_quat::linear_blend(&[(twist(90.0), 0.5), (twist(-90.0), 0.5)]);
+            assert_approx_eq!($dual_quat::IDENTITY, blend);
+            let matrix_blend = $mat4::from(twist(90.0)) * 0.5 + $mat4::from(twist(-90.0)) * 0.5;
+            assert_approx_eq!(0.0, matrix_blend.determinant());
+
+            assert_eq!(
+                $dual_quat::IDENTITY,
+                $dual_quat::linear_blend(&[(a, 1.0), (a, -1.0)])
+            );
+            should_glam_assert!({ $dual_quat::linear_blend(&[(a * 2.0, 1.0)]) });
+        });
+    };
+}
+
+mod dual_quat {
+    use super::support::{deg, FloatCompare};
+    use glam::{Affine3A, DualQuat, EulerRot, Mat4, Quat, Vec3};
+
+    impl FloatCompare for DualQuat {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                real: self.real.abs_diff(&other.real),
+                dual: self.dual.abs_diff(&other.dual),
+            }
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(32, mem::size_of::<DualQuat>());
+        assert_eq!(mem::align_of::<Quat>(), mem::align_of::<DualQuat>());
+    });
+
+    impl_dual_quat_tests!(f32, DualQuat, Quat, Vec3, Mat4, Affine3A);
+}
+
+mod ddual_quat {
+    use super::support::{deg, FloatCompare};
+    use glam::{DAffine3, DDualQuat, DMat4, DQuat, DVec3, EulerRot};
+
+    impl FloatCompare for DDualQuat {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff as f64)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                real: self.real.abs_diff(&other.real),
+                dual: self.dual.abs_diff(&other.dual),
+            }
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(64, mem::size_of::<DDualQuat>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DDualQuat>());
+    });
+
+    impl_dual_quat_tests!(f64, DDualQuat, DQuat, DVec3, DMat4, DAffine3);
+}
@@ -82,12 +82,63 @@ macro_rules! impl_eq_approx {
 impl_eq_approx!(f32, Quat, std::f32::consts::PI);
 impl_eq_approx!(f64, DQuat, std::f64::consts::PI);
 
-#[macro_export]
+/// Composes the rotations around the axes in the order of the euler rotation sequence
+macro_rules! compose {
+    ($quat:ident, $vec:ident, $extrinsic:expr, $U:ident, $V:ident, $W:ident, $u:expr, $v:expr, $w:expr) => {{
+        let (u, v, w) = (
+            $quat::from_axis_angle($vec::$U, $u),
+            $quat::from_axis_angle($vec::$V, $v),
+            $quat::from_axis_angle($vec::$W, $w),
+        );
+        if $extrinsic {
+            (w * v * u).normalize()
+        } else {
+            (u * v * w).normalize()
+        }
+    }};
+}
+
+/// Checks that the angles of a rotation reconstruct the rotation and are the same for the
+/// quaternion and matrix types
+macro_rules! assert_euler_angles {
+    ($quat:ident, $mat3:ident, $mat4:ident, $vec:ident, $euler:expr, $q:expr) => {{
+        let euler = $euler;
+        let q = $q;
+        let (u, v, w) = q.to_euler(euler);
+        let q2 = $quat::from_euler(euler, u, v, w);
+        assert_approx_eq!(q * $vec::X, q2 * $vec::X, 1e-4);
+        assert_approx_eq!(q * $vec::Y, q2 * $vec::Y, 1e-4);
+        assert_approx_eq!(q * $vec::Z, q2 * $vec::Z, 1e-4);
+
+        let (u3, v3, w3) = $mat3::from_quat(q).to_euler(euler);
+        assert_approx_angle!(u, u3, 1e-4);
+        assert_approx_angle!(v, v3, 1e-4);
+        assert_approx_angle!(w, w3, 1e-4);
+        let (u4, v4, w4) = $mat4::from_quat(q).to_euler(euler);
+        assert_approx_angle!(u, u4, 1e-4);
+        assert_approx_angle!(v, v4, 1e-4);
+        assert_approx_angle!(w, w4, 1e-4);
+        (u, v, w)
+    }};
+}
+
+/// Checks the angle of the rotation that is applied first in gimbal lock
+macro_rules! assert_gimbal_lock {
+    ($extrinsic:expr, $angles:expr) => {{
+        let (u, _, w) = $angles;
+        if $extrinsic {
+            assert_eq!(0.0, u);
+        } else {
+            assert_eq!(0.0, w);
+        }
+    }};
+}
+
 macro_rules! impl_3axis_test {
-    ($name:ident, $t:ty, $quat:ident, $euler:path, $U:path, $V:path, $W:path, $vec:ident) => {
+    ($name:ident, ($t:ty, $quat:ident, $mat3:ident, $mat4:ident, $vec:ident), $euler:ident, $extrinsic:expr, $U:ident, $V:ident, $W:ident) => {
         glam_test!($name, {
-            let euler = $euler;
-            assert!($U != $W); // First and last axis must be different for three axis
+            let euler = EulerRot::$euler;
+            assert!($vec::$U != $vec::$W); // First and last axis must be different for three axis
             for u in (-176..=176).step_by(44) {
                 for v in (-88..=88).step_by(44) {
                     for w in (-176..=176).step_by(44) {
@@ -95,26 +146,34 @@ macro_rules! impl_3axis_test {
                         let v1 = (v as $t).to_radians();
                         let w1 = (w as $t).to_radians();
 
-                        let q1: $quat = ($quat::from_axis_angle($U, u1)
-                            * $quat::from_axis_angle($V, v1)
-                            * $quat::from_axis_angle($W, w1))
-                        .normalize();
+                        let q1 = compose!($quat, $vec, $extrinsic, $U, $V, $W, u1, v1, w1);
 
                         // Test if the rotation is the expected
                         let q2: $quat = $quat::from_euler(euler, u1, v1, w1).normalize();
                         assert_approx_eq!(q1, q2, 1e-5);
 
                         // Test angle reconstruction
-                        let (u2, v2, w2) = q1.to_euler(euler);
-                        let q3 = $quat::from_euler(euler, u2, v2, w2).normalize();
-
+                        let (u2, v2, w2) =
+                            assert_euler_angles!($quat, $mat3, $mat4, $vec, euler, q1);
                         assert_approx_angle!(u1, u2, 1e-4 as $t);
                         assert_approx_angle!(v1, v2, 1e-4 as $t);
                         assert_approx_angle!(w1, w2, 1e-4 as $t);
+                    }
+                }
+            }
 
-                        assert_approx_eq!(q1 * $vec::X, q3 * $vec::X, 1e-4);
-                        assert_approx_eq!(q1 * $vec::Y, q3 * $vec::Y, 1e-4);
-                        assert_approx_eq!(q1 * $vec::Z, q3 * $vec::Z, 1e-4);
+            // Gimbal lock
+            for u in (-176..=176).step_by(44) {
+                for &v in &[-90, 90] {
+                    for w in (-176..=176).step_by(44) {
+                        let u1 = (u as $t).to_radians();
+                        let v1 = (v as $t).to_radians();
+                        let w1 = (w as $t).to_radians();
+
+                        let q1 = compose!($quat, $vec, $extrinsic, $U, $V, $W, u1, v1, w1);
+                        let angles = assert_euler_angles!($quat, $mat3, $mat4, $vec, euler, q1);
+                        assert_approx_angle!(v1, angles.1, 1e-4 as $t);
+                        assert_gimbal_lock!($extrinsic, angles);
                     }
                 }
             }
@@ -122,13 +181,11 @@ macro_rules! impl_3axis_test {
     };
 }
 
-#[macro_export]
 macro_rules! impl_2axis_test {
-    ($name:ident, $t:ty, $quat:ident, $euler:path, $U:path, $V:path, $W:path, $vec:ident) => {
+    ($name:ident, ($t:ty, $quat:ident, $mat3:ident, $mat4:ident, $vec:ident), $euler:ident, $extrinsic:expr, $U:ident, $V:ident, $W:ident) => {
         glam_test!($name, {
-            #[allow(deprecated)]
-            let euler = $euler;
-            assert!($U == $W); // First and last axis must be different for three axis
+            let euler = EulerRot::$euler;
+            assert!($vec::$U == $vec::$W); // First and last axis must be the same for two axis
             for u in (-176..=176).step_by(44) {
                 for v in (-176..=176).step_by(44) {
                     for w in (-176..=176).step_by(44) {
@@ -136,28 +193,44 @@ macro_rules! impl_2axis_test {
                         let v1 = (v as $t).to_radians();
                         let w1 = (w as $t).to_radians();
 
-                        let q1: $quat = ($quat::from_axis_angle($U, u1)
-                            * $quat::from_axis_angle($V, v1)
-                            * $quat::from_axis_angle($W, w1))
-                        .normalize();
+                        let q1 = compose!($quat, $vec, $extrinsic, $U, $V, $W, u1, v1, w1);
 
                         // Test if the rotation is the expected
                         let q2 = $quat::from_euler(euler, u1, v1, w1).normalize();
                         assert_approx_eq!(q1, q2, 1e-5);
 
                         // Test angle reconstruction
-                        let (u2, v2, w2) = q1.to_euler(euler);
-                        let _q3 = $quat::from_euler(euler, u2, v2, w2).normalize();
+                        let angles = assert_euler_angles!($quat, $mat3, $mat4, $vec, euler, q1);
+                        let (u2, v2, w2) = angles;
+                        if v == 0 {
+                            assert_approx_angle!(0.0, v2, 1e-4 as $t);
+                            assert_gimbal_lock!($extrinsic, angles);
+                        } else if v > 0 {
+                            assert_approx_angle!(u1, u2, 1e-4 as $t);
+                            assert_approx_angle!(v1, v2, 1e-4 as $t);
+                            assert_approx_angle!(w1, w2, 1e-4 as $t);
+                        } else {
+                            // The second angle is positive, with the others rotated by PI
+                            let pi = (180 as $t).to_radians();
+                            assert_approx_angle!(u1 + pi, u2, 1e-4 as $t);
+                            assert_approx_angle!(-v1, v2, 1e-4 as $t);
+                            assert_approx_angle!(w1 + pi, w2, 1e-4 as $t);
+                        }
+                    }
+                }
+            }
 
-                        // Disabled tests, since no generic tests for ambiguous results in the two-axis results...
-                        // assert_approx_angle!(u1, u2, 1e-4 as $t);
-                        // assert_approx_angle!(v1, v2, 1e-4 as $t);
-                        // assert_approx_angle!(w1, w2, 1e-4 as $t);
+            // Gimbal lock with a half turn
+            for u in (-176..=176).step_by(44) {
+                for w in (-176..=176).step_by(44) {
+                    let u1 = (u as $t).to_radians();
+                    let v1 = (180 as $t).to_radians();
+                    let w1 = (w as $t).to_radians();
 
-                        // assert_approx_eq!(q1 * $vec::X, q3 * $vec::X, 1e-4);
-                        // assert_approx_eq!(q1 * $vec::Y, q3 * $vec::Y, 1e-4);
-                        // assert_approx_eq!(q1 * $vec::Z, q3 * $vec::Z, 1e-4);
-                    }
+                    let q1 = compose!($quat, $vec, $extrinsic, $U, $V, $W, u1, v1, w1);
+                    let angles = assert_euler_angles!($quat, $mat3, $mat4, $vec, euler, q1);
+                    assert_approx_angle!(v1, angles.1, 1e-4 as $t);
+                    assert_gimbal_lock!($extrinsic, angles);
                 }
             }
         });
@@ -165,45 +238,169 @@ macro_rules! impl_2axis_test {
 }
 
 macro_rules! impl_all_quat_tests_three_axis {
-    ($t:ty, $q:ident, $v:ident) => {
-        impl_3axis_test!(test_euler_zyx, $t, $q, ER::ZYX, $v::Z, $v::Y, $v::X, $v);
-        impl_3axis_test!(test_euler_zxy, $t, $q, ER::ZXY, $v::Z, $v::X, $v::Y, $v);
-        impl_3axis_test!(test_euler_yxz, $t, $q, ER::YXZ, $v::Y, $v::X, $v::Z, $v);
-        impl_3axis_test!(test_euler_yzx, $t, $q, ER::YZX, $v::Y, $v::Z, $v::X, $v);
-        impl_3axis_test!(test_euler_xyz, $t, $q, ER::XYZ, $v::X, $v::Y, $v::Z, $v);
-        impl_3axis_test!(test_euler_xzy, $t, $q, ER::XZY, $v::X, $v::Z, $v::Y, $v);
+    ($types:tt) => {
+        impl_3axis_test!(test_euler_zyx, $types, ZYX, false, Z, Y, X);
+        impl_3axis_test!(test_euler_zxy, $types, ZXY, false, Z, X, Y);
+        impl_3axis_test!(test_euler_yxz, $types, YXZ, false, Y, X, Z);
+        impl_3axis_test!(test_euler_yzx, $types, YZX, false, Y, Z, X);
+        impl_3axis_test!(test_euler_xyz, $types, XYZ, false, X, Y, Z);
+        impl_3axis_test!(test_euler_xzy, $types, XZY, false, X, Z, Y);
+
+        impl_3axis_test!(test_euler_zyx_ex, $types, ZYXEx, true, Z, Y, X);
+        impl_3axis_test!(test_euler_zxy_ex, $types, ZXYEx, true, Z, X, Y);
+        impl_3axis_test!(test_euler_yxz_ex, $types, YXZEx, true, Y, X, Z);
+        impl_3axis_test!(test_euler_yzx_ex, $types, YZXEx, true, Y, Z, X);
+        impl_3axis_test!(test_euler_xyz_ex, $types, XYZEx, true, X, Y, Z);
+        impl_3axis_test!(test_euler_xzy_ex, $types, XZYEx, true, X, Z, Y);
     };
 }
 
 macro_rules! impl_all_quat_tests_two_axis {
-    ($t:ty, $q:ident, $v:ident) => {
-        impl_2axis_test!(test_euler_zyz, $t, $q, ER::ZYZ, $v::Z, $v::Y, $v::Z, $v);
-        impl_2axis_test!(test_euler_zxz, $t, $q, ER::ZXZ, $v::Z, $v::X, $v::Z, $v);
-        impl_2axis_test!(test_euler_yxy, $t, $q, ER::YXY, $v::Y, $v::X, $v::Y, $v);
-        impl_2axis_test!(test_euler_yzy, $t, $q, ER::YZY, $v::Y, $v::Z, $v::Y, $v);
-        impl_2axis_test!(test_euler_xyx, $t, $q, ER::XYX, $v::X, $v::Y, $v::X, $v);
-        impl_2axis_test!(test_euler_xzx, $t, $q, ER::XZX, $v::X, $v::Z, $v::X, $v);
+    ($types:tt) => {
+        impl_2axis_test!(test_euler_zyz, $types, ZYZ, false, Z, Y, Z);
+        impl_2axis_test!(test_euler_zxz, $types, ZXZ, false, Z, X, Z);
+        impl_2axis_test!(test_euler_yxy, $types, YXY, false, Y, X, Y);
+        impl_2axis_test!(test_euler_yzy, $types, YZY, false, Y, Z, Y);
+        impl_2axis_test!(test_euler_xyx, $types, XYX, false, X, Y, X);
+        impl_2axis_test!(test_euler_xzx, $types, XZX, false, X, Z, X);
+
+        impl_2axis_test!(test_euler_zyz_ex, $types, ZYZEx, true, Z, Y, Z);
+        impl_2axis_test!(test_euler_zxz_ex, $types, ZXZEx, true, Z, X, Z);
+        impl_2axis_test!(test_euler_yxy_ex, $types, YXYEx, true, Y, X, Y);
+        impl_2axis_test!(test_euler_yzy_ex, $types, YZYEx, true, Y, Z, Y);
+        impl_2axis_test!(test_euler_xyx_ex, $types, XYXEx, true, X, Y, X);
+        impl_2axis_test!(test_euler_xzx_ex, $types, XZXEx, true, X, Z, X);
+    };
+}
+
+macro_rules! impl_closest_tests {
+    ($t:ident, $quat:ident, $mat3:ident, $vec:ident) => {
+        use core::$t::consts::PI;
+
+        fn assert_angles_eq(expected: ($t, $t, $t), angles: ($t, $t, $t)) {
+            assert_approx_eq!(expected.0, angles.0, 1e-4);
+            assert_approx_eq!(expected.1, angles.1, 1e-4);
+            assert_approx_eq!(expected.2, angles.2, 1e-4);
+        }
+
+        fn assert_same_rotation(euler: EulerRot, q: $quat, angles: ($t, $t, $t)) {
+            let q2 = $quat::from_euler(euler, angles.0, angles.1, angles.2);
+            assert_approx_eq!(q * $vec::X, q2 * $vec::X, 1e-4);
+            assert_approx_eq!(q * $vec::Y, q2 * $vec::Y, 1e-4);
+            assert_approx_eq!(q * $vec::Z, q2 * $vec::Z, 1e-4);
+        }
+
+        glam_test!(test_euler_closest, {
+            let euler = EulerRot::XYZ;
+            let q = $quat::from_euler(euler, 3.0, 0.2, -3.0);
+            let m = $mat3::from_quat(q);
+            assert_angles_eq(
+                (3.0, 0.2, -3.0),
+                q.to_euler_closest(euler, (3.0, 0.0, -3.0)),
+            );
+
+            // Multiples of 2*PI
+            let expected = (3.0 + 2.0 * PI, 0.2, -3.0 - 2.0 * PI);
+            assert_angles_eq(expected, q.to_euler_closest(euler, (9.0, 0.0, -9.0)));
+            assert_angles_eq(expected, m.to_euler_closest(euler, (9.0, 0.0, -9.0)));
+
+            // The second solution
+            let expected = (3.0 + PI, PI - 0.2, -3.0 + PI);
+            assert_angles_eq(expected, q.to_euler_closest(euler, (6.0, 3.0, 0.0)));
+            assert_angles_eq(expected, m.to_euler_closest(euler, (6.0, 3.0, 0.0)));
+
+            let euler = EulerRot::ZYZEx;
+            let q = $quat::from_euler(euler, 1.0, 0.3, 2.0);
+            let expected = (1.0 - PI, -0.3, 2.0 - PI);
+            assert_angles_eq(expected, q.to_euler_closest(euler, (-2.0, -0.2, -1.0)));
+
+            // Gimbal lock keeps both angles close to the previous ones
+            let euler = EulerRot::YXZ;
+            let q = $quat::from_euler(euler, 1.0, PI * 0.5, 0.5);
+            let angles = q.to_euler_closest(euler, (0.0, PI * 0.5, 0.0));
+            assert_angles_eq((0.25, PI * 0.5, -0.25), angles);
+            assert_same_rotation(euler, q, angles);
+            let q = $quat::from_euler(euler, 1.0, -PI * 0.5, 0.5);
+            let angles = q.to_euler_closest(euler, (0.0, -PI * 0.5, 0.0));
+            assert_angles_eq((0.75, -PI * 0.5, 0.75), angles);
+            assert_same_rotation(euler, q, angles);
+        });
+
+        glam_test!(test_euler_curve, {
+            const COUNT: usize = 200;
+            let path = |b: &dyn Fn($t) -> $t| -> Vec<($t, $t, $t)> {
+                (0..COUNT)
+                    .map(|n| {
+                        let t = n as $t / (COUNT - 1) as $t;
+                        (-3.0 + 8.0 * t, b(t), 2.0 - 7.0 * t)
+                    })
+                    .collect()
+            };
+            let three_axis = |t: $t| -1.0 + 3.0 * t;
+            let two_axis = |t: $t| 1.0 - 2.0 * t;
+            for &(euler, b) in &[
+                (EulerRot::XYZ, &three_axis as &dyn Fn($t) -> $t),
+                (EulerRot::ZYXEx, &three_axis),
+                (EulerRot::ZXZ, &two_axis),
+                (EulerRot::YXYEx, &two_axis),
+            ] {
+                let expected = path(b);
+                let rotations: Vec<$quat> = expected
+                    .iter()
+                    .map(|&(a, b, c)| $quat::from_euler(euler, a, b, c))
+                    .collect();
+                let mut curve = vec![(0.0, 0.0, 0.0); COUNT];
+                $quat::to_euler_curve(euler, &rotations, &mut curve);
+                for (&expected, &angles) in expected.iter().zip(&curve) {
+                    assert_approx_eq!(expected.0, angles.0, 1e-3);
+                    assert_approx_eq!(expected.1, angles.1, 1e-3);
+                    assert_approx_eq!(expected.2, angles.2, 1e-3);
+                }
+            }
+
+            // A path in gimbal lock
+            let euler = EulerRot::XYZ;
+            let rotations: Vec<$quat> = (0..COUNT)
+                .map(|n| {
+                    let t = n as $t / (COUNT - 1) as $t;
+                    $quat::from_euler(euler, 4.0 * t, PI * 0.5, -2.0 * t)
+                })
+                .collect();
+            let mut curve = vec![(0.0, 0.0, 0.0); COUNT];
+            $quat::to_euler_curve(euler, &rotations, &mut curve);
+            for (&q, &angles) in rotations.iter().zip(&curve) {
+                assert_same_rotation(euler, q, angles);
+            }
+            for pair in curve.windows(2) {
+                assert!((pair[1].0 - pair[0].0).abs() < 0.05);
+                assert!((pair[1].1 - pair[0].1).abs() < 0.05);
+                assert!((pair[1].2 - pair[0].2).abs() < 0.05);
+            }
+        });
     };
 }
 
 mod euler {
     use super::AngleDiff;
     use glam::*;
-    type ER = EulerRot;
 
     mod quat {
         use super::*;
 
-        impl_all_quat_tests_three_axis!(f32, Quat, Vec3);
+        impl_all_quat_tests_three_axis!((f32, Quat, Mat3, Mat4, Vec3));
+
+        impl_all_quat_tests_two_axis!((f32, Quat, Mat3, Mat4, Vec3));
 
-        impl_all_quat_tests_two_axis!(f32, Quat, Vec3);
+        impl_closest_tests!(f32, Quat, Mat3, Vec3);
     }
 
     mod dquat {
         use super::*;
 
-        impl_all_quat_tests_three_axis!(f64, DQuat, DVec3);
+        impl_all_quat_tests_three_axis!((f64, DQuat, DMat3, DMat4, DVec3));
+
+        impl_all_quat_tests_two_axis!((f64, DQuat, DMat3, DMat4, DVec3));
 
-        impl_all_quat_tests_two_axis!(f64, DQuat, DVec3);
+        impl_closest_tests!(f64, DQuat, DMat3, DVec3);
     }
 }
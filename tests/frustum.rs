@@ -0,0 +1,242 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_frustum_tests {
+    ($t:ident, $frustum:ident, $plane:ident, $vec3:ident, $mat4:ident, $aabb3:ident) => {
+        const EYE: $vec3 = const_vec3!([1.0, 2.0, 3.0]);
+        const FORWARD: $vec3 = const_vec3!([0.0, 0.0, -1.0]);
+        const FOV: $t = core::$t::consts::FRAC_PI_2;
+        const ASPECT: $t = 2.0;
+        const NEAR: $t = 1.0;
+        const FAR: $t = 20.0;
+
+        fn view_rh() -> $mat4 {
+            $mat4::look_at_rh(EYE, EYE + FORWARD, $vec3::Y)
+        }
+
+        // The same camera as `view_rh()` with view space z pointing forwards.
+        fn view_lh() -> $mat4 {
+            $mat4::from_scale($vec3::new(1.0, 1.0, -1.0)) * view_rh()
+        }
+
+        // Checks the frustum corners against the unprojected clip space corners.
+        fn assert_corners(frustum: &$frustum, view_projection: &$mat4, near_z: $t, far_z: $t) {
+            let inverse = view_projection.inverse();
+            let corners = frustum.corners().unwrap();
+            for (i, &corner) in corners.iter().enumerate() {
+                let z = if i < 4 { near_z } else { far_z };
+                let (x, y) = match i % 4 {
+                    0 => (-1.0, -1.0),
+                    1 => (1.0, -1.0),
+                    2 => (1.0, 1.0),
+                    _ => (-1.0, 1.0),
+                };
+                let expected = inverse.project_point3($vec3::new(x, y, z));
+                assert_approx_eq!(expected, corner, 1e-4);
+            }
+        }
+
+        // Checks the planes are normalized and containment of points along the view direction.
+        fn assert_finite_perspective(frustum: &$frustum) {
+            for plane in frustum.planes().iter() {
+                assert!(plane.is_normalized());
+            }
+            assert_approx_eq!(-NEAR, frustum.plane(4).signed_distance(EYE), 1e-5);
+            assert_approx_eq!(FAR, frustum.plane(5).signed_distance(EYE), 1e-4);
+            assert_approx_eq!(FORWARD, frustum.plane(4).normal, 1e-6);
+            assert_approx_eq!(-FORWARD, frustum.plane(5).normal, 1e-6);
+            assert!(!frustum.contains_point(EYE));
+            assert!(!frustum.contains_point(EYE + FORWARD * 0.5));
+            assert!(frustum.contains_point(EYE + FORWARD * 1.5));
+            assert!(frustum.contains_point(EYE + FORWARD * 19.5));
+            assert!(!frustum.contains_point(EYE + FORWARD * 20.5));
+            // 90 degree vertical field of view with an aspect ratio of 2
+            assert!(frustum.contains_point(EYE + FORWARD * 10.0 + $vec3::new(19.0, 9.0, 0.0)));
+            assert!(!frustum.contains_point(EYE + FORWARD * 10.0 + $vec3::new(21.0, 0.0, 0.0)));
+            assert!(!frustum.contains_point(EYE + FORWARD * 10.0 + $vec3::new(0.0, -11.0, 0.0)));
+        }
+
+        // Checks an infinite perspective frustum has an unbounded far plane.
+        fn assert_infinite_perspective(frustum: &$frustum) {
+            let far = frustum.plane(5);
+            assert_eq!($vec3::ZERO, far.normal);
+            assert_eq!(core::$t::INFINITY, far.d);
+            assert!(frustum.corners().is_none());
+            assert_approx_eq!(-NEAR, frustum.plane(4).signed_distance(EYE), 1e-5);
+            assert!(!frustum.contains_point(EYE + FORWARD * 0.5));
+            assert!(frustum.contains_point(EYE + FORWARD * 1.5));
+            assert!(frustum.contains_point(EYE + FORWARD * 1.0e6));
+            assert!(!frustum.contains_point(EYE + FORWARD * 1.0e6 + $vec3::X * 2.1e6));
+            assert!(frustum.intersects_sphere(EYE + FORWARD * 1.0e6, 1.0));
+        }
+
+        glam_test!(test_frustum_perspective, {
+            let view_projection = $mat4::perspective_rh(FOV, ASPECT, NEAR, FAR) * view_rh();
+            let frustum = $frustum::from_view_projection(&view_projection);
+            assert_finite_perspective(&frustum);
+            assert_corners(&frustum, &view_projection, 0.0, 1.0);
+
+            let view_projection = $mat4::perspective_lh(FOV, ASPECT, NEAR, FAR) * view_lh();
+            let frustum = $frustum::from_view_projection(&view_projection);
+            assert_finite_perspective(&frustum);
+            assert_corners(&frustum, &view_projection, 0.0, 1.0);
+
+            let view_projection = $mat4::perspective_rh_gl(FOV, ASPECT, NEAR, FAR) * view_rh();
+            let frustum = $frustum::from_view_projection_gl(&view_projection);
+            assert_finite_perspective(&frustum);
+            assert_corners(&frustum, &view_projection, -1.0, 1.0);
+
+            let corners = frustum.corners().unwrap();
+            assert_approx_eq!($vec3::new(-1.0, 1.0, 2.0), corners[0], 1e-5);
+            assert_approx_eq!($vec3::new(41.0, 22.0, -17.0), corners[6], 1e-4);
+        });
+
+        glam_test!(test_frustum_perspective_infinite, {
+            let view_projection = $mat4::perspective_infinite_rh(FOV, ASPECT, NEAR) * view_rh();
+            let frustum = $frustum::from_view_projection(&view_projection);
+            assert_infinite_perspective(&frustum);
+
+            let view_projection = $mat4::perspective_infinite_lh(FOV, ASPECT, NEAR) * view_lh();
+            let frustum = $frustum::from_view_projection(&view_projection);
+            assert_infinite_perspective(&frustum);
+
+            let view_projection =
+                $mat4::perspective_infinite_reverse_rh(FOV, ASPECT, NEAR) * view_rh();
+            let frustum = $frustum::from_view_projection_reverse_z(&view_projection);
+            assert_infinite_perspective(&frustum);
+
+            let view_projection =
+                $mat4::perspective_infinite_reverse_lh(FOV, ASPECT, NEAR) * view_lh();
+            let frustum = $frustum::from_view_projection_reverse_z(&view_projection);
+            assert_infinite_perspective(&frustum);
+        });
+
+        glam_test!(test_frustum_orthographic, {
+            let check = |frustum: &$frustum| {
+                for plane in frustum.planes().iter() {
+                    assert!(plane.is_normalized());
+                }
+                assert_approx_eq!(-FORWARD, frustum.plane(0).normal.cross($vec3::Y), 1e-6);
+                assert!(frustum.contains_point(EYE + $vec3::new(-1.9, 2.9, -1.5)));
+                assert!(!frustum.contains_point(EYE + $vec3::new(-2.1, 0.0, -1.5)));
+                assert!(!frustum.contains_point(EYE + $vec3::new(0.0, 3.1, -1.5)));
+                assert!(!frustum.contains_point(EYE + $vec3::new(0.0, 0.0, -0.5)));
+                assert!(!frustum.contains_point(EYE + $vec3::new(0.0, 0.0, -20.5)));
+            };
+
+            let projection = $mat4::orthographic_rh(-2.0, 4.0, -1.0, 3.0, NEAR, FAR);
+            let view_projection = projection * view_rh();
+            let frustum = $frustum::from_view_projection(&view_projection);
+            check(&frustum);
+            assert_corners(&frustum, &view_projection, 0.0, 1.0);
+
+            let projection = $mat4::orthographic_lh(-2.0, 4.0, -1.0, 3.0, NEAR, FAR);
+            let view_projection = projection * view_lh();
+            let frustum = $frustum::from_view_projection(&view_projection);
+            check(&frustum);
+            assert_corners(&frustum, &view_projection, 0.0, 1.0);
+
+            let projection = $mat4::orthographic_rh_gl(-2.0, 4.0, -1.0, 3.0, NEAR, FAR);
+            let view_projection = projection * view_rh();
+            let frustum = $frustum::from_view_projection_gl(&view_projection);
+            check(&frustum);
+            assert_corners(&frustum, &view_projection, -1.0, 1.0);
+        });
+
+        glam_test!(test_frustum_planes, {
+            let view_projection = $mat4::perspective_rh(FOV, ASPECT, NEAR, FAR) * view_rh();
+            let frustum = $frustum::from_view_projection(&view_projection);
+            let planes = frustum.planes();
+            for (i, plane) in planes.iter().enumerate() {
+                assert_eq!(*plane, frustum.plane(i));
+            }
+            assert_eq!(frustum, $frustum::from_planes(planes));
+            assert_eq!(
+                format!("{:?}", frustum),
+                format!("{} {{ planes: {:?} }}", stringify!($frustum), planes)
+            );
+
+            // An axis aligned box built from planes
+            let frustum = $frustum::from_planes([
+                $plane::new($vec3::X, 1.0),
+                $plane::new(-$vec3::X, 1.0),
+                $plane::new($vec3::Y, 2.0),
+                $plane::new(-$vec3::Y, 2.0),
+                $plane::new($vec3::Z, 3.0),
+                $plane::new(-$vec3::Z, 3.0),
+            ]);
+            let corners = frustum.corners().unwrap();
+            assert_approx_eq!($vec3::new(-1.0, -2.0, -3.0), corners[0], 1e-6);
+            assert_approx_eq!($vec3::new(1.0, 2.0, 3.0), corners[6], 1e-6);
+            assert!(frustum.contains_point($vec3::new(1.0, -2.0, 3.0)));
+            assert!(!frustum.contains_point($vec3::new(1.1, -2.0, 3.0)));
+
+            should_panic!({ frustum.plane(6) });
+        });
+
+        glam_test!(test_frustum_culling, {
+            let view_projection = $mat4::perspective_rh(FOV, ASPECT, NEAR, FAR) * view_rh();
+            let frustum = $frustum::from_view_projection(&view_projection);
+            let center = EYE + FORWARD * 10.0;
+
+            assert!(frustum.intersects_sphere(center, 1.0));
+            assert!(frustum.intersects_sphere(center, 100.0));
+            // Straddling the right plane
+            assert!(frustum.intersects_sphere(center + $vec3::new(21.0, 0.0, 0.0), 1.0));
+            assert!(!frustum.intersects_sphere(center + $vec3::new(23.0, 0.0, 0.0), 1.0));
+            // Straddling the near and far planes
+            assert!(frustum.intersects_sphere(EYE + FORWARD * 0.5, 0.6));
+            assert!(!frustum.intersects_sphere(EYE + FORWARD * 0.5, 0.4));
+            assert!(frustum.intersects_sphere(EYE + FORWARD * 20.5, 0.6));
+            assert!(!frustum.intersects_sphere(EYE + FORWARD * 20.5, 0.4));
+            assert!(!frustum.intersects_sphere(EYE - FORWARD * 5.0, 2.0));
+
+            let aabb = |center: $vec3, half_extents: $vec3| {
+                $aabb3::from_center_half_extents(center, half_extents)
+            };
+            assert!(frustum.intersects_aabb(&aabb(center, $vec3::ONE)));
+            assert!(frustum.intersects_aabb(&aabb(center, $vec3::splat(100.0))));
+            assert!(frustum.intersects_aabb(&aabb(center + $vec3::new(21.0, 0.0, 0.0), $vec3::ONE)));
+            assert!(!frustum.intersects_aabb(&aabb(center + $vec3::new(25.0, 0.0, 0.0), $vec3::ONE)));
+            assert!(frustum.intersects_aabb(&aabb(center + $vec3::new(0.0, 11.0, 0.0), $vec3::new(0.1, 1.1, 0.1))));
+            assert!(!frustum.intersects_aabb(&aabb(center + $vec3::new(0.0, 11.0, 0.0), $vec3::new(0.1, 0.7, 0.1))));
+            assert!(!frustum.intersects_aabb(&aabb(EYE - FORWARD * 5.0, $vec3::splat(2.0))));
+            assert!(!frustum.intersects_aabb(&aabb(EYE + FORWARD * 30.0, $vec3::splat(2.0))));
+
+            // The test is conservative, this box is outside the far right edge but isn't fully
+            // behind any single plane
+            let corners = frustum.corners().unwrap();
+            let edge = (corners[5] + corners[6]) * 0.5;
+            let outside = aabb(edge + $vec3::new(2.0, 0.0, -0.5), $vec3::splat(0.6));
+            assert!(frustum.intersects_aabb(&outside));
+        });
+    };
+}
+
+mod frustum {
+    use glam::{const_vec3, Aabb3, Aabb3A, Frustum, Mat4, Plane, Vec3, Vec3A};
+
+    impl_frustum_tests!(f32, Frustum, Plane, Vec3, Mat4, Aabb3);
+
+    glam_test!(test_frustum_aabb3a, {
+        let view_projection = Mat4::perspective_rh(FOV, ASPECT, NEAR, FAR) * view_rh();
+        let frustum = Frustum::from_view_projection(&view_projection);
+        let center = Vec3A::from(EYE + FORWARD * 10.0);
+        let aabb = Aabb3A::from_center_half_extents(center, Vec3A::ONE);
+        assert!(frustum.intersects_aabb3a(&aabb));
+        let aabb = Aabb3A::from_center_half_extents(center + Vec3A::X * 25.0, Vec3A::ONE);
+        assert!(!frustum.intersects_aabb3a(&aabb));
+    });
+}
+
+mod dfrustum {
+    use glam::{const_dvec3, DAabb3, DFrustum, DMat4, DPlane, DVec3};
+
+    macro_rules! const_vec3 {
+        ($fxyz:expr) => {
+            const_dvec3!($fxyz)
+        };
+    }
+
+    impl_frustum_tests!(f64, DFrustum, DPlane, DVec3, DMat4, DAabb3);
+}
@@ -0,0 +1,168 @@
+#[cfg(feature = "half")]
+#[macro_use]
+mod support;
+
+#[cfg(feature = "half")]
+mod hvec {
+    use glam::{hvec2, hvec3, hvec4, HVec2, HVec3, HVec4, Vec2, Vec3, Vec3A, Vec4};
+    use half::f16;
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(4, mem::size_of::<HVec2>());
+        assert_eq!(2, mem::align_of::<HVec2>());
+        assert_eq!(6, mem::size_of::<HVec3>());
+        assert_eq!(2, mem::align_of::<HVec3>());
+        assert_eq!(8, mem::size_of::<HVec4>());
+        assert_eq!(2, mem::align_of::<HVec4>());
+    });
+
+    glam_test!(test_new, {
+        let one = f16::ONE;
+        let two = f16::from_f32(2.0);
+        let v = hvec4(one, two, one, two);
+        assert_eq!(HVec4::new(one, two, one, two), v);
+        assert_eq!([one, two, one, two], v.to_array());
+        assert_eq!(v, HVec4::from([one, two, one, two]));
+        assert_eq!(one, v.x);
+        assert_eq!(two, v.w);
+        assert_eq!(HVec2::ONE, HVec2::splat(one));
+        assert_eq!(HVec3::ZERO, HVec3::default());
+        assert_eq!(hvec2(two, one), HVec2::new(two, one));
+        assert_eq!(hvec3(two, one, two).as_ref(), &[two, one, two]);
+
+        let mut v = HVec3::ZERO;
+        v.y = one;
+        v.as_mut()[2] = two;
+        assert_eq!(HVec3::new(f16::ZERO, one, two), v);
+    });
+
+    glam_test!(test_fmt, {
+        let a = Vec3::new(1.0, 2.0, 3.0).as_hvec3();
+        assert_eq!(format!("{:?}", a), "HVec3(1.0, 2.0, 3.0)");
+        assert_eq!(format!("{}", a), "[1.0, 2.0, 3.0]");
+    });
+
+    glam_test!(test_as, {
+        let v = Vec4::new(1.0, -2.0, 0.5, 65504.0);
+        let h = v.as_hvec4();
+        assert_eq!(v, h.as_vec4());
+        assert_eq!(h, HVec4::from(v));
+        assert_eq!(v, Vec4::from(h));
+
+        let v = Vec2::new(0.25, -0.125);
+        assert_eq!(v, v.as_hvec2().as_vec2());
+
+        let v = Vec3::new(-1.5, 2.5, 1024.0);
+        assert_eq!(v, v.as_hvec3().as_vec3());
+        let v = Vec3A::new(-1.5, 2.5, 1024.0);
+        assert_eq!(v, v.as_hvec3().as_vec3a());
+        assert_eq!(v, Vec3A::from(HVec3::from(v)));
+    });
+
+    glam_test!(test_round_to_nearest_even, {
+        // the f16 ulp at 1.0 is 2^-10, these are exactly half way between two f16 values
+        let a = 1.0 + 2f32.powi(-11);
+        let b = 1.0 + 3.0 * 2f32.powi(-11);
+        let h = Vec2::new(a, b).as_hvec2();
+        assert_eq!(Vec2::new(1.0, 1.0 + 2f32.powi(-9)), h.as_vec2());
+
+        // overflow and underflow
+        let h = Vec4::new(65520.0, -65520.0, 1e-8, -1e-8).as_hvec4();
+        assert_eq!(f16::INFINITY, h.x);
+        assert_eq!(f16::NEG_INFINITY, h.y);
+        assert_eq!(f16::ZERO, h.z);
+        assert!(h.w.is_sign_negative() && h.w.to_f32() == 0.0);
+        assert!(Vec2::new(f32::NAN, 0.0).as_hvec2().x.is_nan());
+    });
+
+    fn test_values(n: usize) -> impl Iterator<Item = f32> {
+        (0..n).map(|i| {
+            let f = i as f32;
+            // include values that need rounding
+            (f * 0.37 - 10.0) * (1.0 + 2f32.powi(-11))
+        })
+    }
+
+    glam_test!(test_vec2_slice, {
+        // odd lengths exercise the remainder of any SIMD conversion
+        for n in 0..11 {
+            let mut values = test_values(n * 2);
+            let src: Vec<Vec2> = (0..n)
+                .map(|_| Vec2::new(values.next().unwrap(), values.next().unwrap()))
+                .collect();
+            let mut dst = vec![HVec2::NAN; n];
+            HVec2::from_vec2_slice(&src, &mut dst);
+            for (s, d) in src.iter().zip(&dst) {
+                assert_eq!(s.as_hvec2(), *d);
+            }
+            let mut back = vec![Vec2::ZERO; n];
+            HVec2::to_vec2_slice(&dst, &mut back);
+            for (d, b) in dst.iter().zip(&back) {
+                assert_eq!(d.as_vec2(), *b);
+            }
+        }
+    });
+
+    glam_test!(test_vec3_slice, {
+        for n in 0..11 {
+            let mut values = test_values(n * 3);
+            let src: Vec<Vec3> = (0..n)
+                .map(|_| {
+                    Vec3::new(
+                        values.next().unwrap(),
+                        values.next().unwrap(),
+                        values.next().unwrap(),
+                    )
+                })
+                .collect();
+            let mut dst = vec![HVec3::NAN; n];
+            HVec3::from_vec3_slice(&src, &mut dst);
+            for (s, d) in src.iter().zip(&dst) {
+                assert_eq!(s.as_hvec3(), *d);
+            }
+            let mut back = vec![Vec3::ZERO; n];
+            HVec3::to_vec3_slice(&dst, &mut back);
+            for (d, b) in dst.iter().zip(&back) {
+                assert_eq!(d.as_vec3(), *b);
+            }
+        }
+    });
+
+    glam_test!(test_vec4_slice, {
+        for n in 0..11 {
+            let mut values = test_values(n * 4);
+            let src: Vec<Vec4> = (0..n)
+                .map(|_| {
+                    Vec4::new(
+                        values.next().unwrap(),
+                        values.next().unwrap(),
+                        values.next().unwrap(),
+                        values.next().unwrap(),
+                    )
+                })
+                .collect();
+            let mut dst = vec![HVec4::NAN; n];
+            HVec4::from_vec4_slice(&src, &mut dst);
+            for (s, d) in src.iter().zip(&dst) {
+                assert_eq!(s.as_hvec4(), *d);
+            }
+            let mut back = vec![Vec4::ZERO; n];
+            HVec4::to_vec4_slice(&dst, &mut back);
+            for (d, b) in dst.iter().zip(&back) {
+                assert_eq!(d.as_vec4(), *b);
+            }
+        }
+    });
+
+    glam_test!(test_slice_length_mismatch, {
+        should_panic!({
+            let mut dst = [HVec4::ZERO; 2];
+            HVec4::from_vec4_slice(&[Vec4::ONE; 3], &mut dst);
+        });
+        should_panic!({
+            let mut dst = [Vec2::ZERO; 1];
+            HVec2::to_vec2_slice(&[HVec2::ONE; 2], &mut dst);
+        });
+    });
+}
@@ -151,6 +151,24 @@ macro_rules! impl_mat2_tests {
             should_glam_assert!({ $mat2::ZERO.inverse() });
         });
 
+        glam_test!(test_mat2_try_inverse, {
+            assert_eq!(None, $mat2::ZERO.try_inverse());
+            assert_eq!(None, $mat2::NAN.try_inverse());
+            let singular = $mat2::from_cols($newvec2(1.0, 2.0), $newvec2(2.0, 4.0));
+            assert_eq!(None, singular.try_inverse());
+            assert_eq!($mat2::ZERO, singular.inverse_or_zero());
+
+            let m = $mat2::from_diagonal($newvec2(4.0, 5.0)) * $mat2::from_angle(deg(90.0));
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+            assert_eq!(m.inverse(), m.inverse_or_zero());
+            assert_eq!(Some(m.inverse()), m.inverse_with_epsilon(1.0e-6));
+
+            let near_singular = $mat2::from_diagonal($newvec2(1.0e-4, 1.0e-4));
+            assert_eq!(None, near_singular.inverse_with_epsilon(1.0e-6));
+            let inv = near_singular.try_inverse().unwrap();
+            assert_approx_eq!($mat2::IDENTITY, near_singular * inv);
+        });
+
         glam_test!(test_mat2_ops, {
             let m0 = $mat2::from_cols_array_2d(&MATRIX);
             let m0x2 = $mat2::from_cols_array_2d(&[[2.0, 4.0], [6.0, 8.0]]);
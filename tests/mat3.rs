@@ -284,6 +284,150 @@ macro_rules! impl_mat3_tests {
             should_glam_assert!({ $mat3::ZERO.inverse() });
         });
 
+        glam_test!(test_mat3_symmetric_eigen, {
+            // Checks the decomposition is a proper rotation which reconstructs `m`
+            let eigen = |m: $mat3| {
+                let (values, vectors) = m.symmetric_eigen();
+                assert!(values.x >= values.y && values.y >= values.z);
+                assert_approx_eq!(1.0, vectors.determinant(), 1.0e-5);
+                assert_approx_eq!($mat3::IDENTITY, vectors.transpose() * vectors, 1.0e-5);
+                let reconstructed = vectors * $mat3::from_diagonal(values) * vectors.transpose();
+                assert_approx_eq!(m, reconstructed, 1.0e-5);
+                (values, vectors)
+            };
+
+            let (values, vectors) = eigen($mat3::ZERO);
+            assert_eq!($vec3::ZERO, values);
+            assert_eq!($mat3::IDENTITY, vectors);
+
+            let (values, vectors) = eigen($mat3::from_diagonal($vec3::new(3.0, 3.0, 3.0)));
+            assert_eq!($vec3::new(3.0, 3.0, 3.0), values);
+            assert_eq!($mat3::IDENTITY, vectors);
+
+            // Already diagonal, only the order changes
+            let (values, vectors) = eigen($mat3::from_diagonal($vec3::new(2.0, 5.0, 3.0)));
+            assert_eq!($vec3::new(5.0, 3.0, 2.0), values);
+            assert_approx_eq!(1.0, vectors.x_axis.dot($newvec3(0.0, 1.0, 0.0)).abs());
+            assert_approx_eq!(1.0, vectors.y_axis.dot($newvec3(0.0, 0.0, 1.0)).abs());
+            assert_approx_eq!(1.0, vectors.z_axis.dot($newvec3(1.0, 0.0, 0.0)).abs());
+
+            // Eigenvalues 5, 3 and 1 with eigenvectors z, (1, 1, 0) and (1, -1, 0)
+            let m = $mat3::from_cols_array(&[2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0]);
+            let (values, vectors) = eigen(m);
+            assert_approx_eq!($vec3::new(5.0, 3.0, 1.0), values, 1.0e-5);
+            let diag = $newvec3(1.0, 1.0, 0.0).normalize();
+            let anti_diag = $newvec3(1.0, -1.0, 0.0).normalize();
+            assert_approx_eq!(
+                1.0,
+                vectors.x_axis.dot($newvec3(0.0, 0.0, 1.0)).abs(),
+                1.0e-5
+            );
+            assert_approx_eq!(1.0, vectors.y_axis.dot(diag).abs(), 1.0e-5);
+            assert_approx_eq!(1.0, vectors.z_axis.dot(anti_diag).abs(), 1.0e-5);
+
+            // Only the upper triangle is read
+            let lower = $mat3::from_cols_array(&[2.0, 9.0, 9.0, 1.0, 2.0, 9.0, 0.0, 0.0, 5.0]);
+            assert_eq!(m.symmetric_eigen(), lower.symmetric_eigen());
+
+            // A diagonal matrix in a rotated frame recovers the frame
+            let rot = $mat3::from_axis_angle($vec3::new(1.0, 2.0, 3.0).normalize(), 0.7);
+            let m = rot * $mat3::from_diagonal($vec3::new(4.0, -2.0, 1.0)) * rot.transpose();
+            let (values, vectors) = eigen(m);
+            assert_approx_eq!($vec3::new(4.0, 1.0, -2.0), values, 1.0e-5);
+            assert_approx_eq!(1.0, vectors.x_axis.dot(rot.x_axis).abs(), 1.0e-5);
+            assert_approx_eq!(1.0, vectors.y_axis.dot(rot.z_axis).abs(), 1.0e-5);
+            assert_approx_eq!(1.0, vectors.z_axis.dot(rot.y_axis).abs(), 1.0e-5);
+
+            // Inertia tensor of a solid box with half extents (1, 2, 3) and unit mass
+            let (x2, y2, z2) = (1.0, 4.0, 9.0);
+            let inertia = $mat3::from_diagonal($vec3::new(y2 + z2, x2 + z2, x2 + y2) / 3.0);
+            let m = rot * inertia * rot.transpose();
+            let (values, _) = eigen(m);
+            assert_approx_eq!($vec3::new(13.0, 10.0, 5.0) / 3.0, values, 1.0e-5);
+        });
+
+        glam_test!(test_mat3_svd, {
+            // Checks `u` and `v` are rotations which reconstruct `m`
+            let svd = |m: $mat3| {
+                let (u, sigma, v) = m.svd();
+                assert_approx_eq!(1.0, u.determinant(), 1.0e-5);
+                assert_approx_eq!(1.0, v.determinant(), 1.0e-5);
+                assert_approx_eq!($mat3::IDENTITY, u.transpose() * u, 1.0e-5);
+                assert_approx_eq!($mat3::IDENTITY, v.transpose() * v, 1.0e-5);
+                assert!(sigma.x >= sigma.y && sigma.y >= sigma.z.abs());
+                let reconstructed = u * $mat3::from_diagonal(sigma) * v.transpose();
+                assert_approx_eq!(m, reconstructed, 1.0e-5);
+                sigma
+            };
+
+            assert_eq!($vec3::ONE, svd($mat3::IDENTITY));
+            assert_eq!($vec3::ZERO, svd($mat3::ZERO));
+
+            // A reflection is moved into the last singular value
+            let sigma = svd($mat3::from_diagonal($vec3::new(2.0, -3.0, 1.0)));
+            assert_approx_eq!($vec3::new(3.0, 2.0, -1.0), sigma, 1.0e-6);
+
+            let rot1 = $mat3::from_axis_angle($vec3::new(1.0, 2.0, 3.0).normalize(), 0.7);
+            let rot2 = $mat3::from_axis_angle($vec3::new(-2.0, 1.0, 0.5).normalize(), 2.1);
+            let m = rot1 * $mat3::from_diagonal($vec3::new(0.5, 5.0, 3.0)) * rot2.transpose();
+            assert_approx_eq!($vec3::new(5.0, 3.0, 0.5), svd(m), 1.0e-5);
+
+            let m = rot1 * $mat3::from_diagonal($vec3::new(0.5, -5.0, 3.0)) * rot2.transpose();
+            assert_approx_eq!($vec3::new(5.0, 3.0, -0.5), svd(m), 1.0e-5);
+
+            // Rank deficient matrices
+            let a = $newvec3(1.0, 2.0, -1.0);
+            let b = $newvec3(0.5, -1.0, 2.0);
+            let sigma = svd($mat3::from_cols(a, a * 2.0, b));
+            assert_approx_eq!(0.0, sigma.z, 1.0e-5);
+            let sigma = svd($mat3::from_cols(a, a * 2.0, -a));
+            assert_approx_eq!(
+                $vec3::new(a.length() * (6.0 as $t).sqrt(), 0.0, 0.0),
+                sigma,
+                1.0e-5
+            );
+        });
+
+        glam_test!(test_mat3_polar, {
+            let (rotation, stretch) = $mat3::IDENTITY.polar();
+            assert_approx_eq!($mat3::IDENTITY, rotation);
+            assert_approx_eq!($mat3::IDENTITY, stretch);
+
+            let rot1 = $mat3::from_axis_angle($vec3::new(1.0, 2.0, 3.0).normalize(), 0.7);
+            let rot2 = $mat3::from_axis_angle($vec3::new(-2.0, 1.0, 0.5).normalize(), 2.1);
+            let s = rot2 * $mat3::from_diagonal($vec3::new(0.5, 5.0, 3.0)) * rot2.transpose();
+            let (rotation, stretch) = (rot1 * s).polar();
+            assert_approx_eq!(rot1, rotation, 1.0e-5);
+            assert_approx_eq!(s, stretch, 1.0e-5);
+
+            // A reflection ends up in the stretch
+            let m = rot1 * $mat3::from_diagonal($vec3::new(1.0, 2.0, -3.0));
+            let (rotation, stretch) = m.polar();
+            assert_approx_eq!(1.0, rotation.determinant(), 1.0e-5);
+            assert_approx_eq!(stretch, stretch.transpose(), 1.0e-5);
+            assert!(stretch.determinant() < 0.0);
+            assert_approx_eq!(m, rotation * stretch, 1.0e-5);
+        });
+
+        glam_test!(test_mat3_try_inverse, {
+            assert_eq!(None, $mat3::ZERO.try_inverse());
+            assert_eq!(None, $mat3::NAN.try_inverse());
+            let singular = $mat3::from_diagonal($vec3::new(1.0, 2.0, 0.0));
+            assert_eq!(None, singular.try_inverse());
+            assert_eq!($mat3::ZERO, singular.inverse_or_zero());
+
+            let m =
+                $mat3::from_diagonal($vec3::new(4.0, 5.0, 6.0)) * $mat3::from_rotation_z(deg(90.0));
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+            assert_eq!(m.inverse(), m.inverse_or_zero());
+            assert_eq!(Some(m.inverse()), m.inverse_with_epsilon(1.0e-6));
+
+            let near_singular = $mat3::from_diagonal($vec3::new(1.0e-3, 1.0e-3, 1.0e-3));
+            assert_eq!(None, near_singular.inverse_with_epsilon(1.0e-6));
+            let inv = near_singular.try_inverse().unwrap();
+            assert_approx_eq!($mat3::IDENTITY, near_singular * inv);
+        });
+
         glam_test!(test_mat3_ops, {
             let m0 = $mat3::from_cols_array_2d(&MATRIX);
             let m0x2 = $mat3::from_cols_array_2d(&[
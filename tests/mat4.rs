@@ -300,6 +300,15 @@ macro_rules! impl_mat4_tests {
                 2.0 * 2.0 * 2.0,
                 $mat4::from_scale($newvec3(2.0, 2.0, 2.0)).determinant()
             );
+            // Every element contributes to the result
+            assert_eq!(
+                -146.0,
+                $mat4::from_cols_array(&[
+                    0.0, 2.0, -1.0, 3.0, 1.0, 4.0, 0.5, -2.0, 2.0, -3.0, 5.0, 1.0, 1.5, 0.25, -1.0,
+                    6.0
+                ])
+                .determinant()
+            );
         });
 
         glam_test!(test_mat4_inverse, {
@@ -351,6 +360,26 @@ macro_rules! impl_mat4_tests {
             should_glam_assert!({ $mat4::ZERO.inverse() });
         });
 
+        glam_test!(test_mat4_try_inverse, {
+            assert_eq!(None, $mat4::ZERO.try_inverse());
+            assert_eq!(None, $mat4::NAN.try_inverse());
+            let singular = $mat4::from_scale($newvec3(1.0, 2.0, 0.0));
+            assert_eq!(None, singular.try_inverse());
+            assert_eq!($mat4::ZERO, singular.inverse_or_zero());
+
+            let m = $mat4::from_scale($newvec3(4.0, 5.0, 6.0))
+                * $mat4::from_rotation_z(deg(90.0))
+                * $mat4::from_translation($newvec3(1.0, 2.0, 3.0));
+            assert_eq!(Some(m.inverse()), m.try_inverse());
+            assert_eq!(m.inverse(), m.inverse_or_zero());
+            assert_eq!(Some(m.inverse()), m.inverse_with_epsilon(1.0e-6));
+
+            let near_singular = $mat4::from_scale($newvec3(1.0e-3, 1.0e-3, 1.0e-3));
+            assert_eq!(None, near_singular.inverse_with_epsilon(1.0e-6));
+            let inv = near_singular.try_inverse().unwrap();
+            assert_approx_eq!($mat4::IDENTITY, near_singular * inv);
+        });
+
         glam_test!(test_mat4_decompose, {
             // identity
             let (out_scale, out_rotation, out_translation) =
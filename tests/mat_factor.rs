@@ -0,0 +1,177 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_mat_factor_tests {
+    ($t:ident, $n:expr, $mat:ident, $vec:ident) => {
+        // A zero in the first element forces the LU decomposition to pivot
+        const ELEMENTS: [$t; 16] = [
+            0.0, 2.0, -1.0, 3.0, 1.0, 4.0, 0.5, -2.0, 2.0, -3.0, 5.0, 1.0, 1.5, 0.25, -1.0, 6.0,
+        ];
+        const X: [$t; 4] = [1.0, -2.0, 0.5, 3.0];
+
+        fn matrix() -> $mat {
+            $mat::from_cols_slice(&ELEMENTS[..$n * $n])
+        }
+
+        fn singular() -> $mat {
+            let mut m = matrix();
+            m.y_axis = m.x_axis * 2.0;
+            m
+        }
+
+        // Rank deficient matrices which rounding leaves not exactly singular
+        fn nearly_singular() -> [$mat; 2] {
+            // The outer product of two vectors
+            const U: [$t; 4] = [0.1, 0.7, 1.3, 0.3];
+            const V: [$t; 4] = [0.3, 1.1, 0.7, 1.9];
+            let mut outer = [0.0; 16];
+            // The elements 0.1, 0.2, 0.3, ... which have rank 2
+            let mut ramp = [0.0; 16];
+            for j in 0..$n {
+                for i in 0..$n {
+                    outer[j * $n + i] = U[i] * V[j];
+                    ramp[j * $n + i] = 0.1 * (j * $n + i + 1) as $t;
+                }
+            }
+            // A 2x2 ramp has full rank so use the outer product twice
+            let ramp = if $n > 2 { &ramp } else { &outer };
+            [
+                $mat::from_cols_slice(&outer[..$n * $n]),
+                $mat::from_cols_slice(&ramp[..$n * $n]),
+            ]
+        }
+
+        // A symmetric positive definite matrix
+        fn spd() -> $mat {
+            let m = matrix();
+            m.transpose() * m + $mat::IDENTITY
+        }
+
+        fn assert_upper_triangular(m: $mat) {
+            let cols = m.to_cols_array_2d();
+            for (col, column) in cols.iter().enumerate() {
+                for row in col + 1..$n {
+                    assert_eq!(0.0, column[row]);
+                }
+            }
+        }
+
+        glam_test!(test_solve, {
+            let m = matrix();
+            let x = $vec::from_slice(&X[..$n]);
+            assert_approx_eq!(x, m.solve(m * x).unwrap(), 1.0e-5);
+            assert_approx_eq!(x, m.lu().unwrap().solve(m * x), 1.0e-5);
+            assert_approx_eq!(x, m.qr().unwrap().solve(m * x), 1.0e-5);
+
+            let m = spd();
+            assert_approx_eq!(x, m.solve(m * x).unwrap(), 1.0e-4);
+            assert_approx_eq!(x, m.cholesky().unwrap().solve(m * x), 1.0e-4);
+
+            assert_eq!(None, $mat::ZERO.solve(x));
+            assert_eq!(None, singular().solve(x));
+            for m in &nearly_singular() {
+                assert_eq!(None, m.solve(x));
+            }
+            assert_eq!(None, $mat::NAN.solve(x));
+        });
+
+        glam_test!(test_lu, {
+            let m = matrix();
+            let lu = m.lu().unwrap();
+            assert_approx_eq!(lu.p() * m, lu.l() * lu.u(), 1.0e-5);
+            assert_upper_triangular(lu.u());
+            assert_upper_triangular(lu.l().transpose());
+            assert_approx_eq!(m.determinant(), lu.determinant(), 1.0e-4);
+            assert_approx_eq!(m.inverse(), lu.inverse(), 1.0e-5);
+            assert_approx_eq!($mat::IDENTITY, m * lu.inverse(), 1.0e-5);
+
+            let lu = $mat::IDENTITY.lu().unwrap();
+            assert_eq!($mat::IDENTITY, lu.p());
+            assert_eq!($mat::IDENTITY, lu.l());
+            assert_eq!($mat::IDENTITY, lu.u());
+            assert_eq!(1.0, lu.determinant());
+
+            assert_eq!(None, $mat::ZERO.lu());
+            assert_eq!(None, singular().lu());
+            for m in &nearly_singular() {
+                assert_eq!(None, m.lu());
+            }
+        });
+
+        glam_test!(test_qr, {
+            let m = matrix();
+            let qr = m.qr().unwrap();
+            let q = qr.q();
+            assert_approx_eq!(m, q * qr.r(), 1.0e-5);
+            assert_approx_eq!($mat::IDENTITY, q.transpose() * q, 1.0e-5);
+            assert_upper_triangular(qr.r());
+            assert_approx_eq!(m.determinant(), qr.determinant(), 1.0e-4);
+            assert_approx_eq!(
+                q.determinant(),
+                qr.determinant() / qr.r().determinant(),
+                1.0e-5
+            );
+
+            assert_eq!(None, $mat::ZERO.qr());
+            assert_eq!(None, singular().qr());
+            for m in &nearly_singular() {
+                assert_eq!(None, m.qr());
+            }
+        });
+
+        glam_test!(test_cholesky, {
+            let m = spd();
+            let cholesky = m.cholesky().unwrap();
+            let l = cholesky.l();
+            assert_approx_eq!(m, l * l.transpose(), 1.0e-4);
+            assert_upper_triangular(l.transpose());
+            assert_approx_eq!(1.0, m.determinant() / cholesky.determinant(), 1.0e-4);
+            assert_approx_eq!($mat::IDENTITY, m * cholesky.inverse(), 1.0e-4);
+
+            // Only the upper triangle is read
+            let mut lower = m.transpose().to_cols_array_2d();
+            lower[$n - 1][0] = 100.0;
+            let lower = $mat::from_cols_array_2d(&lower).transpose();
+            assert_eq!(Some(cholesky), lower.cholesky());
+
+            assert_eq!(None, $mat::ZERO.cholesky());
+            assert_eq!(None, ($mat::IDENTITY * -1.0).cholesky());
+            // Positive semi-definite
+            let mut semi_definite = $mat::IDENTITY;
+            semi_definite.x_axis *= 0.0;
+            assert_eq!(None, semi_definite.cholesky());
+            // Symmetric but indefinite
+            assert_eq!(None, (m - $mat::IDENTITY * 1000.0).cholesky());
+        });
+    };
+}
+
+mod mat2 {
+    use glam::{Mat2, Vec2};
+    impl_mat_factor_tests!(f32, 2, Mat2, Vec2);
+}
+
+mod mat3 {
+    use glam::{Mat3, Vec3};
+    impl_mat_factor_tests!(f32, 3, Mat3, Vec3);
+}
+
+mod mat4 {
+    use glam::{Mat4, Vec4};
+    impl_mat_factor_tests!(f32, 4, Mat4, Vec4);
+}
+
+mod dmat2 {
+    use glam::{DMat2, DVec2};
+    impl_mat_factor_tests!(f64, 2, DMat2, DVec2);
+}
+
+mod dmat3 {
+    use glam::{DMat3, DVec3};
+    impl_mat_factor_tests!(f64, 3, DMat3, DVec3);
+}
+
+mod dmat4 {
+    use glam::{DMat4, DVec4};
+    impl_mat_factor_tests!(f64, 4, DMat4, DVec4);
+}
@@ -0,0 +1,376 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_matrc_tests {
+    ($t:ident, $matrc:ident, $transpose:ident, $colvec:ident, $rowvec:ident, $rows:expr, $cols:expr) => {
+        const LEN: usize = $rows * $cols;
+
+        fn sequence() -> [$t; LEN] {
+            let mut a = [0.0; LEN];
+            for (i, e) in a.iter_mut().enumerate() {
+                *e = (i + 1) as $t;
+            }
+            a
+        }
+
+        glam_test!(test_constants, {
+            assert_eq!([0.0; LEN], $matrc::ZERO.to_cols_array());
+            assert!($matrc::NAN.is_nan());
+            assert!(!$matrc::NAN.is_finite());
+            assert!($matrc::ZERO.is_finite());
+            assert_eq!($matrc::IDENTITY, $matrc::default());
+            for col in 0..$cols {
+                for row in 0..$rows {
+                    let e = $matrc::IDENTITY.col(col).to_array()[row];
+                    assert_eq!(if row == col { 1.0 } else { 0.0 }, e);
+                }
+            }
+        });
+
+        glam_test!(test_from_cols, {
+            let a = sequence();
+            let m = $matrc::from_cols_array(&a);
+            assert_eq!(a, m.to_cols_array());
+            assert_eq!(&a, m.as_ref());
+            assert_eq!(m, $matrc::from_cols_slice(&a));
+            assert_eq!(m, $matrc::from_cols_array_2d(&m.to_cols_array_2d()));
+
+            let mut b = [0.0; LEN + 1];
+            m.write_cols_to_slice(&mut b);
+            assert_eq!(a[..], b[..LEN]);
+
+            for col in 0..$cols {
+                let expected = $colvec::from_slice(&a[col * $rows..]);
+                assert_eq!(expected, m.col(col));
+                assert_eq!(expected.to_array(), m.to_cols_array_2d()[col]);
+            }
+
+            should_panic!({ $matrc::from_cols_slice(&a[..LEN - 1]) });
+            should_panic!({
+                let mut b = [0.0; LEN];
+                m.write_cols_to_slice(&mut b[..LEN - 1])
+            });
+        });
+
+        glam_test!(test_accessors, {
+            let mut m = $matrc::from_cols_array(&sequence());
+            for row in 0..$rows {
+                let r = m.row(row).to_array();
+                for col in 0..$cols {
+                    assert_eq!(m.col(col).to_array()[row], r[col]);
+                }
+            }
+
+            *m.col_mut(1) = $colvec::ONE;
+            assert_eq!($colvec::ONE, m.y_axis);
+            m.as_mut()[0] = -1.0;
+            assert_eq!(-1.0, m.x_axis.x);
+
+            should_panic!({ $matrc::ZERO.col($cols) });
+            should_panic!({
+                let mut m = $matrc::ZERO;
+                m.col_mut($cols);
+            });
+            should_panic!({ $matrc::ZERO.row($rows) });
+        });
+
+        glam_test!(test_transpose, {
+            let m = $matrc::from_cols_array(&sequence());
+            let mt: $transpose = m.transpose();
+            for row in 0..$rows {
+                assert_eq!(m.row(row), mt.col(row));
+            }
+            for col in 0..$cols {
+                assert_eq!(m.col(col), mt.row(col));
+            }
+            assert_eq!(m, mt.transpose());
+        });
+
+        glam_test!(test_mul_vec, {
+            let m = $matrc::from_cols_array(&sequence());
+            let v = $rowvec::from_slice(&[1.0, -2.0, 3.0, -4.0]);
+            let r = (m * v).to_array();
+            for row in 0..$rows {
+                assert_eq!(m.row(row).dot(v), r[row]);
+            }
+            assert_eq!($colvec::ZERO, $matrc::ZERO * v);
+        });
+
+        glam_test!(test_ops, {
+            let m = $matrc::from_cols_array(&sequence());
+            let m2 = m * 2.0;
+            assert_eq!(m2, 2.0 * m);
+            assert_eq!(m2, m + m);
+            assert_eq!(m, m2 - m);
+            assert_eq!(m2, m.mul_scalar(2.0));
+
+            let mut n = m;
+            n += m;
+            assert_eq!(m2, n);
+            n -= m;
+            assert_eq!(m, n);
+            n *= 2.0;
+            assert_eq!(m2, n);
+
+            assert_eq!(m2, [m, m].iter().sum());
+
+            assert!(m.abs_diff_eq(m2 * 0.5, 0.0));
+            assert!(!m.abs_diff_eq(m2, 0.5));
+        });
+
+        glam_test!(test_fmt, {
+            let m = $matrc::IDENTITY;
+            let s = format!("{}", m);
+            assert!(s.starts_with("[[1"));
+            assert!(format!("{:?}", m).starts_with(stringify!($matrc)));
+        });
+    };
+}
+
+macro_rules! impl_matrc_mul_test {
+    ($name:ident, $lhs:ident, $rhs:ident, $out:ident, $lhs_rows:expr, $inner:expr, $rhs_cols:expr) => {
+        glam_test!($name, {
+            let mut a = [0.0; 19];
+            for (i, e) in a.iter_mut().enumerate() {
+                *e = (i as i32 - 5) as _;
+            }
+            let lhs = $lhs::from_cols_slice(&a);
+            let rhs = $rhs::from_cols_slice(&a[3..]);
+            let out: $out = lhs * rhs;
+            for row in 0..$lhs_rows {
+                for col in 0..$rhs_cols {
+                    let mut expected = 0.0;
+                    for k in 0..$inner {
+                        expected += lhs.col(k).to_array()[row] * rhs.col(col).to_array()[k];
+                    }
+                    assert_eq!(expected, out.col(col).to_array()[row]);
+                }
+            }
+        });
+    };
+}
+
+macro_rules! impl_matrc_mul_tests {
+    ($mat2:ident, $mat3:ident, $mat4:ident, $mat2x3:ident, $mat3x2:ident, $mat3x4:ident,
+     $mat4x3:ident, $mat2x4:ident, $mat4x2:ident) => {
+        impl_matrc_mul_test!(test_mul_2_2x3, $mat2, $mat2x3, $mat2x3, 2, 2, 3);
+        impl_matrc_mul_test!(test_mul_2_2x4, $mat2, $mat2x4, $mat2x4, 2, 2, 4);
+        impl_matrc_mul_test!(test_mul_3x2_2, $mat3x2, $mat2, $mat3x2, 3, 2, 2);
+        impl_matrc_mul_test!(test_mul_3x2_2x3, $mat3x2, $mat2x3, $mat3, 3, 2, 3);
+        impl_matrc_mul_test!(test_mul_3x2_2x4, $mat3x2, $mat2x4, $mat3x4, 3, 2, 4);
+        impl_matrc_mul_test!(test_mul_4x2_2, $mat4x2, $mat2, $mat4x2, 4, 2, 2);
+        impl_matrc_mul_test!(test_mul_4x2_2x3, $mat4x2, $mat2x3, $mat4x3, 4, 2, 3);
+        impl_matrc_mul_test!(test_mul_4x2_2x4, $mat4x2, $mat2x4, $mat4, 4, 2, 4);
+        impl_matrc_mul_test!(test_mul_2x3_3, $mat2x3, $mat3, $mat2x3, 2, 3, 3);
+        impl_matrc_mul_test!(test_mul_2x3_3x2, $mat2x3, $mat3x2, $mat2, 2, 3, 2);
+        impl_matrc_mul_test!(test_mul_2x3_3x4, $mat2x3, $mat3x4, $mat2x4, 2, 3, 4);
+        impl_matrc_mul_test!(test_mul_3_3x2, $mat3, $mat3x2, $mat3x2, 3, 3, 2);
+        impl_matrc_mul_test!(test_mul_3_3x4, $mat3, $mat3x4, $mat3x4, 3, 3, 4);
+        impl_matrc_mul_test!(test_mul_4x3_3, $mat4x3, $mat3, $mat4x3, 4, 3, 3);
+        impl_matrc_mul_test!(test_mul_4x3_3x2, $mat4x3, $mat3x2, $mat4x2, 4, 3, 2);
+        impl_matrc_mul_test!(test_mul_4x3_3x4, $mat4x3, $mat3x4, $mat4, 4, 3, 4);
+        impl_matrc_mul_test!(test_mul_2x4_4, $mat2x4, $mat4, $mat2x4, 2, 4, 4);
+        impl_matrc_mul_test!(test_mul_2x4_4x2, $mat2x4, $mat4x2, $mat2, 2, 4, 2);
+        impl_matrc_mul_test!(test_mul_2x4_4x3, $mat2x4, $mat4x3, $mat2x3, 2, 4, 3);
+        impl_matrc_mul_test!(test_mul_3x4_4, $mat3x4, $mat4, $mat3x4, 3, 4, 4);
+        impl_matrc_mul_test!(test_mul_3x4_4x2, $mat3x4, $mat4x2, $mat3x2, 3, 4, 2);
+        impl_matrc_mul_test!(test_mul_3x4_4x3, $mat3x4, $mat4x3, $mat3, 3, 4, 3);
+        impl_matrc_mul_test!(test_mul_4_4x2, $mat4, $mat4x2, $mat4x2, 4, 4, 2);
+        impl_matrc_mul_test!(test_mul_4_4x3, $mat4, $mat4x3, $mat4x3, 4, 4, 3);
+    };
+}
+
+macro_rules! impl_matrc_conversion_tests {
+    ($t:ident, $mat3:ident, $mat4:ident, $mat2x3:ident, $mat3x2:ident, $mat3x4:ident,
+     $mat4x3:ident, $affine2:ident, $affine3:ident, $vec2:ident, $vec3:ident) => {
+        glam_test!(test_mat3_conversions, {
+            let m3 = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            let m2x3 = $mat2x3::from_mat3(m3);
+            assert_eq!(
+                $mat2x3::from_cols_array(&[1.0, 2.0, 4.0, 5.0, 7.0, 8.0]),
+                m2x3
+            );
+            assert_eq!(
+                $mat3::from_cols_array(&[1.0, 2.0, 0.0, 4.0, 5.0, 0.0, 7.0, 8.0, 1.0]),
+                $mat3::from(m2x3)
+            );
+
+            let m3x2 = $mat3x2::from_mat3(m3);
+            assert_eq!(
+                $mat3x2::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+                m3x2
+            );
+            assert_eq!(
+                $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 0.0, 0.0, 1.0]),
+                $mat3::from(m3x2)
+            );
+        });
+
+        glam_test!(test_mat4_conversions, {
+            let mut a = [0.0; 16];
+            for (i, e) in a.iter_mut().enumerate() {
+                *e = (i + 1) as $t;
+            }
+            let m4 = $mat4::from_cols_array(&a);
+            let m3x4 = $mat3x4::from_mat4(m4);
+            assert_eq!(m4.row(0), m3x4.row(0));
+            assert_eq!(m4.row(2), m3x4.row(2));
+            let e = $mat4::from(m3x4);
+            assert_eq!(m4.row(2), e.row(2));
+            assert_eq!([0.0, 0.0, 0.0, 1.0], e.row(3).to_array());
+
+            let m4x3 = $mat4x3::from_mat4(m4);
+            assert_eq!(m4.z_axis, m4x3.z_axis);
+            let e = $mat4::from(m4x3);
+            assert_eq!(m4.z_axis, e.z_axis);
+            assert_eq!([0.0, 0.0, 0.0, 1.0], e.w_axis.to_array());
+        });
+
+        glam_test!(test_affine_conversions, {
+            let a2 = $affine2::from_scale_angle_translation(
+                $vec2::new(2.0, 3.0),
+                deg(30.0),
+                $vec2::new(4.0, 5.0),
+            );
+            let m2x3 = $mat2x3::from(a2);
+            let p = $vec2::new(1.0, 2.0);
+            assert_approx_eq!(a2.transform_point2(p), m2x3 * p.extend(1.0));
+            assert_eq!(a2, $affine2::from(m2x3));
+            assert_eq!($mat3::from(a2), $mat3::from(m2x3));
+
+            let a3 = $affine3::from_scale_rotation_translation(
+                $vec3::new(2.0, 3.0, 4.0),
+                Default::default(),
+                $vec3::new(5.0, 6.0, 7.0),
+            );
+            let m3x4 = $mat3x4::from(a3);
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_approx_eq!(a3.transform_point3(p), m3x4 * p.extend(1.0));
+            assert_eq!(a3, $affine3::from(m3x4));
+            assert_eq!($mat4::from(a3), $mat4::from(m3x4));
+
+            // a 4x3 matrix is the row major layout of an affine transform
+            let rows = m3x4.transpose();
+            assert_eq!(m3x4.row(0), rows.x_axis);
+        });
+    };
+}
+
+mod mat2x3 {
+    use glam::{Mat2x3, Mat3x2, Vec2, Vec3};
+    impl_matrc_tests!(f32, Mat2x3, Mat3x2, Vec2, Vec3, 2, 3);
+}
+
+mod mat3x2 {
+    use glam::{Mat2x3, Mat3x2, Vec2, Vec3};
+    impl_matrc_tests!(f32, Mat3x2, Mat2x3, Vec3, Vec2, 3, 2);
+}
+
+mod mat3x4 {
+    use glam::{Mat3x4, Mat4x3, Vec3, Vec4};
+    impl_matrc_tests!(f32, Mat3x4, Mat4x3, Vec3, Vec4, 3, 4);
+}
+
+mod mat4x3 {
+    use glam::{Mat3x4, Mat4x3, Vec3, Vec4};
+    impl_matrc_tests!(f32, Mat4x3, Mat3x4, Vec4, Vec3, 4, 3);
+}
+
+mod mat2x4 {
+    use glam::{Mat2x4, Mat4x2, Vec2, Vec4};
+    impl_matrc_tests!(f32, Mat2x4, Mat4x2, Vec2, Vec4, 2, 4);
+}
+
+mod mat4x2 {
+    use glam::{Mat2x4, Mat4x2, Vec2, Vec4};
+    impl_matrc_tests!(f32, Mat4x2, Mat2x4, Vec4, Vec2, 4, 2);
+}
+
+mod dmat2x3 {
+    use glam::{DMat2x3, DMat3x2, DVec2, DVec3};
+    impl_matrc_tests!(f64, DMat2x3, DMat3x2, DVec2, DVec3, 2, 3);
+}
+
+mod dmat3x2 {
+    use glam::{DMat2x3, DMat3x2, DVec2, DVec3};
+    impl_matrc_tests!(f64, DMat3x2, DMat2x3, DVec3, DVec2, 3, 2);
+}
+
+mod dmat3x4 {
+    use glam::{DMat3x4, DMat4x3, DVec3, DVec4};
+    impl_matrc_tests!(f64, DMat3x4, DMat4x3, DVec3, DVec4, 3, 4);
+}
+
+mod dmat4x3 {
+    use glam::{DMat3x4, DMat4x3, DVec3, DVec4};
+    impl_matrc_tests!(f64, DMat4x3, DMat3x4, DVec4, DVec3, 4, 3);
+}
+
+mod dmat2x4 {
+    use glam::{DMat2x4, DMat4x2, DVec2, DVec4};
+    impl_matrc_tests!(f64, DMat2x4, DMat4x2, DVec2, DVec4, 2, 4);
+}
+
+mod dmat4x2 {
+    use glam::{DMat2x4, DMat4x2, DVec2, DVec4};
+    impl_matrc_tests!(f64, DMat4x2, DMat2x4, DVec4, DVec2, 4, 2);
+}
+
+mod f32_products {
+    use glam::{Mat2, Mat2x3, Mat2x4, Mat3, Mat3x2, Mat3x4, Mat4, Mat4x2, Mat4x3};
+    impl_matrc_mul_tests!(Mat2, Mat3, Mat4, Mat2x3, Mat3x2, Mat3x4, Mat4x3, Mat2x4, Mat4x2);
+}
+
+mod f64_products {
+    use glam::{DMat2, DMat2x3, DMat2x4, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x2, DMat4x3};
+    impl_matrc_mul_tests!(
+        DMat2, DMat3, DMat4, DMat2x3, DMat3x2, DMat3x4, DMat4x3, DMat2x4, DMat4x2
+    );
+}
+
+mod f32_conversions {
+    use super::support::deg;
+    use glam::{
+        mat2x3, mat2x4, mat3x2, mat3x4, mat4x2, mat4x3, Affine2, Affine3A, DVec3, Mat2x3, Mat2x4,
+        Mat3, Mat3x2, Mat3x4, Mat4, Mat4x2, Mat4x3, Vec2, Vec3, Vec4,
+    };
+    impl_matrc_conversion_tests!(
+        f32, Mat3, Mat4, Mat2x3, Mat3x2, Mat3x4, Mat4x3, Affine2, Affine3A, Vec2, Vec3
+    );
+
+    glam_test!(test_new, {
+        assert_eq!(Mat2x3::IDENTITY, mat2x3(Vec2::X, Vec2::Y, Vec2::ZERO));
+        assert_eq!(Mat3x2::IDENTITY, mat3x2(Vec3::X, Vec3::Y));
+        assert_eq!(
+            Mat3x4::IDENTITY,
+            mat3x4(Vec3::X, Vec3::Y, Vec3::Z, Vec3::ZERO)
+        );
+        assert_eq!(Mat4x3::IDENTITY, mat4x3(Vec4::X, Vec4::Y, Vec4::Z));
+        assert_eq!(
+            Mat2x4::IDENTITY,
+            mat2x4(Vec2::X, Vec2::Y, Vec2::ZERO, Vec2::ZERO)
+        );
+        assert_eq!(Mat4x2::IDENTITY, mat4x2(Vec4::X, Vec4::Y));
+    });
+
+    glam_test!(test_as_dmat, {
+        let m = Mat3x4::from_cols_array(&[
+            1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+        ]);
+        let d = m.as_dmat3x4();
+        assert_eq!(DVec3::new(10.0, 11.0, 12.0), d.w_axis);
+        assert_eq!(m, m.as_dmat3x4().as_mat3x4());
+        let m = Mat2x3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(m, m.as_dmat2x3().as_mat2x3());
+    });
+}
+
+mod f64_conversions {
+    use super::support::deg;
+    use glam::{
+        DAffine2, DAffine3, DMat2x3, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x3, DVec2, DVec3,
+    };
+    impl_matrc_conversion_tests!(
+        f64, DMat3, DMat4, DMat2x3, DMat3x2, DMat3x4, DMat4x3, DAffine2, DAffine3, DVec2, DVec3
+    );
+}
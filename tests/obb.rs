@@ -0,0 +1,317 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_obb3_tests {
+    ($t:ident, $obb3:ident, $sphere:ident, $aabb3:ident, $vec3:ident, $quat:ident) => {
+        use core::$t::consts::{FRAC_PI_4, SQRT_2};
+
+        fn rotated_box() -> $obb3 {
+            $obb3::new(
+                $vec3::new(1.0, 2.0, 3.0),
+                $vec3::new(2.0, 1.0, 0.5),
+                $quat::from_rotation_z(FRAC_PI_4),
+            )
+        }
+
+        glam_test!(test_obb3_new, {
+            let obb = rotated_box();
+            assert_eq!($vec3::new(1.0, 2.0, 3.0), obb.center);
+            assert_eq!($vec3::new(2.0, 1.0, 0.5), obb.half_extents);
+            assert_approx_eq!(
+                $vec3::new(SQRT_2, SQRT_2, 0.0) * 0.5,
+                $vec3::from(obb.rotation.x_axis),
+                1e-6
+            );
+            assert!(obb.is_finite());
+            assert_eq!(8.0, obb.volume());
+            assert!(obb.abs_diff_eq(&obb, 0.0));
+            assert_eq!(
+                format!("{:?}", obb),
+                format!(
+                    "{} {{ center: {:?}, half_extents: {:?}, rotation: {:?} }}",
+                    stringify!($obb3),
+                    obb.center,
+                    obb.half_extents,
+                    obb.rotation
+                )
+            );
+            assert_eq!(
+                format!("{}", obb),
+                format!("[{}, {}, {}]", obb.center, obb.half_extents, obb.rotation)
+            );
+
+            let aabb = $aabb3::new($vec3::new(-1.0, 0.0, 1.0), $vec3::new(3.0, 1.0, 2.0));
+            let obb = $obb3::from_aabb(&aabb);
+            assert_eq!($vec3::new(1.0, 0.5, 1.5), obb.center);
+            assert_eq!($vec3::new(2.0, 0.5, 0.5), obb.half_extents);
+            assert_eq!(aabb, obb.to_aabb());
+
+            should_glam_assert!({ $obb3::new($vec3::ZERO, -$vec3::ONE, $quat::IDENTITY) });
+            should_glam_assert!({
+                $obb3::new(
+                    $vec3::ZERO,
+                    $vec3::ONE,
+                    $quat::from_xyzw(1.0, 1.0, 0.0, 0.0),
+                )
+            });
+        });
+
+        glam_test!(test_obb3_corners, {
+            let obb = rotated_box();
+            let corners = obb.corners();
+            let x = $vec3::new(SQRT_2, SQRT_2, 0.0);
+            let y = $vec3::new(-SQRT_2, SQRT_2, 0.0) * 0.5;
+            let z = $vec3::new(0.0, 0.0, 0.5);
+            assert_approx_eq!(obb.center - x - y - z, corners[0], 1e-6);
+            assert_approx_eq!(obb.center + x - y - z, corners[1], 1e-6);
+            assert_approx_eq!(obb.center - x + y + z, corners[6], 1e-6);
+            assert_approx_eq!(obb.center + x + y + z, corners[7], 1e-6);
+            for &corner in corners.iter() {
+                assert!(obb.contains_point(obb.center + (corner - obb.center) * 0.999));
+                assert!(!obb.contains_point(obb.center + (corner - obb.center) * 1.001));
+            }
+
+            // The bounds of the corners
+            let aabb = obb.to_aabb();
+            assert_approx_eq!($aabb3::from_points(&corners), aabb, 1e-6);
+            assert_approx_eq!(
+                $vec3::new(1.5 * SQRT_2, 1.5 * SQRT_2, 0.5),
+                aabb.half_extents(),
+                1e-6
+            );
+        });
+
+        glam_test!(test_obb3_point_queries, {
+            let obb = rotated_box();
+            let x = $vec3::new(SQRT_2, SQRT_2, 0.0) * 0.5;
+            let y = $vec3::new(-SQRT_2, SQRT_2, 0.0) * 0.5;
+            assert!(obb.contains_point(obb.center));
+            assert!(obb.contains_point(obb.center + x * 1.9 + y * 0.9));
+            assert!(!obb.contains_point(obb.center + x * 2.1));
+            assert!(!obb.contains_point(obb.center + y * 1.1));
+            // Inside the axis-aligned bounds but outside the box
+            assert!(!obb.contains_point(obb.center + $vec3::new(1.5, 0.0, 0.0)));
+
+            let inside = obb.center + x - y * 0.5;
+            assert_approx_eq!(inside, obb.closest_point(inside), 1e-6);
+            assert_approx_eq!(
+                obb.center + x * 2.0 + y,
+                obb.closest_point(obb.center + x * 3.0 + y * 5.0),
+                1e-5
+            );
+            assert_approx_eq!(
+                obb.center + y + $vec3::Z * 0.5,
+                obb.closest_point(obb.center + y * 2.0 + $vec3::Z),
+                1e-5
+            );
+        });
+
+        glam_test!(test_obb3_sphere, {
+            let obb = rotated_box();
+            let x = $vec3::new(SQRT_2, SQRT_2, 0.0) * 0.5;
+
+            assert!(obb.contains_sphere(&$sphere::new(obb.center, 0.5)));
+            assert!(obb.contains_sphere(&$sphere::new(obb.center + x * 1.4, 0.5)));
+            assert!(!obb.contains_sphere(&$sphere::new(obb.center, 0.6)));
+            assert!(!obb.contains_sphere(&$sphere::new(obb.center + x * 1.6, 0.5)));
+
+            assert!(obb.intersects_sphere(&$sphere::new(obb.center + x * 2.9, 1.0)));
+            assert!(!obb.intersects_sphere(&$sphere::new(obb.center + x * 3.1, 1.0)));
+            assert!(obb.intersects_sphere(&$sphere::new(obb.center, 10.0)));
+            // Near a corner
+            let corner = obb.corners()[7];
+            let outward = (corner - obb.center).normalize();
+            assert!(!obb.intersects_sphere(&$sphere::new(corner + outward * 0.2, 0.1)));
+            assert!($sphere::new(corner + outward * 0.2, 0.3).intersects_obb(&obb));
+
+            let radius = obb.half_extents.length() + 1e-4;
+            assert!($sphere::new(obb.center, radius).contains_obb(&obb));
+            assert!(!$sphere::new(obb.center, radius * 0.99).contains_obb(&obb));
+            assert!(!$sphere::new(obb.center + x * 0.1, radius).contains_obb(&obb));
+            assert!($sphere::new(obb.center + x * 0.1, radius + 0.1).contains_obb(&obb));
+        });
+
+        glam_test!(test_obb3_contains_obb, {
+            let obb = rotated_box();
+            assert!(obb.contains_obb(&obb));
+
+            let inner = $obb3::new(obb.center, $vec3::splat(0.3), $quat::from_rotation_x(0.3));
+            assert!(obb.contains_obb(&inner));
+            assert!(!inner.contains_obb(&obb));
+
+            // Rotating the inner box a quarter turn about z swaps its x and y extents
+            let rotated = $obb3::new(
+                obb.center,
+                $vec3::new(0.9, 1.9, 0.5),
+                $quat::from_rotation_z(FRAC_PI_4 + core::$t::consts::FRAC_PI_2),
+            );
+            assert!(obb.contains_obb(&rotated));
+            let rotated = $obb3::new(
+                obb.center,
+                $vec3::new(0.9, 1.9, 0.5),
+                $quat::from_rotation_z(FRAC_PI_4 + 0.1),
+            );
+            assert!(!obb.contains_obb(&rotated));
+        });
+
+        glam_test!(test_obb3_intersects_obb, {
+            let a = $obb3::new($vec3::ZERO, $vec3::ONE, $quat::IDENTITY);
+            assert!(a.intersects_obb(&a));
+
+            // Separated along an axis of the first box
+            let b = $obb3::new($vec3::new(2.1, 0.0, 0.0), $vec3::ONE, $quat::IDENTITY);
+            assert!(!a.intersects_obb(&b));
+            assert!(!b.intersects_obb(&a));
+            let b = $obb3::new($vec3::new(1.9, 1.9, -1.9), $vec3::ONE, $quat::IDENTITY);
+            assert!(a.intersects_obb(&b));
+            assert!(b.intersects_obb(&a));
+
+            // Separated along an axis of the second box
+            let b = $obb3::new(
+                $vec3::new(2.0, 2.0, 0.0),
+                $vec3::ONE,
+                $quat::from_rotation_z(FRAC_PI_4),
+            );
+            assert!(!a.intersects_obb(&b));
+            assert!(!b.intersects_obb(&a));
+            let b = $obb3::new(
+                $vec3::new(2.1, 0.0, 0.0),
+                $vec3::ONE,
+                $quat::from_rotation_z(FRAC_PI_4),
+            );
+            assert!(a.intersects_obb(&b));
+            assert!(b.intersects_obb(&a));
+
+            // Separated only along the cross product of an edge of each box, the first box has an
+            // edge along z at x = sqrt(2) and the second has an edge along y at x = c - sqrt(2)
+            let a = $obb3::new($vec3::ZERO, $vec3::ONE, $quat::from_rotation_z(FRAC_PI_4));
+            let b = |c: $t| {
+                $obb3::new(
+                    $vec3::new(c, 0.0, 0.0),
+                    $vec3::ONE,
+                    $quat::from_rotation_y(FRAC_PI_4),
+                )
+            };
+            let separated = b(2.0 * SQRT_2 + 0.05);
+            assert!(!a.intersects_obb(&separated));
+            assert!(!separated.intersects_obb(&a));
+            let overlapping = b(2.0 * SQRT_2 - 0.05);
+            assert!(a.intersects_obb(&overlapping));
+            assert!(overlapping.intersects_obb(&a));
+
+            // Parallel boxes with nearly parallel edges
+            let a = $obb3::new($vec3::ZERO, $vec3::ONE, $quat::from_rotation_x(1.0));
+            let b = $obb3::new(
+                $vec3::new(0.0, 0.0, 1.5),
+                $vec3::ONE,
+                $quat::from_rotation_x(1.0),
+            );
+            assert!(a.intersects_obb(&b));
+            let b = $obb3::new(
+                $vec3::new(0.0, 0.0, 3.0),
+                $vec3::ONE,
+                $quat::from_rotation_x(1.0),
+            );
+            assert!(!a.intersects_obb(&b));
+        });
+
+        glam_test!(test_obb3_from_points_pca, {
+            let expected = $obb3::new(
+                $vec3::new(-1.0, 2.0, 0.5),
+                $vec3::new(4.0, 2.0, 1.0),
+                $quat::from_rotation_y(0.4) * $quat::from_rotation_z(-0.7),
+            );
+            let mut points = expected.corners().to_vec();
+            points.push(expected.center);
+            let obb = $obb3::from_points_pca(&points);
+
+            assert_approx_eq!(expected.center, obb.center, 1e-5);
+            assert_approx_eq!(expected.half_extents, obb.half_extents, 1e-5);
+            // The axes are recovered up to sign
+            let axes = [
+                (
+                    $vec3::from(expected.rotation.x_axis),
+                    $vec3::from(obb.rotation.x_axis),
+                ),
+                (
+                    $vec3::from(expected.rotation.y_axis),
+                    $vec3::from(obb.rotation.y_axis),
+                ),
+                (
+                    $vec3::from(expected.rotation.z_axis),
+                    $vec3::from(obb.rotation.z_axis),
+                ),
+            ];
+            for &(expected, actual) in axes.iter() {
+                assert_approx_eq!(1.0, expected.dot(actual).abs(), 1e-5);
+            }
+            for &point in points.iter() {
+                assert!(obb.closest_point(point).distance(point) <= 1e-5);
+            }
+
+            // A single point gives an empty box
+            let obb = $obb3::from_points_pca(&[$vec3::new(1.0, 2.0, 3.0)]);
+            assert_eq!($vec3::new(1.0, 2.0, 3.0), obb.center);
+            assert_eq!($vec3::ZERO, obb.half_extents);
+
+            // Points on a diagonal line give a box with no thickness along the line
+            let direction = $vec3::new(1.0, 2.0, 2.0) / 3.0;
+            let points = [-2.0, 0.5, 1.0, 4.0]
+                .iter()
+                .map(|&s: &$t| $vec3::ONE + direction * s)
+                .collect::<Vec<_>>();
+            let obb = $obb3::from_points_pca(&points);
+            assert_approx_eq!($vec3::ONE + direction, obb.center, 1e-5);
+            assert_approx_eq!($vec3::new(3.0, 0.0, 0.0), obb.half_extents, 1e-5);
+            assert_approx_eq!(
+                1.0,
+                $vec3::from(obb.rotation.x_axis).dot(direction).abs(),
+                1e-5
+            );
+
+            should_panic!({ $obb3::from_points_pca(&[]) });
+        });
+    };
+}
+
+mod obb3 {
+    use super::support::FloatCompare;
+    use glam::{Aabb3, Obb3, Quat, Sphere, Vec3};
+
+    impl FloatCompare for Aabb3 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                min: (self.min - other.min).abs(),
+                max: (self.max - other.max).abs(),
+            }
+        }
+    }
+
+    impl_obb3_tests!(f32, Obb3, Sphere, Aabb3, Vec3, Quat);
+}
+
+mod dobb3 {
+    use super::support::FloatCompare;
+    use glam::{DAabb3, DObb3, DQuat, DSphere, DVec3};
+
+    impl FloatCompare for DAabb3 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff as f64)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                min: (self.min - other.min).abs(),
+                max: (self.max - other.max).abs(),
+            }
+        }
+    }
+
+    impl_obb3_tests!(f64, DObb3, DSphere, DAabb3, DVec3, DQuat);
+}
@@ -0,0 +1,247 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_plane_tests {
+    ($t:ident, $plane:ident, $vec3:ident, $vec4:ident, $mat4:ident, $quat:ident) => {
+        glam_test!(test_plane_new, {
+            let plane = $plane::new($vec3::Y, -2.0);
+            assert_eq!($vec3::Y, plane.normal);
+            assert_eq!(-2.0, plane.d);
+            assert!(plane.is_normalized());
+            assert!(plane.is_finite());
+            assert!(!$plane::new($vec3::Y, $t::NAN).is_finite());
+            assert_eq!($vec4::new(0.0, 1.0, 0.0, -2.0), plane.to_vec4());
+            assert_eq!($vec4::new(0.0, 1.0, 0.0, -2.0), $vec4::from(plane));
+            assert_eq!(
+                format!("{:?}", plane),
+                format!(
+                    "{} {{ normal: {:?}, d: {:?} }}",
+                    stringify!($plane),
+                    $vec3::Y,
+                    -2.0 as $t
+                )
+            );
+            assert_eq!(format!("{}", plane), "[[0, 1, 0], -2]");
+
+            should_glam_assert!({ $plane::new($vec3::ONE, 0.0) });
+        });
+
+        glam_test!(test_plane_constructors, {
+            let point = $vec3::new(1.0, 2.0, 3.0);
+            let plane = $plane::from_point_normal(point, $vec3::Y);
+            assert_eq!($plane::new($vec3::Y, -2.0), plane);
+            assert_eq!(0.0, plane.signed_distance(point));
+
+            // Counter-clockwise points seen from +z
+            let a = $vec3::new(0.0, 0.0, 3.0);
+            let b = $vec3::new(1.0, 0.0, 3.0);
+            let c = $vec3::new(0.0, 1.0, 3.0);
+            let plane = $plane::from_points(a, b, c);
+            assert_approx_eq!($plane::new($vec3::Z, -3.0), plane, 1e-6);
+            assert_approx_eq!(
+                $plane::new(-$vec3::Z, 3.0),
+                $plane::from_points(a, c, b),
+                1e-6
+            );
+
+            let plane = $plane::from_vec4($vec4::new(0.0, 3.0, 4.0, -10.0));
+            assert!(plane.is_normalized());
+            assert_approx_eq!($plane::new($vec3::new(0.0, 0.6, 0.8), -2.0), plane, 1e-6);
+            let unnormalized = $plane {
+                normal: $vec3::new(0.0, 0.0, 2.0),
+                d: 4.0,
+            };
+            assert!(!unnormalized.is_normalized());
+            assert_eq!($plane::new($vec3::Z, 2.0), unnormalized.normalize());
+
+            should_glam_assert!({ $plane::from_points(a, a, c) });
+            should_glam_assert!({ $plane::from_vec4($vec4::W) });
+            should_glam_assert!({ $plane::from_point_normal(point, $vec3::ZERO) });
+        });
+
+        glam_test!(test_plane_distance, {
+            let normal = $vec3::new(1.0, 2.0, 2.0) / 3.0;
+            let plane = $plane::from_point_normal($vec3::new(3.0, 0.0, 0.0), normal);
+            let point = $vec3::new(4.0, 5.0, -1.0);
+            let distance = plane.signed_distance(point);
+            assert_approx_eq!(
+                (point - $vec3::new(3.0, 0.0, 0.0)).dot(normal),
+                distance,
+                1e-6
+            );
+            assert_approx_eq!(
+                -distance,
+                $plane::new(-plane.normal, -plane.d).signed_distance(point),
+                1e-6
+            );
+
+            let projected = plane.project_point(point);
+            assert_approx_eq!(0.0, plane.signed_distance(projected), 1e-5);
+            assert_approx_eq!(point - normal * distance, projected, 1e-5);
+
+            let reflected = plane.reflect_point(point);
+            assert_approx_eq!(-distance, plane.signed_distance(reflected), 1e-5);
+            assert_approx_eq!(projected, (point + reflected) * 0.5, 1e-5);
+            assert_approx_eq!(point, plane.reflect_point(reflected), 1e-5);
+        });
+
+        glam_test!(test_plane_intersect_plane, {
+            // x = 1 and y = 2 meet along a line parallel to z
+            let a = $plane::new($vec3::X, -1.0);
+            let b = $plane::new($vec3::Y, -2.0);
+            let line = a.intersect_plane(b).unwrap();
+            assert_approx_eq!($vec3::new(1.0, 2.0, 0.0), line.origin, 1e-6);
+            assert_approx_eq!($vec3::Z, line.direction, 1e-6);
+
+            let a = $plane::from_point_normal(
+                $vec3::new(1.0, 2.0, 3.0),
+                $vec3::new(1.0, 1.0, 0.0).normalize(),
+            );
+            let b =
+                $plane::from_point_normal($vec3::new(-1.0, 0.0, 4.0), $vec3::new(0.0, 0.6, 0.8));
+            let line = a.intersect_plane(b).unwrap();
+            assert!(line.direction.is_normalized());
+            for &s in &[-3.0, 0.0, 2.5] {
+                let point = line.at(s);
+                assert_approx_eq!(0.0, a.signed_distance(point), 1e-5);
+                assert_approx_eq!(0.0, b.signed_distance(point), 1e-5);
+            }
+            // The origin is the closest point on the line to the world origin
+            assert_approx_eq!(0.0, line.origin.dot(line.direction), 1e-5);
+
+            // Parallel and coincident planes
+            assert!(a.intersect_plane($plane::new(a.normal, 5.0)).is_none());
+            assert!(a.intersect_plane($plane::new(-a.normal, -a.d)).is_none());
+        });
+
+        glam_test!(test_plane_intersect_three_planes, {
+            let a = $plane::new($vec3::X, -1.0);
+            let b = $plane::new($vec3::Y, -2.0);
+            let c = $plane::new($vec3::Z, 3.0);
+            assert_approx_eq!(
+                $vec3::new(1.0, 2.0, -3.0),
+                $plane::intersect_three_planes(a, b, c).unwrap(),
+                1e-6
+            );
+
+            let point = $vec3::new(-2.0, 0.5, 7.0);
+            let a = $plane::from_point_normal(point, $vec3::new(1.0, 2.0, 2.0) / 3.0);
+            let b = $plane::from_point_normal(point, $vec3::new(0.0, 0.6, -0.8));
+            let c = $plane::from_point_normal(point, $vec3::new(-1.0, 1.0, 0.0).normalize());
+            assert_approx_eq!(
+                point,
+                $plane::intersect_three_planes(a, b, c).unwrap(),
+                1e-5
+            );
+            assert_approx_eq!(
+                point,
+                $plane::intersect_three_planes(c, a, b).unwrap(),
+                1e-5
+            );
+
+            // Two parallel planes
+            let d = $plane::new(a.normal, a.d + 1.0);
+            assert!($plane::intersect_three_planes(a, b, d).is_none());
+            // Three planes sharing a line
+            let e = $plane::from_point_normal(point, (a.normal + b.normal).normalize());
+            assert!($plane::intersect_three_planes(a, b, e).is_none());
+        });
+
+        glam_test!(test_plane_transform, {
+            let plane =
+                $plane::from_point_normal($vec3::new(1.0, 2.0, 3.0), $vec3::new(0.0, 0.6, 0.8));
+            let mat = $mat4::from_scale_rotation_translation(
+                $vec3::new(2.0, 0.5, 3.0),
+                $quat::from_rotation_y(0.7) * $quat::from_rotation_x(-0.4),
+                $vec3::new(-3.0, 1.0, 4.0),
+            );
+            let transformed = plane.transform_mat4(&mat);
+            assert!(transformed.is_normalized());
+            let inverse_transpose = mat.inverse().transpose();
+            assert_eq!(
+                transformed,
+                plane.transform_inverse_transpose(&inverse_transpose)
+            );
+
+            // Points on the plane stay on the plane and sides are preserved
+            let x_axis = plane.normal.cross($vec3::Y).normalize();
+            let y_axis = plane.normal.cross(x_axis);
+            let on_plane = plane.project_point($vec3::ZERO);
+            for &(x, y) in &[(0.0, 0.0), (1.0, 0.0), (-2.0, 3.0)] {
+                let point = mat.transform_point3(on_plane + x_axis * x + y_axis * y);
+                assert_approx_eq!(0.0, transformed.signed_distance(point), 1e-5);
+            }
+            let above = mat.transform_point3(on_plane + plane.normal);
+            assert!(transformed.signed_distance(above) > 0.0);
+
+            // Rigid transforms preserve distances
+            let rigid = $mat4::from_rotation_translation(
+                $quat::from_rotation_z(1.2),
+                $vec3::new(4.0, -1.0, 2.0),
+            );
+            let transformed = plane.transform_mat4(&rigid);
+            let point = $vec3::new(5.0, -2.0, 0.5);
+            assert_approx_eq!(
+                plane.signed_distance(point),
+                transformed.signed_distance(rigid.transform_point3(point)),
+                1e-5
+            );
+
+            should_glam_assert!({ plane.transform_mat4(&$mat4::ZERO) });
+        });
+    };
+}
+
+mod plane {
+    use super::support::FloatCompare;
+    use glam::{Mat4, Plane, Quat, Vec3, Vec4};
+
+    impl FloatCompare for Plane {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                normal: (self.normal - other.normal).abs(),
+                d: (self.d - other.d).abs(),
+            }
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<Plane>());
+        assert_eq!(4, mem::align_of::<Plane>());
+    });
+
+    impl_plane_tests!(f32, Plane, Vec3, Vec4, Mat4, Quat);
+}
+
+mod dplane {
+    use super::support::FloatCompare;
+    use glam::{DMat4, DPlane, DQuat, DVec3, DVec4};
+
+    impl FloatCompare for DPlane {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff as f64)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                normal: (self.normal - other.normal).abs(),
+                d: (self.d - other.d).abs(),
+            }
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(32, mem::size_of::<DPlane>());
+        assert_eq!(8, mem::align_of::<DPlane>());
+    });
+
+    impl_plane_tests!(f64, DPlane, DVec3, DVec4, DMat4, DQuat);
+}
@@ -289,6 +289,275 @@ macro_rules! impl_quat_tests {
             }
         });
 
+        glam_test!(test_exp_ln, {
+            assert_eq!($quat::from_xyzw(0.0, 0.0, 0.0, 0.0), $quat::IDENTITY.ln());
+            assert_eq!($quat::IDENTITY, $quat::from_xyzw(0.0, 0.0, 0.0, 0.0).exp());
+
+            let axis = $vec3::new(1.0, -2.0, 3.0).normalize();
+            // Near the identity, at 180 degrees and near a full turn
+            for &angle in &[
+                1.0e-6,
+                1.0e-3,
+                deg(45.0),
+                deg(180.0) - 1.0e-3,
+                deg(180.0),
+                deg(180.0) + 1.0e-3,
+                deg(360.0) - 1.0e-3,
+            ] {
+                let q = $quat::from_axis_angle(axis, angle);
+                let ln = q.ln();
+                assert_approx_eq!(0.0, ln.w, 1.0e-6);
+                assert_approx_eq!(axis * angle * 0.5, ln.xyz(), 1.0e-5);
+                assert_approx_eq!(q, ln.exp(), 1.0e-6);
+            }
+
+            // A negative identity has no unique axis
+            let ln = (-$quat::IDENTITY).ln();
+            assert_approx_eq!($vec3::X * deg(180.0), ln.xyz(), 1.0e-6);
+            assert_approx_eq!(-$quat::IDENTITY, ln.exp(), 1.0e-6);
+
+            // Non-unit quaternions
+            let q = $quat::from_rotation_x(deg(60.0)) * 2.0;
+            let ln = q.ln();
+            assert_approx_eq!((2.0 as $t).ln(), ln.w, 1.0e-6);
+            assert_approx_eq!(q, ln.exp(), 1.0e-5);
+            assert_approx_eq!(
+                $quat::IDENTITY * (1.0 as $t).exp(),
+                $quat::from_xyzw(0.0, 0.0, 0.0, 1.0).exp()
+            );
+        });
+
+        glam_test!(test_powf, {
+            let q = $quat::from_rotation_z(deg(90.0));
+            assert_approx_eq!($quat::IDENTITY, q.powf(0.0), 1.0e-6);
+            assert_approx_eq!(q, q.powf(1.0), 1.0e-6);
+            assert_approx_eq!($quat::from_rotation_z(deg(45.0)), q.powf(0.5), 1.0e-6);
+            assert_approx_eq!(q.conjugate(), q.powf(-1.0), 1.0e-6);
+            assert_approx_eq!($quat::from_rotation_z(deg(270.0)), q.powf(3.0), 1.0e-6);
+
+            let axis = $vec3::new(1.0, -2.0, 3.0).normalize();
+            for &angle in &[1.0e-4, deg(180.0) - 1.0e-3, deg(180.0)] {
+                let q = $quat::from_axis_angle(axis, angle);
+                assert_approx_eq!(
+                    $quat::from_axis_angle(axis, angle * 0.25),
+                    q.powf(0.25),
+                    1.0e-6
+                );
+                assert_approx_eq!(
+                    $quat::from_axis_angle(axis, angle * 2.0),
+                    q.powf(2.0),
+                    1.0e-5
+                );
+            }
+        });
+
+        glam_test!(test_swing_twist, {
+            let axis = $vec3::new(1.0, 2.0, -2.0).normalize();
+            let q = $quat::from_euler(EulerRot::YXZ, deg(30.0), deg(60.0), deg(-20.0));
+            let (swing, twist) = q.to_swing_twist(axis);
+            assert!(swing.is_normalized());
+            assert!(twist.is_normalized());
+            assert_approx_eq!(q, swing * twist, 1.0e-6);
+            assert_approx_eq!(q, $quat::from_swing_twist(swing, twist), 1.0e-6);
+            assert_approx_eq!(0.0, swing.xyz().dot(axis), 1.0e-6);
+            assert_approx_eq!($vec3::ZERO, twist.xyz().cross(axis), 1.0e-6);
+            // The twist leaves the axis unchanged
+            assert_approx_eq!(swing * axis, q * axis, 1.0e-6);
+
+            // The negated quaternion gives the same twist
+            let (_, negated_twist) = (-q).to_swing_twist(axis);
+            assert_approx_eq!(twist, negated_twist, 1.0e-6);
+
+            // Pure twist and pure swing
+            let (swing, twist) = $quat::from_rotation_z(deg(40.0)).to_swing_twist($vec3::Z);
+            assert_approx_eq!($quat::IDENTITY, swing, 1.0e-6);
+            assert_approx_eq!($quat::from_rotation_z(deg(40.0)), twist, 1.0e-6);
+            let (swing, twist) = $quat::from_rotation_x(deg(40.0)).to_swing_twist($vec3::Z);
+            assert_approx_eq!($quat::from_rotation_x(deg(40.0)), swing, 1.0e-6);
+            assert_approx_eq!($quat::IDENTITY, twist, 1.0e-6);
+
+            // Half turn twist, the swing takes the sign of `q`
+            let q = $quat::from_rotation_x(deg(30.0)) * $quat::from_rotation_z(deg(180.0));
+            let (swing, twist) = q.to_swing_twist($vec3::Z);
+            assert!(twist.w >= 0.0);
+            assert_approx_eq!(
+                0.0,
+                swing.angle_between($quat::from_rotation_x(deg(30.0))),
+                1.0e-3
+            );
+            assert_approx_eq!(deg(180.0), twist.angle_between($quat::IDENTITY), 1.0e-3);
+            assert_approx_eq!(q, swing * twist, 1.0e-6);
+
+            // Half turn swing has an undefined twist
+            let q = $quat::from_rotation_x(deg(180.0));
+            assert_eq!((q, $quat::IDENTITY), q.to_swing_twist($vec3::Z));
+            let q = $quat::from_rotation_x(deg(180.0)) * $quat::from_rotation_z(deg(30.0));
+            assert_eq!((q, $quat::IDENTITY), q.to_swing_twist($vec3::Z));
+
+            should_glam_assert!({ $quat::IDENTITY.to_swing_twist($vec3::ZERO) });
+            should_glam_assert!({ $quat::IDENTITY.to_swing_twist($vec3::ONE) });
+        });
+
+        glam_test!(test_clamp_twist, {
+            let swing = $quat::from_rotation_x(deg(30.0));
+            let q = swing * $quat::from_rotation_z(deg(60.0));
+            assert_eq!(q, q.clamp_twist($vec3::Z, deg(-90.0), deg(90.0)));
+            assert_approx_eq!(
+                swing * $quat::from_rotation_z(deg(45.0)),
+                q.clamp_twist($vec3::Z, deg(-45.0), deg(45.0)),
+                1.0e-6
+            );
+            let q = swing * $quat::from_rotation_z(deg(-60.0));
+            assert_approx_eq!(
+                swing * $quat::from_rotation_z(deg(-10.0)),
+                q.clamp_twist($vec3::Z, deg(-10.0), deg(10.0)),
+                1.0e-6
+            );
+            assert_approx_eq!(
+                swing * $quat::from_rotation_z(deg(70.0)),
+                q.clamp_twist($vec3::Z, deg(70.0), deg(80.0)),
+                1.0e-6
+            );
+
+            // Twists either side of a half turn
+            let q = swing * $quat::from_rotation_z(deg(179.0));
+            let expected = swing * $quat::from_rotation_z(deg(90.0));
+            let clamped = q.clamp_twist($vec3::Z, deg(-90.0), deg(90.0));
+            assert_approx_eq!(0.0, clamped.angle_between(expected), 1.0e-3);
+            let q = swing * $quat::from_rotation_z(deg(181.0));
+            let expected = swing * $quat::from_rotation_z(deg(-90.0));
+            let clamped = q.clamp_twist($vec3::Z, deg(-90.0), deg(90.0));
+            assert_approx_eq!(0.0, clamped.angle_between(expected), 1.0e-3);
+
+            should_glam_assert!({ $quat::IDENTITY.clamp_twist($vec3::Z, 1.0, -1.0) });
+        });
+
+        glam_test!(test_clamp_swing_cone, {
+            let twist = $quat::from_rotation_z(deg(20.0));
+            let q = $quat::from_rotation_x(deg(60.0)) * twist;
+            assert_eq!(q, q.clamp_swing_cone($vec3::Z, deg(90.0)));
+            assert_approx_eq!(
+                $quat::from_rotation_x(deg(45.0)) * twist,
+                q.clamp_swing_cone($vec3::Z, deg(45.0)),
+                1.0e-6
+            );
+            assert_approx_eq!(twist, q.clamp_swing_cone($vec3::Z, 0.0), 1.0e-6);
+            assert_approx_eq!(
+                -$quat::from_rotation_x(deg(-45.0)) * twist,
+                (-$quat::from_rotation_x(deg(-60.0)) * twist).clamp_swing_cone($vec3::Z, deg(45.0)),
+                1.0e-6
+            );
+
+            // The direction of a near half turn swing is kept
+            let axis = $vec3::new(1.0, -1.0, 0.0).normalize();
+            let q = $quat::from_axis_angle(axis, deg(179.0)) * twist;
+            let clamped = q.clamp_swing_cone($vec3::Z, deg(30.0));
+            assert!(clamped.dot(q) >= 0.0);
+            let (swing, clamped_twist) = clamped.to_swing_twist($vec3::Z);
+            assert_approx_eq!(twist, clamped_twist, 1.0e-5);
+            assert_approx_eq!(deg(30.0), swing.angle_between($quat::IDENTITY), 1.0e-3);
+            assert_approx_eq!(0.0, swing.xyz().cross(axis).length(), 1.0e-6);
+
+            // A half turn swing has no twist
+            let q = $quat::from_axis_angle(axis, deg(180.0));
+            let clamped = q.clamp_swing_cone($vec3::Z, deg(30.0));
+            assert_approx_eq!(deg(30.0), clamped.angle_between($quat::IDENTITY), 1.0e-3);
+            assert_approx_eq!(0.0, clamped.xyz().cross(axis).length(), 1.0e-6);
+
+            should_glam_assert!({ $quat::IDENTITY.clamp_swing_cone($vec3::Z, -1.0) });
+        });
+
+        // Finite difference approximations of the world space angular velocity of a curve
+        fn velocity_after(f: impl Fn($t) -> $quat, s: $t) -> $vec3 {
+            let h = 1.0e-3;
+            let delta = f(s + h) * f(s).conjugate();
+            let delta = if delta.w < 0.0 { -delta } else { delta };
+            $vec3::new(delta.x, delta.y, delta.z) * (2.0 / h)
+        }
+
+        fn velocity_before(f: impl Fn($t) -> $quat, s: $t) -> $vec3 {
+            velocity_after(f, s - 1.0e-3)
+        }
+
+        fn spline_keys() -> [$quat; 4] {
+            [
+                $quat::IDENTITY,
+                $quat::from_rotation_x(deg(40.0)),
+                $quat::from_euler(EulerRot::YXZ, deg(30.0), deg(60.0), deg(-20.0)),
+                // Opposite hemisphere to the previous key
+                -$quat::from_euler(EulerRot::YXZ, deg(90.0), deg(45.0), deg(10.0)),
+            ]
+        }
+
+        glam_test!(test_squad, {
+            let [q0, q1, q2, q3] = spline_keys();
+            let a1 = $quat::squad_control_point(q0, q1, q2);
+            let a2 = $quat::squad_control_point(q1, q2, q3);
+            let a3 = $quat::squad_control_point(q2, -q3, -q3);
+            assert!(a1.is_normalized());
+            assert_approx_eq!(q1, q1.squad(a1, a2, q2, 0.0), 1.0e-6);
+            assert_approx_eq!(q2, q1.squad(a1, a2, q2, 1.0), 1.0e-6);
+
+            // Evenly spaced keys about one axis
+            let key = |angle| $quat::from_rotation_z(deg(angle));
+            let a = $quat::squad_control_point(key(0.0), key(30.0), key(60.0));
+            assert_approx_eq!(key(30.0), a, 1.0e-6);
+            let b = $quat::squad_control_point(key(30.0), key(60.0), key(90.0));
+            assert_approx_eq!(key(45.0), key(30.0).squad(a, b, key(60.0), 0.5), 1.0e-6);
+
+            // The angular velocity is continuous across keys
+            let q3 = -q3;
+            let segment1 = |s| q1.squad(a1, a2, q2, s);
+            let segment2 = |s| q2.squad(a2, a3, q3, s);
+            assert_approx_eq!(
+                velocity_before(segment1, 1.0),
+                velocity_after(segment2, 0.0),
+                1.0e-2
+            );
+        });
+
+        glam_test!(test_hermite, {
+            let [_, q1, q2, _] = spline_keys();
+            let w1 = $vec3::new(0.5, -1.0, 0.25);
+            let w2 = $vec3::new(-0.25, 0.5, 1.0);
+            assert_approx_eq!(q1, q1.hermite(w1, q2, w2, 0.0), 1.0e-6);
+            assert_approx_eq!(q2, q1.hermite(w1, q2, w2, 1.0), 1.0e-6);
+            assert_approx_eq!(q2, q1.hermite(w1, -q2, w2, 1.0), 1.0e-6);
+
+            let curve = |s| q1.hermite(w1, q2, w2, s);
+            assert_approx_eq!(w1, velocity_after(curve, 0.0), 1.0e-2);
+            assert_approx_eq!(w2, velocity_before(curve, 1.0), 1.0e-2);
+
+            // Constant angular velocity about one axis
+            let w = $vec3::Z * deg(90.0);
+            let end = $quat::from_rotation_z(deg(90.0));
+            assert_approx_eq!(
+                $quat::from_rotation_z(deg(30.0)),
+                $quat::IDENTITY.hermite(w, end, w, 1.0 / 3.0),
+                1.0e-6
+            );
+        });
+
+        glam_test!(test_catmull_rom, {
+            let [q0, q1, q2, q3] = spline_keys();
+            assert_approx_eq!(q1, $quat::catmull_rom(q0, q1, q2, q3, 0.0), 1.0e-6);
+            assert_approx_eq!(q2, $quat::catmull_rom(q0, q1, q2, q3, 1.0), 1.0e-6);
+
+            let key = |angle| $quat::from_rotation_z(deg(angle));
+            assert_approx_eq!(
+                key(45.0),
+                $quat::catmull_rom(key(0.0), key(30.0), key(60.0), key(90.0), 0.5),
+                1.0e-6
+            );
+
+            // The angular velocity at a key is half the rotation between its neighbours
+            let segment1 = |s| $quat::catmull_rom(q0, q1, q2, q3, s);
+            let segment2 = |s| $quat::catmull_rom(q1, q2, q3, q3, s);
+            let velocity = (-q3 * q1.conjugate()).to_scaled_axis() * 0.5;
+            assert_approx_eq!(velocity, velocity_before(segment1, 1.0), 1.0e-2);
+            assert_approx_eq!(velocity, velocity_after(segment2, 0.0), 1.0e-2);
+        });
+
         glam_test!(test_fmt, {
             let a = $quat::IDENTITY;
             assert_eq!(
@@ -435,13 +704,166 @@ macro_rules! impl_quat_tests {
             should_glam_assert!({ $quat::from_rotation_arc_colinear($vec3::ZERO, $vec3::X) });
             should_glam_assert!({ $quat::from_rotation_arc_colinear($vec3::X, $vec3::ZERO) });
         });
+
+        #[cfg(feature = "std")]
+        glam_test!(test_look_rotation, {
+            let forward = $vec3::new(1.0, 0.0, 1.0).normalize();
+            let q = $quat::from_look_rotation_lh(forward, $vec3::Y);
+            assert!(q.is_normalized());
+            assert_approx_eq!(forward, q * $vec3::Z);
+            assert_approx_eq!($vec3::Y, q * $vec3::Y);
+            assert_approx_eq!(
+                $mat4::look_at_lh($vec3::ZERO, forward, $vec3::Y),
+                $mat4::from_quat(q.conjugate())
+            );
+
+            let q = $quat::from_look_rotation_rh(forward, $vec3::Y);
+            assert!(q.is_normalized());
+            assert_approx_eq!(forward, q * -$vec3::Z);
+            assert_approx_eq!($vec3::Y, q * $vec3::Y);
+            assert_approx_eq!(
+                $mat4::look_at_rh($vec3::ZERO, forward, $vec3::Y),
+                $mat4::from_quat(q.conjugate())
+            );
+
+            // `up` is only a hint and does not need to be orthogonal to `forward`
+            let up = $vec3::new(0.0, 1.0, 1.0).normalize();
+            assert_approx_eq!($quat::IDENTITY, $quat::from_look_rotation_lh($vec3::Z, up));
+            assert_approx_eq!($quat::IDENTITY, $quat::from_look_rotation_rh(-$vec3::Z, up));
+
+            // Colinear `forward` and `up` fall back to the shortest arc
+            let q = $quat::from_look_rotation_lh($vec3::Y, $vec3::Y);
+            assert_approx_eq!($quat::from_rotation_x(deg(-90.0)), q);
+            let q = $quat::from_look_rotation_rh($vec3::Y, -$vec3::Y);
+            assert_approx_eq!($quat::from_rotation_x(deg(90.0)), q);
+
+            should_glam_assert!({ $quat::from_look_rotation_lh($vec3::ZERO, $vec3::Y) });
+            should_glam_assert!({ $quat::from_look_rotation_rh($vec3::Z, $vec3::ZERO) });
+        });
+
+        glam_test!(test_rotate_towards, {
+            let start = $quat::from_rotation_z(deg(10.0));
+            let target = $quat::from_rotation_z(deg(100.0));
+            let expected = $quat::from_rotation_z(deg(40.0));
+            assert_approx_eq!(expected, start.rotate_towards(target, deg(30.0)), 1.0e-6);
+            assert_approx_eq!(expected, start.rotate_towards(-target, deg(30.0)), 1.0e-6);
+            assert_approx_eq!(start, start.rotate_towards(target, 0.0), 1.0e-6);
+
+            // Never overshoots the target
+            assert_eq!(target, start.rotate_towards(target, deg(90.0)));
+            assert_eq!(target, start.rotate_towards(target, deg(120.0)));
+            assert_eq!(start, start.rotate_towards(start, 0.0));
+
+            should_glam_assert!({ start.rotate_towards(target, -1.0) });
+        });
+
+        glam_test!(test_angular_velocity, {
+            let q0 = $quat::from_euler(EulerRot::YXZ, deg(10.0), deg(20.0), deg(30.0));
+            let velocity = $vec3::new(0.5, -1.0, 2.0);
+            let q1 = q0.integrate(velocity, 0.5);
+            assert!(q1.is_normalized());
+            assert_approx_eq!(q0, q0.integrate(velocity, 0.0));
+            assert_approx_eq!(q0, q0.integrate($vec3::ZERO, 0.5));
+            assert_approx_eq!(q1, q1.integrate(velocity, -0.5).integrate(velocity, 0.5));
+
+            assert_approx_eq!(
+                velocity,
+                $quat::angular_velocity_between(q0, q1, 0.5),
+                1.0e-5
+            );
+            assert_approx_eq!(
+                velocity,
+                $quat::angular_velocity_between(q0, -q1, 0.5),
+                1.0e-5
+            );
+            assert_approx_eq!($vec3::ZERO, $quat::angular_velocity_between(q0, q0, 0.5));
+
+            // The velocity is constant and in the world frame
+            let rotation = |s| q0.integrate(velocity, s);
+            assert_approx_eq!(velocity, velocity_after(rotation, 0.0), 1.0e-2);
+            assert_approx_eq!(velocity, velocity_after(rotation, 0.25), 1.0e-2);
+
+            should_glam_assert!({ $quat::angular_velocity_between(q0, q1, 0.0) });
+            should_glam_assert!({ ($quat::IDENTITY * 2.0).integrate(velocity, 0.5) });
+        });
+    };
+}
+
+macro_rules! impl_quat_average_tests {
+    ($quat:ident, $vec3:ident, $accumulator:ident) => {
+        glam_test!(test_average, {
+            let key = |angle| $quat::from_axis_angle($vec3::Y, deg(angle));
+            let expected = key(40.0);
+            let rotations = [key(20.0), -key(40.0), key(60.0)];
+            assert_approx_eq!(expected, $quat::average(&rotations), 1.0e-6);
+
+            // The result does not depend on order or sign, other than matching the first input
+            let rotations = [key(60.0), key(20.0), -key(40.0)];
+            assert_approx_eq!(expected, $quat::average(&rotations), 1.0e-6);
+            let rotations = [-key(60.0), key(20.0), key(40.0)];
+            assert_approx_eq!(-expected, $quat::average(&rotations), 1.0e-6);
+
+            let q = $quat::from_scaled_axis($vec3::new(0.5, -1.0, 2.0));
+            assert_approx_eq!(q, $quat::average(&[q]), 1.0e-6);
+            assert_approx_eq!(q, $quat::average(&[q, -q, q]), 1.0e-6);
+
+            assert_eq!($quat::IDENTITY, $quat::average(&[]));
+        });
+
+        glam_test!(test_weighted_average, {
+            let key = |angle| $quat::from_axis_angle($vec3::X, deg(angle));
+            let average = $quat::weighted_average(&[(key(20.0), 1.0), (key(60.0), 1.0)]);
+            assert_approx_eq!(key(40.0), average, 1.0e-6);
+
+            // Close to slerp for rotations which are near each other
+            let (start, end) = (key(0.0), key(40.0));
+            let average = $quat::weighted_average(&[(start, 3.0), (-end, 1.0)]);
+            assert!(average.angle_between(start.slerp(end, 0.25)) < 1.0e-2);
+
+            // Zero weights are ignored
+            let average = $quat::weighted_average(&[(key(30.0), 1.0), (key(90.0), 0.0)]);
+            assert_approx_eq!(key(30.0), average, 1.0e-6);
+
+            assert_eq!($quat::IDENTITY, $quat::weighted_average(&[]));
+            assert_eq!(
+                $quat::IDENTITY,
+                $quat::weighted_average(&[(key(30.0), 0.0), (key(60.0), 0.0)])
+            );
+            assert_eq!(
+                $quat::IDENTITY,
+                $quat::weighted_average(&[(key(30.0), 1.0), (key(60.0), -2.0)])
+            );
+        });
+
+        glam_test!(test_accumulator, {
+            let key = |angle| $quat::from_axis_angle($vec3::Z, deg(angle));
+            let mut accumulator = $accumulator::new();
+            assert_eq!($accumulator::default(), accumulator);
+            assert_eq!($quat::IDENTITY, accumulator.result());
+
+            accumulator.add(key(20.0), 1.0);
+            accumulator.add(-key(40.0), 1.0);
+            assert_eq!(2.0, accumulator.total_weight());
+            assert_approx_eq!(key(30.0), accumulator.result(), 1.0e-6);
+
+            // Close to the eigenvector method for rotations which are near each other
+            accumulator.add(-key(25.0), 2.0);
+            let average =
+                $quat::weighted_average(&[(key(20.0), 1.0), (key(40.0), 1.0), (key(25.0), 2.0)]);
+            assert_eq!(4.0, accumulator.total_weight());
+            assert!(average.angle_between(accumulator.result()) < 1.0e-3);
+
+            should_glam_assert!({
+                $accumulator::new().add($quat::from_xyzw(0.0, 0.0, 0.0, 2.0), 1.0)
+            });
+        });
     };
 }
 
 mod quat {
     use crate::support::{deg, rad};
     use core::ops::Neg;
-    use glam::{const_quat, quat, EulerRot, Mat3, Mat4, Quat, Vec3, Vec3A, Vec4};
+    use glam::{const_quat, quat, EulerRot, Mat3, Mat4, Quat, QuatAccumulator, Vec3, Vec3A, Vec4};
 
     glam_test!(test_align, {
         use std::mem;
@@ -527,12 +949,13 @@ mod quat {
     });
 
     impl_quat_tests!(f32, const_quat, quat, Mat3, Mat4, Quat, Vec3, Vec4);
+    impl_quat_average_tests!(Quat, Vec3, QuatAccumulator);
 }
 
 mod dquat {
     use crate::support::{deg, rad};
     use core::ops::Neg;
-    use glam::{const_dquat, dquat, DMat3, DMat4, DQuat, DVec3, DVec4, EulerRot};
+    use glam::{const_dquat, dquat, DMat3, DMat4, DQuat, DQuatAccumulator, DVec3, DVec4, EulerRot};
 
     glam_test!(test_align, {
         use std::mem;
@@ -541,4 +964,5 @@ mod dquat {
     });
 
     impl_quat_tests!(f64, const_dquat, dquat, DMat3, DMat4, DQuat, DVec3, DVec4);
+    impl_quat_average_tests!(DQuat, DVec3, DQuatAccumulator);
 }
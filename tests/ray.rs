@@ -0,0 +1,362 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_ray3_tests {
+    ($t:ident, $ray3:ident, $vec3:ident, $quat:ident, $aabb3:ident) => {
+        use core::$t::consts::{FRAC_PI_4, SQRT_2};
+
+        glam_test!(test_ray3_new, {
+            let origin = $vec3::new(1.0, 2.0, 3.0);
+            let ray = $ray3::new(origin, $vec3::Y);
+            assert_eq!(origin, ray.origin);
+            assert_eq!($vec3::Y, ray.direction);
+            assert_eq!(origin, ray.at(0.0));
+            assert_eq!($vec3::new(1.0, 4.5, 3.0), ray.at(2.5));
+            assert_eq!($vec3::new(1.0, 1.0, 3.0), ray.at(-1.0));
+            assert_eq!(
+                format!("{:?}", ray),
+                format!(
+                    "{} {{ origin: {:?}, direction: {:?} }}",
+                    stringify!($ray3),
+                    origin,
+                    $vec3::Y
+                )
+            );
+
+            should_glam_assert!({ $ray3::new(origin, $vec3::ONE) });
+        });
+
+        glam_test!(test_ray3_plane, {
+            // The plane z = 2
+            let normal = $vec3::Z;
+            let d = -2.0;
+            let ray = $ray3::new($vec3::new(1.0, 1.0, -3.0), $vec3::Z);
+            assert_eq!(Some(5.0), ray.intersect_plane(normal, d));
+            // Both sides of the plane are hit
+            assert_eq!(Some(5.0), ray.intersect_plane(-normal, -d));
+            let ray = $ray3::new($vec3::new(0.0, 0.0, 4.0), -$vec3::Z);
+            assert_eq!(Some(2.0), ray.intersect_plane(normal, d));
+            // Behind
+            let ray = $ray3::new($vec3::new(0.0, 0.0, 4.0), $vec3::Z);
+            assert_eq!(None, ray.intersect_plane(normal, d));
+            // Parallel
+            let ray = $ray3::new($vec3::new(0.0, 0.0, 1.0), $vec3::X);
+            assert_eq!(None, ray.intersect_plane(normal, d));
+            // Starting on the plane
+            let ray = $ray3::new($vec3::new(3.0, 0.0, 2.0), $vec3::new(0.6, 0.0, 0.8));
+            assert_eq!(Some(0.0), ray.intersect_plane(normal, d));
+            // Oblique
+            let ray = $ray3::new($vec3::ZERO, $vec3::new(0.6, 0.0, 0.8));
+            assert_approx_eq!(2.5, ray.intersect_plane(normal, d).unwrap(), 1e-6);
+
+            should_glam_assert!({ ray.intersect_plane($vec3::ONE, d) });
+        });
+
+        glam_test!(test_ray3_sphere, {
+            let center = $vec3::new(0.0, 0.0, 5.0);
+            let ray = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_eq!(Some(4.0), ray.intersect_sphere(center, 1.0));
+            // Inside hits the exit point
+            let ray = $ray3::new(center, $vec3::Z);
+            assert_eq!(Some(2.0), ray.intersect_sphere(center, 2.0));
+            // Behind
+            let ray = $ray3::new($vec3::ZERO, -$vec3::Z);
+            assert_eq!(None, ray.intersect_sphere(center, 1.0));
+            // Miss
+            let ray = $ray3::new($vec3::new(1.5, 0.0, 0.0), $vec3::Z);
+            assert_eq!(None, ray.intersect_sphere(center, 1.0));
+            // Tangent
+            let ray = $ray3::new($vec3::new(1.0, 0.0, 0.0), $vec3::Z);
+            assert_eq!(Some(5.0), ray.intersect_sphere(center, 1.0));
+            // Starting on the surface pointing inwards and outwards
+            let ray = $ray3::new($vec3::new(0.0, 0.0, 4.0), $vec3::Z);
+            assert_eq!(Some(2.0), ray.intersect_sphere(center, 1.0));
+            let ray = $ray3::new($vec3::new(0.0, 0.0, 4.0), -$vec3::Z);
+            assert_eq!(Some(0.0), ray.intersect_sphere(center, 1.0));
+            // Far away small spheres remain accurate
+            let ray = $ray3::new($vec3::ZERO, $vec3::X);
+            let far = $vec3::new(10000.0, 0.0, 0.0);
+            assert_approx_eq!(9999.99, ray.intersect_sphere(far, 0.01).unwrap(), 1e-3);
+        });
+
+        glam_test!(test_ray3_aabb, {
+            let aabb = $aabb3::new($vec3::new(1.0, -1.0, -1.0), $vec3::new(3.0, 1.0, 1.0));
+            let ray = $ray3::new($vec3::ZERO, $vec3::X);
+            assert_eq!(Some(1.0), ray.intersect_aabb(&aabb));
+            let ray = $ray3::new($vec3::new(5.0, 0.0, 0.0), -$vec3::X);
+            assert_eq!(Some(2.0), ray.intersect_aabb(&aabb));
+            // Behind
+            let ray = $ray3::new($vec3::new(5.0, 0.0, 0.0), $vec3::X);
+            assert_eq!(None, ray.intersect_aabb(&aabb));
+            // Inside
+            let ray = $ray3::new($vec3::new(2.0, 0.0, 0.0), -$vec3::Y);
+            assert_eq!(Some(0.0), ray.intersect_aabb(&aabb));
+            // Parallel inside and outside the slabs
+            let ray = $ray3::new($vec3::new(0.0, 0.5, 0.5), $vec3::X);
+            assert_eq!(Some(1.0), ray.intersect_aabb(&aabb));
+            let ray = $ray3::new($vec3::new(0.0, 1.5, 0.5), $vec3::X);
+            assert_eq!(None, ray.intersect_aabb(&aabb));
+            // Grazing along a face and an edge
+            let ray = $ray3::new($vec3::new(0.0, 1.0, 0.0), $vec3::X);
+            assert_eq!(Some(1.0), ray.intersect_aabb(&aabb));
+            let ray = $ray3::new($vec3::new(0.0, -1.0, 1.0), $vec3::X);
+            assert_eq!(Some(1.0), ray.intersect_aabb(&aabb));
+            let ray = $ray3::new($vec3::new(4.0, 1.0, -1.0), -$vec3::X);
+            assert_eq!(Some(1.0), ray.intersect_aabb(&aabb));
+            // Just missing an edge
+            let ray = $ray3::new($vec3::new(0.0, 1.001, 0.0), $vec3::X);
+            assert_eq!(None, ray.intersect_aabb(&aabb));
+            // Through a corner
+            let corner = $vec3::new(1.0, 1.0, 1.0);
+            let direction = $vec3::new(1.0, 1.0, 1.0).normalize();
+            let ray = $ray3::new(corner - direction * 2.0, direction);
+            assert_approx_eq!(2.0, ray.intersect_aabb(&aabb).unwrap(), 1e-6);
+            // Oblique with negative direction components
+            let direction = $vec3::new(-2.0, 1.0, -1.0).normalize();
+            let ray = $ray3::new($vec3::new(2.5, 0.0, 0.0) - direction * 3.0, direction);
+            let distance = ray.intersect_aabb(&aabb).unwrap();
+            assert!(distance > 0.0 && distance < 3.0);
+            let hit = ray.at(distance);
+            assert!(aabb.expand(1e-5).contains_point(hit));
+            assert!(!aabb.expand(-1e-3).contains_point(hit));
+            // Degenerate boxes
+            let flat = $aabb3::new($vec3::new(-1.0, -1.0, 2.0), $vec3::new(1.0, 1.0, 2.0));
+            let ray = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_eq!(Some(2.0), ray.intersect_aabb(&flat));
+            let point = $aabb3::new($vec3::X, $vec3::X);
+            let ray = $ray3::new($vec3::ZERO, $vec3::X);
+            assert_eq!(Some(1.0), ray.intersect_aabb(&point));
+        });
+
+        glam_test!(test_ray3_obb, {
+            let center = $vec3::new(5.0, 0.0, 0.0);
+            let half_extents = $vec3::ONE;
+            let rotation = $quat::from_rotation_z(FRAC_PI_4);
+            let ray = $ray3::new($vec3::ZERO, $vec3::X);
+            assert_approx_eq!(
+                5.0 - SQRT_2,
+                ray.intersect_obb(center, half_extents, rotation).unwrap(),
+                1e-5
+            );
+            assert_eq!(
+                Some(4.0),
+                ray.intersect_obb(center, half_extents, $quat::IDENTITY)
+            );
+            // The rotated box is a diamond |x| + |y| <= sqrt(2) in the xy plane
+            let ray = $ray3::new($vec3::new(0.0, 1.2, 0.0), $vec3::X);
+            assert_eq!(
+                None,
+                ray.intersect_obb(center, half_extents, $quat::IDENTITY)
+            );
+            assert_approx_eq!(
+                5.0 - (SQRT_2 - 1.2),
+                ray.intersect_obb(center, half_extents, rotation).unwrap(),
+                1e-5
+            );
+            let ray = $ray3::new($vec3::new(0.0, 1.5, 0.0), $vec3::X);
+            assert_eq!(None, ray.intersect_obb(center, half_extents, rotation));
+            // Inside
+            let ray = $ray3::new(center, $vec3::Y);
+            assert_eq!(Some(0.0), ray.intersect_obb(center, half_extents, rotation));
+
+            should_glam_assert!({
+                ray.intersect_obb(center, half_extents, $quat::from_xyzw(0.0, 0.0, 0.0, 2.0))
+            });
+        });
+
+        glam_test!(test_ray3_triangle, {
+            let v0 = $vec3::new(0.0, 0.0, 2.0);
+            let v1 = $vec3::new(4.0, 0.0, 2.0);
+            let v2 = $vec3::new(0.0, 4.0, 2.0);
+            let cases = [
+                // Interior, from both sides
+                ($vec3::new(1.0, 2.0, 0.0), $vec3::Z, Some((2.0, 0.25, 0.5))),
+                ($vec3::new(1.0, 2.0, 5.0), -$vec3::Z, Some((3.0, 0.25, 0.5))),
+                // Vertices and edges
+                ($vec3::new(0.0, 0.0, 0.0), $vec3::Z, Some((2.0, 0.0, 0.0))),
+                ($vec3::new(4.0, 0.0, 0.0), $vec3::Z, Some((2.0, 1.0, 0.0))),
+                ($vec3::new(0.0, 4.0, 0.0), $vec3::Z, Some((2.0, 0.0, 1.0))),
+                ($vec3::new(2.0, 0.0, 0.0), $vec3::Z, Some((2.0, 0.5, 0.0))),
+                ($vec3::new(2.0, 2.0, 0.0), $vec3::Z, Some((2.0, 0.5, 0.5))),
+                // Outside
+                ($vec3::new(3.0, 3.0, 0.0), $vec3::Z, None),
+                ($vec3::new(-0.1, 1.0, 0.0), $vec3::Z, None),
+                ($vec3::new(1.0, -0.1, 0.0), $vec3::Z, None),
+                // Behind
+                ($vec3::new(1.0, 1.0, 3.0), $vec3::Z, None),
+                // Parallel, in and out of the plane
+                ($vec3::new(-1.0, 1.0, 2.0), $vec3::X, None),
+                ($vec3::new(-1.0, 1.0, 0.0), $vec3::X, None),
+            ];
+            for &(origin, direction, expected) in &cases {
+                let ray = $ray3::new(origin, direction);
+                for &hit in &[
+                    ray.intersect_triangle(v0, v1, v2),
+                    ray.intersect_triangle_watertight(v0, v1, v2),
+                ] {
+                    match (hit, expected) {
+                        (Some(hit), Some((distance, u, v))) => {
+                            assert_approx_eq!(distance, hit.distance, 1e-6);
+                            assert_approx_eq!(u, hit.u, 1e-6);
+                            assert_approx_eq!(v, hit.v, 1e-6);
+                        }
+                        (None, None) => {}
+                        _ => panic!("{:?} {:?} {:?} {:?}", origin, direction, hit, expected),
+                    }
+                }
+            }
+
+            // Barycentrics reproduce the hit point for an oblique ray
+            let direction = $vec3::new(0.3, -0.2, 1.0).normalize();
+            let ray = $ray3::new($vec3::new(0.5, 1.5, -1.0), direction);
+            let a = ray.intersect_triangle(v0, v1, v2).unwrap();
+            let b = ray.intersect_triangle_watertight(v0, v1, v2).unwrap();
+            assert_approx_eq!(a.distance, b.distance, 1e-5);
+            assert_approx_eq!(a.u, b.u, 1e-5);
+            assert_approx_eq!(a.v, b.v, 1e-5);
+            let point = v0 * (1.0 - a.u - a.v) + v1 * a.u + v2 * a.v;
+            assert_approx_eq!(ray.at(a.distance), point, 1e-5);
+            // The winding of the triangle does not matter
+            let c = ray.intersect_triangle_watertight(v0, v2, v1).unwrap();
+            assert_approx_eq!(a.distance, c.distance, 1e-5);
+            assert_approx_eq!(a.u, c.v, 1e-5);
+            assert_approx_eq!(a.v, c.u, 1e-5);
+
+            // Small triangles are hit the same by both algorithms
+            let (w0, w1, w2) = (
+                $vec3::new(0.0, 0.0, 1.0),
+                $vec3::new(1e-4, 0.0, 1.0),
+                $vec3::new(0.0, 1e-4, 1.0),
+            );
+            let ray = $ray3::new($vec3::new(2e-5, 2e-5, 0.0), $vec3::Z);
+            for &hit in &[
+                ray.intersect_triangle(w0, w1, w2),
+                ray.intersect_triangle_watertight(w0, w1, w2),
+            ] {
+                let hit = hit.unwrap();
+                assert_approx_eq!(1.0, hit.distance, 1e-6);
+                assert_approx_eq!(0.2, hit.u, 1e-5);
+                assert_approx_eq!(0.2, hit.v, 1e-5);
+            }
+
+            // Degenerate triangles are never hit
+            let ray = $ray3::new($vec3::ZERO, $vec3::Z);
+            assert_eq!(None, ray.intersect_triangle(v0, v0, v2));
+            assert_eq!(None, ray.intersect_triangle_watertight(v0, v0, v2));
+        });
+
+        glam_test!(test_ray3_triangle_watertight_shared_edge, {
+            // Two triangles forming a quad with a diagonal shared edge, rays through points on the
+            // shared edge must hit at least one of them.
+            let a = $vec3::new(-1.3, -0.7, 0.1);
+            let b = $vec3::new(0.9, -1.1, 0.3);
+            let c = $vec3::new(1.7, 1.3, -0.2);
+            let d = $vec3::new(-0.9, 0.8, 0.4);
+            let direction = $vec3::new(0.13, -0.27, -1.0).normalize();
+            for i in 1..1000 {
+                let s = i as $t / 1000.0;
+                let on_edge = a.lerp(c, s);
+                let ray = $ray3::new(on_edge - direction * 3.7, direction);
+                let hit1 = ray.intersect_triangle_watertight(a, b, c);
+                let hit2 = ray.intersect_triangle_watertight(a, c, d);
+                assert!(hit1.is_some() || hit2.is_some(), "{}", s);
+            }
+        });
+    };
+}
+
+mod ray2 {
+    use glam::{Aabb2, Ray2, Vec2};
+
+    glam_test!(test_ray2, {
+        let ray = Ray2::new(Vec2::new(1.0, 2.0), Vec2::X);
+        assert_eq!(Vec2::new(3.0, 2.0), ray.at(2.0));
+        assert_eq!(
+            format!("{:?}", ray),
+            format!(
+                "Ray2 {{ origin: {:?}, direction: {:?} }}",
+                ray.origin, ray.direction
+            )
+        );
+
+        should_glam_assert!({ Ray2::new(Vec2::ZERO, Vec2::ONE) });
+    });
+
+    glam_test!(test_ray2_line, {
+        // The line x = 4
+        let ray = Ray2::new(Vec2::new(1.0, 2.0), Vec2::X);
+        assert_eq!(Some(3.0), ray.intersect_plane(Vec2::X, -4.0));
+        assert_eq!(Some(3.0), ray.intersect_plane(-Vec2::X, 4.0));
+        assert_eq!(None, ray.intersect_plane(Vec2::X, 4.0));
+        assert_eq!(None, ray.intersect_plane(Vec2::Y, 4.0));
+    });
+
+    glam_test!(test_ray2_circle, {
+        let ray = Ray2::new(Vec2::ZERO, Vec2::Y);
+        assert_eq!(Some(2.0), ray.intersect_circle(Vec2::new(0.0, 3.0), 1.0));
+        assert_eq!(Some(1.0), ray.intersect_circle(Vec2::ZERO, 1.0));
+        assert_eq!(Some(3.0), ray.intersect_circle(Vec2::new(1.0, 3.0), 1.0));
+        assert_eq!(None, ray.intersect_circle(Vec2::new(2.0, 3.0), 1.0));
+        assert_eq!(None, ray.intersect_circle(Vec2::new(0.0, -3.0), 1.0));
+    });
+
+    glam_test!(test_ray2_aabb, {
+        let aabb = Aabb2::new(Vec2::new(1.0, -1.0), Vec2::new(2.0, 1.0));
+        let ray = Ray2::new(Vec2::ZERO, Vec2::X);
+        assert_eq!(Some(1.0), ray.intersect_aabb(&aabb));
+        assert_eq!(None, Ray2::new(Vec2::ZERO, -Vec2::X).intersect_aabb(&aabb));
+        assert_eq!(None, Ray2::new(Vec2::ZERO, Vec2::Y).intersect_aabb(&aabb));
+        assert_eq!(
+            Some(0.0),
+            Ray2::new(Vec2::new(1.5, 0.0), Vec2::Y).intersect_aabb(&aabb)
+        );
+        // Grazing along an edge
+        assert_eq!(
+            Some(1.0),
+            Ray2::new(Vec2::new(0.0, 1.0), Vec2::X).intersect_aabb(&aabb)
+        );
+        assert_eq!(
+            Some(2.0),
+            Ray2::new(Vec2::new(2.0, 3.0), -Vec2::Y).intersect_aabb(&aabb)
+        );
+        assert_eq!(
+            None,
+            Ray2::new(Vec2::new(2.5, 3.0), -Vec2::Y).intersect_aabb(&aabb)
+        );
+    });
+}
+
+mod ray3 {
+    use glam::{Aabb3, Aabb3A, Quat, Ray3, Vec3};
+
+    glam_test!(test_ray3_aabb3a, {
+        let aabb = Aabb3::new(Vec3::new(1.0, -1.0, -1.0), Vec3::new(3.0, 1.0, 1.0));
+        let aabb3a = Aabb3A::from(aabb);
+        let origins = [
+            Vec3::ZERO,
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(-2.0, 3.0, 0.5),
+        ];
+        let directions = [
+            Vec3::X,
+            -Vec3::Y,
+            Vec3::new(1.0, -1.0, 0.0).normalize(),
+            Vec3::new(0.8, -0.6, 0.0),
+        ];
+        for &origin in &origins {
+            for &direction in &directions {
+                let ray = Ray3::new(origin, direction);
+                assert_eq!(ray.intersect_aabb(&aabb), ray.intersect_aabb3a(&aabb3a));
+            }
+        }
+    });
+
+    impl_ray3_tests!(f32, Ray3, Vec3, Quat, Aabb3);
+}
+
+mod dray3 {
+    use glam::{DAabb3, DQuat, DRay3, DVec3};
+
+    impl_ray3_tests!(f64, DRay3, DVec3, DQuat, DAabb3);
+}
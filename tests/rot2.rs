@@ -0,0 +1,216 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_rot2_tests {
+    ($t:ident, $rot2:ident, $vec2:ident, $mat2:ident, $mat3:ident, $affine2:ident) => {
+        use core::$t::consts::{FRAC_PI_2, PI};
+
+        glam_test!(test_rot2_identity, {
+            assert_eq!($rot2::IDENTITY, $rot2::default());
+            assert_eq!($rot2::IDENTITY, $rot2::from_angle(0.0));
+            assert_eq!($rot2::IDENTITY, $rot2::IDENTITY * $rot2::IDENTITY);
+            assert_eq!(0.0, $rot2::IDENTITY.to_angle());
+            let v = $vec2::new(1.0, 2.0);
+            assert_eq!(v, $rot2::IDENTITY * v);
+        });
+
+        glam_test!(test_rot2_nan, {
+            assert!($rot2::NAN.is_nan());
+            assert!(!$rot2::NAN.is_finite());
+            assert!($rot2::IDENTITY.is_finite());
+            assert!(!$rot2::IDENTITY.is_nan());
+        });
+
+        glam_test!(test_rot2_fmt, {
+            let r = $rot2::IDENTITY;
+            assert_eq!(
+                format!("{:?}", r),
+                format!("{} {{ cos: 1.0, sin: 0.0 }}", stringify!($rot2))
+            );
+            assert_eq!(format!("{}", r), "[1, 0]");
+        });
+
+        glam_test!(test_rot2_angle, {
+            for &angle in &[-3.0, -FRAC_PI_2, -0.5, 0.25, 1.0, FRAC_PI_2, 3.0] {
+                let r = $rot2::from_angle(angle);
+                assert!(r.is_normalized());
+                assert_approx_eq!(angle, r.to_angle(), 1.0e-6);
+            }
+            // Angles are wrapped to [-π, π]
+            assert_approx_eq!(
+                -FRAC_PI_2,
+                $rot2::from_angle(3.0 * FRAC_PI_2).to_angle(),
+                1.0e-6
+            );
+            assert_approx_eq!(PI, $rot2::from_angle(PI).to_angle().abs(), 1.0e-6);
+
+            let r = $rot2::from_sin_cos(0.6, 0.8);
+            assert_eq!(0.8, r.cos);
+            assert_eq!(0.6, r.sin);
+            assert_eq!($vec2::new(0.8, 0.6), $vec2::from(r));
+        });
+
+        glam_test!(test_rot2_mul, {
+            let a = $rot2::from_angle(0.5);
+            let b = $rot2::from_angle(-1.25);
+            assert_approx_eq!(-0.75, (a * b).to_angle(), 1.0e-6);
+            assert_eq!(a * b, b * a);
+            let mut c = a;
+            c *= b;
+            assert_eq!(a * b, c);
+            assert_eq!(a * b, [a, b].iter().product());
+
+            let v = $vec2::new(3.0, -4.0);
+            assert_approx_eq!($vec2::Y, $rot2::from_angle(FRAC_PI_2) * $vec2::X, 1.0e-6);
+            assert_approx_eq!($mat2::from_angle(0.5) * v, a * v, 1.0e-6);
+            assert_approx_eq!(a * v, a.mul_vec2(v));
+            assert_approx_eq!(a * (b * v), (a * b) * v, 1.0e-6);
+
+            should_glam_assert!({ $rot2::from_sin_cos(0.0, 2.0) * $vec2::X });
+            should_glam_assert!({ $rot2::from_sin_cos(0.0, 2.0) * a });
+
+            // Rounding error from repeated composition stays within the normalized threshold
+            let step = $rot2::from_angle(0.1);
+            let mut r = $rot2::IDENTITY;
+            for _ in 0..100 {
+                r *= step;
+            }
+            assert!(r.is_normalized());
+        });
+
+        glam_test!(test_rot2_inverse, {
+            let r = $rot2::from_angle(1.0);
+            assert_approx_eq!(-1.0, r.inverse().to_angle(), 1.0e-6);
+            assert_approx_eq!($rot2::IDENTITY, r * r.inverse(), 1.0e-6);
+            let v = $vec2::new(3.0, -4.0);
+            assert_approx_eq!(v, r.inverse() * (r * v), 1.0e-6);
+
+            should_glam_assert!({ $rot2::from_sin_cos(0.0, 2.0).inverse() });
+        });
+
+        glam_test!(test_rot2_normalize, {
+            let r = $rot2::from_sin_cos(3.0, 4.0);
+            assert!(!r.is_normalized());
+            assert_eq!(5.0, r.length());
+            assert_eq!(25.0, r.length_squared());
+            assert_approx_eq!($rot2::from_sin_cos(0.6, 0.8), r.normalize(), 1.0e-6);
+            assert!(r.normalize().is_normalized());
+
+            should_glam_assert!({ $rot2::from_sin_cos(0.0, 0.0).normalize() });
+        });
+
+        glam_test!(test_rot2_from_rotation_arc, {
+            let from = $vec2::X;
+            let to = $vec2::new(-1.0, 1.0).normalize();
+            let r = $rot2::from_rotation_arc(from, to);
+            assert!(r.is_normalized());
+            assert_approx_eq!(3.0 * PI / 4.0, r.to_angle(), 1.0e-6);
+            assert_approx_eq!(to, r * from, 1.0e-6);
+            assert_approx_eq!(
+                -FRAC_PI_2,
+                $rot2::from_rotation_arc($vec2::Y, $vec2::X).to_angle(),
+                1.0e-6
+            );
+            assert_eq!($rot2::IDENTITY, $rot2::from_rotation_arc(from, from));
+
+            should_glam_assert!({ $rot2::from_rotation_arc($vec2::ZERO, $vec2::X) });
+            should_glam_assert!({ $rot2::from_rotation_arc($vec2::X, $vec2::ZERO) });
+        });
+
+        glam_test!(test_rot2_interpolation, {
+            let start = $rot2::from_angle(0.0);
+            let end = $rot2::from_angle(2.0);
+            assert_approx_eq!(start, start.slerp(end, 0.0), 1.0e-6);
+            assert_approx_eq!(end, start.slerp(end, 1.0), 1.0e-6);
+            assert_approx_eq!($rot2::from_angle(0.5), start.slerp(end, 0.25), 1.0e-6);
+            assert_approx_eq!($rot2::from_angle(1.0), start.nlerp(end, 0.5), 1.0e-6);
+            assert_approx_eq!(start, start.nlerp(end, 0.0), 1.0e-6);
+            assert_approx_eq!(end, start.nlerp(end, 1.0), 1.0e-6);
+            // nlerp does not rotate at a constant rate
+            assert!(start.nlerp(end, 0.25).to_angle() < 0.49);
+
+            // The shortest path is taken across the -π to π boundary
+            let start = $rot2::from_angle(3.0);
+            let end = $rot2::from_angle(-3.0);
+            assert_approx_eq!($rot2::from_angle(PI), start.slerp(end, 0.5), 1.0e-6);
+            assert_approx_eq!($rot2::from_angle(PI), start.nlerp(end, 0.5), 1.0e-6);
+            assert_approx_eq!(2.0 * PI - 6.0, start.angle_between(end), 1.0e-6);
+            assert_approx_eq!(
+                1.0,
+                $rot2::IDENTITY.angle_between($rot2::from_angle(-1.0)),
+                1.0e-6
+            );
+        });
+
+        glam_test!(test_rot2_matrix, {
+            let angle = 0.75;
+            let r = $rot2::from_angle(angle);
+            assert_approx_eq!($mat2::from_angle(angle), $mat2::from_rot2(r), 1.0e-6);
+            assert_approx_eq!($mat3::from_angle(angle), $mat3::from_rot2(r), 1.0e-6);
+            assert!($affine2::from_angle(angle).abs_diff_eq($affine2::from_rot2(r), 1.0e-6));
+            let translation = $vec2::new(1.0, -2.0);
+            assert!($affine2::from_angle_translation(angle, translation)
+                .abs_diff_eq($affine2::from_rot2_translation(r, translation), 1.0e-6));
+
+            assert_approx_eq!(r, $rot2::from_mat2(&$mat2::from_rot2(r)), 1.0e-6);
+            let scaled = $mat2::from_scale_angle($vec2::new(2.0, 2.0), angle);
+            assert_approx_eq!(r, $rot2::from_mat2(&scaled), 1.0e-6);
+
+            should_glam_assert!({ $mat2::from_rot2($rot2::from_sin_cos(0.0, 2.0)) });
+            should_glam_assert!({ $rot2::from_mat2(&$mat2::ZERO) });
+        });
+    };
+}
+
+mod rot2 {
+    use super::support::FloatCompare;
+    use glam::{Affine2, Mat2, Mat3, Mat3A, Rot2, Vec2};
+
+    impl FloatCompare for Rot2 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self::from_sin_cos((self.sin - other.sin).abs(), (self.cos - other.cos).abs())
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(8, mem::size_of::<Rot2>());
+        assert_eq!(mem::align_of::<f32>(), mem::align_of::<Rot2>());
+    });
+
+    glam_test!(test_mat3a, {
+        let r = Rot2::from_angle(0.75);
+        assert_approx_eq!(Mat3A::from_angle(0.75), Mat3A::from_rot2(r), 1.0e-6);
+    });
+
+    impl_rot2_tests!(f32, Rot2, Vec2, Mat2, Mat3, Affine2);
+}
+
+mod drot2 {
+    use super::support::FloatCompare;
+    use glam::{DAffine2, DMat2, DMat3, DRot2, DVec2};
+
+    impl FloatCompare for DRot2 {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff as f64)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self::from_sin_cos((self.sin - other.sin).abs(), (self.cos - other.cos).abs())
+        }
+    }
+
+    glam_test!(test_align, {
+        use std::mem;
+        assert_eq!(16, mem::size_of::<DRot2>());
+        assert_eq!(mem::align_of::<f64>(), mem::align_of::<DRot2>());
+    });
+
+    impl_rot2_tests!(f64, DRot2, DVec2, DMat2, DMat3, DAffine2);
+}
@@ -0,0 +1,214 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_sphere_tests {
+    ($t:ident, $sphere:ident, $vec3:ident) => {
+        // A deterministic cloud of points in an ellipsoidal shell around `(1, -2, 3)`.
+        fn point_cloud(count: usize) -> Vec<$vec3> {
+            (0..count)
+                .map(|i| {
+                    let i = i as $t;
+                    let direction =
+                        $vec3::new((i * 1.7).sin(), (i * 2.3).cos(), (i * 0.9).sin()).normalize();
+                    let radius = 2.0 + (i * 3.1).sin();
+                    $vec3::new(1.0, -2.0, 3.0) + direction * $vec3::new(3.0, 1.0, 2.0) * radius
+                })
+                .collect()
+        }
+
+        fn assert_encloses(sphere: $sphere, points: &[$vec3]) {
+            for &point in points {
+                assert!(sphere.center.distance(point) <= sphere.radius * (1.0 + 1e-5));
+            }
+        }
+
+        glam_test!(test_sphere_new, {
+            let sphere = $sphere::new($vec3::new(1.0, 2.0, 3.0), 4.0);
+            assert_eq!($vec3::new(1.0, 2.0, 3.0), sphere.center);
+            assert_eq!(4.0, sphere.radius);
+            assert!(sphere.is_finite());
+            assert!(!$sphere::new($vec3::ZERO, $t::INFINITY).is_finite());
+            assert!(sphere.abs_diff_eq($sphere::new($vec3::new(1.0, 2.0, 3.1), 3.9), 0.11));
+            assert!(!sphere.abs_diff_eq($sphere::new($vec3::new(1.0, 2.0, 3.0), 3.8), 0.11));
+            assert_eq!(
+                format!("{:?}", sphere),
+                format!(
+                    "{} {{ center: {:?}, radius: {:?} }}",
+                    stringify!($sphere),
+                    sphere.center,
+                    sphere.radius
+                )
+            );
+            assert_eq!(format!("{}", sphere), "[[1, 2, 3], 4]");
+
+            should_glam_assert!({ $sphere::new($vec3::ZERO, -1.0) });
+        });
+
+        glam_test!(test_sphere_queries, {
+            let sphere = $sphere::new($vec3::new(1.0, 0.0, 0.0), 2.0);
+            assert!(sphere.contains_point($vec3::new(3.0, 0.0, 0.0)));
+            assert!(sphere.contains_point($vec3::new(0.0, 1.0, 1.0)));
+            assert!(!sphere.contains_point($vec3::new(3.0, 0.1, 0.0)));
+
+            assert!(sphere.contains_sphere($sphere::new($vec3::new(2.0, 0.0, 0.0), 1.0)));
+            assert!(sphere.contains_sphere(sphere));
+            assert!(!sphere.contains_sphere($sphere::new($vec3::new(2.0, 0.0, 0.0), 1.1)));
+            assert!(!$sphere::new($vec3::ZERO, 1.0).contains_sphere(sphere));
+
+            assert!(sphere.intersects_sphere($sphere::new($vec3::new(4.0, 0.0, 0.0), 1.0)));
+            assert!(!sphere.intersects_sphere($sphere::new($vec3::new(4.0, 0.0, 0.0), 0.9)));
+            assert!(sphere.intersects_sphere($sphere::new($vec3::new(1.0, 0.0, 0.0), 0.1)));
+
+            assert_eq!($vec3::ZERO, sphere.closest_point($vec3::ZERO));
+            assert_approx_eq!(
+                $vec3::new(1.0, 0.0, -2.0),
+                sphere.closest_point($vec3::new(1.0, 0.0, -5.0)),
+                1e-6
+            );
+
+            assert_approx_eq!(32.0 / 3.0 * core::$t::consts::PI, sphere.volume(), 1e-5);
+            assert_approx_eq!(16.0 * core::$t::consts::PI, sphere.surface_area(), 1e-5);
+        });
+
+        glam_test!(test_sphere_grow, {
+            let sphere = $sphere::new($vec3::ZERO, 1.0);
+            assert_eq!(sphere, sphere.grow($vec3::new(0.0, 0.5, 0.0)));
+            let grown = sphere.grow($vec3::new(3.0, 0.0, 0.0));
+            assert_approx_eq!($sphere::new($vec3::new(1.0, 0.0, 0.0), 2.0), grown, 1e-6);
+            assert!(grown.contains_sphere(sphere));
+
+            let point = $sphere::new($vec3::new(1.0, 1.0, 1.0), 0.0);
+            assert_approx_eq!(
+                $sphere::new($vec3::new(1.0, 1.0, 2.0), 1.0),
+                point.grow($vec3::new(1.0, 1.0, 3.0)),
+                1e-6
+            );
+        });
+
+        glam_test!(test_sphere_from_points_ritter, {
+            let sphere = $sphere::from_points_ritter(&[$vec3::new(1.0, 2.0, 3.0)]);
+            assert_eq!($sphere::new($vec3::new(1.0, 2.0, 3.0), 0.0), sphere);
+
+            let points = [$vec3::new(-1.0, 0.0, 0.0), $vec3::new(3.0, 0.0, 0.0)];
+            let sphere = $sphere::from_points_ritter(&points);
+            assert_approx_eq!($sphere::new($vec3::new(1.0, 0.0, 0.0), 2.0), sphere, 1e-6);
+
+            let points = point_cloud(200);
+            let sphere = $sphere::from_points_ritter(&points);
+            assert_encloses(sphere, &points);
+            let minimal = $sphere::from_points_welzl(&points);
+            assert!(sphere.radius >= minimal.radius);
+            assert!(sphere.radius <= minimal.radius * 1.2);
+
+            should_panic!({ $sphere::from_points_ritter(&[]) });
+        });
+
+        glam_test!(test_sphere_from_points_welzl, {
+            let sphere = $sphere::from_points_welzl(&[$vec3::new(1.0, 2.0, 3.0)]);
+            assert_eq!($sphere::new($vec3::new(1.0, 2.0, 3.0), 0.0), sphere);
+
+            // Repeated points
+            let points = [$vec3::ONE; 4];
+            assert_eq!(
+                $sphere::new($vec3::ONE, 0.0),
+                $sphere::from_points_welzl(&points)
+            );
+
+            // Colinear points are spanned by the outermost pair
+            let points = [
+                $vec3::new(0.0, 1.0, 0.0),
+                $vec3::new(0.0, -3.0, 0.0),
+                $vec3::new(0.0, 2.0, 0.0),
+                $vec3::new(0.0, 5.0, 0.0),
+            ];
+            let sphere = $sphere::from_points_welzl(&points);
+            assert_approx_eq!($sphere::new($vec3::new(0.0, 1.0, 0.0), 4.0), sphere, 1e-6);
+
+            // Coplanar points on a circle with one inside it
+            let points = [
+                $vec3::new(1.0, 0.0, 2.0),
+                $vec3::new(0.0, 1.0, 2.0),
+                $vec3::new(-1.0, 0.0, 2.0),
+                $vec3::new(0.0, -1.0, 2.0),
+                $vec3::new(0.1, 0.2, 2.0),
+            ];
+            let sphere = $sphere::from_points_welzl(&points);
+            assert_approx_eq!($sphere::new($vec3::new(0.0, 0.0, 2.0), 1.0), sphere, 1e-6);
+
+            // An obtuse triangle is spanned by its longest edge
+            let points = [
+                $vec3::new(-2.0, 0.0, 0.0),
+                $vec3::new(2.0, 0.0, 0.0),
+                $vec3::new(0.0, 0.5, 0.0),
+            ];
+            let sphere = $sphere::from_points_welzl(&points);
+            assert_approx_eq!($sphere::new($vec3::ZERO, 2.0), sphere, 1e-6);
+
+            // A regular tetrahedron is bounded by its circumsphere
+            let points = [
+                $vec3::new(1.0, 1.0, 1.0),
+                $vec3::new(1.0, -1.0, -1.0),
+                $vec3::new(-1.0, 1.0, -1.0),
+                $vec3::new(-1.0, -1.0, 1.0),
+            ];
+            let sphere = $sphere::from_points_welzl(&points);
+            assert_approx_eq!($sphere::new($vec3::ZERO, (3.0 as $t).sqrt()), sphere, 1e-6);
+
+            // The minimal sphere is unique so the order of the points doesn't matter
+            let mut points = point_cloud(200);
+            let sphere = $sphere::from_points_welzl(&points);
+            assert_encloses(sphere, &points);
+            points.reverse();
+            assert_approx_eq!(sphere, $sphere::from_points_welzl(&points), 1e-4);
+            points.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+            assert_approx_eq!(sphere, $sphere::from_points_welzl(&points), 1e-4);
+            // and is tight, with points on its boundary
+            let shrunk = $sphere::new(sphere.center, sphere.radius * 0.999);
+            assert!(points.iter().any(|&point| !shrunk.contains_point(point)));
+
+            should_panic!({ $sphere::from_points_welzl(&[]) });
+        });
+    };
+}
+
+mod sphere {
+    use super::support::FloatCompare;
+    use glam::{Sphere, Vec3};
+
+    impl FloatCompare for Sphere {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                center: (self.center - other.center).abs(),
+                radius: (self.radius - other.radius).abs(),
+            }
+        }
+    }
+
+    impl_sphere_tests!(f32, Sphere, Vec3);
+}
+
+mod dsphere {
+    use super::support::FloatCompare;
+    use glam::{DSphere, DVec3};
+
+    impl FloatCompare for DSphere {
+        #[inline]
+        fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+            self.abs_diff_eq(*other, max_abs_diff as f64)
+        }
+        #[inline]
+        fn abs_diff(&self, other: &Self) -> Self {
+            Self {
+                center: (self.center - other.center).abs(),
+                radius: (self.radius - other.radius).abs(),
+            }
+        }
+    }
+
+    impl_sphere_tests!(f64, DSphere, DVec3);
+}
@@ -0,0 +1,497 @@
+// Generated by swizzlegen. Do not edit.
+#[macro_use]
+mod support;
+use glam::*;
+
+glam_test!(test_i64vec4_swizzles, {
+    let v = i64vec4(1_i64, 2_i64, 3_i64, 4_i64);
+    assert_eq!(v, v.xyzw());
+    assert_eq!(v.xxxx(), i64vec4(1_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.xxxy(), i64vec4(1_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.xxxz(), i64vec4(1_i64, 1_i64, 1_i64, 3_i64));
+    assert_eq!(v.xxxw(), i64vec4(1_i64, 1_i64, 1_i64, 4_i64));
+    assert_eq!(v.xxyx(), i64vec4(1_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.xxyy(), i64vec4(1_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.xxyz(), i64vec4(1_i64, 1_i64, 2_i64, 3_i64));
+    assert_eq!(v.xxyw(), i64vec4(1_i64, 1_i64, 2_i64, 4_i64));
+    assert_eq!(v.xxzx(), i64vec4(1_i64, 1_i64, 3_i64, 1_i64));
+    assert_eq!(v.xxzy(), i64vec4(1_i64, 1_i64, 3_i64, 2_i64));
+    assert_eq!(v.xxzz(), i64vec4(1_i64, 1_i64, 3_i64, 3_i64));
+    assert_eq!(v.xxzw(), i64vec4(1_i64, 1_i64, 3_i64, 4_i64));
+    assert_eq!(v.xxwx(), i64vec4(1_i64, 1_i64, 4_i64, 1_i64));
+    assert_eq!(v.xxwy(), i64vec4(1_i64, 1_i64, 4_i64, 2_i64));
+    assert_eq!(v.xxwz(), i64vec4(1_i64, 1_i64, 4_i64, 3_i64));
+    assert_eq!(v.xxww(), i64vec4(1_i64, 1_i64, 4_i64, 4_i64));
+    assert_eq!(v.xyxx(), i64vec4(1_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.xyxy(), i64vec4(1_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.xyxz(), i64vec4(1_i64, 2_i64, 1_i64, 3_i64));
+    assert_eq!(v.xyxw(), i64vec4(1_i64, 2_i64, 1_i64, 4_i64));
+    assert_eq!(v.xyyx(), i64vec4(1_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.xyyy(), i64vec4(1_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.xyyz(), i64vec4(1_i64, 2_i64, 2_i64, 3_i64));
+    assert_eq!(v.xyyw(), i64vec4(1_i64, 2_i64, 2_i64, 4_i64));
+    assert_eq!(v.xyzx(), i64vec4(1_i64, 2_i64, 3_i64, 1_i64));
+    assert_eq!(v.xyzy(), i64vec4(1_i64, 2_i64, 3_i64, 2_i64));
+    assert_eq!(v.xyzz(), i64vec4(1_i64, 2_i64, 3_i64, 3_i64));
+    assert_eq!(v.xywx(), i64vec4(1_i64, 2_i64, 4_i64, 1_i64));
+    assert_eq!(v.xywy(), i64vec4(1_i64, 2_i64, 4_i64, 2_i64));
+    assert_eq!(v.xywz(), i64vec4(1_i64, 2_i64, 4_i64, 3_i64));
+    assert_eq!(v.xyww(), i64vec4(1_i64, 2_i64, 4_i64, 4_i64));
+    assert_eq!(v.xzxx(), i64vec4(1_i64, 3_i64, 1_i64, 1_i64));
+    assert_eq!(v.xzxy(), i64vec4(1_i64, 3_i64, 1_i64, 2_i64));
+    assert_eq!(v.xzxz(), i64vec4(1_i64, 3_i64, 1_i64, 3_i64));
+    assert_eq!(v.xzxw(), i64vec4(1_i64, 3_i64, 1_i64, 4_i64));
+    assert_eq!(v.xzyx(), i64vec4(1_i64, 3_i64, 2_i64, 1_i64));
+    assert_eq!(v.xzyy(), i64vec4(1_i64, 3_i64, 2_i64, 2_i64));
+    assert_eq!(v.xzyz(), i64vec4(1_i64, 3_i64, 2_i64, 3_i64));
+    assert_eq!(v.xzyw(), i64vec4(1_i64, 3_i64, 2_i64, 4_i64));
+    assert_eq!(v.xzzx(), i64vec4(1_i64, 3_i64, 3_i64, 1_i64));
+    assert_eq!(v.xzzy(), i64vec4(1_i64, 3_i64, 3_i64, 2_i64));
+    assert_eq!(v.xzzz(), i64vec4(1_i64, 3_i64, 3_i64, 3_i64));
+    assert_eq!(v.xzzw(), i64vec4(1_i64, 3_i64, 3_i64, 4_i64));
+    assert_eq!(v.xzwx(), i64vec4(1_i64, 3_i64, 4_i64, 1_i64));
+    assert_eq!(v.xzwy(), i64vec4(1_i64, 3_i64, 4_i64, 2_i64));
+    assert_eq!(v.xzwz(), i64vec4(1_i64, 3_i64, 4_i64, 3_i64));
+    assert_eq!(v.xzww(), i64vec4(1_i64, 3_i64, 4_i64, 4_i64));
+    assert_eq!(v.xwxx(), i64vec4(1_i64, 4_i64, 1_i64, 1_i64));
+    assert_eq!(v.xwxy(), i64vec4(1_i64, 4_i64, 1_i64, 2_i64));
+    assert_eq!(v.xwxz(), i64vec4(1_i64, 4_i64, 1_i64, 3_i64));
+    assert_eq!(v.xwxw(), i64vec4(1_i64, 4_i64, 1_i64, 4_i64));
+    assert_eq!(v.xwyx(), i64vec4(1_i64, 4_i64, 2_i64, 1_i64));
+    assert_eq!(v.xwyy(), i64vec4(1_i64, 4_i64, 2_i64, 2_i64));
+    assert_eq!(v.xwyz(), i64vec4(1_i64, 4_i64, 2_i64, 3_i64));
+    assert_eq!(v.xwyw(), i64vec4(1_i64, 4_i64, 2_i64, 4_i64));
+    assert_eq!(v.xwzx(), i64vec4(1_i64, 4_i64, 3_i64, 1_i64));
+    assert_eq!(v.xwzy(), i64vec4(1_i64, 4_i64, 3_i64, 2_i64));
+    assert_eq!(v.xwzz(), i64vec4(1_i64, 4_i64, 3_i64, 3_i64));
+    assert_eq!(v.xwzw(), i64vec4(1_i64, 4_i64, 3_i64, 4_i64));
+    assert_eq!(v.xwwx(), i64vec4(1_i64, 4_i64, 4_i64, 1_i64));
+    assert_eq!(v.xwwy(), i64vec4(1_i64, 4_i64, 4_i64, 2_i64));
+    assert_eq!(v.xwwz(), i64vec4(1_i64, 4_i64, 4_i64, 3_i64));
+    assert_eq!(v.xwww(), i64vec4(1_i64, 4_i64, 4_i64, 4_i64));
+    assert_eq!(v.yxxx(), i64vec4(2_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.yxxy(), i64vec4(2_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.yxxz(), i64vec4(2_i64, 1_i64, 1_i64, 3_i64));
+    assert_eq!(v.yxxw(), i64vec4(2_i64, 1_i64, 1_i64, 4_i64));
+    assert_eq!(v.yxyx(), i64vec4(2_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.yxyy(), i64vec4(2_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.yxyz(), i64vec4(2_i64, 1_i64, 2_i64, 3_i64));
+    assert_eq!(v.yxyw(), i64vec4(2_i64, 1_i64, 2_i64, 4_i64));
+    assert_eq!(v.yxzx(), i64vec4(2_i64, 1_i64, 3_i64, 1_i64));
+    assert_eq!(v.yxzy(), i64vec4(2_i64, 1_i64, 3_i64, 2_i64));
+    assert_eq!(v.yxzz(), i64vec4(2_i64, 1_i64, 3_i64, 3_i64));
+    assert_eq!(v.yxzw(), i64vec4(2_i64, 1_i64, 3_i64, 4_i64));
+    assert_eq!(v.yxwx(), i64vec4(2_i64, 1_i64, 4_i64, 1_i64));
+    assert_eq!(v.yxwy(), i64vec4(2_i64, 1_i64, 4_i64, 2_i64));
+    assert_eq!(v.yxwz(), i64vec4(2_i64, 1_i64, 4_i64, 3_i64));
+    assert_eq!(v.yxww(), i64vec4(2_i64, 1_i64, 4_i64, 4_i64));
+    assert_eq!(v.yyxx(), i64vec4(2_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.yyxy(), i64vec4(2_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.yyxz(), i64vec4(2_i64, 2_i64, 1_i64, 3_i64));
+    assert_eq!(v.yyxw(), i64vec4(2_i64, 2_i64, 1_i64, 4_i64));
+    assert_eq!(v.yyyx(), i64vec4(2_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.yyyy(), i64vec4(2_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.yyyz(), i64vec4(2_i64, 2_i64, 2_i64, 3_i64));
+    assert_eq!(v.yyyw(), i64vec4(2_i64, 2_i64, 2_i64, 4_i64));
+    assert_eq!(v.yyzx(), i64vec4(2_i64, 2_i64, 3_i64, 1_i64));
+    assert_eq!(v.yyzy(), i64vec4(2_i64, 2_i64, 3_i64, 2_i64));
+    assert_eq!(v.yyzz(), i64vec4(2_i64, 2_i64, 3_i64, 3_i64));
+    assert_eq!(v.yyzw(), i64vec4(2_i64, 2_i64, 3_i64, 4_i64));
+    assert_eq!(v.yywx(), i64vec4(2_i64, 2_i64, 4_i64, 1_i64));
+    assert_eq!(v.yywy(), i64vec4(2_i64, 2_i64, 4_i64, 2_i64));
+    assert_eq!(v.yywz(), i64vec4(2_i64, 2_i64, 4_i64, 3_i64));
+    assert_eq!(v.yyww(), i64vec4(2_i64, 2_i64, 4_i64, 4_i64));
+    assert_eq!(v.yzxx(), i64vec4(2_i64, 3_i64, 1_i64, 1_i64));
+    assert_eq!(v.yzxy(), i64vec4(2_i64, 3_i64, 1_i64, 2_i64));
+    assert_eq!(v.yzxz(), i64vec4(2_i64, 3_i64, 1_i64, 3_i64));
+    assert_eq!(v.yzxw(), i64vec4(2_i64, 3_i64, 1_i64, 4_i64));
+    assert_eq!(v.yzyx(), i64vec4(2_i64, 3_i64, 2_i64, 1_i64));
+    assert_eq!(v.yzyy(), i64vec4(2_i64, 3_i64, 2_i64, 2_i64));
+    assert_eq!(v.yzyz(), i64vec4(2_i64, 3_i64, 2_i64, 3_i64));
+    assert_eq!(v.yzyw(), i64vec4(2_i64, 3_i64, 2_i64, 4_i64));
+    assert_eq!(v.yzzx(), i64vec4(2_i64, 3_i64, 3_i64, 1_i64));
+    assert_eq!(v.yzzy(), i64vec4(2_i64, 3_i64, 3_i64, 2_i64));
+    assert_eq!(v.yzzz(), i64vec4(2_i64, 3_i64, 3_i64, 3_i64));
+    assert_eq!(v.yzzw(), i64vec4(2_i64, 3_i64, 3_i64, 4_i64));
+    assert_eq!(v.yzwx(), i64vec4(2_i64, 3_i64, 4_i64, 1_i64));
+    assert_eq!(v.yzwy(), i64vec4(2_i64, 3_i64, 4_i64, 2_i64));
+    assert_eq!(v.yzwz(), i64vec4(2_i64, 3_i64, 4_i64, 3_i64));
+    assert_eq!(v.yzww(), i64vec4(2_i64, 3_i64, 4_i64, 4_i64));
+    assert_eq!(v.ywxx(), i64vec4(2_i64, 4_i64, 1_i64, 1_i64));
+    assert_eq!(v.ywxy(), i64vec4(2_i64, 4_i64, 1_i64, 2_i64));
+    assert_eq!(v.ywxz(), i64vec4(2_i64, 4_i64, 1_i64, 3_i64));
+    assert_eq!(v.ywxw(), i64vec4(2_i64, 4_i64, 1_i64, 4_i64));
+    assert_eq!(v.ywyx(), i64vec4(2_i64, 4_i64, 2_i64, 1_i64));
+    assert_eq!(v.ywyy(), i64vec4(2_i64, 4_i64, 2_i64, 2_i64));
+    assert_eq!(v.ywyz(), i64vec4(2_i64, 4_i64, 2_i64, 3_i64));
+    assert_eq!(v.ywyw(), i64vec4(2_i64, 4_i64, 2_i64, 4_i64));
+    assert_eq!(v.ywzx(), i64vec4(2_i64, 4_i64, 3_i64, 1_i64));
+    assert_eq!(v.ywzy(), i64vec4(2_i64, 4_i64, 3_i64, 2_i64));
+    assert_eq!(v.ywzz(), i64vec4(2_i64, 4_i64, 3_i64, 3_i64));
+    assert_eq!(v.ywzw(), i64vec4(2_i64, 4_i64, 3_i64, 4_i64));
+    assert_eq!(v.ywwx(), i64vec4(2_i64, 4_i64, 4_i64, 1_i64));
+    assert_eq!(v.ywwy(), i64vec4(2_i64, 4_i64, 4_i64, 2_i64));
+    assert_eq!(v.ywwz(), i64vec4(2_i64, 4_i64, 4_i64, 3_i64));
+    assert_eq!(v.ywww(), i64vec4(2_i64, 4_i64, 4_i64, 4_i64));
+    assert_eq!(v.zxxx(), i64vec4(3_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.zxxy(), i64vec4(3_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.zxxz(), i64vec4(3_i64, 1_i64, 1_i64, 3_i64));
+    assert_eq!(v.zxxw(), i64vec4(3_i64, 1_i64, 1_i64, 4_i64));
+    assert_eq!(v.zxyx(), i64vec4(3_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.zxyy(), i64vec4(3_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.zxyz(), i64vec4(3_i64, 1_i64, 2_i64, 3_i64));
+    assert_eq!(v.zxyw(), i64vec4(3_i64, 1_i64, 2_i64, 4_i64));
+    assert_eq!(v.zxzx(), i64vec4(3_i64, 1_i64, 3_i64, 1_i64));
+    assert_eq!(v.zxzy(), i64vec4(3_i64, 1_i64, 3_i64, 2_i64));
+    assert_eq!(v.zxzz(), i64vec4(3_i64, 1_i64, 3_i64, 3_i64));
+    assert_eq!(v.zxzw(), i64vec4(3_i64, 1_i64, 3_i64, 4_i64));
+    assert_eq!(v.zxwx(), i64vec4(3_i64, 1_i64, 4_i64, 1_i64));
+    assert_eq!(v.zxwy(), i64vec4(3_i64, 1_i64, 4_i64, 2_i64));
+    assert_eq!(v.zxwz(), i64vec4(3_i64, 1_i64, 4_i64, 3_i64));
+    assert_eq!(v.zxww(), i64vec4(3_i64, 1_i64, 4_i64, 4_i64));
+    assert_eq!(v.zyxx(), i64vec4(3_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.zyxy(), i64vec4(3_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.zyxz(), i64vec4(3_i64, 2_i64, 1_i64, 3_i64));
+    assert_eq!(v.zyxw(), i64vec4(3_i64, 2_i64, 1_i64, 4_i64));
+    assert_eq!(v.zyyx(), i64vec4(3_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.zyyy(), i64vec4(3_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.zyyz(), i64vec4(3_i64, 2_i64, 2_i64, 3_i64));
+    assert_eq!(v.zyyw(), i64vec4(3_i64, 2_i64, 2_i64, 4_i64));
+    assert_eq!(v.zyzx(), i64vec4(3_i64, 2_i64, 3_i64, 1_i64));
+    assert_eq!(v.zyzy(), i64vec4(3_i64, 2_i64, 3_i64, 2_i64));
+    assert_eq!(v.zyzz(), i64vec4(3_i64, 2_i64, 3_i64, 3_i64));
+    assert_eq!(v.zyzw(), i64vec4(3_i64, 2_i64, 3_i64, 4_i64));
+    assert_eq!(v.zywx(), i64vec4(3_i64, 2_i64, 4_i64, 1_i64));
+    assert_eq!(v.zywy(), i64vec4(3_i64, 2_i64, 4_i64, 2_i64));
+    assert_eq!(v.zywz(), i64vec4(3_i64, 2_i64, 4_i64, 3_i64));
+    assert_eq!(v.zyww(), i64vec4(3_i64, 2_i64, 4_i64, 4_i64));
+    assert_eq!(v.zzxx(), i64vec4(3_i64, 3_i64, 1_i64, 1_i64));
+    assert_eq!(v.zzxy(), i64vec4(3_i64, 3_i64, 1_i64, 2_i64));
+    assert_eq!(v.zzxz(), i64vec4(3_i64, 3_i64, 1_i64, 3_i64));
+    assert_eq!(v.zzxw(), i64vec4(3_i64, 3_i64, 1_i64, 4_i64));
+    assert_eq!(v.zzyx(), i64vec4(3_i64, 3_i64, 2_i64, 1_i64));
+    assert_eq!(v.zzyy(), i64vec4(3_i64, 3_i64, 2_i64, 2_i64));
+    assert_eq!(v.zzyz(), i64vec4(3_i64, 3_i64, 2_i64, 3_i64));
+    assert_eq!(v.zzyw(), i64vec4(3_i64, 3_i64, 2_i64, 4_i64));
+    assert_eq!(v.zzzx(), i64vec4(3_i64, 3_i64, 3_i64, 1_i64));
+    assert_eq!(v.zzzy(), i64vec4(3_i64, 3_i64, 3_i64, 2_i64));
+    assert_eq!(v.zzzz(), i64vec4(3_i64, 3_i64, 3_i64, 3_i64));
+    assert_eq!(v.zzzw(), i64vec4(3_i64, 3_i64, 3_i64, 4_i64));
+    assert_eq!(v.zzwx(), i64vec4(3_i64, 3_i64, 4_i64, 1_i64));
+    assert_eq!(v.zzwy(), i64vec4(3_i64, 3_i64, 4_i64, 2_i64));
+    assert_eq!(v.zzwz(), i64vec4(3_i64, 3_i64, 4_i64, 3_i64));
+    assert_eq!(v.zzww(), i64vec4(3_i64, 3_i64, 4_i64, 4_i64));
+    assert_eq!(v.zwxx(), i64vec4(3_i64, 4_i64, 1_i64, 1_i64));
+    assert_eq!(v.zwxy(), i64vec4(3_i64, 4_i64, 1_i64, 2_i64));
+    assert_eq!(v.zwxz(), i64vec4(3_i64, 4_i64, 1_i64, 3_i64));
+    assert_eq!(v.zwxw(), i64vec4(3_i64, 4_i64, 1_i64, 4_i64));
+    assert_eq!(v.zwyx(), i64vec4(3_i64, 4_i64, 2_i64, 1_i64));
+    assert_eq!(v.zwyy(), i64vec4(3_i64, 4_i64, 2_i64, 2_i64));
+    assert_eq!(v.zwyz(), i64vec4(3_i64, 4_i64, 2_i64, 3_i64));
+    assert_eq!(v.zwyw(), i64vec4(3_i64, 4_i64, 2_i64, 4_i64));
+    assert_eq!(v.zwzx(), i64vec4(3_i64, 4_i64, 3_i64, 1_i64));
+    assert_eq!(v.zwzy(), i64vec4(3_i64, 4_i64, 3_i64, 2_i64));
+    assert_eq!(v.zwzz(), i64vec4(3_i64, 4_i64, 3_i64, 3_i64));
+    assert_eq!(v.zwzw(), i64vec4(3_i64, 4_i64, 3_i64, 4_i64));
+    assert_eq!(v.zwwx(), i64vec4(3_i64, 4_i64, 4_i64, 1_i64));
+    assert_eq!(v.zwwy(), i64vec4(3_i64, 4_i64, 4_i64, 2_i64));
+    assert_eq!(v.zwwz(), i64vec4(3_i64, 4_i64, 4_i64, 3_i64));
+    assert_eq!(v.zwww(), i64vec4(3_i64, 4_i64, 4_i64, 4_i64));
+    assert_eq!(v.wxxx(), i64vec4(4_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.wxxy(), i64vec4(4_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.wxxz(), i64vec4(4_i64, 1_i64, 1_i64, 3_i64));
+    assert_eq!(v.wxxw(), i64vec4(4_i64, 1_i64, 1_i64, 4_i64));
+    assert_eq!(v.wxyx(), i64vec4(4_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.wxyy(), i64vec4(4_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.wxyz(), i64vec4(4_i64, 1_i64, 2_i64, 3_i64));
+    assert_eq!(v.wxyw(), i64vec4(4_i64, 1_i64, 2_i64, 4_i64));
+    assert_eq!(v.wxzx(), i64vec4(4_i64, 1_i64, 3_i64, 1_i64));
+    assert_eq!(v.wxzy(), i64vec4(4_i64, 1_i64, 3_i64, 2_i64));
+    assert_eq!(v.wxzz(), i64vec4(4_i64, 1_i64, 3_i64, 3_i64));
+    assert_eq!(v.wxzw(), i64vec4(4_i64, 1_i64, 3_i64, 4_i64));
+    assert_eq!(v.wxwx(), i64vec4(4_i64, 1_i64, 4_i64, 1_i64));
+    assert_eq!(v.wxwy(), i64vec4(4_i64, 1_i64, 4_i64, 2_i64));
+    assert_eq!(v.wxwz(), i64vec4(4_i64, 1_i64, 4_i64, 3_i64));
+    assert_eq!(v.wxww(), i64vec4(4_i64, 1_i64, 4_i64, 4_i64));
+    assert_eq!(v.wyxx(), i64vec4(4_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.wyxy(), i64vec4(4_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.wyxz(), i64vec4(4_i64, 2_i64, 1_i64, 3_i64));
+    assert_eq!(v.wyxw(), i64vec4(4_i64, 2_i64, 1_i64, 4_i64));
+    assert_eq!(v.wyyx(), i64vec4(4_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.wyyy(), i64vec4(4_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.wyyz(), i64vec4(4_i64, 2_i64, 2_i64, 3_i64));
+    assert_eq!(v.wyyw(), i64vec4(4_i64, 2_i64, 2_i64, 4_i64));
+    assert_eq!(v.wyzx(), i64vec4(4_i64, 2_i64, 3_i64, 1_i64));
+    assert_eq!(v.wyzy(), i64vec4(4_i64, 2_i64, 3_i64, 2_i64));
+    assert_eq!(v.wyzz(), i64vec4(4_i64, 2_i64, 3_i64, 3_i64));
+    assert_eq!(v.wyzw(), i64vec4(4_i64, 2_i64, 3_i64, 4_i64));
+    assert_eq!(v.wywx(), i64vec4(4_i64, 2_i64, 4_i64, 1_i64));
+    assert_eq!(v.wywy(), i64vec4(4_i64, 2_i64, 4_i64, 2_i64));
+    assert_eq!(v.wywz(), i64vec4(4_i64, 2_i64, 4_i64, 3_i64));
+    assert_eq!(v.wyww(), i64vec4(4_i64, 2_i64, 4_i64, 4_i64));
+    assert_eq!(v.wzxx(), i64vec4(4_i64, 3_i64, 1_i64, 1_i64));
+    assert_eq!(v.wzxy(), i64vec4(4_i64, 3_i64, 1_i64, 2_i64));
+    assert_eq!(v.wzxz(), i64vec4(4_i64, 3_i64, 1_i64, 3_i64));
+    assert_eq!(v.wzxw(), i64vec4(4_i64, 3_i64, 1_i64, 4_i64));
+    assert_eq!(v.wzyx(), i64vec4(4_i64, 3_i64, 2_i64, 1_i64));
+    assert_eq!(v.wzyy(), i64vec4(4_i64, 3_i64, 2_i64, 2_i64));
+    assert_eq!(v.wzyz(), i64vec4(4_i64, 3_i64, 2_i64, 3_i64));
+    assert_eq!(v.wzyw(), i64vec4(4_i64, 3_i64, 2_i64, 4_i64));
+    assert_eq!(v.wzzx(), i64vec4(4_i64, 3_i64, 3_i64, 1_i64));
+    assert_eq!(v.wzzy(), i64vec4(4_i64, 3_i64, 3_i64, 2_i64));
+    assert_eq!(v.wzzz(), i64vec4(4_i64, 3_i64, 3_i64, 3_i64));
+    assert_eq!(v.wzzw(), i64vec4(4_i64, 3_i64, 3_i64, 4_i64));
+    assert_eq!(v.wzwx(), i64vec4(4_i64, 3_i64, 4_i64, 1_i64));
+    assert_eq!(v.wzwy(), i64vec4(4_i64, 3_i64, 4_i64, 2_i64));
+    assert_eq!(v.wzwz(), i64vec4(4_i64, 3_i64, 4_i64, 3_i64));
+    assert_eq!(v.wzww(), i64vec4(4_i64, 3_i64, 4_i64, 4_i64));
+    assert_eq!(v.wwxx(), i64vec4(4_i64, 4_i64, 1_i64, 1_i64));
+    assert_eq!(v.wwxy(), i64vec4(4_i64, 4_i64, 1_i64, 2_i64));
+    assert_eq!(v.wwxz(), i64vec4(4_i64, 4_i64, 1_i64, 3_i64));
+    assert_eq!(v.wwxw(), i64vec4(4_i64, 4_i64, 1_i64, 4_i64));
+    assert_eq!(v.wwyx(), i64vec4(4_i64, 4_i64, 2_i64, 1_i64));
+    assert_eq!(v.wwyy(), i64vec4(4_i64, 4_i64, 2_i64, 2_i64));
+    assert_eq!(v.wwyz(), i64vec4(4_i64, 4_i64, 2_i64, 3_i64));
+    assert_eq!(v.wwyw(), i64vec4(4_i64, 4_i64, 2_i64, 4_i64));
+    assert_eq!(v.wwzx(), i64vec4(4_i64, 4_i64, 3_i64, 1_i64));
+    assert_eq!(v.wwzy(), i64vec4(4_i64, 4_i64, 3_i64, 2_i64));
+    assert_eq!(v.wwzz(), i64vec4(4_i64, 4_i64, 3_i64, 3_i64));
+    assert_eq!(v.wwzw(), i64vec4(4_i64, 4_i64, 3_i64, 4_i64));
+    assert_eq!(v.wwwx(), i64vec4(4_i64, 4_i64, 4_i64, 1_i64));
+    assert_eq!(v.wwwy(), i64vec4(4_i64, 4_i64, 4_i64, 2_i64));
+    assert_eq!(v.wwwz(), i64vec4(4_i64, 4_i64, 4_i64, 3_i64));
+    assert_eq!(v.wwww(), i64vec4(4_i64, 4_i64, 4_i64, 4_i64));
+    assert_eq!(v.xxx(), i64vec3(1_i64, 1_i64, 1_i64));
+    assert_eq!(v.xxy(), i64vec3(1_i64, 1_i64, 2_i64));
+    assert_eq!(v.xxz(), i64vec3(1_i64, 1_i64, 3_i64));
+    assert_eq!(v.xxw(), i64vec3(1_i64, 1_i64, 4_i64));
+    assert_eq!(v.xyx(), i64vec3(1_i64, 2_i64, 1_i64));
+    assert_eq!(v.xyy(), i64vec3(1_i64, 2_i64, 2_i64));
+    assert_eq!(v.xyz(), i64vec3(1_i64, 2_i64, 3_i64));
+    assert_eq!(v.xyw(), i64vec3(1_i64, 2_i64, 4_i64));
+    assert_eq!(v.xzx(), i64vec3(1_i64, 3_i64, 1_i64));
+    assert_eq!(v.xzy(), i64vec3(1_i64, 3_i64, 2_i64));
+    assert_eq!(v.xzz(), i64vec3(1_i64, 3_i64, 3_i64));
+    assert_eq!(v.xzw(), i64vec3(1_i64, 3_i64, 4_i64));
+    assert_eq!(v.xwx(), i64vec3(1_i64, 4_i64, 1_i64));
+    assert_eq!(v.xwy(), i64vec3(1_i64, 4_i64, 2_i64));
+    assert_eq!(v.xwz(), i64vec3(1_i64, 4_i64, 3_i64));
+    assert_eq!(v.xww(), i64vec3(1_i64, 4_i64, 4_i64));
+    assert_eq!(v.yxx(), i64vec3(2_i64, 1_i64, 1_i64));
+    assert_eq!(v.yxy(), i64vec3(2_i64, 1_i64, 2_i64));
+    assert_eq!(v.yxz(), i64vec3(2_i64, 1_i64, 3_i64));
+    assert_eq!(v.yxw(), i64vec3(2_i64, 1_i64, 4_i64));
+    assert_eq!(v.yyx(), i64vec3(2_i64, 2_i64, 1_i64));
+    assert_eq!(v.yyy(), i64vec3(2_i64, 2_i64, 2_i64));
+    assert_eq!(v.yyz(), i64vec3(2_i64, 2_i64, 3_i64));
+    assert_eq!(v.yyw(), i64vec3(2_i64, 2_i64, 4_i64));
+    assert_eq!(v.yzx(), i64vec3(2_i64, 3_i64, 1_i64));
+    assert_eq!(v.yzy(), i64vec3(2_i64, 3_i64, 2_i64));
+    assert_eq!(v.yzz(), i64vec3(2_i64, 3_i64, 3_i64));
+    assert_eq!(v.yzw(), i64vec3(2_i64, 3_i64, 4_i64));
+    assert_eq!(v.ywx(), i64vec3(2_i64, 4_i64, 1_i64));
+    assert_eq!(v.ywy(), i64vec3(2_i64, 4_i64, 2_i64));
+    assert_eq!(v.ywz(), i64vec3(2_i64, 4_i64, 3_i64));
+    assert_eq!(v.yww(), i64vec3(2_i64, 4_i64, 4_i64));
+    assert_eq!(v.zxx(), i64vec3(3_i64, 1_i64, 1_i64));
+    assert_eq!(v.zxy(), i64vec3(3_i64, 1_i64, 2_i64));
+    assert_eq!(v.zxz(), i64vec3(3_i64, 1_i64, 3_i64));
+    assert_eq!(v.zxw(), i64vec3(3_i64, 1_i64, 4_i64));
+    assert_eq!(v.zyx(), i64vec3(3_i64, 2_i64, 1_i64));
+    assert_eq!(v.zyy(), i64vec3(3_i64, 2_i64, 2_i64));
+    assert_eq!(v.zyz(), i64vec3(3_i64, 2_i64, 3_i64));
+    assert_eq!(v.zyw(), i64vec3(3_i64, 2_i64, 4_i64));
+    assert_eq!(v.zzx(), i64vec3(3_i64, 3_i64, 1_i64));
+    assert_eq!(v.zzy(), i64vec3(3_i64, 3_i64, 2_i64));
+    assert_eq!(v.zzz(), i64vec3(3_i64, 3_i64, 3_i64));
+    assert_eq!(v.zzw(), i64vec3(3_i64, 3_i64, 4_i64));
+    assert_eq!(v.zwx(), i64vec3(3_i64, 4_i64, 1_i64));
+    assert_eq!(v.zwy(), i64vec3(3_i64, 4_i64, 2_i64));
+    assert_eq!(v.zwz(), i64vec3(3_i64, 4_i64, 3_i64));
+    assert_eq!(v.zww(), i64vec3(3_i64, 4_i64, 4_i64));
+    assert_eq!(v.wxx(), i64vec3(4_i64, 1_i64, 1_i64));
+    assert_eq!(v.wxy(), i64vec3(4_i64, 1_i64, 2_i64));
+    assert_eq!(v.wxz(), i64vec3(4_i64, 1_i64, 3_i64));
+    assert_eq!(v.wxw(), i64vec3(4_i64, 1_i64, 4_i64));
+    assert_eq!(v.wyx(), i64vec3(4_i64, 2_i64, 1_i64));
+    assert_eq!(v.wyy(), i64vec3(4_i64, 2_i64, 2_i64));
+    assert_eq!(v.wyz(), i64vec3(4_i64, 2_i64, 3_i64));
+    assert_eq!(v.wyw(), i64vec3(4_i64, 2_i64, 4_i64));
+    assert_eq!(v.wzx(), i64vec3(4_i64, 3_i64, 1_i64));
+    assert_eq!(v.wzy(), i64vec3(4_i64, 3_i64, 2_i64));
+    assert_eq!(v.wzz(), i64vec3(4_i64, 3_i64, 3_i64));
+    assert_eq!(v.wzw(), i64vec3(4_i64, 3_i64, 4_i64));
+    assert_eq!(v.wwx(), i64vec3(4_i64, 4_i64, 1_i64));
+    assert_eq!(v.wwy(), i64vec3(4_i64, 4_i64, 2_i64));
+    assert_eq!(v.wwz(), i64vec3(4_i64, 4_i64, 3_i64));
+    assert_eq!(v.www(), i64vec3(4_i64, 4_i64, 4_i64));
+    assert_eq!(v.xx(), i64vec2(1_i64, 1_i64));
+    assert_eq!(v.xy(), i64vec2(1_i64, 2_i64));
+    assert_eq!(v.xz(), i64vec2(1_i64, 3_i64));
+    assert_eq!(v.xw(), i64vec2(1_i64, 4_i64));
+    assert_eq!(v.yx(), i64vec2(2_i64, 1_i64));
+    assert_eq!(v.yy(), i64vec2(2_i64, 2_i64));
+    assert_eq!(v.yz(), i64vec2(2_i64, 3_i64));
+    assert_eq!(v.yw(), i64vec2(2_i64, 4_i64));
+    assert_eq!(v.zx(), i64vec2(3_i64, 1_i64));
+    assert_eq!(v.zy(), i64vec2(3_i64, 2_i64));
+    assert_eq!(v.zz(), i64vec2(3_i64, 3_i64));
+    assert_eq!(v.zw(), i64vec2(3_i64, 4_i64));
+    assert_eq!(v.wx(), i64vec2(4_i64, 1_i64));
+    assert_eq!(v.wy(), i64vec2(4_i64, 2_i64));
+    assert_eq!(v.wz(), i64vec2(4_i64, 3_i64));
+    assert_eq!(v.ww(), i64vec2(4_i64, 4_i64));
+});
+
+glam_test!(test_i64vec3_swizzles, {
+    let v = i64vec3(1_i64, 2_i64, 3_i64);
+    assert_eq!(v, v.xyz());
+    assert_eq!(v.xxxx(), i64vec4(1_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.xxxy(), i64vec4(1_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.xxxz(), i64vec4(1_i64, 1_i64, 1_i64, 3_i64));
+    assert_eq!(v.xxyx(), i64vec4(1_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.xxyy(), i64vec4(1_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.xxyz(), i64vec4(1_i64, 1_i64, 2_i64, 3_i64));
+    assert_eq!(v.xxzx(), i64vec4(1_i64, 1_i64, 3_i64, 1_i64));
+    assert_eq!(v.xxzy(), i64vec4(1_i64, 1_i64, 3_i64, 2_i64));
+    assert_eq!(v.xxzz(), i64vec4(1_i64, 1_i64, 3_i64, 3_i64));
+    assert_eq!(v.xyxx(), i64vec4(1_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.xyxy(), i64vec4(1_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.xyxz(), i64vec4(1_i64, 2_i64, 1_i64, 3_i64));
+    assert_eq!(v.xyyx(), i64vec4(1_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.xyyy(), i64vec4(1_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.xyyz(), i64vec4(1_i64, 2_i64, 2_i64, 3_i64));
+    assert_eq!(v.xyzx(), i64vec4(1_i64, 2_i64, 3_i64, 1_i64));
+    assert_eq!(v.xyzy(), i64vec4(1_i64, 2_i64, 3_i64, 2_i64));
+    assert_eq!(v.xyzz(), i64vec4(1_i64, 2_i64, 3_i64, 3_i64));
+    assert_eq!(v.xzxx(), i64vec4(1_i64, 3_i64, 1_i64, 1_i64));
+    assert_eq!(v.xzxy(), i64vec4(1_i64, 3_i64, 1_i64, 2_i64));
+    assert_eq!(v.xzxz(), i64vec4(1_i64, 3_i64, 1_i64, 3_i64));
+    assert_eq!(v.xzyx(), i64vec4(1_i64, 3_i64, 2_i64, 1_i64));
+    assert_eq!(v.xzyy(), i64vec4(1_i64, 3_i64, 2_i64, 2_i64));
+    assert_eq!(v.xzyz(), i64vec4(1_i64, 3_i64, 2_i64, 3_i64));
+    assert_eq!(v.xzzx(), i64vec4(1_i64, 3_i64, 3_i64, 1_i64));
+    assert_eq!(v.xzzy(), i64vec4(1_i64, 3_i64, 3_i64, 2_i64));
+    assert_eq!(v.xzzz(), i64vec4(1_i64, 3_i64, 3_i64, 3_i64));
+    assert_eq!(v.yxxx(), i64vec4(2_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.yxxy(), i64vec4(2_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.yxxz(), i64vec4(2_i64, 1_i64, 1_i64, 3_i64));
+    assert_eq!(v.yxyx(), i64vec4(2_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.yxyy(), i64vec4(2_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.yxyz(), i64vec4(2_i64, 1_i64, 2_i64, 3_i64));
+    assert_eq!(v.yxzx(), i64vec4(2_i64, 1_i64, 3_i64, 1_i64));
+    assert_eq!(v.yxzy(), i64vec4(2_i64, 1_i64, 3_i64, 2_i64));
+    assert_eq!(v.yxzz(), i64vec4(2_i64, 1_i64, 3_i64, 3_i64));
+    assert_eq!(v.yyxx(), i64vec4(2_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.yyxy(), i64vec4(2_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.yyxz(), i64vec4(2_i64, 2_i64, 1_i64, 3_i64));
+    assert_eq!(v.yyyx(), i64vec4(2_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.yyyy(), i64vec4(2_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.yyyz(), i64vec4(2_i64, 2_i64, 2_i64, 3_i64));
+    assert_eq!(v.yyzx(), i64vec4(2_i64, 2_i64, 3_i64, 1_i64));
+    assert_eq!(v.yyzy(), i64vec4(2_i64, 2_i64, 3_i64, 2_i64));
+    assert_eq!(v.yyzz(), i64vec4(2_i64, 2_i64, 3_i64, 3_i64));
+    assert_eq!(v.yzxx(), i64vec4(2_i64, 3_i64, 1_i64, 1_i64));
+    assert_eq!(v.yzxy(), i64vec4(2_i64, 3_i64, 1_i64, 2_i64));
+    assert_eq!(v.yzxz(), i64vec4(2_i64, 3_i64, 1_i64, 3_i64));
+    assert_eq!(v.yzyx(), i64vec4(2_i64, 3_i64, 2_i64, 1_i64));
+    assert_eq!(v.yzyy(), i64vec4(2_i64, 3_i64, 2_i64, 2_i64));
+    assert_eq!(v.yzyz(), i64vec4(2_i64, 3_i64, 2_i64, 3_i64));
+    assert_eq!(v.yzzx(), i64vec4(2_i64, 3_i64, 3_i64, 1_i64));
+    assert_eq!(v.yzzy(), i64vec4(2_i64, 3_i64, 3_i64, 2_i64));
+    assert_eq!(v.yzzz(), i64vec4(2_i64, 3_i64, 3_i64, 3_i64));
+    assert_eq!(v.zxxx(), i64vec4(3_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.zxxy(), i64vec4(3_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.zxxz(), i64vec4(3_i64, 1_i64, 1_i64, 3_i64));
+    assert_eq!(v.zxyx(), i64vec4(3_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.zxyy(), i64vec4(3_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.zxyz(), i64vec4(3_i64, 1_i64, 2_i64, 3_i64));
+    assert_eq!(v.zxzx(), i64vec4(3_i64, 1_i64, 3_i64, 1_i64));
+    assert_eq!(v.zxzy(), i64vec4(3_i64, 1_i64, 3_i64, 2_i64));
+    assert_eq!(v.zxzz(), i64vec4(3_i64, 1_i64, 3_i64, 3_i64));
+    assert_eq!(v.zyxx(), i64vec4(3_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.zyxy(), i64vec4(3_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.zyxz(), i64vec4(3_i64, 2_i64, 1_i64, 3_i64));
+    assert_eq!(v.zyyx(), i64vec4(3_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.zyyy(), i64vec4(3_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.zyyz(), i64vec4(3_i64, 2_i64, 2_i64, 3_i64));
+    assert_eq!(v.zyzx(), i64vec4(3_i64, 2_i64, 3_i64, 1_i64));
+    assert_eq!(v.zyzy(), i64vec4(3_i64, 2_i64, 3_i64, 2_i64));
+    assert_eq!(v.zyzz(), i64vec4(3_i64, 2_i64, 3_i64, 3_i64));
+    assert_eq!(v.zzxx(), i64vec4(3_i64, 3_i64, 1_i64, 1_i64));
+    assert_eq!(v.zzxy(), i64vec4(3_i64, 3_i64, 1_i64, 2_i64));
+    assert_eq!(v.zzxz(), i64vec4(3_i64, 3_i64, 1_i64, 3_i64));
+    assert_eq!(v.zzyx(), i64vec4(3_i64, 3_i64, 2_i64, 1_i64));
+    assert_eq!(v.zzyy(), i64vec4(3_i64, 3_i64, 2_i64, 2_i64));
+    assert_eq!(v.zzyz(), i64vec4(3_i64, 3_i64, 2_i64, 3_i64));
+    assert_eq!(v.zzzx(), i64vec4(3_i64, 3_i64, 3_i64, 1_i64));
+    assert_eq!(v.zzzy(), i64vec4(3_i64, 3_i64, 3_i64, 2_i64));
+    assert_eq!(v.zzzz(), i64vec4(3_i64, 3_i64, 3_i64, 3_i64));
+    assert_eq!(v.xxx(), i64vec3(1_i64, 1_i64, 1_i64));
+    assert_eq!(v.xxy(), i64vec3(1_i64, 1_i64, 2_i64));
+    assert_eq!(v.xxz(), i64vec3(1_i64, 1_i64, 3_i64));
+    assert_eq!(v.xyx(), i64vec3(1_i64, 2_i64, 1_i64));
+    assert_eq!(v.xyy(), i64vec3(1_i64, 2_i64, 2_i64));
+    assert_eq!(v.xzx(), i64vec3(1_i64, 3_i64, 1_i64));
+    assert_eq!(v.xzy(), i64vec3(1_i64, 3_i64, 2_i64));
+    assert_eq!(v.xzz(), i64vec3(1_i64, 3_i64, 3_i64));
+    assert_eq!(v.yxx(), i64vec3(2_i64, 1_i64, 1_i64));
+    assert_eq!(v.yxy(), i64vec3(2_i64, 1_i64, 2_i64));
+    assert_eq!(v.yxz(), i64vec3(2_i64, 1_i64, 3_i64));
+    assert_eq!(v.yyx(), i64vec3(2_i64, 2_i64, 1_i64));
+    assert_eq!(v.yyy(), i64vec3(2_i64, 2_i64, 2_i64));
+    assert_eq!(v.yyz(), i64vec3(2_i64, 2_i64, 3_i64));
+    assert_eq!(v.yzx(), i64vec3(2_i64, 3_i64, 1_i64));
+    assert_eq!(v.yzy(), i64vec3(2_i64, 3_i64, 2_i64));
+    assert_eq!(v.yzz(), i64vec3(2_i64, 3_i64, 3_i64));
+    assert_eq!(v.zxx(), i64vec3(3_i64, 1_i64, 1_i64));
+    assert_eq!(v.zxy(), i64vec3(3_i64, 1_i64, 2_i64));
+    assert_eq!(v.zxz(), i64vec3(3_i64, 1_i64, 3_i64));
+    assert_eq!(v.zyx(), i64vec3(3_i64, 2_i64, 1_i64));
+    assert_eq!(v.zyy(), i64vec3(3_i64, 2_i64, 2_i64));
+    assert_eq!(v.zyz(), i64vec3(3_i64, 2_i64, 3_i64));
+    assert_eq!(v.zzx(), i64vec3(3_i64, 3_i64, 1_i64));
+    assert_eq!(v.zzy(), i64vec3(3_i64, 3_i64, 2_i64));
+    assert_eq!(v.zzz(), i64vec3(3_i64, 3_i64, 3_i64));
+    assert_eq!(v.xx(), i64vec2(1_i64, 1_i64));
+    assert_eq!(v.xy(), i64vec2(1_i64, 2_i64));
+    assert_eq!(v.xz(), i64vec2(1_i64, 3_i64));
+    assert_eq!(v.yx(), i64vec2(2_i64, 1_i64));
+    assert_eq!(v.yy(), i64vec2(2_i64, 2_i64));
+    assert_eq!(v.yz(), i64vec2(2_i64, 3_i64));
+    assert_eq!(v.zx(), i64vec2(3_i64, 1_i64));
+    assert_eq!(v.zy(), i64vec2(3_i64, 2_i64));
+    assert_eq!(v.zz(), i64vec2(3_i64, 3_i64));
+});
+
+glam_test!(test_i64vec2_swizzles, {
+    let v = i64vec2(1_i64, 2_i64);
+    assert_eq!(v, v.xy());
+    assert_eq!(v.xxxx(), i64vec4(1_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.xxxy(), i64vec4(1_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.xxyx(), i64vec4(1_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.xxyy(), i64vec4(1_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.xyxx(), i64vec4(1_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.xyxy(), i64vec4(1_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.xyyx(), i64vec4(1_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.xyyy(), i64vec4(1_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.yxxx(), i64vec4(2_i64, 1_i64, 1_i64, 1_i64));
+    assert_eq!(v.yxxy(), i64vec4(2_i64, 1_i64, 1_i64, 2_i64));
+    assert_eq!(v.yxyx(), i64vec4(2_i64, 1_i64, 2_i64, 1_i64));
+    assert_eq!(v.yxyy(), i64vec4(2_i64, 1_i64, 2_i64, 2_i64));
+    assert_eq!(v.yyxx(), i64vec4(2_i64, 2_i64, 1_i64, 1_i64));
+    assert_eq!(v.yyxy(), i64vec4(2_i64, 2_i64, 1_i64, 2_i64));
+    assert_eq!(v.yyyx(), i64vec4(2_i64, 2_i64, 2_i64, 1_i64));
+    assert_eq!(v.yyyy(), i64vec4(2_i64, 2_i64, 2_i64, 2_i64));
+    assert_eq!(v.xxx(), i64vec3(1_i64, 1_i64, 1_i64));
+    assert_eq!(v.xxy(), i64vec3(1_i64, 1_i64, 2_i64));
+    assert_eq!(v.xyx(), i64vec3(1_i64, 2_i64, 1_i64));
+    assert_eq!(v.xyy(), i64vec3(1_i64, 2_i64, 2_i64));
+    assert_eq!(v.yxx(), i64vec3(2_i64, 1_i64, 1_i64));
+    assert_eq!(v.yxy(), i64vec3(2_i64, 1_i64, 2_i64));
+    assert_eq!(v.yyx(), i64vec3(2_i64, 2_i64, 1_i64));
+    assert_eq!(v.yyy(), i64vec3(2_i64, 2_i64, 2_i64));
+    assert_eq!(v.xx(), i64vec2(1_i64, 1_i64));
+    assert_eq!(v.yx(), i64vec2(2_i64, 1_i64));
+    assert_eq!(v.yy(), i64vec2(2_i64, 2_i64));
+});
@@ -613,6 +613,25 @@ macro_rules! impl_vec2_float_tests {
             assert_approx_eq!(-core::$t::consts::FRAC_PI_2, angle, 1e-6);
         });
 
+        glam_test!(test_rotate, {
+            let v = $vec2::new(3.0, 4.0);
+            assert_eq!(v, $vec2::X.rotate(v));
+            assert_eq!(v.perp(), $vec2::Y.rotate(v));
+            assert_eq!(-v, (-$vec2::X).rotate(v));
+            assert_eq!(v.rotate($vec2::Y), $vec2::Y.rotate(v));
+            // A non unit vector also scales
+            assert_eq!(v * 2.0, $vec2::new(2.0, 0.0).rotate(v));
+
+            let angle: $t = 0.5;
+            let rotation = $vec2::new(angle.cos(), angle.sin());
+            assert_approx_eq!($mat2::from_angle(angle) * v, rotation.rotate(v), 1e-6);
+            assert_approx_eq!(
+                $vec2::new((2.0 * angle).cos(), (2.0 * angle).sin()),
+                rotation.rotate(rotation),
+                1e-6
+            );
+        });
+
         glam_test!(test_clamp_length, {
             // Too long gets shortened
             assert_eq!(
@@ -745,6 +764,16 @@ macro_rules! impl_vec2_shift_op_tests {
             impl_vec2_shift_op_test!($vec2, UVec2, 0, 2);
         }
     };
+    ($vec2:ident, $ivec2:ident, $uvec2:ident) => {
+        mod shift_by_signed {
+            use super::*;
+            impl_vec2_shift_op_test!($vec2, $ivec2, 0, 2);
+        }
+        mod shift_by_unsigned {
+            use super::*;
+            impl_vec2_shift_op_test!($vec2, $uvec2, 0, 2);
+        }
+    };
 }
 
 macro_rules! impl_vec2_scalar_bit_op_tests {
@@ -792,6 +821,175 @@ macro_rules! impl_vec2_bit_op_tests {
     };
 }
 
+macro_rules! impl_vec2_integer_tests {
+    ($t:ident, $vec2:ident, $uvec2:ident, $mask:ident) => {
+        glam_test!(test_wrapping_ops, {
+            let a = $vec2::new($t::MAX, 2);
+            let b = $vec2::new(1, 3);
+            assert_eq!($vec2::new($t::MIN, 5), a.wrapping_add(b));
+            assert_eq!(
+                $vec2::new($t::MAX, 0),
+                $vec2::new($t::MIN, 3).wrapping_sub(b)
+            );
+            assert_eq!(
+                $vec2::new($t::MAX.wrapping_mul(2), 4),
+                a.wrapping_mul($vec2::new(2, 2))
+            );
+            assert_eq!($vec2::new($t::MAX, 0), a.wrapping_div(b));
+        });
+
+        glam_test!(test_saturating_ops, {
+            let a = $vec2::new($t::MAX, 2);
+            let b = $vec2::new(1, 3);
+            assert_eq!($vec2::new($t::MAX, 5), a.saturating_add(b));
+            assert_eq!(
+                $vec2::new($t::MIN, 1),
+                $vec2::new($t::MIN, 2).saturating_sub($vec2::new(1, 1))
+            );
+            assert_eq!($vec2::new($t::MAX, 4), a.saturating_mul($vec2::new(2, 2)));
+            assert_eq!($vec2::new($t::MAX, 0), a.saturating_div(b));
+        });
+
+        glam_test!(test_checked_ops, {
+            let a = $vec2::new($t::MAX, 2);
+            let b = $vec2::new(1, 3);
+            assert_eq!(None, a.checked_add(b));
+            assert_eq!(
+                Some($vec2::new($t::MAX, 5)),
+                a.checked_add($vec2::new(0, 3))
+            );
+            assert_eq!(None, $vec2::new($t::MIN, 3).checked_sub(b));
+            assert_eq!(
+                Some($vec2::new($t::MAX - 1, 1)),
+                a.checked_sub($vec2::new(1, 1))
+            );
+            assert_eq!(None, a.checked_mul($vec2::new(2, 2)));
+            assert_eq!(Some($vec2::new($t::MAX, 6)), a.checked_mul(b));
+            assert_eq!(Some($vec2::new($t::MAX, 0)), a.checked_div(b));
+            assert_eq!(None, a.checked_div($vec2::new(1, 0)));
+        });
+
+        glam_test!(test_overflowing_ops, {
+            let a = $vec2::new($t::MAX, 2);
+            let b = $vec2::new(1, 3);
+            assert_eq!(
+                ($vec2::new($t::MIN, 5), $mask::new(true, false)),
+                a.overflowing_add(b)
+            );
+            assert_eq!(
+                ($vec2::new($t::MAX, 0), $mask::new(true, false)),
+                $vec2::new($t::MIN, 3).overflowing_sub(b)
+            );
+            assert_eq!(
+                (
+                    $vec2::new($t::MAX.wrapping_mul(2), 4),
+                    $mask::new(true, false)
+                ),
+                a.overflowing_mul($vec2::new(2, 2))
+            );
+            assert_eq!(
+                ($vec2::new($t::MAX, 0), $mask::new(false, false)),
+                a.overflowing_div(b)
+            );
+        });
+
+        glam_test!(test_div_by_zero, {
+            should_panic!({ $vec2::new(1, 1).wrapping_div($vec2::new(0, 0)) });
+            should_panic!({ $vec2::new(1, 1).saturating_div($vec2::new(0, 0)) });
+            should_panic!({ $vec2::new(1, 1).overflowing_div($vec2::new(0, 0)) });
+        });
+
+        glam_test!(test_euclid, {
+            let a = $vec2::new(7, 8);
+            let b = $vec2::new(2, 4);
+            assert_eq!($vec2::new(3, 2), a.div_euclid(b));
+            assert_eq!($vec2::new(1, 0), a.rem_euclid(b));
+            should_panic!({ a.div_euclid($vec2::new(0, 0)) });
+            should_panic!({ a.rem_euclid($vec2::new(0, 0)) });
+        });
+
+        glam_test!(test_abs_diff, {
+            let a = $vec2::new($t::MAX, 2);
+            // the difference between `MAX` and `MIN` is all ones for both signed and unsigned
+            let b = $vec2::new($t::MIN, 5);
+            assert_eq!($uvec2::new(!0, 3), a.abs_diff(b));
+            assert_eq!(a.abs_diff(b), b.abs_diff(a));
+        });
+
+        glam_test!(test_element_sum_product, {
+            let a = $vec2::new(2, 3);
+            assert_eq!(5, a.element_sum());
+            assert_eq!(6, a.element_product());
+        });
+
+        glam_test!(test_bit_counts, {
+            let bits = (8 * core::mem::size_of::<$t>()) as _;
+            let a = $vec2::new(0, 1);
+            assert_eq!($uvec2::new(0, 1), a.count_ones());
+            assert_eq!(
+                $uvec2::new($t::MAX.count_ones() as _, 1),
+                $vec2::new($t::MAX, 1).count_ones()
+            );
+            assert_eq!($uvec2::new(bits, bits - 1), a.leading_zeros());
+            assert_eq!($uvec2::new(bits, 0), a.trailing_zeros());
+        });
+    };
+}
+
+macro_rules! impl_vec2_signed_integer_tests {
+    ($t:ident, $vec2:ident, $uvec2:ident, $mask:ident) => {
+        impl_vec2_integer_tests!($t, $vec2, $uvec2, $mask);
+
+        glam_test!(test_signed_overflow_ops, {
+            let a = $vec2::new($t::MIN, -4);
+            let b = $vec2::new(-1, 2);
+            assert_eq!($vec2::new($t::MIN, -2), a.wrapping_div(b));
+            assert_eq!($vec2::new($t::MAX, -2), a.saturating_div(b));
+            assert_eq!(None, a.checked_div(b));
+            assert_eq!(
+                ($vec2::new($t::MIN, -2), $mask::new(true, false)),
+                a.overflowing_div(b)
+            );
+            assert_eq!($vec2::new($t::MIN, -2), a.saturating_add($vec2::new(-1, 2)));
+            assert_eq!($vec2::new($t::MIN, -5), a.saturating_sub($vec2::new(1, 1)));
+            assert_eq!($vec2::new($t::MAX, 4), a.saturating_mul($vec2::new(-1, -1)));
+            assert_eq!($vec2::new($t::MIN, -8), a.saturating_mul($vec2::new(2, 2)));
+        });
+
+        glam_test!(test_signed_euclid, {
+            let a = $vec2::new(-7, 7);
+            let b = $vec2::new(2, -2);
+            assert_eq!($vec2::new(-4, -3), a.div_euclid(b));
+            assert_eq!($vec2::new(1, 1), a.rem_euclid(b));
+            assert_eq!(a, a.div_euclid(b) * b + a.rem_euclid(b));
+        });
+
+        glam_test!(test_signed_bit_counts, {
+            let bits = (8 * core::mem::size_of::<$t>()) as _;
+            let a = $vec2::new(-1, $t::MIN);
+            assert_eq!($uvec2::new(bits, 1), a.count_ones());
+            assert_eq!($uvec2::new(0, 0), a.leading_zeros());
+            assert_eq!($uvec2::new(0, bits - 1), a.trailing_zeros());
+        });
+    };
+}
+
+macro_rules! impl_vec2_unsigned_integer_tests {
+    ($t:ident, $vec2:ident, $mask:ident) => {
+        impl_vec2_integer_tests!($t, $vec2, $vec2, $mask);
+
+        glam_test!(test_power_of_two, {
+            let a = $vec2::new(0, 1);
+            assert_eq!($mask::new(false, true), a.is_power_of_two());
+            assert_eq!($vec2::new(1, 1), a.next_power_of_two());
+            assert_eq!(
+                $mask::new(false, false),
+                $vec2::new($t::MAX, 3).is_power_of_two()
+            );
+        });
+    };
+}
+
 mod vec2 {
     use glam::{const_vec2, vec2, BVec2, Mat2, Vec2, Vec3};
 
@@ -858,6 +1056,8 @@ mod ivec2 {
 
     impl_vec2_scalar_bit_op_tests!(IVec2, -2, 2);
     impl_vec2_bit_op_tests!(IVec2, -2, 2);
+
+    impl_vec2_signed_integer_tests!(i32, IVec2, UVec2, BVec2);
 }
 
 mod uvec2 {
@@ -879,4 +1079,313 @@ mod uvec2 {
 
     impl_vec2_scalar_bit_op_tests!(UVec2, 0, 2);
     impl_vec2_bit_op_tests!(UVec2, 0, 2);
+
+    impl_vec2_unsigned_integer_tests!(u32, UVec2, BVec2);
+}
+
+mod i64vec2 {
+    use glam::{const_i64vec2, i64vec2, BVec2, I64Vec2, I64Vec3, U64Vec2};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(16, mem::size_of::<I64Vec2>());
+        assert_eq!(8, mem::align_of::<I64Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec2, IVec2, UVec2, Vec2};
+        assert_eq!(Vec2::new(-1.0, -2.0), I64Vec2::new(-1, -2).as_vec2());
+        assert_eq!(DVec2::new(-1.0, -2.0), I64Vec2::new(-1, -2).as_dvec2());
+        assert_eq!(IVec2::new(-1, -2), I64Vec2::new(-1, -2).as_ivec2());
+        assert_eq!(UVec2::new(1, 2), I64Vec2::new(1, 2).as_uvec2());
+        assert_eq!(U64Vec2::new(1, 2), I64Vec2::new(1, 2).as_u64vec2());
+
+        assert_eq!(I64Vec2::new(-1, -2), Vec2::new(-1.0, -2.0).as_i64vec2());
+        assert_eq!(I64Vec2::new(-1, -2), DVec2::new(-1.0, -2.0).as_i64vec2());
+        assert_eq!(I64Vec2::new(-1, -2), IVec2::new(-1, -2).as_i64vec2());
+        assert_eq!(I64Vec2::new(1, 2), UVec2::new(1, 2).as_i64vec2());
+    });
+
+    impl_vec2_signed_tests!(i64, const_i64vec2, i64vec2, I64Vec2, I64Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(i64, i64vec2);
+
+    impl_vec2_scalar_shift_op_tests!(I64Vec2, -2, 2);
+    mod shift_by_i64 {
+        use glam::I64Vec2;
+        impl_vec2_scalar_shift_op_test!(I64Vec2, -2, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::I64Vec2;
+        impl_vec2_scalar_shift_op_test!(I64Vec2, -2, 2, 0u64, 2);
+    }
+    impl_vec2_shift_op_tests!(I64Vec2, I64Vec2, U64Vec2);
+
+    impl_vec2_scalar_bit_op_tests!(I64Vec2, -2, 2);
+    impl_vec2_bit_op_tests!(I64Vec2, -2, 2);
+
+    impl_vec2_signed_integer_tests!(i64, I64Vec2, U64Vec2, BVec2);
+}
+
+mod u64vec2 {
+    use glam::{const_u64vec2, u64vec2, BVec2, I64Vec2, U64Vec2, U64Vec3};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(16, mem::size_of::<U64Vec2>());
+        assert_eq!(8, mem::align_of::<U64Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec2, IVec2, UVec2, Vec2};
+        assert_eq!(Vec2::new(1.0, 2.0), U64Vec2::new(1, 2).as_vec2());
+        assert_eq!(DVec2::new(1.0, 2.0), U64Vec2::new(1, 2).as_dvec2());
+        assert_eq!(IVec2::new(1, 2), U64Vec2::new(1, 2).as_ivec2());
+        assert_eq!(UVec2::new(1, 2), U64Vec2::new(1, 2).as_uvec2());
+        assert_eq!(I64Vec2::new(1, 2), U64Vec2::new(1, 2).as_i64vec2());
+
+        assert_eq!(U64Vec2::new(1, 2), Vec2::new(1.0, 2.0).as_u64vec2());
+        assert_eq!(U64Vec2::new(1, 2), DVec2::new(1.0, 2.0).as_u64vec2());
+        assert_eq!(U64Vec2::new(1, 2), IVec2::new(1, 2).as_u64vec2());
+        assert_eq!(U64Vec2::new(1, 2), UVec2::new(1, 2).as_u64vec2());
+    });
+
+    impl_vec2_tests!(u64, const_u64vec2, u64vec2, U64Vec2, U64Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(u64, u64vec2);
+
+    impl_vec2_scalar_shift_op_tests!(U64Vec2, 0, 2);
+    mod shift_by_i64 {
+        use glam::U64Vec2;
+        impl_vec2_scalar_shift_op_test!(U64Vec2, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U64Vec2;
+        impl_vec2_scalar_shift_op_test!(U64Vec2, 0, 2, 0u64, 2);
+    }
+    impl_vec2_shift_op_tests!(U64Vec2, I64Vec2, U64Vec2);
+
+    impl_vec2_scalar_bit_op_tests!(U64Vec2, 0, 2);
+    impl_vec2_bit_op_tests!(U64Vec2, 0, 2);
+
+    impl_vec2_unsigned_integer_tests!(u64, U64Vec2, BVec2);
+}
+
+mod i16vec2 {
+    use glam::{const_i16vec2, i16vec2, BVec2, I16Vec2, I16Vec3, U16Vec2};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(4, mem::size_of::<I16Vec2>());
+        assert_eq!(2, mem::align_of::<I16Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec2, I64Vec2, IVec2, U16Vec2, U64Vec2, U8Vec2, UVec2, Vec2};
+        assert_eq!(Vec2::new(-1.0, -2.0), I16Vec2::new(-1, -2).as_vec2());
+        assert_eq!(DVec2::new(-1.0, -2.0), I16Vec2::new(-1, -2).as_dvec2());
+        assert_eq!(IVec2::new(-1, -2), I16Vec2::new(-1, -2).as_ivec2());
+        assert_eq!(UVec2::new(1, 2), I16Vec2::new(1, 2).as_uvec2());
+        assert_eq!(I64Vec2::new(-1, -2), I16Vec2::new(-1, -2).as_i64vec2());
+        assert_eq!(U64Vec2::new(1, 2), I16Vec2::new(1, 2).as_u64vec2());
+        assert_eq!(U16Vec2::new(1, 2), I16Vec2::new(1, 2).as_u16vec2());
+        assert_eq!(U8Vec2::new(1, 2), I16Vec2::new(1, 2).as_u8vec2());
+
+        assert_eq!(I16Vec2::new(-1, -2), Vec2::new(-1.0, -2.0).as_i16vec2());
+        assert_eq!(I16Vec2::new(-1, -2), DVec2::new(-1.0, -2.0).as_i16vec2());
+        assert_eq!(I16Vec2::new(-1, -2), IVec2::new(-1, -2).as_i16vec2());
+        assert_eq!(I16Vec2::new(1, 2), UVec2::new(1, 2).as_i16vec2());
+        assert_eq!(I16Vec2::new(-1, -2), I64Vec2::new(-1, -2).as_i16vec2());
+        assert_eq!(I16Vec2::new(1, 2), U64Vec2::new(1, 2).as_i16vec2());
+        assert_eq!(I16Vec2::new(1, 2), U16Vec2::new(1, 2).as_i16vec2());
+        assert_eq!(I16Vec2::new(1, 2), U8Vec2::new(1, 2).as_i16vec2());
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{IVec2, U16Vec2, U64Vec2, U8Vec2};
+        assert_eq!(
+            U8Vec2::new(0, 255),
+            I16Vec2::new(-1, 300).saturating_as_u8vec2()
+        );
+        assert_eq!(
+            U16Vec2::new(0, 300),
+            I16Vec2::new(-1, 300).saturating_as_u16vec2()
+        );
+        assert_eq!(
+            I16Vec2::new(i16::MIN, i16::MAX),
+            IVec2::new(-40000, 40000).saturating_as_i16vec2()
+        );
+        assert_eq!(
+            I16Vec2::new(i16::MAX, i16::MAX),
+            U64Vec2::new(u64::MAX, 40000).saturating_as_i16vec2()
+        );
+        assert_eq!(U8Vec2::new(255, 44), I16Vec2::new(-1, 300).as_u8vec2());
+    });
+
+    impl_vec2_signed_tests!(i16, const_i16vec2, i16vec2, I16Vec2, I16Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(i16, i16vec2);
+
+    impl_vec2_scalar_shift_op_tests!(I16Vec2, -2, 2);
+    mod shift_by_i64 {
+        use glam::I16Vec2;
+        impl_vec2_scalar_shift_op_test!(I16Vec2, -2, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::I16Vec2;
+        impl_vec2_scalar_shift_op_test!(I16Vec2, -2, 2, 0u64, 2);
+    }
+    impl_vec2_shift_op_tests!(I16Vec2, I16Vec2, U16Vec2);
+
+    impl_vec2_scalar_bit_op_tests!(I16Vec2, -2, 2);
+    impl_vec2_bit_op_tests!(I16Vec2, -2, 2);
+
+    impl_vec2_signed_integer_tests!(i16, I16Vec2, U16Vec2, BVec2);
+}
+
+mod u16vec2 {
+    use glam::{const_u16vec2, u16vec2, BVec2, I16Vec2, U16Vec2, U16Vec3};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(4, mem::size_of::<U16Vec2>());
+        assert_eq!(2, mem::align_of::<U16Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec2, I16Vec2, I64Vec2, IVec2, U64Vec2, U8Vec2, UVec2, Vec2};
+        assert_eq!(Vec2::new(1.0, 2.0), U16Vec2::new(1, 2).as_vec2());
+        assert_eq!(DVec2::new(1.0, 2.0), U16Vec2::new(1, 2).as_dvec2());
+        assert_eq!(IVec2::new(1, 2), U16Vec2::new(1, 2).as_ivec2());
+        assert_eq!(UVec2::new(1, 2), U16Vec2::new(1, 2).as_uvec2());
+        assert_eq!(I64Vec2::new(1, 2), U16Vec2::new(1, 2).as_i64vec2());
+        assert_eq!(U64Vec2::new(1, 2), U16Vec2::new(1, 2).as_u64vec2());
+        assert_eq!(I16Vec2::new(1, 2), U16Vec2::new(1, 2).as_i16vec2());
+        assert_eq!(U8Vec2::new(1, 2), U16Vec2::new(1, 2).as_u8vec2());
+
+        assert_eq!(U16Vec2::new(1, 2), Vec2::new(1.0, 2.0).as_u16vec2());
+        assert_eq!(U16Vec2::new(1, 2), DVec2::new(1.0, 2.0).as_u16vec2());
+        assert_eq!(U16Vec2::new(1, 2), IVec2::new(1, 2).as_u16vec2());
+        assert_eq!(U16Vec2::new(1, 2), UVec2::new(1, 2).as_u16vec2());
+        assert_eq!(U16Vec2::new(1, 2), I64Vec2::new(1, 2).as_u16vec2());
+        assert_eq!(U16Vec2::new(1, 2), U64Vec2::new(1, 2).as_u16vec2());
+        assert_eq!(U16Vec2::new(1, 2), I16Vec2::new(1, 2).as_u16vec2());
+        assert_eq!(U16Vec2::new(1, 2), U8Vec2::new(1, 2).as_u16vec2());
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{I16Vec2, I64Vec2, U8Vec2};
+        assert_eq!(
+            U8Vec2::new(1, 255),
+            U16Vec2::new(1, 300).saturating_as_u8vec2()
+        );
+        assert_eq!(
+            I16Vec2::new(1, 300),
+            U16Vec2::new(1, 300).saturating_as_i16vec2()
+        );
+        assert_eq!(
+            U16Vec2::new(0, u16::MAX),
+            I64Vec2::new(-1, 70000).saturating_as_u16vec2()
+        );
+        assert_eq!(
+            U16Vec2::new(0, 300),
+            I16Vec2::new(-1, 300).saturating_as_u16vec2()
+        );
+        assert_eq!(I16Vec2::new(1, 300), U16Vec2::new(1, 300).as_i16vec2());
+    });
+
+    impl_vec2_tests!(u16, const_u16vec2, u16vec2, U16Vec2, U16Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(u16, u16vec2);
+
+    impl_vec2_scalar_shift_op_tests!(U16Vec2, 0, 2);
+    mod shift_by_i64 {
+        use glam::U16Vec2;
+        impl_vec2_scalar_shift_op_test!(U16Vec2, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U16Vec2;
+        impl_vec2_scalar_shift_op_test!(U16Vec2, 0, 2, 0u64, 2);
+    }
+    impl_vec2_shift_op_tests!(U16Vec2, I16Vec2, U16Vec2);
+
+    impl_vec2_scalar_bit_op_tests!(U16Vec2, 0, 2);
+    impl_vec2_bit_op_tests!(U16Vec2, 0, 2);
+
+    impl_vec2_unsigned_integer_tests!(u16, U16Vec2, BVec2);
+}
+
+mod u8vec2 {
+    use glam::{const_u8vec2, u8vec2, BVec2, U8Vec2, U8Vec3};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(2, mem::size_of::<U8Vec2>());
+        assert_eq!(1, mem::align_of::<U8Vec2>());
+        assert_eq!(2, mem::size_of::<BVec2>());
+        assert_eq!(1, mem::align_of::<BVec2>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec2, I16Vec2, I64Vec2, IVec2, U16Vec2, U64Vec2, UVec2, Vec2};
+        assert_eq!(Vec2::new(1.0, 2.0), U8Vec2::new(1, 2).as_vec2());
+        assert_eq!(DVec2::new(1.0, 2.0), U8Vec2::new(1, 2).as_dvec2());
+        assert_eq!(IVec2::new(1, 2), U8Vec2::new(1, 2).as_ivec2());
+        assert_eq!(UVec2::new(1, 2), U8Vec2::new(1, 2).as_uvec2());
+        assert_eq!(I64Vec2::new(1, 2), U8Vec2::new(1, 2).as_i64vec2());
+        assert_eq!(U64Vec2::new(1, 2), U8Vec2::new(1, 2).as_u64vec2());
+        assert_eq!(I16Vec2::new(1, 2), U8Vec2::new(1, 2).as_i16vec2());
+        assert_eq!(U16Vec2::new(1, 2), U8Vec2::new(1, 2).as_u16vec2());
+
+        assert_eq!(U8Vec2::new(1, 2), Vec2::new(1.0, 2.0).as_u8vec2());
+        assert_eq!(U8Vec2::new(1, 2), DVec2::new(1.0, 2.0).as_u8vec2());
+        assert_eq!(U8Vec2::new(1, 2), IVec2::new(1, 2).as_u8vec2());
+        assert_eq!(U8Vec2::new(1, 2), UVec2::new(1, 2).as_u8vec2());
+        assert_eq!(U8Vec2::new(1, 2), I64Vec2::new(1, 2).as_u8vec2());
+        assert_eq!(U8Vec2::new(1, 2), U64Vec2::new(1, 2).as_u8vec2());
+        assert_eq!(U8Vec2::new(1, 2), I16Vec2::new(1, 2).as_u8vec2());
+        assert_eq!(U8Vec2::new(1, 2), U16Vec2::new(1, 2).as_u8vec2());
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{I16Vec2, IVec2, U64Vec2};
+        assert_eq!(
+            I16Vec2::new(1, 255),
+            U8Vec2::new(1, 255).saturating_as_i16vec2()
+        );
+        assert_eq!(
+            U8Vec2::new(0, 255),
+            IVec2::new(-1, 256).saturating_as_u8vec2()
+        );
+        assert_eq!(
+            U8Vec2::new(255, 255),
+            U64Vec2::new(u64::MAX, 256).saturating_as_u8vec2()
+        );
+    });
+
+    impl_vec2_tests!(u8, const_u8vec2, u8vec2, U8Vec2, U8Vec3, BVec2);
+    impl_vec2_eq_hash_tests!(u8, u8vec2);
+
+    impl_vec2_scalar_shift_op_tests!(U8Vec2, 0, 2);
+    mod shift_by_i64 {
+        use glam::U8Vec2;
+        impl_vec2_scalar_shift_op_test!(U8Vec2, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U8Vec2;
+        impl_vec2_scalar_shift_op_test!(U8Vec2, 0, 2, 0u64, 2);
+    }
+    mod shift_by_u8vec2 {
+        use super::*;
+        impl_vec2_shift_op_test!(U8Vec2, U8Vec2, 0, 2);
+    }
+
+    impl_vec2_scalar_bit_op_tests!(U8Vec2, 0, 2);
+    impl_vec2_bit_op_tests!(U8Vec2, 0, 2);
+
+    impl_vec2_unsigned_integer_tests!(u8, U8Vec2, BVec2);
 }
@@ -831,6 +831,16 @@ macro_rules! impl_vec3_shift_op_tests {
             impl_vec3_shift_op_test!($vec3, UVec3, 0, 2);
         }
     };
+    ($vec3:ident, $ivec3:ident, $uvec3:ident) => {
+        mod shift_by_signed {
+            use super::*;
+            impl_vec3_shift_op_test!($vec3, $ivec3, 0, 2);
+        }
+        mod shift_by_unsigned {
+            use super::*;
+            impl_vec3_shift_op_test!($vec3, $uvec3, 0, 2);
+        }
+    };
 }
 
 macro_rules! impl_vec3_scalar_bit_op_tests {
@@ -893,6 +903,190 @@ macro_rules! impl_vec3_bit_op_tests {
     };
 }
 
+macro_rules! impl_vec3_integer_tests {
+    ($t:ident, $vec3:ident, $uvec3:ident, $mask:ident) => {
+        glam_test!(test_wrapping_ops, {
+            let a = $vec3::new($t::MAX, 2, 2);
+            let b = $vec3::new(1, 3, 1);
+            assert_eq!($vec3::new($t::MIN, 5, 3), a.wrapping_add(b));
+            assert_eq!(
+                $vec3::new($t::MAX, 0, 2),
+                $vec3::new($t::MIN, 3, 3).wrapping_sub(b)
+            );
+            assert_eq!(
+                $vec3::new($t::MAX.wrapping_mul(2), 4, 4),
+                a.wrapping_mul($vec3::new(2, 2, 2))
+            );
+            assert_eq!($vec3::new($t::MAX, 0, 2), a.wrapping_div(b));
+        });
+
+        glam_test!(test_saturating_ops, {
+            let a = $vec3::new($t::MAX, 2, 2);
+            let b = $vec3::new(1, 3, 1);
+            assert_eq!($vec3::new($t::MAX, 5, 3), a.saturating_add(b));
+            assert_eq!(
+                $vec3::new($t::MIN, 1, $t::MIN),
+                $vec3::new($t::MIN, 2, $t::MIN).saturating_sub($vec3::new(1, 1, 1))
+            );
+            assert_eq!(
+                $vec3::new($t::MAX, 4, 4),
+                a.saturating_mul($vec3::new(2, 2, 2))
+            );
+            assert_eq!($vec3::new($t::MAX, 0, 2), a.saturating_div(b));
+        });
+
+        glam_test!(test_checked_ops, {
+            let a = $vec3::new($t::MAX, 2, 2);
+            let b = $vec3::new(1, 3, 1);
+            assert_eq!(None, a.checked_add(b));
+            assert_eq!(
+                Some($vec3::new($t::MAX, 5, 3)),
+                a.checked_add($vec3::new(0, 3, 1))
+            );
+            assert_eq!(None, $vec3::new($t::MIN, 3, 3).checked_sub(b));
+            assert_eq!(
+                Some($vec3::new($t::MAX - 1, 1, 1)),
+                a.checked_sub($vec3::new(1, 1, 1))
+            );
+            assert_eq!(None, a.checked_mul($vec3::new(2, 2, 2)));
+            assert_eq!(Some($vec3::new($t::MAX, 6, 2)), a.checked_mul(b));
+            assert_eq!(Some($vec3::new($t::MAX, 0, 2)), a.checked_div(b));
+            assert_eq!(None, a.checked_div($vec3::new(1, 0, 1)));
+        });
+
+        glam_test!(test_overflowing_ops, {
+            let a = $vec3::new($t::MAX, 2, 2);
+            let b = $vec3::new(1, 3, 1);
+            assert_eq!(
+                ($vec3::new($t::MIN, 5, 3), $mask::new(true, false, false)),
+                a.overflowing_add(b)
+            );
+            assert_eq!(
+                ($vec3::new($t::MAX, 0, 2), $mask::new(true, false, false)),
+                $vec3::new($t::MIN, 3, 3).overflowing_sub(b)
+            );
+            assert_eq!(
+                (
+                    $vec3::new($t::MAX.wrapping_mul(2), 4, 4),
+                    $mask::new(true, false, false)
+                ),
+                a.overflowing_mul($vec3::new(2, 2, 2))
+            );
+            assert_eq!(
+                ($vec3::new($t::MAX, 0, 2), $mask::new(false, false, false)),
+                a.overflowing_div(b)
+            );
+        });
+
+        glam_test!(test_div_by_zero, {
+            should_panic!({ $vec3::new(1, 1, 1).wrapping_div($vec3::new(0, 0, 0)) });
+            should_panic!({ $vec3::new(1, 1, 1).saturating_div($vec3::new(0, 0, 0)) });
+            should_panic!({ $vec3::new(1, 1, 1).overflowing_div($vec3::new(0, 0, 0)) });
+        });
+
+        glam_test!(test_euclid, {
+            let a = $vec3::new(7, 8, 9);
+            let b = $vec3::new(2, 4, 5);
+            assert_eq!($vec3::new(3, 2, 1), a.div_euclid(b));
+            assert_eq!($vec3::new(1, 0, 4), a.rem_euclid(b));
+            should_panic!({ a.div_euclid($vec3::new(0, 0, 0)) });
+            should_panic!({ a.rem_euclid($vec3::new(0, 0, 0)) });
+        });
+
+        glam_test!(test_abs_diff, {
+            let a = $vec3::new($t::MAX, 2, 7);
+            // the difference between `MAX` and `MIN` is all ones for both signed and unsigned
+            let b = $vec3::new($t::MIN, 5, 7);
+            assert_eq!($uvec3::new(!0, 3, 0), a.abs_diff(b));
+            assert_eq!(a.abs_diff(b), b.abs_diff(a));
+        });
+
+        glam_test!(test_element_sum_product, {
+            let a = $vec3::new(2, 3, 4);
+            assert_eq!(9, a.element_sum());
+            assert_eq!(24, a.element_product());
+        });
+
+        glam_test!(test_bit_counts, {
+            let bits = (8 * core::mem::size_of::<$t>()) as _;
+            let a = $vec3::new(0, 1, 6);
+            assert_eq!($uvec3::new(0, 1, 2), a.count_ones());
+            assert_eq!(
+                $uvec3::new($t::MAX.count_ones() as _, 1, 2),
+                $vec3::new($t::MAX, 1, 6).count_ones()
+            );
+            assert_eq!($uvec3::new(bits, bits - 1, bits - 3), a.leading_zeros());
+            assert_eq!($uvec3::new(bits, 0, 1), a.trailing_zeros());
+        });
+    };
+}
+
+macro_rules! impl_vec3_signed_integer_tests {
+    ($t:ident, $vec3:ident, $uvec3:ident, $mask:ident) => {
+        impl_vec3_integer_tests!($t, $vec3, $uvec3, $mask);
+
+        glam_test!(test_signed_overflow_ops, {
+            let a = $vec3::new($t::MIN, -4, 4);
+            let b = $vec3::new(-1, 2, -2);
+            assert_eq!($vec3::new($t::MIN, -2, -2), a.wrapping_div(b));
+            assert_eq!($vec3::new($t::MAX, -2, -2), a.saturating_div(b));
+            assert_eq!(None, a.checked_div(b));
+            assert_eq!(
+                ($vec3::new($t::MIN, -2, -2), $mask::new(true, false, false)),
+                a.overflowing_div(b)
+            );
+            assert_eq!(
+                $vec3::new($t::MIN, -2, 2),
+                a.saturating_add($vec3::new(-1, 2, -2))
+            );
+            assert_eq!(
+                $vec3::new($t::MIN, -5, 5),
+                a.saturating_sub($vec3::new(1, 1, -1))
+            );
+            assert_eq!(
+                $vec3::new($t::MAX, 4, -4),
+                a.saturating_mul($vec3::new(-1, -1, -1))
+            );
+            assert_eq!(
+                $vec3::new($t::MIN, -8, -8),
+                a.saturating_mul($vec3::new(2, 2, -2))
+            );
+        });
+
+        glam_test!(test_signed_euclid, {
+            let a = $vec3::new(-7, 7, -8);
+            let b = $vec3::new(2, -2, -4);
+            assert_eq!($vec3::new(-4, -3, 2), a.div_euclid(b));
+            assert_eq!($vec3::new(1, 1, 0), a.rem_euclid(b));
+            assert_eq!(a, a.div_euclid(b) * b + a.rem_euclid(b));
+        });
+
+        glam_test!(test_signed_bit_counts, {
+            let bits = (8 * core::mem::size_of::<$t>()) as _;
+            let a = $vec3::new(-1, $t::MIN, -2);
+            assert_eq!($uvec3::new(bits, 1, bits - 1), a.count_ones());
+            assert_eq!($uvec3::new(0, 0, 0), a.leading_zeros());
+            assert_eq!($uvec3::new(0, bits - 1, 1), a.trailing_zeros());
+        });
+    };
+}
+
+macro_rules! impl_vec3_unsigned_integer_tests {
+    ($t:ident, $vec3:ident, $mask:ident) => {
+        impl_vec3_integer_tests!($t, $vec3, $vec3, $mask);
+
+        glam_test!(test_power_of_two, {
+            let a = $vec3::new(0, 1, 6);
+            assert_eq!($mask::new(false, true, false), a.is_power_of_two());
+            assert_eq!($vec3::new(1, 1, 8), a.next_power_of_two());
+            assert_eq!(
+                $mask::new(false, false, false),
+                $vec3::new($t::MAX, 3, $t::MAX).is_power_of_two()
+            );
+        });
+    };
+}
+
 mod vec3 {
     use glam::{const_vec3, vec3, BVec3, Vec3};
 
@@ -1062,6 +1256,8 @@ mod ivec3 {
 
     impl_vec3_scalar_bit_op_tests!(IVec3, -2, 2);
     impl_vec3_bit_op_tests!(IVec3, -2, 2);
+
+    impl_vec3_signed_integer_tests!(i32, IVec3, UVec3, BVec3);
 }
 
 mod uvec3 {
@@ -1083,4 +1279,369 @@ mod uvec3 {
 
     impl_vec3_scalar_bit_op_tests!(UVec3, 0, 2);
     impl_vec3_bit_op_tests!(UVec3, 0, 2);
+
+    impl_vec3_unsigned_integer_tests!(u32, UVec3, BVec3);
+}
+
+mod i64vec3 {
+    use glam::{const_i64vec3, i64vec3, BVec3, I64Vec3, U64Vec3};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(24, mem::size_of::<I64Vec3>());
+        assert_eq!(8, mem::align_of::<I64Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec3, IVec3, UVec3, Vec3, Vec3A};
+        assert_eq!(
+            Vec3::new(-1.0, -2.0, -3.0),
+            I64Vec3::new(-1, -2, -3).as_vec3()
+        );
+        assert_eq!(
+            DVec3::new(-1.0, -2.0, -3.0),
+            I64Vec3::new(-1, -2, -3).as_dvec3()
+        );
+        assert_eq!(IVec3::new(-1, -2, -3), I64Vec3::new(-1, -2, -3).as_ivec3());
+        assert_eq!(UVec3::new(1, 2, 3), I64Vec3::new(1, 2, 3).as_uvec3());
+        assert_eq!(U64Vec3::new(1, 2, 3), I64Vec3::new(1, 2, 3).as_u64vec3());
+
+        assert_eq!(
+            I64Vec3::new(-1, -2, -3),
+            Vec3::new(-1.0, -2.0, -3.0).as_i64vec3()
+        );
+        assert_eq!(
+            I64Vec3::new(-1, -2, -3),
+            DVec3::new(-1.0, -2.0, -3.0).as_i64vec3()
+        );
+        assert_eq!(
+            I64Vec3::new(-1, -2, -3),
+            IVec3::new(-1, -2, -3).as_i64vec3()
+        );
+        assert_eq!(I64Vec3::new(1, 2, 3), UVec3::new(1, 2, 3).as_i64vec3());
+        assert_eq!(
+            I64Vec3::new(-1, -2, -3),
+            Vec3A::new(-1.0, -2.0, -3.0).as_i64vec3()
+        );
+    });
+
+    impl_vec3_signed_tests!(i64, const_i64vec3, i64vec3, I64Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(i64, i64vec3);
+
+    impl_vec3_scalar_shift_op_tests!(I64Vec3, -2, 2);
+    mod shift_by_i64 {
+        use glam::I64Vec3;
+        impl_vec3_scalar_shift_op_test!(I64Vec3, -2, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::I64Vec3;
+        impl_vec3_scalar_shift_op_test!(I64Vec3, -2, 2, 0u64, 2);
+    }
+    impl_vec3_shift_op_tests!(I64Vec3, I64Vec3, U64Vec3);
+
+    impl_vec3_scalar_bit_op_tests!(I64Vec3, -2, 2);
+    impl_vec3_bit_op_tests!(I64Vec3, -2, 2);
+
+    impl_vec3_signed_integer_tests!(i64, I64Vec3, U64Vec3, BVec3);
+}
+
+mod u64vec3 {
+    use glam::{const_u64vec3, u64vec3, BVec3, I64Vec3, U64Vec3};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(24, mem::size_of::<U64Vec3>());
+        assert_eq!(8, mem::align_of::<U64Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec3, IVec3, UVec3, Vec3, Vec3A};
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), U64Vec3::new(1, 2, 3).as_vec3());
+        assert_eq!(DVec3::new(1.0, 2.0, 3.0), U64Vec3::new(1, 2, 3).as_dvec3());
+        assert_eq!(IVec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_ivec3());
+        assert_eq!(UVec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_uvec3());
+        assert_eq!(I64Vec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_i64vec3());
+
+        assert_eq!(U64Vec3::new(1, 2, 3), Vec3::new(1.0, 2.0, 3.0).as_u64vec3());
+        assert_eq!(
+            U64Vec3::new(1, 2, 3),
+            DVec3::new(1.0, 2.0, 3.0).as_u64vec3()
+        );
+        assert_eq!(U64Vec3::new(1, 2, 3), IVec3::new(1, 2, 3).as_u64vec3());
+        assert_eq!(U64Vec3::new(1, 2, 3), UVec3::new(1, 2, 3).as_u64vec3());
+        assert_eq!(
+            U64Vec3::new(1, 2, 3),
+            Vec3A::new(1.0, 2.0, 3.0).as_u64vec3()
+        );
+    });
+
+    impl_vec3_tests!(u64, const_u64vec3, u64vec3, U64Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(u64, u64vec3);
+
+    impl_vec3_scalar_shift_op_tests!(U64Vec3, 0, 2);
+    mod shift_by_i64 {
+        use glam::U64Vec3;
+        impl_vec3_scalar_shift_op_test!(U64Vec3, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U64Vec3;
+        impl_vec3_scalar_shift_op_test!(U64Vec3, 0, 2, 0u64, 2);
+    }
+    impl_vec3_shift_op_tests!(U64Vec3, I64Vec3, U64Vec3);
+
+    impl_vec3_scalar_bit_op_tests!(U64Vec3, 0, 2);
+    impl_vec3_bit_op_tests!(U64Vec3, 0, 2);
+
+    impl_vec3_unsigned_integer_tests!(u64, U64Vec3, BVec3);
+}
+
+mod i16vec3 {
+    use glam::{const_i16vec3, i16vec3, BVec3, I16Vec3, U16Vec3};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(6, mem::size_of::<I16Vec3>());
+        assert_eq!(2, mem::align_of::<I16Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec3, I64Vec3, IVec3, U16Vec3, U64Vec3, U8Vec3, UVec3, Vec3};
+        assert_eq!(
+            Vec3::new(-1.0, -2.0, -3.0),
+            I16Vec3::new(-1, -2, -3).as_vec3()
+        );
+        assert_eq!(
+            DVec3::new(-1.0, -2.0, -3.0),
+            I16Vec3::new(-1, -2, -3).as_dvec3()
+        );
+        assert_eq!(IVec3::new(-1, -2, -3), I16Vec3::new(-1, -2, -3).as_ivec3());
+        assert_eq!(UVec3::new(1, 2, 3), I16Vec3::new(1, 2, 3).as_uvec3());
+        assert_eq!(
+            I64Vec3::new(-1, -2, -3),
+            I16Vec3::new(-1, -2, -3).as_i64vec3()
+        );
+        assert_eq!(U64Vec3::new(1, 2, 3), I16Vec3::new(1, 2, 3).as_u64vec3());
+        assert_eq!(U16Vec3::new(1, 2, 3), I16Vec3::new(1, 2, 3).as_u16vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), I16Vec3::new(1, 2, 3).as_u8vec3());
+
+        assert_eq!(
+            I16Vec3::new(-1, -2, -3),
+            Vec3::new(-1.0, -2.0, -3.0).as_i16vec3()
+        );
+        assert_eq!(
+            I16Vec3::new(-1, -2, -3),
+            DVec3::new(-1.0, -2.0, -3.0).as_i16vec3()
+        );
+        assert_eq!(
+            I16Vec3::new(-1, -2, -3),
+            IVec3::new(-1, -2, -3).as_i16vec3()
+        );
+        assert_eq!(I16Vec3::new(1, 2, 3), UVec3::new(1, 2, 3).as_i16vec3());
+        assert_eq!(
+            I16Vec3::new(-1, -2, -3),
+            I64Vec3::new(-1, -2, -3).as_i16vec3()
+        );
+        assert_eq!(I16Vec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_i16vec3());
+        assert_eq!(I16Vec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_i16vec3());
+        assert_eq!(I16Vec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_i16vec3());
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{IVec3, U16Vec3, U64Vec3, U8Vec3};
+        assert_eq!(
+            U8Vec3::new(0, 255, 0),
+            I16Vec3::new(-1, 300, i16::MIN).saturating_as_u8vec3()
+        );
+        assert_eq!(
+            U16Vec3::new(0, 300, 0),
+            I16Vec3::new(-1, 300, i16::MIN).saturating_as_u16vec3()
+        );
+        assert_eq!(
+            I16Vec3::new(i16::MIN, i16::MAX, 1),
+            IVec3::new(-40000, 40000, 1).saturating_as_i16vec3()
+        );
+        assert_eq!(
+            I16Vec3::new(i16::MAX, i16::MAX, 1),
+            U64Vec3::new(u64::MAX, 40000, 1).saturating_as_i16vec3()
+        );
+        assert_eq!(
+            U8Vec3::new(255, 44, 0),
+            I16Vec3::new(-1, 300, i16::MIN).as_u8vec3()
+        );
+    });
+
+    impl_vec3_signed_tests!(i16, const_i16vec3, i16vec3, I16Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(i16, i16vec3);
+
+    impl_vec3_scalar_shift_op_tests!(I16Vec3, -2, 2);
+    mod shift_by_i64 {
+        use glam::I16Vec3;
+        impl_vec3_scalar_shift_op_test!(I16Vec3, -2, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::I16Vec3;
+        impl_vec3_scalar_shift_op_test!(I16Vec3, -2, 2, 0u64, 2);
+    }
+    impl_vec3_shift_op_tests!(I16Vec3, I16Vec3, U16Vec3);
+
+    impl_vec3_scalar_bit_op_tests!(I16Vec3, -2, 2);
+    impl_vec3_bit_op_tests!(I16Vec3, -2, 2);
+
+    impl_vec3_signed_integer_tests!(i16, I16Vec3, U16Vec3, BVec3);
+}
+
+mod u16vec3 {
+    use glam::{const_u16vec3, u16vec3, BVec3, I16Vec3, U16Vec3};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(6, mem::size_of::<U16Vec3>());
+        assert_eq!(2, mem::align_of::<U16Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec3, I16Vec3, I64Vec3, IVec3, U64Vec3, U8Vec3, UVec3, Vec3};
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), U16Vec3::new(1, 2, 3).as_vec3());
+        assert_eq!(DVec3::new(1.0, 2.0, 3.0), U16Vec3::new(1, 2, 3).as_dvec3());
+        assert_eq!(IVec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_ivec3());
+        assert_eq!(UVec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_uvec3());
+        assert_eq!(I64Vec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_i64vec3());
+        assert_eq!(U64Vec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_u64vec3());
+        assert_eq!(I16Vec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_i16vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_u8vec3());
+
+        assert_eq!(U16Vec3::new(1, 2, 3), Vec3::new(1.0, 2.0, 3.0).as_u16vec3());
+        assert_eq!(
+            U16Vec3::new(1, 2, 3),
+            DVec3::new(1.0, 2.0, 3.0).as_u16vec3()
+        );
+        assert_eq!(U16Vec3::new(1, 2, 3), IVec3::new(1, 2, 3).as_u16vec3());
+        assert_eq!(U16Vec3::new(1, 2, 3), UVec3::new(1, 2, 3).as_u16vec3());
+        assert_eq!(U16Vec3::new(1, 2, 3), I64Vec3::new(1, 2, 3).as_u16vec3());
+        assert_eq!(U16Vec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_u16vec3());
+        assert_eq!(U16Vec3::new(1, 2, 3), I16Vec3::new(1, 2, 3).as_u16vec3());
+        assert_eq!(U16Vec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_u16vec3());
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{I16Vec3, I64Vec3, U8Vec3};
+        assert_eq!(
+            U8Vec3::new(1, 255, 255),
+            U16Vec3::new(1, 300, u16::MAX).saturating_as_u8vec3()
+        );
+        assert_eq!(
+            I16Vec3::new(1, 300, i16::MAX),
+            U16Vec3::new(1, 300, u16::MAX).saturating_as_i16vec3()
+        );
+        assert_eq!(
+            U16Vec3::new(0, u16::MAX, 2),
+            I64Vec3::new(-1, 70000, 2).saturating_as_u16vec3()
+        );
+        assert_eq!(
+            U16Vec3::new(0, 300, 0),
+            I16Vec3::new(-1, 300, i16::MIN).saturating_as_u16vec3()
+        );
+        assert_eq!(
+            I16Vec3::new(1, 300, -1),
+            U16Vec3::new(1, 300, u16::MAX).as_i16vec3()
+        );
+    });
+
+    impl_vec3_tests!(u16, const_u16vec3, u16vec3, U16Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(u16, u16vec3);
+
+    impl_vec3_scalar_shift_op_tests!(U16Vec3, 0, 2);
+    mod shift_by_i64 {
+        use glam::U16Vec3;
+        impl_vec3_scalar_shift_op_test!(U16Vec3, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U16Vec3;
+        impl_vec3_scalar_shift_op_test!(U16Vec3, 0, 2, 0u64, 2);
+    }
+    impl_vec3_shift_op_tests!(U16Vec3, I16Vec3, U16Vec3);
+
+    impl_vec3_scalar_bit_op_tests!(U16Vec3, 0, 2);
+    impl_vec3_bit_op_tests!(U16Vec3, 0, 2);
+
+    impl_vec3_unsigned_integer_tests!(u16, U16Vec3, BVec3);
+}
+
+mod u8vec3 {
+    use glam::{const_u8vec3, u8vec3, BVec3, U8Vec3};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(3, mem::size_of::<U8Vec3>());
+        assert_eq!(1, mem::align_of::<U8Vec3>());
+        assert_eq!(3, mem::size_of::<BVec3>());
+        assert_eq!(1, mem::align_of::<BVec3>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec3, I16Vec3, I64Vec3, IVec3, U16Vec3, U64Vec3, UVec3, Vec3};
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0), U8Vec3::new(1, 2, 3).as_vec3());
+        assert_eq!(DVec3::new(1.0, 2.0, 3.0), U8Vec3::new(1, 2, 3).as_dvec3());
+        assert_eq!(IVec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_ivec3());
+        assert_eq!(UVec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_uvec3());
+        assert_eq!(I64Vec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_i64vec3());
+        assert_eq!(U64Vec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_u64vec3());
+        assert_eq!(I16Vec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_i16vec3());
+        assert_eq!(U16Vec3::new(1, 2, 3), U8Vec3::new(1, 2, 3).as_u16vec3());
+
+        assert_eq!(U8Vec3::new(1, 2, 3), Vec3::new(1.0, 2.0, 3.0).as_u8vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), DVec3::new(1.0, 2.0, 3.0).as_u8vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), IVec3::new(1, 2, 3).as_u8vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), UVec3::new(1, 2, 3).as_u8vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), I64Vec3::new(1, 2, 3).as_u8vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_u8vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), I16Vec3::new(1, 2, 3).as_u8vec3());
+        assert_eq!(U8Vec3::new(1, 2, 3), U16Vec3::new(1, 2, 3).as_u8vec3());
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{I16Vec3, IVec3, U64Vec3};
+        assert_eq!(
+            I16Vec3::new(1, 255, 0),
+            U8Vec3::new(1, 255, 0).saturating_as_i16vec3()
+        );
+        assert_eq!(
+            U8Vec3::new(0, 255, 7),
+            IVec3::new(-1, 256, 7).saturating_as_u8vec3()
+        );
+        assert_eq!(
+            U8Vec3::new(255, 255, 7),
+            U64Vec3::new(u64::MAX, 256, 7).saturating_as_u8vec3()
+        );
+    });
+
+    impl_vec3_tests!(u8, const_u8vec3, u8vec3, U8Vec3, BVec3);
+    impl_vec3_eq_hash_tests!(u8, u8vec3);
+
+    impl_vec3_scalar_shift_op_tests!(U8Vec3, 0, 2);
+    mod shift_by_i64 {
+        use glam::U8Vec3;
+        impl_vec3_scalar_shift_op_test!(U8Vec3, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U8Vec3;
+        impl_vec3_scalar_shift_op_test!(U8Vec3, 0, 2, 0u64, 2);
+    }
+    mod shift_by_u8vec3 {
+        use super::*;
+        impl_vec3_shift_op_test!(U8Vec3, U8Vec3, 0, 2);
+    }
+
+    impl_vec3_scalar_bit_op_tests!(U8Vec3, 0, 2);
+    impl_vec3_bit_op_tests!(U8Vec3, 0, 2);
+
+    impl_vec3_unsigned_integer_tests!(u8, U8Vec3, BVec3);
 }
@@ -132,7 +132,11 @@ macro_rules! impl_vec4_tests {
             let a = $new(2 as $t, 4 as $t, 8 as $t, 16 as $t);
             assert_eq!($new(4 as $t, 8 as $t, 16 as $t, 32 as $t), a + a);
             assert_eq!($new(0 as $t, 0 as $t, 0 as $t, 0 as $t), a - a);
-            assert_eq!($new(4 as $t, 16 as $t, 64 as $t, 256 as $t), a * a);
+            // 16 * 16 overflows `u8`, which is covered by `test_mul_u8` instead
+            #[allow(overflowing_literals)]
+            if core::mem::size_of::<$t>() > 1 {
+                assert_eq!($new(4 as $t, 16 as $t, 64 as $t, 256 as $t), a * a);
+            }
             assert_eq!($new(4 as $t, 8 as $t, 16 as $t, 32 as $t), a * 2 as $t);
             assert_eq!($new(4 as $t, 8 as $t, 16 as $t, 32 as $t), 2 as $t * a);
             assert_eq!($new(1 as $t, 1 as $t, 1 as $t, 1 as $t), a / a);
@@ -884,6 +888,16 @@ macro_rules! impl_vec4_shift_op_tests {
             impl_vec4_shift_op_test!($vec4, UVec4, 0, 2);
         }
     };
+    ($vec4:ident, $ivec4:ident, $uvec4:ident) => {
+        mod shift_by_signed {
+            use super::*;
+            impl_vec4_shift_op_test!($vec4, $ivec4, 0, 2);
+        }
+        mod shift_by_unsigned {
+            use super::*;
+            impl_vec4_shift_op_test!($vec4, $uvec4, 0, 2);
+        }
+    };
 }
 
 macro_rules! impl_vec4_scalar_bit_op_tests {
@@ -954,6 +968,205 @@ macro_rules! impl_vec4_bit_op_tests {
         });
     };
 }
+macro_rules! impl_vec4_integer_tests {
+    ($t:ident, $vec4:ident, $uvec4:ident, $mask:ident) => {
+        glam_test!(test_wrapping_ops, {
+            let a = $vec4::new($t::MAX, 2, 2, 2);
+            let b = $vec4::new(1, 3, 1, 1);
+            assert_eq!($vec4::new($t::MIN, 5, 3, 3), a.wrapping_add(b));
+            assert_eq!(
+                $vec4::new($t::MAX, 0, 2, 2),
+                $vec4::new($t::MIN, 3, 3, 3).wrapping_sub(b)
+            );
+            assert_eq!(
+                $vec4::new($t::MAX.wrapping_mul(2), 4, 4, 4),
+                a.wrapping_mul($vec4::new(2, 2, 2, 2))
+            );
+            assert_eq!($vec4::new($t::MAX, 0, 2, 2), a.wrapping_div(b));
+        });
+
+        glam_test!(test_saturating_ops, {
+            let a = $vec4::new($t::MAX, 2, 2, 2);
+            let b = $vec4::new(1, 3, 1, 1);
+            assert_eq!($vec4::new($t::MAX, 5, 3, 3), a.saturating_add(b));
+            assert_eq!(
+                $vec4::new($t::MIN, 1, $t::MIN, $t::MIN),
+                $vec4::new($t::MIN, 2, $t::MIN, $t::MIN).saturating_sub($vec4::new(1, 1, 1, 1))
+            );
+            assert_eq!(
+                $vec4::new($t::MAX, 4, 4, 4),
+                a.saturating_mul($vec4::new(2, 2, 2, 2))
+            );
+            assert_eq!($vec4::new($t::MAX, 0, 2, 2), a.saturating_div(b));
+        });
+
+        glam_test!(test_checked_ops, {
+            let a = $vec4::new($t::MAX, 2, 2, 2);
+            let b = $vec4::new(1, 3, 1, 1);
+            assert_eq!(None, a.checked_add(b));
+            assert_eq!(
+                Some($vec4::new($t::MAX, 5, 3, 3)),
+                a.checked_add($vec4::new(0, 3, 1, 1))
+            );
+            assert_eq!(None, $vec4::new($t::MIN, 3, 3, 3).checked_sub(b));
+            assert_eq!(
+                Some($vec4::new($t::MAX - 1, 1, 1, 1)),
+                a.checked_sub($vec4::new(1, 1, 1, 1))
+            );
+            assert_eq!(None, a.checked_mul($vec4::new(2, 2, 2, 2)));
+            assert_eq!(Some($vec4::new($t::MAX, 6, 2, 2)), a.checked_mul(b));
+            assert_eq!(Some($vec4::new($t::MAX, 0, 2, 2)), a.checked_div(b));
+            assert_eq!(None, a.checked_div($vec4::new(1, 0, 1, 1)));
+        });
+
+        glam_test!(test_overflowing_ops, {
+            let a = $vec4::new($t::MAX, 2, 2, 2);
+            let b = $vec4::new(1, 3, 1, 1);
+            assert_eq!(
+                (
+                    $vec4::new($t::MIN, 5, 3, 3),
+                    $mask::new(true, false, false, false)
+                ),
+                a.overflowing_add(b)
+            );
+            assert_eq!(
+                (
+                    $vec4::new($t::MAX, 0, 2, 2),
+                    $mask::new(true, false, false, false)
+                ),
+                $vec4::new($t::MIN, 3, 3, 3).overflowing_sub(b)
+            );
+            assert_eq!(
+                (
+                    $vec4::new($t::MAX.wrapping_mul(2), 4, 4, 4),
+                    $mask::new(true, false, false, false)
+                ),
+                a.overflowing_mul($vec4::new(2, 2, 2, 2))
+            );
+            assert_eq!(
+                (
+                    $vec4::new($t::MAX, 0, 2, 2),
+                    $mask::new(false, false, false, false)
+                ),
+                a.overflowing_div(b)
+            );
+        });
+
+        glam_test!(test_div_by_zero, {
+            should_panic!({ $vec4::new(1, 1, 1, 1).wrapping_div($vec4::new(0, 0, 0, 0)) });
+            should_panic!({ $vec4::new(1, 1, 1, 1).saturating_div($vec4::new(0, 0, 0, 0)) });
+            should_panic!({ $vec4::new(1, 1, 1, 1).overflowing_div($vec4::new(0, 0, 0, 0)) });
+        });
+
+        glam_test!(test_euclid, {
+            let a = $vec4::new(7, 8, 9, 9);
+            let b = $vec4::new(2, 4, 5, 5);
+            assert_eq!($vec4::new(3, 2, 1, 1), a.div_euclid(b));
+            assert_eq!($vec4::new(1, 0, 4, 4), a.rem_euclid(b));
+            should_panic!({ a.div_euclid($vec4::new(0, 0, 0, 0)) });
+            should_panic!({ a.rem_euclid($vec4::new(0, 0, 0, 0)) });
+        });
+
+        glam_test!(test_abs_diff, {
+            let a = $vec4::new($t::MAX, 2, 7, 7);
+            // the difference between `MAX` and `MIN` is all ones for both signed and unsigned
+            let b = $vec4::new($t::MIN, 5, 7, 7);
+            assert_eq!($uvec4::new(!0, 3, 0, 0), a.abs_diff(b));
+            assert_eq!(a.abs_diff(b), b.abs_diff(a));
+        });
+
+        glam_test!(test_element_sum_product, {
+            let a = $vec4::new(2, 3, 4, 5);
+            assert_eq!(14, a.element_sum());
+            assert_eq!(120, a.element_product());
+        });
+
+        glam_test!(test_bit_counts, {
+            let bits = (8 * core::mem::size_of::<$t>()) as _;
+            let a = $vec4::new(0, 1, 6, 6);
+            assert_eq!($uvec4::new(0, 1, 2, 2), a.count_ones());
+            assert_eq!(
+                $uvec4::new($t::MAX.count_ones() as _, 1, 2, 2),
+                $vec4::new($t::MAX, 1, 6, 6).count_ones()
+            );
+            assert_eq!(
+                $uvec4::new(bits, bits - 1, bits - 3, bits - 3),
+                a.leading_zeros()
+            );
+            assert_eq!($uvec4::new(bits, 0, 1, 1), a.trailing_zeros());
+        });
+    };
+}
+
+macro_rules! impl_vec4_signed_integer_tests {
+    ($t:ident, $vec4:ident, $uvec4:ident, $mask:ident) => {
+        impl_vec4_integer_tests!($t, $vec4, $uvec4, $mask);
+
+        glam_test!(test_signed_overflow_ops, {
+            let a = $vec4::new($t::MIN, -4, 4, 4);
+            let b = $vec4::new(-1, 2, -2, -2);
+            assert_eq!($vec4::new($t::MIN, -2, -2, -2), a.wrapping_div(b));
+            assert_eq!($vec4::new($t::MAX, -2, -2, -2), a.saturating_div(b));
+            assert_eq!(None, a.checked_div(b));
+            assert_eq!(
+                (
+                    $vec4::new($t::MIN, -2, -2, -2),
+                    $mask::new(true, false, false, false)
+                ),
+                a.overflowing_div(b)
+            );
+            assert_eq!(
+                $vec4::new($t::MIN, -2, 2, 2),
+                a.saturating_add($vec4::new(-1, 2, -2, -2))
+            );
+            assert_eq!(
+                $vec4::new($t::MIN, -5, 5, 5),
+                a.saturating_sub($vec4::new(1, 1, -1, -1))
+            );
+            assert_eq!(
+                $vec4::new($t::MAX, 4, -4, -4),
+                a.saturating_mul($vec4::new(-1, -1, -1, -1))
+            );
+            assert_eq!(
+                $vec4::new($t::MIN, -8, -8, -8),
+                a.saturating_mul($vec4::new(2, 2, -2, -2))
+            );
+        });
+
+        glam_test!(test_signed_euclid, {
+            let a = $vec4::new(-7, 7, -8, -8);
+            let b = $vec4::new(2, -2, -4, -4);
+            assert_eq!($vec4::new(-4, -3, 2, 2), a.div_euclid(b));
+            assert_eq!($vec4::new(1, 1, 0, 0), a.rem_euclid(b));
+            assert_eq!(a, a.div_euclid(b) * b + a.rem_euclid(b));
+        });
+
+        glam_test!(test_signed_bit_counts, {
+            let bits = (8 * core::mem::size_of::<$t>()) as _;
+            let a = $vec4::new(-1, $t::MIN, -2, -2);
+            assert_eq!($uvec4::new(bits, 1, bits - 1, bits - 1), a.count_ones());
+            assert_eq!($uvec4::new(0, 0, 0, 0), a.leading_zeros());
+            assert_eq!($uvec4::new(0, bits - 1, 1, 1), a.trailing_zeros());
+        });
+    };
+}
+
+macro_rules! impl_vec4_unsigned_integer_tests {
+    ($t:ident, $vec4:ident, $mask:ident) => {
+        impl_vec4_integer_tests!($t, $vec4, $vec4, $mask);
+
+        glam_test!(test_power_of_two, {
+            let a = $vec4::new(0, 1, 6, 64);
+            assert_eq!($mask::new(false, true, false, true), a.is_power_of_two());
+            assert_eq!($vec4::new(1, 1, 8, 64), a.next_power_of_two());
+            assert_eq!(
+                $mask::new(false, false, false, false),
+                $vec4::new($t::MAX, 3, $t::MAX, $t::MAX).is_power_of_two()
+            );
+        });
+    };
+}
+
 mod vec4 {
     use glam::{const_vec4, vec4, Vec2, Vec3, Vec4};
 
@@ -1119,6 +1332,8 @@ mod ivec4 {
 
     impl_vec4_scalar_bit_op_tests!(IVec4, -2, 2);
     impl_vec4_bit_op_tests!(IVec4, -2, 2);
+
+    impl_vec4_signed_integer_tests!(i32, IVec4, UVec4, BVec4);
 }
 
 mod uvec4 {
@@ -1140,4 +1355,526 @@ mod uvec4 {
 
     impl_vec4_scalar_bit_op_tests!(UVec4, 0, 2);
     impl_vec4_bit_op_tests!(UVec4, 0, 2);
+
+    impl_vec4_unsigned_integer_tests!(u32, UVec4, BVec4);
+}
+
+mod i64vec4 {
+    use glam::{const_i64vec4, i64vec4, BVec4, I64Vec2, I64Vec3, I64Vec4, U64Vec4};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(32, mem::size_of::<I64Vec4>());
+        assert_eq!(8, mem::align_of::<I64Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec4, IVec4, UVec4, Vec4};
+        assert_eq!(
+            Vec4::new(-1.0, -2.0, -3.0, -4.0),
+            I64Vec4::new(-1, -2, -3, -4).as_vec4()
+        );
+        assert_eq!(
+            DVec4::new(-1.0, -2.0, -3.0, -4.0),
+            I64Vec4::new(-1, -2, -3, -4).as_dvec4()
+        );
+        assert_eq!(
+            IVec4::new(-1, -2, -3, -4),
+            I64Vec4::new(-1, -2, -3, -4).as_ivec4()
+        );
+        assert_eq!(UVec4::new(1, 2, 3, 4), I64Vec4::new(1, 2, 3, 4).as_uvec4());
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            I64Vec4::new(1, 2, 3, 4).as_u64vec4()
+        );
+
+        assert_eq!(
+            I64Vec4::new(-1, -2, -3, -4),
+            Vec4::new(-1.0, -2.0, -3.0, -4.0).as_i64vec4()
+        );
+        assert_eq!(
+            I64Vec4::new(-1, -2, -3, -4),
+            DVec4::new(-1.0, -2.0, -3.0, -4.0).as_i64vec4()
+        );
+        assert_eq!(
+            I64Vec4::new(-1, -2, -3, -4),
+            IVec4::new(-1, -2, -3, -4).as_i64vec4()
+        );
+        assert_eq!(
+            I64Vec4::new(1, 2, 3, 4),
+            UVec4::new(1, 2, 3, 4).as_i64vec4()
+        );
+    });
+
+    impl_vec4_signed_tests!(
+        i64,
+        const_i64vec4,
+        i64vec4,
+        I64Vec4,
+        I64Vec3,
+        I64Vec2,
+        BVec4
+    );
+    impl_vec4_eq_hash_tests!(i64, i64vec4);
+
+    impl_vec4_scalar_shift_op_tests!(I64Vec4, -2, 2);
+    mod shift_by_i64 {
+        use glam::I64Vec4;
+        impl_vec4_scalar_shift_op_test!(I64Vec4, -2, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::I64Vec4;
+        impl_vec4_scalar_shift_op_test!(I64Vec4, -2, 2, 0u64, 2);
+    }
+    impl_vec4_shift_op_tests!(I64Vec4, I64Vec4, U64Vec4);
+
+    impl_vec4_scalar_bit_op_tests!(I64Vec4, -2, 2);
+    impl_vec4_bit_op_tests!(I64Vec4, -2, 2);
+
+    impl_vec4_signed_integer_tests!(i64, I64Vec4, U64Vec4, BVec4);
+}
+
+mod u64vec4 {
+    use glam::{const_u64vec4, u64vec4, BVec4, I64Vec4, U64Vec2, U64Vec3, U64Vec4};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(32, mem::size_of::<U64Vec4>());
+        assert_eq!(8, mem::align_of::<U64Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec4, IVec4, UVec4, Vec4};
+        assert_eq!(
+            Vec4::new(1.0, 2.0, 3.0, 4.0),
+            U64Vec4::new(1, 2, 3, 4).as_vec4()
+        );
+        assert_eq!(
+            DVec4::new(1.0, 2.0, 3.0, 4.0),
+            U64Vec4::new(1, 2, 3, 4).as_dvec4()
+        );
+        assert_eq!(IVec4::new(1, 2, 3, 4), U64Vec4::new(1, 2, 3, 4).as_ivec4());
+        assert_eq!(UVec4::new(1, 2, 3, 4), U64Vec4::new(1, 2, 3, 4).as_uvec4());
+        assert_eq!(
+            I64Vec4::new(1, 2, 3, 4),
+            U64Vec4::new(1, 2, 3, 4).as_i64vec4()
+        );
+
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            Vec4::new(1.0, 2.0, 3.0, 4.0).as_u64vec4()
+        );
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            DVec4::new(1.0, 2.0, 3.0, 4.0).as_u64vec4()
+        );
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            IVec4::new(1, 2, 3, 4).as_u64vec4()
+        );
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            UVec4::new(1, 2, 3, 4).as_u64vec4()
+        );
+    });
+
+    impl_vec4_tests!(
+        u64,
+        const_u64vec4,
+        u64vec4,
+        U64Vec4,
+        U64Vec3,
+        U64Vec2,
+        BVec4
+    );
+    impl_vec4_eq_hash_tests!(u64, u64vec4);
+
+    impl_vec4_scalar_shift_op_tests!(U64Vec4, 0, 2);
+    mod shift_by_i64 {
+        use glam::U64Vec4;
+        impl_vec4_scalar_shift_op_test!(U64Vec4, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U64Vec4;
+        impl_vec4_scalar_shift_op_test!(U64Vec4, 0, 2, 0u64, 2);
+    }
+    impl_vec4_shift_op_tests!(U64Vec4, I64Vec4, U64Vec4);
+
+    impl_vec4_scalar_bit_op_tests!(U64Vec4, 0, 2);
+    impl_vec4_bit_op_tests!(U64Vec4, 0, 2);
+
+    impl_vec4_unsigned_integer_tests!(u64, U64Vec4, BVec4);
+}
+
+mod i16vec4 {
+    use glam::{const_i16vec4, i16vec4, BVec4, I16Vec2, I16Vec3, I16Vec4, U16Vec4};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(8, mem::size_of::<I16Vec4>());
+        assert_eq!(2, mem::align_of::<I16Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec4, I64Vec4, IVec4, U16Vec4, U64Vec4, U8Vec4, UVec4, Vec4};
+        assert_eq!(
+            Vec4::new(-1.0, -2.0, -3.0, -4.0),
+            I16Vec4::new(-1, -2, -3, -4).as_vec4()
+        );
+        assert_eq!(
+            DVec4::new(-1.0, -2.0, -3.0, -4.0),
+            I16Vec4::new(-1, -2, -3, -4).as_dvec4()
+        );
+        assert_eq!(
+            IVec4::new(-1, -2, -3, -4),
+            I16Vec4::new(-1, -2, -3, -4).as_ivec4()
+        );
+        assert_eq!(UVec4::new(1, 2, 3, 4), I16Vec4::new(1, 2, 3, 4).as_uvec4());
+        assert_eq!(
+            I64Vec4::new(-1, -2, -3, -4),
+            I16Vec4::new(-1, -2, -3, -4).as_i64vec4()
+        );
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            I16Vec4::new(1, 2, 3, 4).as_u64vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            I16Vec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            I16Vec4::new(1, 2, 3, 4).as_u8vec4()
+        );
+
+        assert_eq!(
+            I16Vec4::new(-1, -2, -3, -4),
+            Vec4::new(-1.0, -2.0, -3.0, -4.0).as_i16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(-1, -2, -3, -4),
+            DVec4::new(-1.0, -2.0, -3.0, -4.0).as_i16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(-1, -2, -3, -4),
+            IVec4::new(-1, -2, -3, -4).as_i16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(1, 2, 3, 4),
+            UVec4::new(1, 2, 3, 4).as_i16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(-1, -2, -3, -4),
+            I64Vec4::new(-1, -2, -3, -4).as_i16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(1, 2, 3, 4),
+            U64Vec4::new(1, 2, 3, 4).as_i16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(1, 2, 3, 4),
+            U16Vec4::new(1, 2, 3, 4).as_i16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(1, 2, 3, 4),
+            U8Vec4::new(1, 2, 3, 4).as_i16vec4()
+        );
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{IVec4, U16Vec4, U64Vec4, U8Vec4};
+        assert_eq!(
+            U8Vec4::new(0, 255, 0, 255),
+            I16Vec4::new(-1, 300, i16::MIN, i16::MAX).saturating_as_u8vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(0, 300, 0, 32767),
+            I16Vec4::new(-1, 300, i16::MIN, i16::MAX).saturating_as_u16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(i16::MIN, i16::MAX, 1, -1),
+            IVec4::new(-40000, 40000, 1, -1).saturating_as_i16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(i16::MAX, i16::MAX, 1, 0),
+            U64Vec4::new(u64::MAX, 40000, 1, 0).saturating_as_i16vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(255, 44, 0, 255),
+            I16Vec4::new(-1, 300, i16::MIN, i16::MAX).as_u8vec4()
+        );
+    });
+
+    impl_vec4_signed_tests!(
+        i16,
+        const_i16vec4,
+        i16vec4,
+        I16Vec4,
+        I16Vec3,
+        I16Vec2,
+        BVec4
+    );
+    impl_vec4_eq_hash_tests!(i16, i16vec4);
+
+    impl_vec4_scalar_shift_op_tests!(I16Vec4, -2, 2);
+    mod shift_by_i64 {
+        use glam::I16Vec4;
+        impl_vec4_scalar_shift_op_test!(I16Vec4, -2, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::I16Vec4;
+        impl_vec4_scalar_shift_op_test!(I16Vec4, -2, 2, 0u64, 2);
+    }
+    impl_vec4_shift_op_tests!(I16Vec4, I16Vec4, U16Vec4);
+
+    impl_vec4_scalar_bit_op_tests!(I16Vec4, -2, 2);
+    impl_vec4_bit_op_tests!(I16Vec4, -2, 2);
+
+    impl_vec4_signed_integer_tests!(i16, I16Vec4, U16Vec4, BVec4);
+}
+
+mod u16vec4 {
+    use glam::{const_u16vec4, u16vec4, BVec4, I16Vec4, U16Vec2, U16Vec3, U16Vec4};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(8, mem::size_of::<U16Vec4>());
+        assert_eq!(2, mem::align_of::<U16Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec4, I16Vec4, I64Vec4, IVec4, U64Vec4, U8Vec4, UVec4, Vec4};
+        assert_eq!(
+            Vec4::new(1.0, 2.0, 3.0, 4.0),
+            U16Vec4::new(1, 2, 3, 4).as_vec4()
+        );
+        assert_eq!(
+            DVec4::new(1.0, 2.0, 3.0, 4.0),
+            U16Vec4::new(1, 2, 3, 4).as_dvec4()
+        );
+        assert_eq!(IVec4::new(1, 2, 3, 4), U16Vec4::new(1, 2, 3, 4).as_ivec4());
+        assert_eq!(UVec4::new(1, 2, 3, 4), U16Vec4::new(1, 2, 3, 4).as_uvec4());
+        assert_eq!(
+            I64Vec4::new(1, 2, 3, 4),
+            U16Vec4::new(1, 2, 3, 4).as_i64vec4()
+        );
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            U16Vec4::new(1, 2, 3, 4).as_u64vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(1, 2, 3, 4),
+            U16Vec4::new(1, 2, 3, 4).as_i16vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            U16Vec4::new(1, 2, 3, 4).as_u8vec4()
+        );
+
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            Vec4::new(1.0, 2.0, 3.0, 4.0).as_u16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            DVec4::new(1.0, 2.0, 3.0, 4.0).as_u16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            IVec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            UVec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            I64Vec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            U64Vec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            I16Vec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            U8Vec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{I16Vec4, I64Vec4, U8Vec4};
+        assert_eq!(
+            U8Vec4::new(1, 255, 255, 0),
+            U16Vec4::new(1, 300, u16::MAX, 0).saturating_as_u8vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(1, 300, i16::MAX, 0),
+            U16Vec4::new(1, 300, u16::MAX, 0).saturating_as_i16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(0, u16::MAX, 2, 0),
+            I64Vec4::new(-1, 70000, 2, 0).saturating_as_u16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(0, 300, 0, 32767),
+            I16Vec4::new(-1, 300, i16::MIN, i16::MAX).saturating_as_u16vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(1, 300, -1, 0),
+            U16Vec4::new(1, 300, u16::MAX, 0).as_i16vec4()
+        );
+    });
+
+    impl_vec4_tests!(
+        u16,
+        const_u16vec4,
+        u16vec4,
+        U16Vec4,
+        U16Vec3,
+        U16Vec2,
+        BVec4
+    );
+    impl_vec4_eq_hash_tests!(u16, u16vec4);
+
+    impl_vec4_scalar_shift_op_tests!(U16Vec4, 0, 2);
+    mod shift_by_i64 {
+        use glam::U16Vec4;
+        impl_vec4_scalar_shift_op_test!(U16Vec4, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U16Vec4;
+        impl_vec4_scalar_shift_op_test!(U16Vec4, 0, 2, 0u64, 2);
+    }
+    impl_vec4_shift_op_tests!(U16Vec4, I16Vec4, U16Vec4);
+
+    impl_vec4_scalar_bit_op_tests!(U16Vec4, 0, 2);
+    impl_vec4_bit_op_tests!(U16Vec4, 0, 2);
+
+    impl_vec4_unsigned_integer_tests!(u16, U16Vec4, BVec4);
+}
+
+mod u8vec4 {
+    use glam::{const_u8vec4, u8vec4, BVec4, U8Vec2, U8Vec3, U8Vec4};
+
+    glam_test!(test_align, {
+        use core::mem;
+        assert_eq!(4, mem::size_of::<U8Vec4>());
+        assert_eq!(1, mem::align_of::<U8Vec4>());
+        assert_eq!(4, mem::size_of::<BVec4>());
+        assert_eq!(1, mem::align_of::<BVec4>());
+    });
+
+    glam_test!(test_as, {
+        use glam::{DVec4, I16Vec4, I64Vec4, IVec4, U16Vec4, U64Vec4, UVec4, Vec4};
+        assert_eq!(
+            Vec4::new(1.0, 2.0, 3.0, 4.0),
+            U8Vec4::new(1, 2, 3, 4).as_vec4()
+        );
+        assert_eq!(
+            DVec4::new(1.0, 2.0, 3.0, 4.0),
+            U8Vec4::new(1, 2, 3, 4).as_dvec4()
+        );
+        assert_eq!(IVec4::new(1, 2, 3, 4), U8Vec4::new(1, 2, 3, 4).as_ivec4());
+        assert_eq!(UVec4::new(1, 2, 3, 4), U8Vec4::new(1, 2, 3, 4).as_uvec4());
+        assert_eq!(
+            I64Vec4::new(1, 2, 3, 4),
+            U8Vec4::new(1, 2, 3, 4).as_i64vec4()
+        );
+        assert_eq!(
+            U64Vec4::new(1, 2, 3, 4),
+            U8Vec4::new(1, 2, 3, 4).as_u64vec4()
+        );
+        assert_eq!(
+            I16Vec4::new(1, 2, 3, 4),
+            U8Vec4::new(1, 2, 3, 4).as_i16vec4()
+        );
+        assert_eq!(
+            U16Vec4::new(1, 2, 3, 4),
+            U8Vec4::new(1, 2, 3, 4).as_u16vec4()
+        );
+
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            Vec4::new(1.0, 2.0, 3.0, 4.0).as_u8vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            DVec4::new(1.0, 2.0, 3.0, 4.0).as_u8vec4()
+        );
+        assert_eq!(U8Vec4::new(1, 2, 3, 4), IVec4::new(1, 2, 3, 4).as_u8vec4());
+        assert_eq!(U8Vec4::new(1, 2, 3, 4), UVec4::new(1, 2, 3, 4).as_u8vec4());
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            I64Vec4::new(1, 2, 3, 4).as_u8vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            U64Vec4::new(1, 2, 3, 4).as_u8vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            I16Vec4::new(1, 2, 3, 4).as_u8vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(1, 2, 3, 4),
+            U16Vec4::new(1, 2, 3, 4).as_u8vec4()
+        );
+    });
+
+    glam_test!(test_saturating_as, {
+        use glam::{I16Vec4, IVec4, U64Vec4};
+        assert_eq!(
+            I16Vec4::new(1, 255, 0, 128),
+            U8Vec4::new(1, 255, 0, 128).saturating_as_i16vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(0, 255, 7, 255),
+            IVec4::new(-1, 256, 7, 255).saturating_as_u8vec4()
+        );
+        assert_eq!(
+            U8Vec4::new(255, 255, 7, 0),
+            U64Vec4::new(u64::MAX, 256, 7, 0).saturating_as_u8vec4()
+        );
+    });
+
+    glam_test!(test_mul_u8, {
+        let a = U8Vec4::new(2, 4, 8, 16);
+        assert_eq!(U8Vec4::new(4, 16, 64, 128), a * U8Vec4::new(2, 4, 8, 8));
+        assert_eq!(U8Vec4::new(4, 16, 64, 0), a.wrapping_mul(a));
+        assert_eq!(U8Vec4::new(4, 16, 64, 255), a.saturating_mul(a));
+    });
+
+    impl_vec4_tests!(u8, const_u8vec4, u8vec4, U8Vec4, U8Vec3, U8Vec2, BVec4);
+    impl_vec4_eq_hash_tests!(u8, u8vec4);
+
+    impl_vec4_scalar_shift_op_tests!(U8Vec4, 0, 2);
+    mod shift_by_i64 {
+        use glam::U8Vec4;
+        impl_vec4_scalar_shift_op_test!(U8Vec4, 0, 2, 0i64, 2);
+    }
+    mod shift_by_u64 {
+        use glam::U8Vec4;
+        impl_vec4_scalar_shift_op_test!(U8Vec4, 0, 2, 0u64, 2);
+    }
+    mod shift_by_u8vec4 {
+        use super::*;
+        impl_vec4_shift_op_test!(U8Vec4, U8Vec4, 0, 2);
+    }
+
+    impl_vec4_scalar_bit_op_tests!(U8Vec4, 0, 2);
+    impl_vec4_bit_op_tests!(U8Vec4, 0, 2);
+
+    impl_vec4_unsigned_integer_tests!(u8, U8Vec4, BVec4);
 }